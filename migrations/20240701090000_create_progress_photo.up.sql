@@ -0,0 +1,18 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    progress_photo (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        progress_id UUID NOT NULL,
+        pose INTEGER NOT NULL,
+        file_name VARCHAR(255) NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        created_by_id UUID NOT NULL,
+        updated_by_id UUID,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id),
+        CONSTRAINT fk_progress_id FOREIGN KEY (progress_id) REFERENCES progress (id) ON DELETE CASCADE,
+        CONSTRAINT fk_created_by FOREIGN KEY (created_by_id) REFERENCES users_user (id),
+        CONSTRAINT fk_updated_by FOREIGN KEY (updated_by_id) REFERENCES users_user (id),
+        CONSTRAINT progress_photo_unique_progress_id_pose UNIQUE (progress_id, pose)
+    )
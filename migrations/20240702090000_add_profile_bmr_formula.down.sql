@@ -0,0 +1,5 @@
+-- Add down migration script here
+ALTER TABLE user_profile
+DROP COLUMN bmr_formula,
+DROP COLUMN body_fat_percentage,
+DROP COLUMN tdee_override;
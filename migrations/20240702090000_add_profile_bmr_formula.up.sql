@@ -0,0 +1,5 @@
+-- Add up migration script here
+ALTER TABLE user_profile
+ADD COLUMN bmr_formula VARCHAR(2) NOT NULL DEFAULT 'HB',
+ADD COLUMN body_fat_percentage NUMERIC(4, 1),
+ADD COLUMN tdee_override INTEGER;
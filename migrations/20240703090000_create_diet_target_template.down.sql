@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS diet_target_template_day;
+
+DROP TABLE IF EXISTS diet_target_template;
@@ -0,0 +1,33 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    diet_target_template (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        "name" VARCHAR(100) NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        created_by_id UUID NOT NULL,
+        updated_by_id UUID,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id),
+        CONSTRAINT fk_created_by FOREIGN KEY (created_by_id) REFERENCES users_user (id),
+        CONSTRAINT fk_updated_by FOREIGN KEY (updated_by_id) REFERENCES users_user (id),
+        CONSTRAINT diet_target_template_unique_user_id_name UNIQUE (user_id, "name")
+    );
+
+CREATE TABLE IF NOT EXISTS
+    diet_target_template_day (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        template_id UUID NOT NULL,
+        day_type INTEGER NOT NULL,
+        protein_per_kg NUMERIC(5, 2) NOT NULL,
+        carbohydrate_per_kg NUMERIC(5, 2) NOT NULL,
+        fat_per_kg NUMERIC(5, 2) NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        created_by_id UUID NOT NULL,
+        updated_by_id UUID,
+        CONSTRAINT fk_template_id FOREIGN KEY (template_id) REFERENCES diet_target_template (id) ON DELETE CASCADE,
+        CONSTRAINT fk_created_by FOREIGN KEY (created_by_id) REFERENCES users_user (id),
+        CONSTRAINT fk_updated_by FOREIGN KEY (updated_by_id) REFERENCES users_user (id),
+        CONSTRAINT diet_target_template_day_unique_template_id_day_type UNIQUE (template_id, day_type)
+    );
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP VIEW IF EXISTS diet_streak;
+
+DROP VIEW IF EXISTS diet_adherence_day;
@@ -0,0 +1,138 @@
+-- Add up migration script here
+CREATE OR REPLACE VIEW
+    diet_adherence_day AS
+SELECT
+    t1.user_id,
+    t1.date,
+    SUM(t1.quantity * t2.energy) AS energy,
+    SUM(t1.quantity * t2.protein) AS protein,
+    SUM(t1.quantity * t2.carbohydrate) AS carbohydrate,
+    SUM(t1.quantity * t2.fat) AS fat,
+    t3.energy AS target_energy,
+    t3.protein AS target_protein,
+    t3.carbohydrate AS target_carbohydrate,
+    t3.fat AS target_fat
+FROM
+    food_log t1
+    LEFT JOIN food t2 ON t2.id = t1.food_id
+    LEFT JOIN diet_target t3 ON t3.user_id = t1.user_id
+    AND t3.date = (
+        SELECT
+            MAX(date)
+        FROM
+            diet_target
+        WHERE
+            user_id = t1.user_id
+            AND date <= t1.date
+    )
+GROUP BY
+    t1.user_id,
+    t1.date,
+    t3.id;
+
+-- Streaks use the default tolerance of 10%, matching adherence::model::DEFAULT_TOLERANCE_PCT.
+CREATE OR REPLACE VIEW
+    diet_streak AS
+WITH
+    scored AS (
+        SELECT
+            user_id,
+            date,
+            COALESCE(
+                ABS(energy - target_energy) <= target_energy * 0.10
+                AND ABS(protein - target_protein) <= target_protein * 0.10
+                AND ABS(carbohydrate - target_carbohydrate) <= target_carbohydrate * 0.10
+                AND ABS(fat - target_fat) <= target_fat * 0.10,
+                FALSE
+            ) AS adherent
+        FROM
+            diet_adherence_day
+    ),
+    logging_run AS (
+        SELECT
+            user_id,
+            COUNT(*) AS length,
+            MAX(date) AS last_date
+        FROM
+            (
+                SELECT
+                    user_id,
+                    date,
+                    date - (ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY date))::INTEGER AS grp
+                FROM
+                    scored
+            ) s
+        GROUP BY
+            user_id,
+            grp
+    ),
+    adherence_run AS (
+        SELECT
+            user_id,
+            COUNT(*) AS length,
+            MAX(date) AS last_date
+        FROM
+            (
+                SELECT
+                    user_id,
+                    date,
+                    date - (ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY date))::INTEGER AS grp
+                FROM
+                    scored
+                WHERE
+                    adherent
+            ) s
+        GROUP BY
+            user_id,
+            grp
+    )
+SELECT
+    t1.id AS user_id,
+    COALESCE(
+        (
+            SELECT
+                MAX(length)
+            FROM
+                logging_run
+            WHERE
+                user_id = t1.id
+                AND last_date >= CURRENT_DATE - 1
+        ),
+        0
+    ) AS current_logging_streak,
+    COALESCE(
+        (
+            SELECT
+                MAX(length)
+            FROM
+                logging_run
+            WHERE
+                user_id = t1.id
+        ),
+        0
+    ) AS longest_logging_streak,
+    COALESCE(
+        (
+            SELECT
+                MAX(length)
+            FROM
+                adherence_run
+            WHERE
+                user_id = t1.id
+                AND last_date >= CURRENT_DATE - 1
+        ),
+        0
+    ) AS current_adherence_streak,
+    COALESCE(
+        (
+            SELECT
+                MAX(length)
+            FROM
+                adherence_run
+            WHERE
+                user_id = t1.id
+        ),
+        0
+    ) AS longest_adherence_streak
+FROM
+    users_user t1;
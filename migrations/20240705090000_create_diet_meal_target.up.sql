@@ -0,0 +1,21 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    diet_meal_target (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        meal_of_day_id UUID NOT NULL,
+        split_type INTEGER NOT NULL,
+        protein NUMERIC(5, 1) NOT NULL,
+        carbohydrate NUMERIC(5, 1) NOT NULL,
+        fat NUMERIC(5, 1) NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        created_by_id UUID NOT NULL,
+        updated_by_id UUID,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_meal_of_day_id FOREIGN KEY (meal_of_day_id) REFERENCES meal_of_day (id) ON DELETE CASCADE,
+        CONSTRAINT fk_created_by FOREIGN KEY (created_by_id) REFERENCES users_user (id),
+        CONSTRAINT fk_updated_by FOREIGN KEY (updated_by_id) REFERENCES users_user (id),
+        CONSTRAINT chk_split_type CHECK (split_type IN (1, 2)),
+        CONSTRAINT diet_meal_target_unique_user_id_meal_of_day_id UNIQUE (user_id, meal_of_day_id)
+    );
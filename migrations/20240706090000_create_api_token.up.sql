@@ -0,0 +1,18 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    api_token (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        "name" VARCHAR(100) NOT NULL,
+        token_prefix VARCHAR(16) NOT NULL,
+        token_hash VARCHAR(64) NOT NULL,
+        scopes TEXT[] NOT NULL DEFAULT '{}',
+        expires_at TIMESTAMPTZ,
+        last_used_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        created_by_id UUID NOT NULL,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_created_by FOREIGN KEY (created_by_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT api_token_unique_token_hash UNIQUE (token_hash),
+        CONSTRAINT api_token_unique_user_id_name UNIQUE (user_id, "name")
+    );
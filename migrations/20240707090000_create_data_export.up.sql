@@ -0,0 +1,14 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    data_export (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        status INTEGER NOT NULL DEFAULT 1,
+        file_name VARCHAR(255),
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        completed_at TIMESTAMPTZ,
+        created_by_id UUID NOT NULL,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_created_by FOREIGN KEY (created_by_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT chk_status CHECK (status IN (1, 2, 3))
+    );
@@ -0,0 +1,10 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    account_deletion (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        requested_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        delete_after TIMESTAMPTZ NOT NULL,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT account_deletion_unique_user_id UNIQUE (user_id)
+    );
@@ -0,0 +1,23 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    user_totp (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        secret VARCHAR(64) NOT NULL,
+        enabled_at TIMESTAMPTZ,
+        last_used_step BIGINT,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT user_totp_unique_user_id UNIQUE (user_id)
+    );
+
+CREATE TABLE IF NOT EXISTS
+    user_recovery_code (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        code_hash VARCHAR(64) NOT NULL,
+        used_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT user_recovery_code_unique_user_id_code_hash UNIQUE (user_id, code_hash)
+    );
@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    login_throttle (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        scope INT NOT NULL,
+        key VARCHAR(255) NOT NULL,
+        user_id UUID,
+        failure_count INT NOT NULL DEFAULT 0,
+        last_failure_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        blocked_until TIMESTAMPTZ,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT login_throttle_unique_scope_key UNIQUE (scope, key)
+    );
@@ -0,0 +1,14 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    session_refresh_token (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        session_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        token_hash VARCHAR(64) NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        expires_at TIMESTAMPTZ NOT NULL,
+        used_at TIMESTAMPTZ,
+        CONSTRAINT fk_session_id FOREIGN KEY (session_id) REFERENCES user_session (id) ON DELETE CASCADE,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT session_refresh_token_unique_token_hash UNIQUE (token_hash)
+    );
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS social_comment;
+
+DROP TABLE IF EXISTS social_reaction;
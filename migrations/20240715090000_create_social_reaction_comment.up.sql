@@ -0,0 +1,41 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    social_reaction (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        owner_id UUID NOT NULL,
+        date DATE NOT NULL,
+        workout_id UUID,
+        user_id UUID NOT NULL,
+        kind INT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_owner_id FOREIGN KEY (owner_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_workout_id FOREIGN KEY (workout_id) REFERENCES workout (id) ON DELETE CASCADE,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE
+    );
+
+-- Diet day reactions have no workout, so the workout is coalesced to keep them unique.
+CREATE UNIQUE INDEX IF NOT EXISTS social_reaction_unique_target_user_id_kind ON social_reaction (
+    owner_id,
+    date,
+    COALESCE(workout_id, '00000000-0000-0000-0000-000000000000'::UUID),
+    user_id,
+    kind
+);
+
+CREATE TABLE IF NOT EXISTS
+    social_comment (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        owner_id UUID NOT NULL,
+        date DATE NOT NULL,
+        workout_id UUID,
+        parent_id UUID,
+        user_id UUID NOT NULL,
+        body VARCHAR(1000) NOT NULL,
+        is_hidden BOOLEAN NOT NULL DEFAULT FALSE,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        CONSTRAINT fk_owner_id FOREIGN KEY (owner_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_workout_id FOREIGN KEY (workout_id) REFERENCES workout (id) ON DELETE CASCADE,
+        CONSTRAINT fk_parent_id FOREIGN KEY (parent_id) REFERENCES social_comment (id) ON DELETE CASCADE,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE
+    );
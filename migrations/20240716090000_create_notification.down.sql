@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS notification_preference;
+
+DROP TABLE IF EXISTS notification;
@@ -0,0 +1,25 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    notification (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        actor_id UUID,
+        kind INT NOT NULL,
+        message VARCHAR(255) NOT NULL,
+        link VARCHAR(255),
+        read_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_actor_id FOREIGN KEY (actor_id) REFERENCES users_user (id) ON DELETE CASCADE
+    );
+
+CREATE TABLE IF NOT EXISTS
+    notification_preference (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        kind INT NOT NULL,
+        enabled BOOLEAN NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT notification_preference_unique_user_id_kind UNIQUE (user_id, kind)
+    );
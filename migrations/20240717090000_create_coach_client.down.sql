@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS coach_audit;
+
+DROP TABLE IF EXISTS coach_client;
@@ -0,0 +1,29 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    coach_client (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        client_id UUID NOT NULL,
+        coach_id UUID NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_client_id FOREIGN KEY (client_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_coach_id FOREIGN KEY (coach_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT coach_client_unique_client_id_coach_id UNIQUE (client_id, coach_id),
+        CONSTRAINT coach_client_not_self CHECK (client_id <> coach_id)
+    );
+
+CREATE INDEX IF NOT EXISTS coach_client_coach_id_idx ON coach_client (coach_id);
+
+CREATE TABLE IF NOT EXISTS
+    coach_audit (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        client_id UUID NOT NULL,
+        coach_id UUID NOT NULL,
+        entity VARCHAR(50) NOT NULL,
+        entity_id UUID,
+        action INT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_client_id FOREIGN KEY (client_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_coach_id FOREIGN KEY (coach_id) REFERENCES users_user (id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS coach_audit_client_id_created_at_idx ON coach_audit (client_id, created_at DESC);
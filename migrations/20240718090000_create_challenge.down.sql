@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS challenge_participant;
+
+DROP TABLE IF EXISTS challenge;
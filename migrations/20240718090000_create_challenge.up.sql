@@ -0,0 +1,29 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    challenge (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        name VARCHAR(100) NOT NULL,
+        metric INT NOT NULL,
+        start_date DATE NOT NULL,
+        end_date DATE NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT challenge_end_date_after_start_date CHECK (end_date >= start_date)
+    );
+
+CREATE INDEX IF NOT EXISTS challenge_user_id_idx ON challenge (user_id);
+
+CREATE TABLE IF NOT EXISTS
+    challenge_participant (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        challenge_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        joined_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_challenge_id FOREIGN KEY (challenge_id) REFERENCES challenge (id) ON DELETE CASCADE,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT challenge_participant_unique_challenge_id_user_id UNIQUE (challenge_id, user_id)
+    );
+
+CREATE INDEX IF NOT EXISTS challenge_participant_user_id_idx ON challenge_participant (user_id);
@@ -0,0 +1,21 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    shared_template (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        kind INT NOT NULL,
+        meal_id UUID UNIQUE,
+        workout_id UUID UNIQUE,
+        slug VARCHAR(120) UNIQUE NOT NULL,
+        name VARCHAR(100) NOT NULL,
+        description VARCHAR(255) NOT NULL DEFAULT '',
+        clone_count INT NOT NULL DEFAULT 0,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMPTZ,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_meal_id FOREIGN KEY (meal_id) REFERENCES meal (id) ON DELETE CASCADE,
+        CONSTRAINT fk_workout_id FOREIGN KEY (workout_id) REFERENCES workout (id) ON DELETE CASCADE,
+        CONSTRAINT shared_template_one_source CHECK (num_nonnulls (meal_id, workout_id) = 1)
+    );
+
+CREATE INDEX IF NOT EXISTS shared_template_user_id_idx ON shared_template (user_id);
@@ -0,0 +1,40 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    user_report (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        reporter_id UUID NOT NULL,
+        reported_id UUID NOT NULL,
+        comment_id UUID,
+        link VARCHAR(255),
+        reason INT NOT NULL,
+        details VARCHAR(1000) NOT NULL DEFAULT '',
+        status INT NOT NULL DEFAULT 1,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        resolved_at TIMESTAMPTZ,
+        resolved_by_id UUID,
+        CONSTRAINT fk_reporter_id FOREIGN KEY (reporter_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_reported_id FOREIGN KEY (reported_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_comment_id FOREIGN KEY (comment_id) REFERENCES social_comment (id) ON DELETE SET NULL,
+        CONSTRAINT fk_resolved_by_id FOREIGN KEY (resolved_by_id) REFERENCES users_user (id) ON DELETE SET NULL,
+        CONSTRAINT user_report_not_self CHECK (reporter_id <> reported_id)
+    );
+
+CREATE INDEX IF NOT EXISTS user_report_status_created_at_idx ON user_report (status, created_at);
+
+CREATE INDEX IF NOT EXISTS user_report_reported_id_idx ON user_report (reported_id);
+
+CREATE TABLE IF NOT EXISTS
+    moderation_action (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        report_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        actor_id UUID NOT NULL,
+        action INT NOT NULL,
+        note VARCHAR(255) NOT NULL DEFAULT '',
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_report_id FOREIGN KEY (report_id) REFERENCES user_report (id) ON DELETE CASCADE,
+        CONSTRAINT fk_user_id FOREIGN KEY (user_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_actor_id FOREIGN KEY (actor_id) REFERENCES users_user (id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS moderation_action_report_id_idx ON moderation_action (report_id);
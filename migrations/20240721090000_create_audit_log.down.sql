@@ -0,0 +1,70 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS audit_log_record ON api_token;
+
+DROP TRIGGER IF EXISTS audit_log_record ON challenge;
+
+DROP TRIGGER IF EXISTS audit_log_record ON challenge_participant;
+
+DROP TRIGGER IF EXISTS audit_log_record ON coach_client;
+
+DROP TRIGGER IF EXISTS audit_log_record ON diet_meal_target;
+
+DROP TRIGGER IF EXISTS audit_log_record ON diet_target;
+
+DROP TRIGGER IF EXISTS audit_log_record ON diet_target_template;
+
+DROP TRIGGER IF EXISTS audit_log_record ON diet_target_template_day;
+
+DROP TRIGGER IF EXISTS audit_log_record ON exercise;
+
+DROP TRIGGER IF EXISTS audit_log_record ON food;
+
+DROP TRIGGER IF EXISTS audit_log_record ON food_brand;
+
+DROP TRIGGER IF EXISTS audit_log_record ON food_log;
+
+DROP TRIGGER IF EXISTS audit_log_record ON meal;
+
+DROP TRIGGER IF EXISTS audit_log_record ON meal_food;
+
+DROP TRIGGER IF EXISTS audit_log_record ON meal_of_day;
+
+DROP TRIGGER IF EXISTS audit_log_record ON movement;
+
+DROP TRIGGER IF EXISTS audit_log_record ON muscle_group;
+
+DROP TRIGGER IF EXISTS audit_log_record ON notification_preference;
+
+DROP TRIGGER IF EXISTS audit_log_record ON progress;
+
+DROP TRIGGER IF EXISTS audit_log_record ON progress_photo;
+
+DROP TRIGGER IF EXISTS audit_log_record ON shared_template;
+
+DROP TRIGGER IF EXISTS audit_log_record ON social_comment;
+
+DROP TRIGGER IF EXISTS audit_log_record ON social_reaction;
+
+DROP TRIGGER IF EXISTS audit_log_record ON tracked_set;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_block;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_follower;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_meal_of_day;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_privacy_setting;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_profile;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_report;
+
+DROP TRIGGER IF EXISTS audit_log_record ON users_user;
+
+DROP TRIGGER IF EXISTS audit_log_record ON workout;
+
+DROP FUNCTION IF EXISTS audit_log_record ();
+
+DROP TABLE IF EXISTS audit_log;
+
+DROP FUNCTION IF EXISTS audit_log_append_only ();
@@ -0,0 +1,315 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    audit_log (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        entity VARCHAR(50) NOT NULL,
+        entity_id UUID NOT NULL,
+        action INT NOT NULL,
+        actor_id UUID,
+        changes JSONB NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+CREATE INDEX IF NOT EXISTS audit_log_entity_entity_id_idx ON audit_log (entity, entity_id);
+
+CREATE INDEX IF NOT EXISTS audit_log_actor_id_idx ON audit_log (actor_id);
+
+CREATE INDEX IF NOT EXISTS audit_log_created_at_idx ON audit_log (created_at);
+
+-- The log outlives the rows and users it refers to, so entries are never updated or deleted.
+CREATE OR REPLACE FUNCTION audit_log_append_only () RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_append_only BEFORE
+UPDATE
+OR DELETE ON audit_log FOR EACH ROW
+EXECUTE FUNCTION audit_log_append_only ();
+
+CREATE TRIGGER audit_log_append_only_truncate BEFORE TRUNCATE ON audit_log FOR EACH STATEMENT
+EXECUTE FUNCTION audit_log_append_only ();
+
+-- Records the changed columns of a row as {"column": [old, new]}. Secret values are redacted and
+-- an update that only touches bookkeeping columns is not recorded. The actor is the user set with
+-- `app.actor_id` for the transaction, falling back to the user recorded on the row.
+CREATE OR REPLACE FUNCTION audit_log_record () RETURNS TRIGGER AS $$
+DECLARE
+    ignored TEXT[] := ARRAY['id', 'created_at', 'updated_at', 'updated_by_id', 'last_login', 'last_used_at'];
+    secret TEXT[] := ARRAY['password', 'token_hash'];
+    old_data JSONB := CASE WHEN TG_OP <> 'INSERT' THEN to_jsonb(OLD) END;
+    new_data JSONB := CASE WHEN TG_OP <> 'DELETE' THEN to_jsonb(NEW) END;
+    row_data JSONB := COALESCE(new_data, old_data);
+    actor UUID := NULLIF(current_setting('app.actor_id', TRUE), '')::UUID;
+    changes JSONB;
+BEGIN
+    SELECT
+        jsonb_object_agg(
+            key,
+            CASE
+                WHEN key = ANY (secret) THEN jsonb_build_array(
+                    CASE WHEN old_data ? key THEN '[redacted]' END,
+                    CASE WHEN new_data ? key THEN '[redacted]' END
+                )
+                ELSE jsonb_build_array(old_data -> key, new_data -> key)
+            END
+        )
+    INTO
+        changes
+    FROM
+        jsonb_object_keys(row_data) AS key
+    WHERE
+        key <> ALL (ignored)
+        AND COALESCE(old_data -> key, 'null') <> COALESCE(new_data -> key, 'null');
+
+    IF changes IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    IF actor IS NULL AND TG_OP <> 'DELETE' THEN
+        actor := COALESCE(
+            CASE WHEN TG_OP = 'UPDATE' THEN row_data ->> 'updated_by_id' END,
+            row_data ->> 'created_by_id',
+            row_data ->> 'user_id',
+            CASE WHEN TG_TABLE_NAME = 'users_user' THEN row_data ->> 'id' END
+        )::UUID;
+    END IF;
+
+    INSERT INTO
+        audit_log (entity, entity_id, action, actor_id, changes)
+    VALUES
+        (
+            TG_TABLE_NAME,
+            (row_data ->> 'id')::UUID,
+            CASE TG_OP WHEN 'INSERT' THEN 1 WHEN 'UPDATE' THEN 2 ELSE 3 END,
+            actor,
+            changes
+        );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON api_token FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON challenge FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON challenge_participant FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON coach_client FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_meal_target FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_target FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_target_template FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_target_template_day FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON exercise FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON food FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON food_brand FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON food_log FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON meal FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON meal_food FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON meal_of_day FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON movement FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON muscle_group FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON notification_preference FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON progress FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON progress_photo FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON shared_template FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON social_comment FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON social_reaction FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON tracked_set FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_block FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_follower FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_meal_of_day FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_privacy_setting FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_profile FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_report FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON users_user FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON workout FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE user_session
+ADD COLUMN impersonator_id UUID,
+ADD CONSTRAINT fk_impersonator_id FOREIGN KEY (impersonator_id) REFERENCES users_user (id) ON DELETE CASCADE;
@@ -0,0 +1,32 @@
+-- Add up migration script here
+-- A personal record is a set heavier than every set of the same movement the user logged on an
+-- earlier date, or earlier on the same date. The feed and the notifications both read it here.
+CREATE OR REPLACE VIEW
+    personal_record AS
+SELECT
+    t1.id,
+    t1.exercise_id,
+    t2.workout_id,
+    t2.movement_id,
+    t3.user_id,
+    t3.date,
+    t1.weight,
+    t1.reps,
+    t1.created_at
+FROM
+    tracked_set t1
+    JOIN exercise t2 ON t2.id = t1.exercise_id
+    JOIN workout t3 ON t3.id = t2.workout_id
+WHERE
+    t1.weight > (
+        SELECT
+            MAX(s1.weight)
+        FROM
+            tracked_set s1
+            JOIN exercise s2 ON s2.id = s1.exercise_id
+            JOIN workout s3 ON s3.id = s2.workout_id
+        WHERE
+            s3.user_id = t3.user_id
+            AND s2.movement_id = t2.movement_id
+            AND (s3.date, s1.created_at) < (t3.date, t1.created_at)
+    );
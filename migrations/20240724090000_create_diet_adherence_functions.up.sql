@@ -0,0 +1,69 @@
+-- Add up migration script here
+-- Scoring and streaks are computed here for both the adherence panel and user statistics, with
+-- the tolerance passed in by the caller.
+DROP VIEW IF EXISTS diet_streak;
+
+CREATE OR REPLACE FUNCTION diet_day_is_adherent (day diet_adherence_day, tolerance_pct INTEGER) RETURNS BOOLEAN AS $$
+    SELECT
+        COALESCE(
+            ABS(COALESCE(day.energy, 0) - day.target_energy) <= ABS(day.target_energy) * tolerance_pct / 100.0
+            AND ABS(COALESCE(day.protein, 0) - day.target_protein) <= ABS(day.target_protein) * tolerance_pct / 100.0
+            AND ABS(COALESCE(day.carbohydrate, 0) - day.target_carbohydrate) <= ABS(day.target_carbohydrate) * tolerance_pct / 100.0
+            AND ABS(COALESCE(day.fat, 0) - day.target_fat) <= ABS(day.target_fat) * tolerance_pct / 100.0,
+            FALSE
+        )
+$$ LANGUAGE SQL IMMUTABLE;
+
+-- The current streak only counts a run ending today or yesterday, so a day that has not been
+-- logged yet does not reset it.
+CREATE OR REPLACE FUNCTION diet_streak (streak_user_id UUID, tolerance_pct INTEGER) RETURNS TABLE (
+    current_logging_streak BIGINT,
+    longest_logging_streak BIGINT,
+    current_adherence_streak BIGINT,
+    longest_adherence_streak BIGINT
+) AS $$
+    WITH
+        scored AS (
+            SELECT
+                t1.date,
+                diet_day_is_adherent(t1, tolerance_pct) AS adherent
+            FROM
+                diet_adherence_day t1
+            WHERE
+                t1.user_id = streak_user_id
+        ),
+        run AS (
+            SELECT
+                adherent_only,
+                COUNT(*) AS length,
+                MAX(date) AS last_date
+            FROM
+                (
+                    SELECT
+                        FALSE AS adherent_only,
+                        date,
+                        date - (ROW_NUMBER() OVER (ORDER BY date))::INTEGER AS grp
+                    FROM
+                        scored
+                    UNION ALL
+                    SELECT
+                        TRUE,
+                        date,
+                        date - (ROW_NUMBER() OVER (ORDER BY date))::INTEGER
+                    FROM
+                        scored
+                    WHERE
+                        adherent
+                ) s
+            GROUP BY
+                adherent_only,
+                grp
+        )
+    SELECT
+        COALESCE(MAX(length) FILTER (WHERE NOT adherent_only AND last_date >= CURRENT_DATE - 1), 0),
+        COALESCE(MAX(length) FILTER (WHERE NOT adherent_only), 0),
+        COALESCE(MAX(length) FILTER (WHERE adherent_only AND last_date >= CURRENT_DATE - 1), 0),
+        COALESCE(MAX(length) FILTER (WHERE adherent_only), 0)
+    FROM
+        run
+$$ LANGUAGE SQL STABLE;
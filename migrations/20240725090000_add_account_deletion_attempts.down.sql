@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE account_deletion
+DROP COLUMN IF EXISTS failed_attempts,
+DROP COLUMN IF EXISTS last_error;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE account_deletion
+ADD COLUMN failed_attempts INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN last_error TEXT;
@@ -0,0 +1,10 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS tracked_set_exercise_id;
+
+DROP INDEX IF EXISTS exercise_workout_id;
+
+DROP INDEX IF EXISTS progress_user_id_created_at;
+
+DROP INDEX IF EXISTS food_log_user_id_date;
+
+DROP INDEX IF EXISTS workout_user_id_created_at;
@@ -0,0 +1,10 @@
+-- Add up migration script here
+CREATE INDEX IF NOT EXISTS workout_user_id_created_at ON workout (user_id, created_at);
+
+CREATE INDEX IF NOT EXISTS food_log_user_id_date ON food_log (user_id, date);
+
+CREATE INDEX IF NOT EXISTS progress_user_id_created_at ON progress (user_id, created_at);
+
+CREATE INDEX IF NOT EXISTS exercise_workout_id ON exercise (workout_id);
+
+CREATE INDEX IF NOT EXISTS tracked_set_exercise_id ON tracked_set (exercise_id);
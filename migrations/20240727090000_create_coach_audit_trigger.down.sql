@@ -0,0 +1,27 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS coach_audit_record ON diet_meal_target;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON diet_target;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON diet_target_template;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON diet_target_template_day;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON exercise;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON food_log;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON meal;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON meal_food;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON tracked_set;
+
+DROP TRIGGER IF EXISTS coach_audit_record ON workout;
+
+DROP FUNCTION IF EXISTS coach_audit_record;
+
+DROP FUNCTION IF EXISTS row_changes;
+
+ALTER TABLE coach_audit
+DROP COLUMN IF EXISTS changes;
@@ -0,0 +1,177 @@
+-- Add up migration script here
+ALTER TABLE coach_audit
+ADD COLUMN changes JSONB NOT NULL DEFAULT '{}';
+
+-- The changed columns between two row snapshots as {"column": [old, new]}, leaving out
+-- bookkeeping columns and redacting secrets. NULL when nothing else changed.
+CREATE OR REPLACE FUNCTION row_changes (old_data JSONB, new_data JSONB) RETURNS JSONB AS $$
+DECLARE
+    ignored TEXT[] := ARRAY['id', 'created_at', 'updated_at', 'updated_by_id', 'last_login', 'last_used_at'];
+    secret TEXT[] := ARRAY['password', 'token_hash'];
+    changes JSONB;
+BEGIN
+    SELECT
+        jsonb_object_agg(
+            key,
+            CASE
+                WHEN key = ANY (secret) THEN jsonb_build_array(
+                    CASE WHEN old_data ? key THEN '[redacted]' END,
+                    CASE WHEN new_data ? key THEN '[redacted]' END
+                )
+                ELSE jsonb_build_array(old_data -> key, new_data -> key)
+            END
+        )
+    INTO
+        changes
+    FROM
+        jsonb_object_keys(COALESCE(new_data, old_data)) AS key
+    WHERE
+        key <> ALL (ignored)
+        AND COALESCE(old_data -> key, 'null') <> COALESCE(new_data -> key, 'null');
+    RETURN changes;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Records a coach's change to a client's row once the write has happened, in the same
+-- transaction, so a failed or rolled back write leaves no entry. The actor is the user set with
+-- `app.actor_id`, falling back to the user recorded on the row by the write. Rows of a workout or
+-- meal belong to its owner, and rows removed along with their parent are covered by the parent's
+-- entry.
+CREATE OR REPLACE FUNCTION coach_audit_record () RETURNS TRIGGER AS $$
+DECLARE
+    old_data JSONB := CASE WHEN TG_OP <> 'INSERT' THEN to_jsonb(OLD) END;
+    new_data JSONB := CASE WHEN TG_OP <> 'DELETE' THEN to_jsonb(NEW) END;
+    row_data JSONB := COALESCE(new_data, old_data);
+    actor UUID := NULLIF(current_setting('app.actor_id', TRUE), '')::UUID;
+    owner UUID;
+    changes JSONB := row_changes(old_data, new_data);
+BEGIN
+    IF actor IS NULL THEN
+        actor := CASE
+            WHEN TG_OP = 'INSERT' THEN row_data ->> 'created_by_id'
+            WHEN TG_OP = 'UPDATE' AND new_data -> 'updated_at' IS DISTINCT FROM old_data -> 'updated_at' THEN row_data ->> 'updated_by_id'
+        END::UUID;
+    END IF;
+
+    owner := CASE TG_TABLE_NAME
+        WHEN 'diet_target_template_day' THEN (
+            SELECT user_id FROM diet_target_template WHERE id = (row_data ->> 'template_id')::UUID
+        )
+        WHEN 'exercise' THEN (
+            SELECT user_id FROM workout WHERE id = (row_data ->> 'workout_id')::UUID
+        )
+        WHEN 'tracked_set' THEN (
+            SELECT
+                t2.user_id
+            FROM
+                exercise t1
+                JOIN workout t2 ON t2.id = t1.workout_id
+            WHERE
+                t1.id = (row_data ->> 'exercise_id')::UUID
+        )
+        WHEN 'meal_food' THEN (
+            SELECT user_id FROM meal WHERE id = (row_data ->> 'meal_id')::UUID
+        )
+        ELSE (row_data ->> 'user_id')::UUID
+    END;
+
+    IF changes IS NULL
+    OR actor IS NULL
+    OR owner IS NULL
+    OR actor = owner
+    OR NOT EXISTS (
+        SELECT
+            1
+        FROM
+            coach_client
+        WHERE
+            client_id = owner
+            AND coach_id = actor
+    ) THEN
+        RETURN NULL;
+    END IF;
+
+    INSERT INTO
+        coach_audit (client_id, coach_id, entity, entity_id, action, changes)
+    VALUES
+        (
+            owner,
+            actor,
+            TG_TABLE_NAME,
+            (row_data ->> 'id')::UUID,
+            CASE TG_OP WHEN 'INSERT' THEN 1 WHEN 'UPDATE' THEN 2 ELSE 3 END,
+            changes
+        );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_meal_target FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_target FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_target_template FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON diet_target_template_day FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON exercise FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON food_log FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON meal FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON meal_food FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON tracked_set FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
+
+CREATE TRIGGER coach_audit_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON workout FOR EACH ROW
+EXECUTE FUNCTION coach_audit_record ();
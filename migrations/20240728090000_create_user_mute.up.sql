@@ -0,0 +1,19 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS
+    user_mute (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        muter_id UUID NOT NULL,
+        muted_id UUID NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT fk_muter_id FOREIGN KEY (muter_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT fk_muted_id FOREIGN KEY (muted_id) REFERENCES users_user (id) ON DELETE CASCADE,
+        CONSTRAINT user_mute_unique_muter_id_muted_id UNIQUE (muter_id, muted_id),
+        CONSTRAINT user_mute_not_self CHECK (muter_id <> muted_id)
+    );
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_mute FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
@@ -0,0 +1,10 @@
+-- Add down migration script here
+DELETE FROM moderation_action
+WHERE
+    actor_id IS NULL;
+
+ALTER TABLE moderation_action
+ALTER COLUMN actor_id
+SET NOT NULL,
+DROP CONSTRAINT fk_actor_id,
+ADD CONSTRAINT fk_actor_id FOREIGN KEY (actor_id) REFERENCES users_user (id) ON DELETE CASCADE;
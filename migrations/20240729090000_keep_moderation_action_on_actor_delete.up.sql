@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE moderation_action
+ALTER COLUMN actor_id
+DROP NOT NULL,
+DROP CONSTRAINT fk_actor_id,
+ADD CONSTRAINT fk_actor_id FOREIGN KEY (actor_id) REFERENCES users_user (id) ON DELETE SET NULL;
@@ -0,0 +1,229 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS audit_log_record ON exercise_plan;
+
+DROP TRIGGER IF EXISTS audit_log_record ON set_plan;
+
+DROP TRIGGER IF EXISTS audit_log_record ON training_plan;
+
+DROP TRIGGER IF EXISTS audit_log_record ON training_plan_workout_plan;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_identity;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_recovery_code;
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_totp;
+
+DROP TRIGGER IF EXISTS audit_log_record ON workout_plan;
+
+-- The changed columns between two row snapshots as {"column": [old, new]}, leaving out
+-- bookkeeping columns and redacting secrets. NULL when nothing else changed.
+CREATE OR REPLACE FUNCTION row_changes (old_data JSONB, new_data JSONB) RETURNS JSONB AS $$
+DECLARE
+    ignored TEXT[] := ARRAY['id', 'created_at', 'updated_at', 'updated_by_id', 'last_login', 'last_used_at'];
+    secret TEXT[] := ARRAY['password', 'token_hash'];
+    changes JSONB;
+BEGIN
+    SELECT
+        jsonb_object_agg(
+            key,
+            CASE
+                WHEN key = ANY (secret) THEN jsonb_build_array(
+                    CASE WHEN old_data ? key THEN '[redacted]' END,
+                    CASE WHEN new_data ? key THEN '[redacted]' END
+                )
+                ELSE jsonb_build_array(old_data -> key, new_data -> key)
+            END
+        )
+    INTO
+        changes
+    FROM
+        jsonb_object_keys(COALESCE(new_data, old_data)) AS key
+    WHERE
+        key <> ALL (ignored)
+        AND COALESCE(old_data -> key, 'null') <> COALESCE(new_data -> key, 'null');
+    RETURN changes;
+END;
+$$ LANGUAGE plpgsql;
+
+-- The log outlives the rows and users it refers to, so entries are never updated or deleted.
+CREATE OR REPLACE FUNCTION audit_log_append_only () RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+-- Records the changed columns of a row as {"column": [old, new]}. Secret values are redacted and
+-- an update that only touches bookkeeping columns is not recorded. The actor is the user set with
+-- `app.actor_id` for the transaction, falling back to the user recorded on the row.
+CREATE OR REPLACE FUNCTION audit_log_record () RETURNS TRIGGER AS $$
+DECLARE
+    ignored TEXT[] := ARRAY['id', 'created_at', 'updated_at', 'updated_by_id', 'last_login', 'last_used_at'];
+    secret TEXT[] := ARRAY['password', 'token_hash'];
+    old_data JSONB := CASE WHEN TG_OP <> 'INSERT' THEN to_jsonb(OLD) END;
+    new_data JSONB := CASE WHEN TG_OP <> 'DELETE' THEN to_jsonb(NEW) END;
+    row_data JSONB := COALESCE(new_data, old_data);
+    actor UUID := NULLIF(current_setting('app.actor_id', TRUE), '')::UUID;
+    changes JSONB;
+BEGIN
+    SELECT
+        jsonb_object_agg(
+            key,
+            CASE
+                WHEN key = ANY (secret) THEN jsonb_build_array(
+                    CASE WHEN old_data ? key THEN '[redacted]' END,
+                    CASE WHEN new_data ? key THEN '[redacted]' END
+                )
+                ELSE jsonb_build_array(old_data -> key, new_data -> key)
+            END
+        )
+    INTO
+        changes
+    FROM
+        jsonb_object_keys(row_data) AS key
+    WHERE
+        key <> ALL (ignored)
+        AND COALESCE(old_data -> key, 'null') <> COALESCE(new_data -> key, 'null');
+
+    IF changes IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    IF actor IS NULL AND TG_OP <> 'DELETE' THEN
+        actor := COALESCE(
+            CASE WHEN TG_OP = 'UPDATE' THEN row_data ->> 'updated_by_id' END,
+            row_data ->> 'created_by_id',
+            row_data ->> 'user_id',
+            CASE WHEN TG_TABLE_NAME = 'users_user' THEN row_data ->> 'id' END
+        )::UUID;
+    END IF;
+
+    INSERT INTO
+        audit_log (entity, entity_id, action, actor_id, changes)
+    VALUES
+        (
+            TG_TABLE_NAME,
+            (row_data ->> 'id')::UUID,
+            CASE TG_OP WHEN 'INSERT' THEN 1 WHEN 'UPDATE' THEN 2 ELSE 3 END,
+            actor,
+            changes
+        );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS audit_log_record ON challenge;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON challenge FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON challenge_participant;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON challenge_participant FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON coach_client;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON coach_client FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON notification_preference;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON notification_preference FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON shared_template;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON shared_template FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON social_comment;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON social_comment FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON social_reaction;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON social_reaction FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_block;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_block FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_follower;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_follower FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_mute;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_mute FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_privacy_setting;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_privacy_setting FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_report;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_report FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+DROP TRIGGER IF EXISTS audit_log_record ON users_user;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON users_user FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
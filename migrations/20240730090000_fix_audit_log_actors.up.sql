@@ -0,0 +1,281 @@
+-- Add up migration script here
+CREATE OR REPLACE FUNCTION row_changes (old_data JSONB, new_data JSONB) RETURNS JSONB AS $$
+DECLARE
+    ignored TEXT[] := ARRAY['id', 'created_at', 'updated_at', 'updated_by_id', 'last_login', 'last_login_at', 'last_used_at', 'last_used_step'];
+    secret TEXT[] := ARRAY['password', 'token_hash', 'secret', 'code_hash'];
+    changes JSONB;
+BEGIN
+    SELECT
+        jsonb_object_agg(
+            key,
+            CASE
+                WHEN key = ANY (secret) THEN jsonb_build_array(
+                    CASE WHEN old_data ? key THEN '[redacted]' END,
+                    CASE WHEN new_data ? key THEN '[redacted]' END
+                )
+                ELSE jsonb_build_array(old_data -> key, new_data -> key)
+            END
+        )
+    INTO
+        changes
+    FROM
+        jsonb_object_keys(COALESCE(new_data, old_data)) AS key
+    WHERE
+        key <> ALL (ignored)
+        AND COALESCE(old_data -> key, 'null') <> COALESCE(new_data -> key, 'null');
+    RETURN changes;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Entries are only ever changed while a user's account is deleted, with `app.erased_user_id` set
+-- for the transaction. The deleted user's rows lose their recorded values and the user is
+-- removed as the actor, everything else stays as it was.
+CREATE OR REPLACE FUNCTION audit_log_append_only () RETURNS TRIGGER AS $$
+DECLARE
+    erased UUID := NULLIF(current_setting('app.erased_user_id', TRUE), '')::UUID;
+BEGIN
+    IF TG_OP = 'UPDATE'
+        AND erased IS NOT NULL
+        AND NEW.id = OLD.id
+        AND NEW.entity = OLD.entity
+        AND NEW.entity_id = OLD.entity_id
+        AND NEW.action = OLD.action
+        AND NEW.created_at = OLD.created_at
+        AND (NEW.changes = OLD.changes OR NEW.changes = '{}')
+        AND (NEW.actor_id IS NOT DISTINCT FROM OLD.actor_id OR (NEW.actor_id IS NULL AND OLD.actor_id = erased))
+    THEN
+        RETURN NEW;
+    END IF;
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+-- Records the changed columns of a row as {"column": [old, new]}. The actor is the user set with
+-- `app.actor_id` for the transaction. Otherwise a new row is credited to the user in the column
+-- named by the first trigger argument, `created_by_id` by default, and an update to the user in
+-- `updated_by_id`, or in the named column for rows without one, when it also set `updated_at`.
+-- The second argument names the column identifying the row when it is not `id`. While an
+-- account is deleted the values of its rows are dropped from the log, see
+-- `audit_log_append_only`.
+CREATE OR REPLACE FUNCTION audit_log_record () RETURNS TRIGGER AS $$
+DECLARE
+    actor_column TEXT := COALESCE(TG_ARGV[0], 'created_by_id');
+    id_column TEXT := COALESCE(TG_ARGV[1], 'id');
+    old_data JSONB := CASE WHEN TG_OP <> 'INSERT' THEN to_jsonb(OLD) END;
+    new_data JSONB := CASE WHEN TG_OP <> 'DELETE' THEN to_jsonb(NEW) END;
+    row_data JSONB := COALESCE(new_data, old_data);
+    row_id UUID := (row_data ->> id_column)::UUID;
+    actor UUID := NULLIF(current_setting('app.actor_id', TRUE), '')::UUID;
+    erased UUID := NULLIF(current_setting('app.erased_user_id', TRUE), '')::UUID;
+    changes JSONB := row_changes(old_data, new_data);
+BEGIN
+    IF changes IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    IF actor IS NULL THEN
+        actor := CASE
+            WHEN TG_OP = 'INSERT' THEN row_data ->> actor_column
+            WHEN TG_OP = 'UPDATE' AND new_data -> 'updated_at' IS DISTINCT FROM old_data -> 'updated_at' THEN COALESCE(
+                row_data ->> 'updated_by_id',
+                CASE WHEN actor_column <> 'created_by_id' THEN row_data ->> actor_column END
+            )
+        END::UUID;
+    END IF;
+
+    IF erased IS NOT NULL AND TG_OP = 'DELETE' THEN
+        UPDATE audit_log
+        SET
+            changes = '{}'
+        WHERE
+            entity = TG_TABLE_NAME
+            AND entity_id = row_id;
+        changes := '{}';
+    END IF;
+
+    INSERT INTO
+        audit_log (entity, entity_id, action, actor_id, changes)
+    VALUES
+        (
+            TG_TABLE_NAME,
+            row_id,
+            CASE TG_OP WHEN 'INSERT' THEN 1 WHEN 'UPDATE' THEN 2 ELSE 3 END,
+            actor,
+            changes
+        );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS audit_log_record ON challenge;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON challenge FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON challenge_participant;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON challenge_participant FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON coach_client;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON coach_client FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('client_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON notification_preference;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON notification_preference FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON shared_template;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON shared_template FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON social_comment;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON social_comment FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON social_reaction;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON social_reaction FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_block;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_block FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('blocker_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_follower;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_follower FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('follower_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_mute;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_mute FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('muter_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_privacy_setting;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_privacy_setting FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON user_report;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_report FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('reporter_id');
+
+DROP TRIGGER IF EXISTS audit_log_record ON users_user;
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON users_user FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('id');
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON exercise_plan FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON set_plan FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON training_plan FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON training_plan_workout_plan FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('created_by_id', 'training_plan_id');
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_identity FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_recovery_code FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON user_totp FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ('user_id');
+
+CREATE TRIGGER audit_log_record
+AFTER INSERT
+OR
+UPDATE
+OR DELETE ON workout_plan FOR EACH ROW
+EXECUTE FUNCTION audit_log_record ();
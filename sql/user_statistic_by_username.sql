@@ -19,7 +19,11 @@ SELECT
     COALESCE(meal_food_count.meal_food_created_count, 0) AS "meal_food_created_count!",
     COALESCE(exercise_count.exercise_count, 0) AS "exercise_count!",
     COALESCE(set_count.set_count, 0) AS "set_count!",
-    COALESCE(rep_count.rep_count, 0) AS "rep_count!"
+    COALESCE(rep_count.rep_count, 0) AS "rep_count!",
+    COALESCE(streak.current_logging_streak, 0) AS "current_logging_streak!",
+    COALESCE(streak.longest_logging_streak, 0) AS "longest_logging_streak!",
+    COALESCE(streak.current_adherence_streak, 0) AS "current_adherence_streak!",
+    COALESCE(streak.longest_adherence_streak, 0) AS "longest_adherence_streak!"
 FROM
     users_user t1
     LEFT JOIN user_profile t2 ON t2.user_id = t1.id
@@ -191,6 +195,7 @@ FROM
         GROUP BY
             r3.user_id
     ) rep_count ON rep_count.user_id = t1.id
+    CROSS JOIN LATERAL diet_streak (t1.id, $2) streak
 WHERE
     t1.username = $1
 LIMIT
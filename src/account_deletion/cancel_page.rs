@@ -0,0 +1,55 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::button::SubmitButton;
+use crate::util::param::extract_param;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::token::{JwtManager, TokenType},
+    setup::get_pool,
+};
+
+#[cfg(feature = "ssr")]
+use super::model::AccountDeletion;
+
+#[server(endpoint = "account-deletion-cancel")]
+pub async fn account_deletion_cancel(token: String) -> Result<(), ServerFnError> {
+    let token = JwtManager::validate_token(&token, TokenType::AccountDeletionCancel)?;
+    let pool = get_pool()?;
+    AccountDeletion::cancel(&pool, token.user_id).await?;
+    leptos_axum::redirect("/login");
+    Ok(())
+}
+
+#[component]
+pub fn AccountDeletionCancelPage() -> impl IntoView {
+    let action = Action::<AccountDeletionCancel, _>::server();
+    let query = use_query_map();
+    let token = move || extract_param(&query, "token");
+
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors", "token"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    view! {
+        <Title text="Cancel Account Deletion"/>
+        <main class="p-4 lg:p-8">
+            <div class="p-4 mx-auto max-w-md bg-white border shadow-md">
+                <h1 class="mb-4 text-xl font-bold">"Cancel Account Deletion"</h1>
+                <p class="mb-4">
+                    "Your account is scheduled for deletion. Cancel the deletion to reactivate your account and keep your data."
+                </p>
+                <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                <ActionForm action>
+                    <input type="hidden" name="token" value=token/>
+                    <SubmitButton loading=action_loading label="Cancel Deletion"/>
+                </ActionForm>
+            </div>
+        </main>
+    }
+}
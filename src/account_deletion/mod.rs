@@ -0,0 +1,8 @@
+pub mod cancel_page;
+pub mod model;
+pub mod panel;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod requested_page;
+#[cfg(feature = "ssr")]
+pub mod service;
@@ -0,0 +1,19 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AccountDeletion {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub requested_at: DateTime<Utc>,
+    pub delete_after: DateTime<Utc>,
+    pub failed_attempts: i32,
+    pub last_error: Option<String>,
+}
+
+impl AccountDeletion {
+    /// Failed deletions are retried with a growing delay until this many attempts have been made.
+    pub const MAX_ATTEMPTS: i32 = 5;
+}
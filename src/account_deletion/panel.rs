@@ -0,0 +1,96 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::app::UserResource;
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::{
+        cookie::delete_auth_cookies,
+        model::User,
+        service::{get_request_user, AuthService},
+    },
+    config::get_config,
+    error::Error,
+    setup::get_pool,
+    user_session::model::UserSession,
+};
+
+#[cfg(feature = "ssr")]
+use super::model::AccountDeletion;
+
+#[server(endpoint = "account-deletion-create")]
+pub async fn account_deletion_create(password: String) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let config = get_config();
+
+    let user = User::get_by_id(&pool, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    user.verify_password(&password)?;
+
+    let delete_after =
+        chrono::Utc::now() + chrono::TimeDelta::days(config.account_deletion_grace_days);
+    let deletion = AccountDeletion::create(&pool, user.id, delete_after).await?;
+    UserSession::delete_all_by_user_id(&pool, user.id).await?;
+    if let Err(err) = AuthService::send_account_deletion_email(
+        user.id,
+        &user.name,
+        &user.email,
+        deletion.delete_after,
+    )
+    .await
+    {
+        tracing::error!("account deletion email failed for {}: {:?}", user.id, err);
+    }
+
+    delete_auth_cookies()?;
+    leptos_axum::redirect("/account-deletion/requested");
+    Ok(())
+}
+
+#[component]
+pub fn AccountDeletionPanel() -> impl IntoView {
+    let action = Action::<AccountDeletionCreate, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error =
+        move || extract_other_errors(action_value, &["non_field_errors", "password"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let user_resource = expect_context::<UserResource>();
+    create_effect(move |_| {
+        if let Some(Ok(())) = action_value.get() {
+            user_resource.refetch();
+        }
+    });
+
+    view! {
+        <div class="p-4 mt-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Delete Account"</h2>
+            <p class="mb-4">
+                "Your account will be deactivated immediately and permanently deleted, along with all of your data, after a grace period. We will email you a link to cancel the deletion before then."
+            </p>
+            <div class="max-w-sm">
+                <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                <ActionForm action>
+                    <TextInput
+                        action_value
+                        label="Password"
+                        name="password"
+                        input_type="password"
+                        autocomplete="current-password"
+                        placeholder="Enter your password to confirm"
+                    />
+                    <SubmitButton loading=action_loading label="Delete Account"/>
+                </ActionForm>
+            </div>
+        </div>
+    }
+}
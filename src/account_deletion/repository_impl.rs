@@ -0,0 +1,457 @@
+use chrono::prelude::*;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::auth::model::User;
+use crate::error::{Error, Result};
+
+use super::model::AccountDeletion;
+
+/// Rows owned by the user, deleted before the user itself. Child rows such as exercises, sets,
+/// meal foods, template days and progress photos are removed by their cascading foreign keys.
+const OWNED_ROW_DELETES: [&str; 12] = [
+    "DELETE FROM workout WHERE user_id = $1",
+    "DELETE FROM food_log WHERE user_id = $1",
+    "DELETE FROM meal WHERE user_id = $1",
+    "DELETE FROM diet_meal_target WHERE user_id = $1",
+    "DELETE FROM diet_target_template WHERE user_id = $1",
+    "DELETE FROM diet_target WHERE user_id = $1",
+    "DELETE FROM progress_photo WHERE user_id = $1",
+    "DELETE FROM progress WHERE user_id = $1",
+    "DELETE FROM user_meal_of_day WHERE user_id = $1",
+    "DELETE FROM user_follower WHERE user_id = $1 OR follower_id = $1",
+    "DELETE FROM user_block WHERE blocker_id = $1 OR blocked_id = $1",
+    "DELETE FROM user_profile WHERE user_id = $1",
+];
+
+/// Placeholder owner for records the user created on shared or other users' data when there
+/// is no superuser to hand them to. The account is inactive and cannot be logged in to.
+const DELETED_USER_EMAIL: &str = "deleted-user@trackedfitness.invalid";
+
+/// Signup slugifies usernames, so one with an underscore can never be registered.
+const DELETED_USER_USERNAME: &str = "deleted_user";
+
+/// Files left on disk once the user's rows have been deleted.
+#[derive(Debug, Default)]
+pub struct DeletedUserFiles {
+    pub progress_photos: Vec<String>,
+    pub data_exports: Vec<String>,
+}
+
+impl AccountDeletion {
+    pub async fn get_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM account_deletion WHERE user_id = $1",
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Deletions whose grace period has passed, leaving out those that have failed too many
+    /// times to retry automatically.
+    pub async fn get_due(pool: &PgPool) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                *
+            FROM
+                account_deletion
+            WHERE
+                delete_after <= NOW()
+                AND failed_attempts < $1
+            ORDER BY
+                delete_after
+            ",
+            Self::MAX_ATTEMPTS
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Records a failed deletion and backs off the next attempt by an hour for each failure.
+    pub async fn record_failure(pool: &PgPool, user_id: Uuid, error: &str) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            UPDATE account_deletion
+            SET
+                failed_attempts = failed_attempts + 1,
+                last_error = $2,
+                delete_after = NOW() + MAKE_INTERVAL(hours => failed_attempts + 1)
+            WHERE
+                user_id = $1
+            RETURNING
+                *
+            ",
+            user_id,
+            error
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Deactivates the user and schedules their deletion.
+    pub async fn create(pool: &PgPool, user_id: Uuid, delete_after: DateTime<Utc>) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            WITH
+                deactivated AS (
+                    UPDATE users_user
+                    SET
+                        is_active = false,
+                        updated_at = NOW()
+                    WHERE
+                        id = $1
+                )
+            INSERT INTO
+                account_deletion (user_id, delete_after)
+            VALUES
+                ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET
+                requested_at = NOW(),
+                delete_after = EXCLUDED.delete_after,
+                failed_attempts = 0,
+                last_error = NULL
+            RETURNING
+                *
+            ",
+            user_id,
+            delete_after,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Removes a pending deletion and reactivates the user.
+    pub async fn cancel(pool: &PgPool, user_id: Uuid) -> Result<User> {
+        let query = sqlx::query_as!(
+            User,
+            "
+            WITH
+                cancelled AS (
+                    DELETE FROM account_deletion
+                    WHERE
+                        user_id = $1
+                    RETURNING
+                        user_id
+                )
+            UPDATE users_user
+            SET
+                is_active = true,
+                updated_at = NOW()
+            WHERE
+                id IN (
+                    SELECT
+                        user_id
+                    FROM
+                        cancelled
+                )
+            RETURNING
+                *
+            ",
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| Error::Other("This account is not scheduled for deletion.".into()))?;
+        Ok(query)
+    }
+
+    /// Hard deletes the user and every row they own in a single transaction. Records they created
+    /// on shared or other users' data are handed over to the earliest superuser, or to the
+    /// deleted user placeholder when there is none. The audit log keeps its entries, without the
+    /// values of the deleted rows and without the user as their actor.
+    pub async fn delete_user(pool: &PgPool, user_id: Uuid) -> Result<DeletedUserFiles> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_erased_user(&mut tx, user_id).await?;
+
+        let superuser_id = sqlx::query_scalar!(
+            "
+            SELECT
+                id
+            FROM
+                users_user
+            WHERE
+                is_superuser
+                AND id != $1
+            ORDER BY
+                created_at
+            LIMIT
+                1
+            ",
+            user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        let new_owner_id = match superuser_id {
+            Some(id) => id,
+            None => Self::placeholder_user_id(&mut tx).await?,
+        };
+
+        let progress_photos = sqlx::query_scalar!(
+            "SELECT file_name FROM progress_photo WHERE user_id = $1",
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        let data_exports = sqlx::query_scalar!(
+            r#"SELECT file_name AS "file_name!" FROM data_export WHERE user_id = $1 AND file_name IS NOT NULL"#,
+            user_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for statement in OWNED_ROW_DELETES {
+            sqlx::query(statement)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for (table, column) in Self::audit_columns(&mut tx).await? {
+            if column == "created_by_id" {
+                sqlx::query(&format!(
+                    "UPDATE {table} SET created_by_id = $2 WHERE created_by_id = $1"
+                ))
+                .bind(user_id)
+                .bind(new_owner_id)
+                .execute(&mut *tx)
+                .await?;
+            } else {
+                sqlx::query(&format!(
+                    "UPDATE {table} SET {column} = NULL WHERE {column} = $1"
+                ))
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+        sqlx::query!("DELETE FROM users_user WHERE id = $1", user_id)
+            .execute(&mut *tx)
+            .await?;
+        AuditLog::clear_actor(&mut tx, user_id).await?;
+
+        tx.commit().await?;
+        Ok(DeletedUserFiles {
+            progress_photos,
+            data_exports,
+        })
+    }
+
+    /// Looks the placeholder up by email before creating it, so a real user holding its username
+    /// cannot block the sweep.
+    async fn placeholder_user_id(tx: &mut Transaction<'_, Postgres>) -> Result<Uuid> {
+        let existing = sqlx::query_scalar!(
+            "SELECT id FROM users_user WHERE email = $1",
+            DELETED_USER_EMAIL
+        )
+        .fetch_optional(&mut **tx)
+        .await?;
+        if let Some(id) = existing {
+            return Ok(id);
+        }
+        let id = sqlx::query_scalar!(
+            "
+            INSERT INTO
+                users_user (name, username, password, email, is_active)
+            VALUES
+                ('Deleted user', $1, '!', $2, FALSE)
+            RETURNING
+                id
+            ",
+            DELETED_USER_USERNAME,
+            DELETED_USER_EMAIL
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(id)
+    }
+
+    /// The `created_by_id` and `updated_by_id` audit columns of every table, read from the schema
+    /// so new tables are picked up without being listed here. Shared records such as foods, brands
+    /// and movements outlive the user who created them.
+    async fn audit_columns(tx: &mut Transaction<'_, Postgres>) -> Result<Vec<(String, String)>> {
+        let query = sqlx::query!(
+            r#"
+            SELECT
+                t1.table_name AS "table_name!",
+                t1.column_name AS "column_name!"
+            FROM
+                information_schema.columns t1
+                JOIN information_schema.tables t2 ON t2.table_schema = t1.table_schema
+                AND t2.table_name = t1.table_name
+            WHERE
+                t1.table_schema = CURRENT_SCHEMA()
+                AND t1.column_name IN ('created_by_id', 'updated_by_id')
+                AND t2.table_type = 'BASE TABLE'
+            ORDER BY
+                t1.table_name,
+                t1.column_name
+            "#
+        )
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|row| (row.table_name, row.column_name))
+        .collect();
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::util::test_fixture::{create_food, create_food_log, create_user};
+
+    use super::*;
+
+    async fn food_owner(pool: &PgPool, food_id: Uuid) -> (String, bool) {
+        sqlx::query_as(
+            "
+            SELECT
+                t2.email,
+                t2.is_active
+            FROM
+                food t1
+                JOIN users_user t2 ON t2.id = t1.created_by_id
+            WHERE
+                t1.id = $1
+            ",
+        )
+        .bind(food_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    async fn user_exists(pool: &PgPool, user_id: Uuid) -> bool {
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM users_user WHERE id = $1)")
+            .bind(user_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    /// Foreign keys to the user that neither cascade nor are audit columns must be cleared by
+    /// one of the owned row deletes, otherwise deleting the user fails.
+    #[sqlx::test]
+    async fn test_owned_row_deletes_cover_schema(pool: PgPool) {
+        let columns: Vec<(String, String)> = sqlx::query_as(
+            "
+            SELECT
+                t1.conrelid::regclass::TEXT,
+                t2.attname::TEXT
+            FROM
+                pg_constraint t1
+                JOIN pg_attribute t2 ON t2.attrelid = t1.conrelid
+                AND t2.attnum = ANY (t1.conkey)
+            WHERE
+                t1.contype = 'f'
+                AND t1.confrelid = 'users_user'::regclass
+                AND t1.confdeltype NOT IN ('c', 'n')
+                AND t2.attname NOT IN ('created_by_id', 'updated_by_id')
+            ",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert!(!columns.is_empty());
+        for (table, column) in columns {
+            let prefix = format!("DELETE FROM {table} WHERE ");
+            assert!(
+                OWNED_ROW_DELETES.iter().any(|statement| statement
+                    .strip_prefix(&prefix)
+                    .is_some_and(|filter| filter.contains(&format!("{column} = $1")))),
+                "{table}.{column} is not cleared before the user is deleted"
+            );
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_delete_user_hands_records_to_superuser(pool: PgPool) {
+        let admin_id = create_user(&pool, "admin").await;
+        sqlx::query("UPDATE users_user SET is_superuser = TRUE WHERE id = $1")
+            .bind(admin_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let user_id = create_user(&pool, "leaving").await;
+        let food_id = create_food(&pool, user_id).await;
+        create_food_log(&pool, user_id, food_id, Utc::now().date_naive(), 1).await;
+
+        AccountDeletion::delete_user(&pool, user_id).await.unwrap();
+
+        assert!(!user_exists(&pool, user_id).await);
+        assert_eq!(
+            food_owner(&pool, food_id).await,
+            ("admin@example.com".to_string(), true)
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_delete_user_without_superuser(pool: PgPool) {
+        create_user(&pool, "deleted-user").await;
+        let user_id = create_user(&pool, "leaving").await;
+        let food_id = create_food(&pool, user_id).await;
+        create_food_log(&pool, user_id, food_id, Utc::now().date_naive(), 1).await;
+
+        AccountDeletion::delete_user(&pool, user_id).await.unwrap();
+
+        assert!(!user_exists(&pool, user_id).await);
+        assert_eq!(
+            food_owner(&pool, food_id).await,
+            (DELETED_USER_EMAIL.to_string(), false)
+        );
+
+        // A second deletion reuses the placeholder.
+        let user_id = create_user(&pool, "second").await;
+        AccountDeletion::delete_user(&pool, user_id).await.unwrap();
+        assert!(!user_exists(&pool, user_id).await);
+    }
+
+    #[sqlx::test]
+    async fn test_delete_user_scrubs_audit_log(pool: PgPool) {
+        let user_id = create_user(&pool, "leaving").await;
+        let food_id = create_food(&pool, user_id).await;
+        let food_log_id =
+            create_food_log(&pool, user_id, food_id, Utc::now().date_naive(), 1).await;
+
+        AccountDeletion::delete_user(&pool, user_id).await.unwrap();
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM audit_log WHERE actor_id = $1")
+            .bind(user_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        let changes: Vec<String> = sqlx::query_scalar(
+            "SELECT changes::TEXT FROM audit_log WHERE entity_id = ANY ($1) ORDER BY action",
+        )
+        .bind([user_id, food_log_id])
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(changes.len(), 4);
+        assert!(changes.iter().all(|changes| changes == "{}"));
+
+        // The food outlives the user, so its entries keep their values.
+        let changes: String = sqlx::query_scalar(
+            "SELECT changes::TEXT FROM audit_log WHERE entity_id = $1 AND action = 1",
+        )
+        .bind(food_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_ne!(changes, "{}");
+    }
+}
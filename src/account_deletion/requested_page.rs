@@ -0,0 +1,25 @@
+use leptos::*;
+use leptos_meta::Title;
+
+#[component]
+pub fn AccountDeletionRequestedPage() -> impl IntoView {
+    view! {
+        <Title text="Account Deletion Requested"/>
+        <main class="p-4 lg:p-8">
+            <div class="p-4 mx-auto max-w-md bg-white border shadow-md">
+                <h1 class="mb-4 text-xl font-bold">"Account Deletion Requested"</h1>
+                <p class="mb-4">
+                    "Your account has been deactivated and is scheduled for permanent deletion."
+                </p>
+                <p class="mb-4">
+                    "If you change your mind, follow the link in the email we have sent you to cancel the deletion and reactivate your account."
+                </p>
+                <p>
+                    <a href="/" class="text-blue-500 hover:underline">
+                        "Home"
+                    </a>
+                </p>
+            </div>
+        </main>
+    }
+}
@@ -0,0 +1,68 @@
+use std::fs;
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use crate::data_export::model::DataExport;
+use crate::progress_photo::model::ProgressPhoto;
+
+use super::model::AccountDeletion;
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Periodically hard deletes accounts whose grace period has passed. Failures are logged and the
+/// deletion is retried with a growing delay, up to `AccountDeletion::MAX_ATTEMPTS` times.
+pub async fn run_account_deletion_sweeper(pool: PgPool) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        sweep(&pool).await;
+    }
+}
+
+async fn sweep(pool: &PgPool) {
+    let due = match AccountDeletion::get_due(pool).await {
+        Ok(due) => due,
+        Err(err) => {
+            tracing::error!("account deletion sweep failed: {:?}", err);
+            return;
+        }
+    };
+    for deletion in due {
+        match AccountDeletion::delete_user(pool, deletion.user_id).await {
+            Ok(files) => {
+                if let Ok(upload_dir) = ProgressPhoto::upload_dir() {
+                    for file_name in files.progress_photos {
+                        let _ = fs::remove_file(upload_dir.join(file_name));
+                    }
+                }
+                if let Ok(export_dir) = DataExport::export_dir() {
+                    for file_name in files.data_exports {
+                        let _ = fs::remove_file(export_dir.join(file_name));
+                    }
+                }
+                tracing::info!("Account deleted: {}", deletion.user_id);
+            }
+            Err(err) => {
+                tracing::error!(
+                    "account deletion failed for {}: {:?}",
+                    deletion.user_id,
+                    err
+                );
+                match AccountDeletion::record_failure(pool, deletion.user_id, &format!("{:?}", err))
+                    .await
+                {
+                    Ok(deletion) if deletion.failed_attempts >= AccountDeletion::MAX_ATTEMPTS => {
+                        tracing::error!(
+                            "account deletion for {} stopped after {} attempts",
+                            deletion.user_id,
+                            deletion.failed_attempts
+                        )
+                    }
+                    Ok(_) => (),
+                    Err(err) => tracing::error!("account deletion failure not recorded: {:?}", err),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::param::get_username;
+
+use super::model::{AdherenceSummary, DEFAULT_TOLERANCE_PCT, TOLERANCE_OPTIONS};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
+
+#[server(endpoint = "adherence-summary")]
+pub async fn get_adherence_summary(
+    username: String,
+    tolerance: i32,
+) -> Result<AdherenceSummary, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietLog).await?;
+    if !TOLERANCE_OPTIONS.contains(&tolerance) {
+        return Err(ServerFnError::new("Invalid tolerance"));
+    }
+    let summary = AdherenceSummary::get_by_username(&pool, &username, tolerance)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(summary)
+}
+
+#[component]
+pub fn AdherencePanel() -> impl IntoView {
+    let params = use_params_map();
+    let username = move || get_username(&params);
+    let tolerance = RwSignal::new(DEFAULT_TOLERANCE_PCT);
+
+    let resource = Resource::new(
+        move || (username(), tolerance.get()),
+        |(username, tolerance)| get_adherence_summary(username, tolerance),
+    );
+    let response = move || {
+        resource.and_then(|data| {
+            view! { <AdherenceSummaryTable data=data.clone()/> }
+        })
+    };
+    let options = TOLERANCE_OPTIONS.map(|value| {
+        view! {
+            <option value=value selected=move || tolerance.get() == value>
+                {format!("±{}%", value)}
+            </option>
+        }
+    });
+    view! {
+        <div class="p-4 bg-white border">
+            <div class="flex justify-between items-center mb-2">
+                <h2 class="text-xl font-bold">"Adherence"</h2>
+                <select
+                    class="py-1 px-2 text-sm border"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev)
+                            .parse()
+                            .unwrap_or(DEFAULT_TOLERANCE_PCT);
+                        tolerance.set(value);
+                    }
+                >
+                    {options}
+                </select>
+            </div>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+pub fn AdherenceSummaryTable(data: AdherenceSummary) -> impl IntoView {
+    let adherence_pct = format!("{:.0}%", data.adherence_pct());
+    view! {
+        <table class="w-full border-collapse table-fixed">
+            <tbody>
+                <tr>
+                    <td class="p-2 w-1/2 text-left border">"Days Logged"</td>
+                    <td class="p-2 w-1/2 text-right border">{data.logged_days}</td>
+                </tr>
+                <tr>
+                    <td class="p-2 text-left border">"Days on Target"</td>
+                    <td class="p-2 text-right border">
+                        {data.adherent_days} " / " {data.target_days}
+                        <span class="text-xs text-gray-400">" (" {adherence_pct} ")"</span>
+                    </td>
+                </tr>
+                <tr>
+                    <td class="p-2 text-left border">"Logging Streak"</td>
+                    <td class="p-2 text-right border">
+                        {data.current_logging_streak} " days"
+                        <span class="text-xs text-gray-400">
+                            " (best " {data.longest_logging_streak} ")"
+                        </span>
+                    </td>
+                </tr>
+                <tr>
+                    <td class="p-2 text-left border">"Adherence Streak"</td>
+                    <td class="p-2 text-right border">
+                        {data.current_adherence_streak} " days"
+                        <span class="text-xs text-gray-400">
+                            " (best " {data.longest_adherence_streak} ")"
+                        </span>
+                    </td>
+                </tr>
+            </tbody>
+        </table>
+        <p class="mt-2 text-xs text-gray-500">
+            "A day is on target when calories, protein, carbs and fat are all within ±"
+            {data.tolerance} "% of the diet target."
+        </p>
+    }
+}
@@ -0,0 +1,5 @@
+pub mod component;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod service;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Tolerance the user statistics pass to the `diet_streak` function.
+pub const DEFAULT_TOLERANCE_PCT: i32 = 10;
+
+pub const TOLERANCE_OPTIONS: [i32; 4] = [5, 10, 15, 20];
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct AdherenceSummary {
+    pub tolerance: i32,
+    pub logged_days: i64,
+    pub target_days: i64,
+    pub adherent_days: i64,
+    pub current_logging_streak: i64,
+    pub longest_logging_streak: i64,
+    pub current_adherence_streak: i64,
+    pub longest_adherence_streak: i64,
+}
@@ -0,0 +1,106 @@
+use sqlx::PgPool;
+
+use crate::error::Result;
+
+use super::model::AdherenceSummary;
+
+impl AdherenceSummary {
+    /// Days are scored and streaks counted by the `diet_day_is_adherent` and `diet_streak`
+    /// database functions, which the user statistics also use.
+    pub async fn get_by_username(
+        pool: &PgPool,
+        username: &str,
+        tolerance: i32,
+    ) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                $2::INTEGER AS "tolerance!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        diet_adherence_day
+                    WHERE
+                        user_id = t1.id
+                ) AS "logged_days!",
+                (
+                    SELECT
+                        COUNT(target_energy)
+                    FROM
+                        diet_adherence_day
+                    WHERE
+                        user_id = t1.id
+                ) AS "target_days!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        diet_adherence_day t3
+                    WHERE
+                        t3.user_id = t1.id
+                        AND diet_day_is_adherent (t3, $2)
+                ) AS "adherent_days!",
+                t2.current_logging_streak AS "current_logging_streak!",
+                t2.longest_logging_streak AS "longest_logging_streak!",
+                t2.current_adherence_streak AS "current_adherence_streak!",
+                t2.longest_adherence_streak AS "longest_adherence_streak!"
+            FROM
+                users_user t1
+                CROSS JOIN LATERAL diet_streak (t1.id, $2) t2
+            WHERE
+                t1.username = $1
+            "#,
+            username,
+            tolerance,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::util::test_fixture::{
+        create_diet_target, create_food, create_food_log, create_user,
+    };
+
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_summary_scores_days_and_streaks(pool: PgPool) {
+        let user_id = create_user(&pool, "adherence").await;
+        let food_id = create_food(&pool, user_id).await;
+        create_diet_target(&pool, user_id).await;
+
+        // 20 servings is exactly on target and 23 servings is 15% over.
+        let today = Utc::now().date_naive();
+        for (days_ago, quantity) in [(6, 20), (5, 20), (4, 20), (2, 23), (1, 20)] {
+            let date = today - Duration::days(days_ago);
+            create_food_log(&pool, user_id, food_id, date, quantity).await;
+        }
+
+        let summary = AdherenceSummary::get_by_username(&pool, "adherence", 10)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(summary.logged_days, 5);
+        assert_eq!(summary.target_days, 5);
+        assert_eq!(summary.adherent_days, 4);
+        assert_eq!(summary.current_logging_streak, 2);
+        assert_eq!(summary.longest_logging_streak, 3);
+        assert_eq!(summary.current_adherence_streak, 1);
+        assert_eq!(summary.longest_adherence_streak, 3);
+
+        let summary = AdherenceSummary::get_by_username(&pool, "adherence", 15)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(summary.adherent_days, 5);
+        assert_eq!(summary.current_adherence_streak, 2);
+    }
+}
@@ -0,0 +1,32 @@
+use rust_decimal::Decimal;
+
+use super::model::AdherenceSummary;
+
+impl AdherenceSummary {
+    pub fn adherence_pct(&self) -> Decimal {
+        if self.target_days == 0 {
+            return Decimal::ZERO;
+        }
+        Decimal::from(self.adherent_days) / Decimal::from(self.target_days) * Decimal::new(100, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adherence_pct() {
+        let summary = AdherenceSummary {
+            target_days: 4,
+            adherent_days: 3,
+            ..AdherenceSummary::default()
+        };
+        assert_eq!(summary.adherence_pct(), Decimal::from(75));
+    }
+
+    #[test]
+    fn test_adherence_pct_without_targets() {
+        assert_eq!(AdherenceSummary::default().adherence_pct(), Decimal::ZERO);
+    }
+}
@@ -0,0 +1,193 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::audit_log::action::AuditAction;
+use crate::audit_log::model::AuditLog;
+use crate::component::input::FilterInput;
+use crate::component::paginator::Paginator;
+use crate::component::select::FilterSelect;
+use crate::component::template::{
+    ErrorComponent, ListLoadingComponent, ListNotFoundComponent, Loading,
+};
+use crate::util::datetime::format_datetime;
+use crate::util::misc::ListResponse;
+use crate::util::param::{extract_page, extract_param, extract_size};
+
+#[server]
+pub async fn get_admin_audit_log_list(
+    entity: String,
+    entity_id: String,
+    action: String,
+    actor: String,
+    order: String,
+    size: i64,
+    page: i64,
+) -> Result<ListResponse<AuditLog>, ServerFnError> {
+    crate::auth::service::extract_superuser_from_request()?;
+    let pool = crate::setup::get_pool()?;
+    let count = AuditLog::count(&pool, &entity, &entity_id, &action, &actor).await?;
+    let results = AuditLog::filter(
+        &pool, &entity, &entity_id, &action, &actor, &order, size, page,
+    )
+    .await?;
+    Ok(ListResponse { count, results })
+}
+
+#[component]
+pub fn AdminAuditLogListPage() -> impl IntoView {
+    let query = use_query_map();
+    let entity = move || extract_param(&query, "entity");
+    let entity_id = move || extract_param(&query, "entity_id");
+    let action = move || extract_param(&query, "action");
+    let actor = move || extract_param(&query, "actor");
+    let order = move || extract_param(&query, "order");
+    let size = move || extract_size(&query);
+    let page = move || extract_page(&query);
+
+    let resource = Resource::new(
+        move || {
+            (
+                entity(),
+                entity_id(),
+                action(),
+                actor(),
+                order(),
+                size(),
+                page(),
+            )
+        },
+        |(entity, entity_id, action, actor, order, size, page)| {
+            get_admin_audit_log_list(entity, entity_id, action, actor, order, size, page)
+        },
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.results
+                    .iter()
+                    .map(|data| view! { <AdminAuditLogListItem data=data.clone()/> })
+                    .collect_view()
+            }
+        })
+    };
+
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().map(|res| res.count))
+        })
+    };
+    let sort_options = vec![
+        ("-created_at", "Newest"),
+        ("created_at", "Oldest"),
+        ("entity", "Entity (A-z)"),
+        ("actor_username", "Actor (A-z)"),
+    ];
+    view! {
+        <Title text="Admin - Audit Log"/>
+        <main class="lg:p-4">
+            <div class="p-4 mb-2 bg-white">
+                <header class="mb-2">
+                    <h2 class="text-base font-bold">"Admin - Audit Log"</h2>
+                    <p class="text-gray-400">
+                        "Results: " <Transition fallback=Loading>{count}</Transition>
+                    </p>
+                </header>
+
+                <section class="flex flex-wrap gap-2 mb-2 whitespace-nowrap">
+                    <Form method="GET" action="" class="contents">
+                        <input type="hidden" name="page" value=1/>
+                        <input type="hidden" name="size" value=size/>
+                        <FilterInput name="entity" value=Signal::derive(entity)/>
+                        <FilterInput name="entity_id" value=Signal::derive(entity_id)/>
+                        <FilterInput name="actor" value=Signal::derive(actor)/>
+                        <FilterSelect
+                            name="action"
+                            value=Signal::derive(action)
+                            options=AuditAction::to_filter_options()
+                        />
+                        <FilterSelect
+                            name="order"
+                            value=Signal::derive(order)
+                            options=sort_options
+                        />
+                    </Form>
+                </section>
+            </div>
+
+            <section class="grid overflow-auto grid-cols-[auto_auto_auto_auto_1fr] p-4 bg-white">
+                <div class="p-2 font-bold border-b">"Date"</div>
+                <div class="p-2 font-bold border-b">"Entity"</div>
+                <div class="p-2 font-bold border-b">"Action"</div>
+                <div class="p-2 font-bold border-b">"Actor"</div>
+                <div class="p-2 font-bold border-b">"Changes"</div>
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors=errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+            </section>
+
+            <div class="p-4 bg-white">
+                <Form method="GET" action="" class="contents">
+                    <input type="hidden" name="entity" value=entity/>
+                    <input type="hidden" name="entity_id" value=entity_id/>
+                    <input type="hidden" name="action" value=action/>
+                    <input type="hidden" name="actor" value=actor/>
+                    <input type="hidden" name="order" value=order/>
+                    <input type="hidden" name="page" value=page/>
+                    <Transition>
+                        <Paginator count/>
+                    </Transition>
+                </Form>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn AdminAuditLogListItem(data: AuditLog) -> impl IntoView {
+    let created_at = format_datetime(&Some(data.created_at));
+    let action = data.get_action().to_string();
+    let history = format!(
+        "/admin/audit-log?entity={}&entity_id={}",
+        data.entity, data.entity_id
+    );
+    let actor = match (data.actor_id, data.actor_username) {
+        (Some(actor_id), Some(username)) => view! {
+            <A href=format!("/admin/users/{}", actor_id) class="text-blue-500 hover:underline">
+                {username}
+            </A>
+        }
+        .into_view(),
+        (Some(actor_id), None) => actor_id.to_string().into_view(),
+        _ => "-".into_view(),
+    };
+
+    view! {
+        <div class="contents group">
+            <div class="p-2 whitespace-nowrap group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {created_at}
+            </div>
+            <div class="p-2 whitespace-nowrap group-hover:bg-gray-200 group-odd:bg-gray-50">
+                <A href=history class="text-blue-500 hover:underline">
+                    {data.entity}
+                </A>
+                <div class="text-xs text-gray-500">{data.entity_id.to_string()}</div>
+            </div>
+            <div class="p-2 whitespace-nowrap group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {action}
+            </div>
+            <div class="p-2 whitespace-nowrap group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {actor}
+            </div>
+            <div class="p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                <code class="text-xs break-all">{data.changes}</code>
+            </div>
+        </div>
+    }
+}
@@ -13,9 +13,10 @@ pub async fn admin_follower_create(
     follower_id: Uuid,
     status: i32,
 ) -> Result<(), ServerFnError> {
-    crate::auth::service::extract_superuser_from_request()?;
+    let user = crate::auth::service::extract_superuser_from_request()?;
     let pool = crate::setup::get_pool()?;
-    crate::follower::model::Follower::create(&pool, user_id, follower_id, status).await?;
+    crate::follower::model::Follower::create(&pool, user_id, follower_id, status, user.actor_id())
+        .await?;
     Ok(())
 }
 
@@ -23,9 +23,9 @@ pub async fn admin_follower_update(
     follower_id: Uuid,
     status: i32,
 ) -> Result<(), ServerFnError> {
-    extract_superuser_from_request()?;
+    let user = extract_superuser_from_request()?;
     let pool = get_pool()?;
-    Follower::update(&pool, id, user_id, follower_id, status).await?;
+    Follower::update(&pool, id, user_id, follower_id, status, user.actor_id()).await?;
     Ok(())
 }
 
@@ -47,6 +47,22 @@ pub fn AdminLayout() -> impl IntoView {
                     >
                         <IconUsers/>
                     </Link>
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        text="Reports"
+                        href="/admin/reports"
+                    >
+                        <IconUsers/>
+                    </Link>
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        text="Audit Log"
+                        href="/admin/audit-log"
+                    >
+                        <IconUsers/>
+                    </Link>
                     <div class="flex-grow"></div>
                 </section>
                 <section class="col-span-4 lg:col-span-10">
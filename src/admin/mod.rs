@@ -1,8 +1,11 @@
 pub mod admin_follower_list_page;
+pub mod audit_log_list_page;
 pub mod detail_page;
 pub mod follower_create_form;
 pub mod follower_detail_page;
 pub mod layout;
+pub mod report_detail_page;
+pub mod report_list_page;
 pub mod router;
 pub mod user_block_create_form;
 pub mod user_block_detail_page;
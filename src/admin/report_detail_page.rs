@@ -0,0 +1,253 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::component::select::FieldSelect;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::user_report::model::{ModerationAction, UserReportDetail, UserReportQuery};
+use crate::user_report::status::ReportStatus;
+use crate::util::datetime::format_datetime;
+use crate::util::param::UuidParam;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::extract_superuser_from_request,
+    error::Error,
+    notification::service::{notify_report_resolved, notify_warning},
+    setup::get_pool,
+    user_report::model::UserReport,
+    user_session::model::UserSession,
+};
+
+#[server]
+pub async fn get_admin_report_detail(id: Uuid) -> Result<UserReportDetail, ServerFnError> {
+    extract_superuser_from_request()?;
+    let pool = get_pool()?;
+    let report = UserReportQuery::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let actions = ModerationAction::all_by_report_id(&pool, id).await?;
+    Ok(UserReportDetail { report, actions })
+}
+
+/// Records the action on the report and tells the reporter the outcome. A suspended user is
+/// signed out of every session.
+#[server]
+pub async fn admin_report_resolve(
+    id: Uuid,
+    action: i32,
+    note: String,
+) -> Result<(), ServerFnError> {
+    let user = extract_superuser_from_request()?;
+    let pool = get_pool()?;
+    let report = UserReportQuery::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let note = note.trim();
+    ModerationAction::validate(action, note)?;
+    let action = ReportStatus::from(action);
+    report.can_resolve(&user, action)?;
+
+    UserReport::resolve(&pool, report.id, report.reported_id, user.id, action, note).await?;
+    match action {
+        ReportStatus::Warned => notify_warning(&pool, report.reported_id, note).await,
+        ReportStatus::Suspended => {
+            UserSession::delete_all_by_user_id(&pool, report.reported_id).await?;
+        }
+        _ => (),
+    }
+    notify_report_resolved(&pool, &report, action).await;
+    Ok(())
+}
+
+#[component]
+pub fn AdminReportDetailPage() -> impl IntoView {
+    let params = use_params::<UuidParam>();
+    let id = move || params.with(|p| p.as_ref().map(|p| p.id).unwrap_or_default());
+
+    let action = Action::<AdminReportResolve, _>::server();
+    let resource = Resource::new(
+        move || (id(), action.version().get()),
+        |(id, _)| get_admin_report_detail(id),
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            view! {
+                <AdminReportDetailComponent data=data.report.clone()/>
+                <ModerationActionList actions=data.actions.clone()/>
+            }
+        })
+    };
+
+    view! {
+        <Title text="Admin - Report"/>
+        <main class="p-4">
+            <div class="grid grid-cols-4 gap-4 md:grid-cols-8 lg:grid-cols-12">
+                <div class="col-span-4 md:col-span-8">
+                    <div class="p-4 mb-4 bg-white border shadow-sm">
+                        <h2 class="mb-4 text-xl font-bold">"Report"</h2>
+                        <Transition fallback=LoadingComponent>
+                            <ErrorBoundary fallback=|errors| {
+                                view! { <ErrorComponent errors/> }
+                            }>{response}</ErrorBoundary>
+                        </Transition>
+                    </div>
+                </div>
+                <div class="col-span-4">
+                    <div class="p-4 mb-4 bg-white border shadow-sm">
+                        <h2 class="mb-4 text-xl font-bold">"Take Action"</h2>
+                        <AdminReportResolveForm id action/>
+                    </div>
+                </div>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn AdminReportDetailComponent(data: UserReportQuery) -> impl IntoView {
+    let reason = data.get_reason().to_string();
+    let status = data.get_status().to_string();
+    let created_at = format_datetime(&Some(data.created_at));
+    let resolved_at = format_datetime(&data.resolved_at);
+    let resolved_by = data.resolved_by_username.unwrap_or_else(|| "-".to_string());
+    let account = if data.reported_is_active {
+        "Active"
+    } else {
+        "Inactive"
+    };
+    let comment = data.comment_body.map(|body| {
+        view! {
+            <blockquote class="p-2 mb-4 whitespace-pre-wrap bg-gray-100 border-l-4">{body}</blockquote>
+        }
+    });
+    let link = data.link.map(|link| {
+        view! {
+            <A href=link class="block mb-4 text-blue-500 hover:underline">
+                "View reported content"
+            </A>
+        }
+    });
+
+    view! {
+        <table class="mb-4 w-full border-collapse">
+            <tbody>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Reported"</th>
+                    <td class="p-2 w-1/2 text-right border">
+                        <A
+                            href=format!("/admin/users/{}", data.reported_id)
+                            class="text-blue-500 hover:underline"
+                        >
+                            {data.reported_username}
+                        </A>
+                        " (" {account} ")"
+                    </td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Reporter"</th>
+                    <td class="p-2 w-1/2 text-right border">
+                        <A
+                            href=format!("/admin/users/{}", data.reporter_id)
+                            class="text-blue-500 hover:underline"
+                        >
+                            {data.reporter_username}
+                        </A>
+                    </td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Reason"</th>
+                    <td class="p-2 w-1/2 text-right border">{reason}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Status"</th>
+                    <td class="p-2 w-1/2 text-right border">{status}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Created"</th>
+                    <td class="p-2 w-1/2 text-right border">{created_at}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Resolved"</th>
+                    <td class="p-2 w-1/2 text-right border">{resolved_at}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Resolved by"</th>
+                    <td class="p-2 w-1/2 text-right border">{resolved_by}</td>
+                </tr>
+            </tbody>
+        </table>
+        <p class="mb-4 whitespace-pre-wrap">{data.details}</p>
+        {comment}
+        {link}
+    }
+}
+
+#[component]
+fn ModerationActionList(actions: Vec<ModerationAction>) -> impl IntoView {
+    if actions.is_empty() {
+        return view! { <p class="text-gray-500">"No action taken yet."</p> }.into_view();
+    }
+    let rows = actions
+        .into_iter()
+        .map(|action| {
+            let created_at = format_datetime(&Some(action.created_at));
+            let action_name = action.get_action().to_string();
+            let actor = action.actor_username.unwrap_or_else(|| "-".to_string());
+            view! {
+                <div class="contents group">
+                    <div class="p-2 border-b group-odd:bg-gray-50">{created_at}</div>
+                    <div class="p-2 border-b group-odd:bg-gray-50">{actor}</div>
+                    <div class="p-2 border-b group-odd:bg-gray-50">{action_name}</div>
+                    <div class="p-2 border-b group-odd:bg-gray-50">{action.note}</div>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <h3 class="mb-2 font-bold">"History"</h3>
+        <section class="grid grid-cols-[auto_auto_auto_1fr]">
+            <div class="p-2 font-bold border-b">"Date"</div>
+            <div class="p-2 font-bold border-b">"Moderator"</div>
+            <div class="p-2 font-bold border-b">"Action"</div>
+            <div class="p-2 font-bold border-b">"Note"</div>
+            {rows}
+        </section>
+    }
+    .into_view()
+}
+
+#[component]
+fn AdminReportResolveForm<F>(
+    id: F,
+    action: Action<AdminReportResolve, Result<(), ServerFnError>>,
+) -> impl IntoView
+where
+    F: Fn() -> Uuid + 'static,
+{
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error =
+        move || extract_other_errors(action_value, &["non_field_errors", "action", "note"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    view! {
+        <p class="mb-4">
+            "The reporter is notified of the outcome. A warning is sent to the user with the note."
+        </p>
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action>
+            <input type="hidden" name="id" value=move || id().to_string()/>
+            <FieldSelect name="action" options=ReportStatus::to_action_options()/>
+            <TextInput action_value name="note"/>
+            <SubmitButton loading=action_loading label="Save"/>
+        </ActionForm>
+    }
+}
@@ -0,0 +1,182 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::input::FilterInput;
+use crate::component::paginator::Paginator;
+use crate::component::select::FilterSelect;
+use crate::component::template::{
+    ErrorComponent, ListLoadingComponent, ListNotFoundComponent, Loading,
+};
+use crate::user_report::model::UserReportQuery;
+use crate::user_report::reason::ReportReason;
+use crate::user_report::status::ReportStatus;
+use crate::util::datetime::format_datetime;
+use crate::util::misc::ListResponse;
+use crate::util::param::{extract_page, extract_param, extract_size};
+
+#[server]
+pub async fn get_admin_report_list(
+    reported: String,
+    reason: String,
+    status: String,
+    order: String,
+    size: i64,
+    page: i64,
+) -> Result<ListResponse<UserReportQuery>, ServerFnError> {
+    crate::auth::service::extract_superuser_from_request()?;
+    let pool = crate::setup::get_pool()?;
+    let count = UserReportQuery::count(&pool, &reported, &reason, &status).await?;
+    let results =
+        UserReportQuery::filter(&pool, &reported, &reason, &status, &order, size, page).await?;
+    Ok(ListResponse { count, results })
+}
+
+#[component]
+pub fn AdminReportListPage() -> impl IntoView {
+    let query = use_query_map();
+    let reported = move || extract_param(&query, "reported");
+    let reason = move || extract_param(&query, "reason");
+    let status = move || extract_param(&query, "status");
+    let order = move || extract_param(&query, "order");
+    let size = move || extract_size(&query);
+    let page = move || extract_page(&query);
+
+    let resource = Resource::new(
+        move || (reported(), reason(), status(), order(), size(), page()),
+        |(reported, reason, status, order, size, page)| {
+            get_admin_report_list(reported, reason, status, order, size, page)
+        },
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.results
+                    .iter()
+                    .map(|data| view! { <AdminReportListItem data=data.clone()/> })
+                    .collect_view()
+            }
+        })
+    };
+
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().map(|res| res.count))
+        })
+    };
+    let mut reason_options = vec![("", "All")];
+    reason_options.extend(ReportReason::to_form_options());
+    let sort_options = vec![
+        ("created_at", "Oldest"),
+        ("-created_at", "Newest"),
+        ("reported_username", "Reported (A-z)"),
+        ("-reported_username", "Reported (Z-a)"),
+        ("-resolved_at", "Recently Resolved"),
+    ];
+    view! {
+        <Title text="Admin - Reports"/>
+        <main class="lg:p-4">
+            <div class="p-4 mb-2 bg-white">
+                <header class="mb-2">
+                    <h2 class="text-base font-bold">"Admin - Reports"</h2>
+                    <p class="text-gray-400">
+                        "Results: " <Transition fallback=Loading>{count}</Transition>
+                    </p>
+                </header>
+
+                <section class="flex flex-wrap gap-2 mb-2 whitespace-nowrap">
+                    <Form method="GET" action="" class="contents">
+                        <input type="hidden" name="page" value=1/>
+                        <input type="hidden" name="size" value=size/>
+                        <FilterInput name="reported" value=Signal::derive(reported)/>
+                        <FilterSelect
+                            name="reason"
+                            value=Signal::derive(reason)
+                            options=reason_options
+                        />
+                        <FilterSelect
+                            name="status"
+                            value=Signal::derive(status)
+                            options=ReportStatus::to_filter_options()
+                        />
+                        <FilterSelect
+                            name="order"
+                            value=Signal::derive(order)
+                            options=sort_options
+                        />
+                    </Form>
+                </section>
+            </div>
+
+            <section class="grid overflow-auto grid-cols-6 p-4 whitespace-nowrap bg-white">
+                <div class="p-2 font-bold border-b">"Reported"</div>
+                <div class="p-2 font-bold border-b">"Reporter"</div>
+                <div class="p-2 font-bold border-b">"Reason"</div>
+                <div class="p-2 font-bold border-b">"Status"</div>
+                <div class="p-2 font-bold border-b">"Created"</div>
+                <div class="p-2 font-bold border-b">"Review"</div>
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors=errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+            </section>
+
+            <div class="p-4 bg-white">
+                <Form method="GET" action="" class="contents">
+                    <input type="hidden" name="reported" value=reported/>
+                    <input type="hidden" name="reason" value=reason/>
+                    <input type="hidden" name="status" value=status/>
+                    <input type="hidden" name="order" value=order/>
+                    <input type="hidden" name="page" value=page/>
+                    <Transition>
+                        <Paginator count/>
+                    </Transition>
+                </Form>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn AdminReportListItem(data: UserReportQuery) -> impl IntoView {
+    let created_at = format_datetime(&Some(data.created_at));
+    let reason = data.get_reason().to_string();
+    let status = data.get_status().to_string();
+    let kind = data.comment_id.map(|_| " (comment)");
+
+    view! {
+        <div class="contents group">
+            <div class="flex items-center p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                <A
+                    href=format!("/admin/users/{}", data.reported_id)
+                    class="text-blue-500 hover:underline"
+                >
+                    {data.reported_username}
+                </A>
+                {kind}
+            </div>
+            <div class="flex items-center p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {data.reporter_username}
+            </div>
+            <div class="flex items-center p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {reason}
+            </div>
+            <div class="flex items-center p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {status}
+            </div>
+            <div class="flex items-center p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                {created_at}
+            </div>
+            <div class="flex items-center p-2 group-hover:bg-gray-200 group-odd:bg-gray-50">
+                <A href=format!("/admin/reports/{}", data.id) class="text-blue-500 hover:underline">
+                    "Review"
+                </A>
+            </div>
+        </div>
+    }
+}
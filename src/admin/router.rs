@@ -2,9 +2,12 @@ use leptos::*;
 use leptos_router::*;
 
 use super::admin_follower_list_page::AdminFollowerListPage;
+use super::audit_log_list_page::AdminAuditLogListPage;
 use super::detail_page::AdminDetailPage;
 use super::follower_detail_page::AdminFollowerDetailPage;
 use super::layout::AdminLayout;
+use super::report_detail_page::AdminReportDetailPage;
+use super::report_list_page::AdminReportListPage;
 use super::user_block_detail_page::AdminUserBlockDetailPage;
 use super::user_block_list_page::AdminUserBlockListPage;
 use super::user_create_page::AdminUserCreatePage;
@@ -26,6 +29,9 @@ pub fn AdminRouter() -> impl IntoView {
                 <Route path="/blocked-users" view=AdminUserBlockListPage/>
                 <Route path="/blocked-users/:id" view=AdminUserBlockDetailPage/>
                 <Route path="/user-stats" view=AdminUserStatListPage/>
+                <Route path="/reports" view=AdminReportListPage/>
+                <Route path="/reports/:id" view=AdminReportDetailPage/>
+                <Route path="/audit-log" view=AdminAuditLogListPage/>
                 <Route path="/" view=AdminDetailPage/>
             </Route>
         </Route>
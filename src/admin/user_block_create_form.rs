@@ -13,10 +13,17 @@ async fn admin_user_block_create(
     blocked_id: Uuid,
     blocked_status: String,
 ) -> Result<(), ServerFnError> {
-    crate::auth::service::extract_superuser_from_request()?;
+    let user = crate::auth::service::extract_superuser_from_request()?;
     let pool = crate::setup::get_pool()?;
     let status = blocked_status.parse::<i32>().unwrap_or_default();
-    crate::user_block::model::UserBlock::create(&pool, blocker_id, blocked_id, status).await?;
+    crate::user_block::model::UserBlock::create(
+        &pool,
+        blocker_id,
+        blocked_id,
+        status,
+        user.actor_id(),
+    )
+    .await?;
     Ok(())
 }
 
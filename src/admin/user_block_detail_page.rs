@@ -20,9 +20,17 @@ async fn admin_user_block_update(
     blocked_id: Uuid,
     blocked_status: i32,
 ) -> Result<(), ServerFnError> {
-    crate::auth::service::extract_superuser_from_request()?;
+    let user = crate::auth::service::extract_superuser_from_request()?;
     let pool = crate::setup::get_pool()?;
-    UserBlock::update(&pool, id, blocker_id, blocked_id, blocked_status).await?;
+    UserBlock::update(
+        &pool,
+        id,
+        blocker_id,
+        blocked_id,
+        blocked_status,
+        user.actor_id(),
+    )
+    .await?;
     Ok(())
 }
 
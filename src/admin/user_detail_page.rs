@@ -4,18 +4,23 @@ use leptos_router::*;
 
 use uuid::Uuid;
 
+use crate::auth::impersonation::ImpersonationStartForm;
 use crate::auth::model::User;
 use crate::component::button::SubmitButton;
 use crate::component::checkbox::CheckboxInput;
 use crate::component::input::TextInput;
 use crate::component::select::FieldSelect;
 use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::login_throttle::model::LoginThrottle;
 use crate::util::datetime::format_datetime;
 use crate::util::param::UuidParam;
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::service::extract_superuser_from_request, error::Error, setup::get_pool};
+use crate::{
+    auth::service::extract_superuser_from_request, error::Error,
+    notification::kind::NotificationKind, notification::model::Notification, setup::get_pool,
+};
 
 #[server]
 pub async fn get_admin_user_detail(id: Uuid) -> Result<User, ServerFnError> {
@@ -37,7 +42,7 @@ pub async fn admin_user_update(
     is_superuser: bool,
     privacy_level: i32,
 ) -> Result<(), ServerFnError> {
-    extract_superuser_from_request()?;
+    let user = extract_superuser_from_request()?;
     let pool = get_pool()?;
     User::update(
         &pool,
@@ -50,6 +55,7 @@ pub async fn admin_user_update(
         Some(is_staff),
         Some(is_superuser),
         privacy_level,
+        user.actor_id(),
     )
     .await?;
     Ok(())
@@ -60,9 +66,44 @@ pub async fn admin_user_password_change(
     id: Uuid,
     new_password: String,
 ) -> Result<(), ServerFnError> {
+    let user = extract_superuser_from_request()?;
+    let pool = get_pool()?;
+    User::update_password(&pool, id, &new_password, user.actor_id()).await?;
+    Ok(())
+}
+
+#[server]
+pub async fn get_admin_user_login_throttle_list(
+    id: Uuid,
+) -> Result<Vec<LoginThrottle>, ServerFnError> {
     extract_superuser_from_request()?;
     let pool = get_pool()?;
-    User::update_password(&pool, id, &new_password).await?;
+    let query = LoginThrottle::all_by_user_id(&pool, id).await?;
+    Ok(query)
+}
+
+#[server]
+pub async fn admin_user_unlock(id: Uuid) -> Result<(), ServerFnError> {
+    extract_superuser_from_request()?;
+    let pool = get_pool()?;
+    LoginThrottle::delete_by_user_id(&pool, id).await?;
+    Ok(())
+}
+
+#[server]
+pub async fn admin_user_send_message(id: Uuid, message: String) -> Result<(), ServerFnError> {
+    let user = extract_superuser_from_request()?;
+    let pool = get_pool()?;
+    Notification::validate(&message)?;
+    Notification::create(
+        &pool,
+        id,
+        Some(user.id),
+        NotificationKind::System,
+        &message,
+        None,
+    )
+    .await?;
     Ok(())
 }
 
@@ -72,6 +113,8 @@ pub fn AdminUserDetailPage() -> impl IntoView {
     let id = move || params.with(|p| p.as_ref().map(|p| p.id).unwrap_or_default());
 
     let action_password_change = Action::<AdminUserPasswordChange, _>::server();
+    let action_unlock = Action::<AdminUserUnlock, _>::server();
+    let action_send_message = Action::<AdminUserSendMessage, _>::server();
     let action = Action::<AdminUserUpdate, _>::server();
 
     let username = RwSignal::new(String::new());
@@ -96,6 +139,15 @@ pub fn AdminUserDetailPage() -> impl IntoView {
     let password_form_response = move || {
         resource.and_then(|data| view! { <AdminUserPasswordChangeForm id=data.id.clone() action=action_password_change/> })
     };
+    let throttle_resource = Resource::new(
+        move || (id(), action_unlock.version().get()),
+        |(id, _)| get_admin_user_login_throttle_list(id),
+    );
+    let throttle_response = move || {
+        throttle_resource.and_then(|data| {
+            view! { <LoginThrottleComponent id=id() data=data.clone() action=action_unlock/> }
+        })
+    };
     let view_on_site_url = move || username.with(|username| format!("/users/{}", username));
     view! {
         <Title text="Admin User Detail"/>
@@ -135,6 +187,22 @@ pub fn AdminUserDetailPage() -> impl IntoView {
                             }>{password_form_response}</ErrorBoundary>
                         </Transition>
                     </div>
+                    <div class="p-4 mb-4 bg-white border shadow-sm">
+                        <h2 class="mb-4 text-xl font-bold">"Login Lockout"</h2>
+                        <Transition fallback=LoadingComponent>
+                            <ErrorBoundary fallback=|errors| {
+                                view! { <ErrorComponent errors/> }
+                            }>{throttle_response}</ErrorBoundary>
+                        </Transition>
+                    </div>
+                    <div class="p-4 mb-4 bg-white border shadow-sm">
+                        <h2 class="mb-4 text-xl font-bold">"Send Message"</h2>
+                        <AdminUserSendMessageForm id action=action_send_message/>
+                    </div>
+                    <div class="p-4 mb-4 bg-white border shadow-sm">
+                        <h2 class="mb-4 text-xl font-bold">"Impersonate"</h2>
+                        <ImpersonationStartForm id/>
+                    </div>
                 </div>
             </div>
         </main>
@@ -206,6 +274,85 @@ pub fn AdminUserPasswordChangeForm(
     }
 }
 
+#[component]
+pub fn AdminUserSendMessageForm<F>(
+    id: F,
+    action: Action<AdminUserSendMessage, Result<(), ServerFnError>>,
+) -> impl IntoView
+where
+    F: Fn() -> Uuid + 'static,
+{
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors", "message"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let is_sent = move || action_value.with(|value| matches!(value, Some(Ok(()))));
+    view! {
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <Show when=is_sent>
+            <div class="mb-4 font-bold text-green-600">"Message sent."</div>
+        </Show>
+        <ActionForm action>
+            <input type="hidden" name="id" value=move || id().to_string()/>
+            <TextInput action_value name="message"/>
+            <SubmitButton loading=action_loading label="Send Message"/>
+        </ActionForm>
+    }
+}
+
+#[component]
+pub fn LoginThrottleComponent(
+    id: Uuid,
+    data: Vec<LoginThrottle>,
+    action: Action<AdminUserUnlock, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let non_field_errors = move || get_non_field_errors(action.value());
+    let now = chrono::Utc::now();
+    let status = if data.iter().any(|throttle| throttle.is_locked_out(now)) {
+        "Locked out"
+    } else if data.iter().any(|throttle| throttle.is_blocked(now)) {
+        "Delayed after failed attempts"
+    } else {
+        "Not locked"
+    };
+    let rows = data
+        .into_iter()
+        .map(|throttle| {
+            let blocked_until = format_datetime(&throttle.blocked_until);
+            let last_failure_at = format_datetime(&Some(throttle.last_failure_at));
+            view! {
+                <tr>
+                    <td class="p-2 border">{throttle.get_scope().to_string()}</td>
+                    <td class="p-2 text-right border">{throttle.failure_count}</td>
+                    <td class="p-2 text-right border">{last_failure_at}</td>
+                    <td class="p-2 text-right border">{blocked_until}</td>
+                </tr>
+            }
+        })
+        .collect_view();
+    view! {
+        <p class="mb-4 font-bold">{status}</p>
+        <table class="overflow-hidden mb-4 w-full border-collapse">
+            <thead>
+                <tr>
+                    <th class="p-2 text-left border">"Scope"</th>
+                    <th class="p-2 text-right border">"Failures"</th>
+                    <th class="p-2 text-right border">"Last Failure"</th>
+                    <th class="p-2 text-right border">"Blocked Until"</th>
+                </tr>
+            </thead>
+            <tbody>{rows}</tbody>
+        </table>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action>
+            <input type="hidden" name="id" value=id.to_string()/>
+            <SubmitButton loading=action_loading label="Unlock Account"/>
+        </ActionForm>
+    }
+}
+
 #[component]
 pub fn UserDetailComponent(data: User) -> impl IntoView {
     let created_at = format_datetime(&Some(data.created_at));
@@ -109,6 +109,20 @@ pub fn AdminUserStatListPage() -> impl IntoView {
             "muscle_group_created_count",
             "Muscle Group Created Count (Asc)",
         ),
+        ("-current_logging_streak", "Current Logging Streak (Desc)"),
+        ("current_logging_streak", "Current Logging Streak (Asc)"),
+        ("-longest_logging_streak", "Longest Logging Streak (Desc)"),
+        ("longest_logging_streak", "Longest Logging Streak (Asc)"),
+        (
+            "-current_adherence_streak",
+            "Current Adherence Streak (Desc)",
+        ),
+        ("current_adherence_streak", "Current Adherence Streak (Asc)"),
+        (
+            "-longest_adherence_streak",
+            "Longest Adherence Streak (Desc)",
+        ),
+        ("longest_adherence_streak", "Longest Adherence Streak (Asc)"),
     ];
 
     view! {
@@ -0,0 +1,162 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::api_token::scope::ApiScope;
+use crate::auth::model::User;
+use crate::diet::model::{Diet, DietFoodQuery};
+use crate::error::{Error, Result};
+use crate::food::model::FoodQuery;
+use crate::meal_of_day::model::MealOfDay;
+use crate::user_privacy::section::PrivacySection;
+
+use super::extractor::{ApiJson, ApiUser};
+use super::model::DietCreateRequest;
+
+#[utoipa::path(
+    get,
+    path = "/users/{username}/diet/{date}",
+    tag = "diet",
+    params(("username" = String, Path, description = "Username"), ("date" = NaiveDate, Path, description = "Date as YYYY-MM-DD")),
+    responses(
+        (status = 200, description = "Diet log entries for the date", body = Vec<DietFoodQuery>),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn diet_day(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Path((username, date)): Path<(String, NaiveDate)>,
+) -> Result<Json<Vec<DietFoodQuery>>> {
+    api_user.require_scope(ApiScope::DietRead)?;
+    User::check_view_permission(&pool, &api_user.user, &username, PrivacySection::DietLog).await?;
+    let query = DietFoodQuery::all_by_username_date(&pool, &username, date).await?;
+    Ok(Json(query))
+}
+
+#[utoipa::path(
+    post,
+    path = "/diet",
+    tag = "diet",
+    request_body = DietCreateRequest,
+    responses(
+        (status = 201, description = "Diet log entry created", body = DietFoodQuery),
+        (status = 400, description = "Validation failed", body = ErrorBody),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn diet_create(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    ApiJson(data): ApiJson<DietCreateRequest>,
+) -> Result<(StatusCode, Json<DietFoodQuery>)> {
+    api_user.require_scope(ApiScope::DietWrite)?;
+    let user = api_user.user;
+    Diet::can_create(&pool, &user, user.id).await?;
+    Diet::validate(data.date, data.quantity)?;
+
+    let food = FoodQuery::get_by_slug(&pool, &data.food_slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let meal_of_day = MealOfDay::get_by_slug(&pool, &data.meal_of_day_slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let quantity = food.data_measurement.to_quantity_modifier(&data.quantity);
+
+    let diet = Diet::create(
+        &pool,
+        data.date,
+        user.id,
+        meal_of_day.id,
+        food.id,
+        quantity,
+        user.id,
+    )
+    .await?;
+    let query = DietFoodQuery::get_by_id(&pool, diet.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok((StatusCode::CREATED, Json(query)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/diet/{id}",
+    tag = "diet",
+    params(("id" = Uuid, Path, description = "Diet log entry id")),
+    responses(
+        (status = 204, description = "Diet log entry deleted"),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn diet_delete(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode> {
+    api_user.require_scope(ApiScope::DietWrite)?;
+    let diet = Diet::get_by_id(&pool, id).await?.ok_or(Error::NotFound)?;
+    diet.can_delete(&pool, &api_user.user).await?;
+    Diet::delete(&pool, diet.id, api_user.user.id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+
+    use crate::auth::model::RequestUser;
+    use crate::coach::model::CoachClient;
+    use crate::util::test_fixture::{create_food, create_food_log, create_user};
+
+    use super::*;
+
+    fn api_user(id: Uuid) -> ApiUser {
+        ApiUser {
+            user: RequestUser {
+                id,
+                is_active: true,
+                ..Default::default()
+            },
+            scopes: vec![ApiScope::DietWrite],
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_diet_delete_allows_coaches_only(pool: PgPool) {
+        let client_id = create_user(&pool, "client").await;
+        let coach_id = create_user(&pool, "coach").await;
+        let stranger_id = create_user(&pool, "stranger").await;
+        CoachClient::create(&pool, client_id, "coach")
+            .await
+            .unwrap();
+        let food_id = create_food(&pool, client_id).await;
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let diet_id = create_food_log(&pool, client_id, food_id, date, 1).await;
+
+        let response = diet_delete(
+            api_user(stranger_id),
+            Extension(pool.clone()),
+            Path(diet_id),
+        )
+        .await;
+        assert_eq!(response.into_response().status(), StatusCode::FORBIDDEN);
+
+        let response =
+            diet_delete(api_user(coach_id), Extension(pool.clone()), Path(diet_id)).await;
+        assert_eq!(response.unwrap(), StatusCode::NO_CONTENT);
+        assert!(Diet::get_by_id(&pool, diet_id).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,82 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::api_token::scope::ApiScope;
+use crate::auth::model::User;
+use crate::diet_target::model::{DietTarget, DietTargetGramKg, DietTargetInput, DietTargetQuery};
+use crate::error::{Error, Result};
+use crate::user_privacy::section::PrivacySection;
+
+use super::extractor::{ApiJson, ApiUser};
+use super::model::DietTargetCreateRequest;
+
+/// Returns the diet target in effect on the date, i.e. the latest one set on or before it.
+#[utoipa::path(
+    get,
+    path = "/users/{username}/diet-targets/{date}",
+    tag = "diet_target",
+    params(("username" = String, Path, description = "Username"), ("date" = NaiveDate, Path, description = "Date as YYYY-MM-DD")),
+    responses(
+        (status = 200, description = "Diet target in effect on the date", body = DietTargetQuery),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn diet_target_detail(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Path((username, date)): Path<(String, NaiveDate)>,
+) -> Result<Json<DietTargetQuery>> {
+    api_user.require_scope(ApiScope::TargetRead)?;
+    User::check_view_permission(&pool, &api_user.user, &username, PrivacySection::DietTarget)
+        .await?;
+    let query = DietTargetQuery::get_latest_by_username_date(&pool, &username, date)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(Json(query))
+}
+
+#[utoipa::path(
+    post,
+    path = "/diet-targets",
+    tag = "diet_target",
+    request_body = DietTargetCreateRequest,
+    responses(
+        (status = 201, description = "Diet target created", body = DietTargetQuery),
+        (status = 400, description = "Validation failed", body = ErrorBody),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn diet_target_create(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    ApiJson(data): ApiJson<DietTargetCreateRequest>,
+) -> Result<(StatusCode, Json<DietTargetQuery>)> {
+    api_user.require_scope(ApiScope::TargetWrite)?;
+    let user = api_user.user;
+    DietTarget::can_create(&pool, &user, user.id).await?;
+
+    let data = DietTargetGramKg {
+        user_id: user.id,
+        date: data.date,
+        weight: data.weight,
+        protein_per_kg: data.protein_per_kg,
+        carbohydrate_per_kg: data.carbohydrate_per_kg,
+        fat_per_kg: data.fat_per_kg,
+    };
+    data.validate()?;
+    let date = data.date;
+    DietTarget::create(&pool, DietTargetInput::from(data), user.id).await?;
+
+    let query = DietTargetQuery::get_by_username_date(&pool, &user.username, date)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok((StatusCode::CREATED, Json(query)))
+}
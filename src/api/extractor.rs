@@ -0,0 +1,125 @@
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use axum::{async_trait, Extension, Json};
+use serde::de::DeserializeOwned;
+use sqlx::PgPool;
+
+use crate::api_token::model::ApiToken;
+use crate::api_token::scope::ApiScope;
+use crate::auth::model::{RequestUser, User};
+use crate::error::{Error, Result};
+use crate::util::validation_error::ValidationError;
+
+/// The user behind a personal access token sent as `Authorization: Bearer <token>`.
+#[derive(Debug, Clone)]
+pub struct ApiUser {
+    pub user: RequestUser,
+    pub scopes: Vec<ApiScope>,
+}
+
+impl ApiUser {
+    pub fn require_scope(&self, scope: ApiScope) -> Result<()> {
+        self.scopes
+            .contains(&scope)
+            .then_some(())
+            .ok_or(Error::Forbidden)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ApiUser
+where
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let Extension(pool) = Extension::<PgPool>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| Error::InternalServer)?;
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(Error::Unauthorized)?;
+
+        let token_hash = ApiToken::hash_token(token.trim());
+        let api_token = ApiToken::get_by_token_hash(&pool, &token_hash)
+            .await?
+            .ok_or(Error::InvalidToken)?;
+        if api_token.is_expired(chrono::Utc::now()) {
+            return Err(Error::InvalidToken);
+        }
+
+        let user = User::get_by_id(&pool, api_token.user_id)
+            .await?
+            .ok_or(Error::InvalidToken)?;
+        user.is_authenticated()?;
+
+        ApiToken::update_last_used(&pool, api_token.id).await?;
+
+        Ok(ApiUser {
+            user: RequestUser::from(user),
+            scopes: api_token.get_scopes(),
+        })
+    }
+}
+
+/// A JSON request body. Unlike `axum::Json`, a malformed body or missing content type is
+/// rejected with an `ErrorBody` like every other API error.
+#[derive(Debug, Clone)]
+pub struct ApiJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| {
+                let mut errors = ValidationError::new();
+                errors.add_non_field_error(rejection.body_text());
+                Error::from(errors)
+            })?;
+        Ok(ApiJson(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::{to_bytes, Body};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+
+    use crate::api::model::DietCreateRequest;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_api_json_rejection_returns_error_body() {
+        for content_type in ["application/json", "text/plain"] {
+            let req = Request::builder()
+                .header(CONTENT_TYPE, content_type)
+                .body(Body::from("{\"quantity\": "))
+                .unwrap();
+            let response = ApiJson::<DietCreateRequest>::from_request(req, &())
+                .await
+                .unwrap_err()
+                .into_response();
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["error"], "validation");
+            assert!(body["fields"]["field_errors"]["non_field_errors"][0].is_string());
+        }
+    }
+}
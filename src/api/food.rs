@@ -0,0 +1,71 @@
+use axum::extract::{Path, Query};
+use axum::{Extension, Json};
+use sqlx::PgPool;
+
+use crate::api_token::scope::ApiScope;
+use crate::error::{Error, Result};
+use crate::food::model::FoodQuery;
+use crate::util::misc::ListResponse;
+
+use super::extractor::ApiUser;
+use super::model::FoodListParams;
+
+#[utoipa::path(
+    get,
+    path = "/foods",
+    tag = "food",
+    params(FoodListParams),
+    responses(
+        (status = 200, description = "Paginated food list", body = FoodListResponse),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn food_list(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Query(params): Query<FoodListParams>,
+) -> Result<Json<ListResponse<FoodQuery>>> {
+    api_user.require_scope(ApiScope::FoodRead)?;
+    let size = params.size.unwrap_or(25).clamp(1, 100);
+    let page = params.page.unwrap_or(1).max(1);
+    let count = FoodQuery::count(&pool, &params.search, &params.brand, &params.serving).await?;
+    let results = FoodQuery::filter(
+        &pool,
+        &params.search,
+        &params.brand,
+        &params.serving,
+        Some(api_user.user.id),
+        &params.order,
+        size,
+        page,
+    )
+    .await?;
+    Ok(Json(ListResponse { count, results }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/foods/{slug}",
+    tag = "food",
+    params(("slug" = String, Path, description = "Food slug")),
+    responses(
+        (status = 200, description = "Food detail", body = FoodQuery),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn food_detail(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Path(slug): Path<String>,
+) -> Result<Json<FoodQuery>> {
+    api_user.require_scope(ApiScope::FoodRead)?;
+    let food = FoodQuery::get_by_slug(&pool, &slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(Json(food))
+}
@@ -0,0 +1,10 @@
+pub mod diet;
+pub mod diet_target;
+pub mod extractor;
+pub mod food;
+pub mod model;
+pub mod openapi;
+pub mod progress;
+pub mod router;
+pub mod user;
+pub mod workout;
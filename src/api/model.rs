@@ -0,0 +1,62 @@
+use chrono::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::api_token::scope::ApiScope;
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct ApiUserResponse {
+    pub id: Uuid,
+    pub username: String,
+    pub scopes: Vec<ApiScope>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FoodListParams {
+    #[serde(default)]
+    pub search: String,
+    #[serde(default)]
+    pub brand: String,
+    #[serde(default)]
+    pub serving: String,
+    /// Sort column, prefixed with `-` for descending order.
+    #[serde(default)]
+    pub order: String,
+    /// Results per page, between 1 and 100. Defaults to 25.
+    pub size: Option<i64>,
+    pub page: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct DietCreateRequest {
+    pub date: NaiveDate,
+    pub meal_of_day_slug: String,
+    pub food_slug: String,
+    /// Grams or millilitres for weighed foods, otherwise the number of servings.
+    pub quantity: Decimal,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct WorkoutCreateRequest {
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct ProgressCreateRequest {
+    pub date: NaiveDate,
+    pub weight_kg: Option<Decimal>,
+    pub energy_burnt: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+pub struct DietTargetCreateRequest {
+    pub date: NaiveDate,
+    pub weight: Decimal,
+    pub protein_per_kg: Decimal,
+    pub carbohydrate_per_kg: Decimal,
+    pub fat_per_kg: Decimal,
+}
@@ -0,0 +1,212 @@
+use axum::Json;
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::api_token::scope::ApiScope;
+use crate::diet::model::DietFoodQuery;
+use crate::diet_target::model::DietTargetQuery;
+use crate::error::ErrorBody;
+use crate::food::data_measurement::DataMeasurement;
+use crate::food::model::FoodQuery;
+use crate::progress::model::ProgressQuery;
+use crate::util::misc::FoodListResponse;
+use crate::workout::model::{
+    WorkoutBase, WorkoutDayExerciseQuery, WorkoutDayQuery, WorkoutDaySetQuery,
+};
+
+use super::model::{
+    ApiUserResponse, DietCreateRequest, DietTargetCreateRequest, ProgressCreateRequest,
+    WorkoutCreateRequest,
+};
+use super::{diet, diet_target, food, progress, user, workout};
+
+/// Path the OpenAPI document is served at, relative to `/api/v1`.
+pub const OPENAPI_PATH: &str = "/openapi.json";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Trackedfitness API", version = "1"),
+    servers((url = "/api/v1")),
+    paths(
+        user::me,
+        food::food_list,
+        food::food_detail,
+        diet::diet_day,
+        diet::diet_create,
+        diet::diet_delete,
+        workout::workout_day,
+        workout::workout_create,
+        progress::progress_detail,
+        progress::progress_create,
+        diet_target::diet_target_detail,
+        diet_target::diet_target_create,
+    ),
+    components(schemas(
+        ApiScope,
+        ApiUserResponse,
+        DataMeasurement,
+        DietCreateRequest,
+        DietFoodQuery,
+        DietTargetCreateRequest,
+        DietTargetQuery,
+        ErrorBody,
+        FoodListResponse,
+        FoodQuery,
+        ProgressCreateRequest,
+        ProgressQuery,
+        WorkoutBase,
+        WorkoutCreateRequest,
+        WorkoutDayExerciseQuery,
+        WorkoutDayQuery,
+        WorkoutDaySetQuery,
+    )),
+    modifiers(&BearerTokenAddon),
+)]
+pub struct ApiDoc;
+
+struct BearerTokenAddon;
+
+impl Modify for BearerTokenAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_token",
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+            )
+        }
+    }
+}
+
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use regex::Regex;
+    use serde_json::Value;
+    use sqlx::PgPool;
+    use tower::Service;
+
+    use crate::api::router::{api_router, api_routes};
+
+    use super::*;
+
+    /// Method and path pairs the API router answers, with axum `:param` segments rewritten to
+    /// OpenAPI `{param}` form.
+    ///
+    /// Each routed path is requested with every method and without a token, so anything other
+    /// than a 405 means the method is handled. The pool is never connected to because the token
+    /// is checked first.
+    async fn router_operations() -> BTreeSet<(String, String)> {
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let mut router = api_router::<()>(pool);
+        let param = Regex::new(r":(\w+)").unwrap();
+        let mut operations = BTreeSet::new();
+        for (path, _) in api_routes::<()>() {
+            if path == OPENAPI_PATH {
+                continue;
+            }
+            let uri = param.replace_all(path, "00000000-0000-0000-0000-000000000000");
+            for method in [
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ] {
+                let request = Request::builder()
+                    .method(method.clone())
+                    .uri(uri.as_ref())
+                    .body(Body::empty())
+                    .unwrap();
+                let response = router.call(request).await.unwrap();
+                assert_ne!(
+                    response.status(),
+                    StatusCode::NOT_FOUND,
+                    "{path} is not routed"
+                );
+                if response.status() != StatusCode::METHOD_NOT_ALLOWED {
+                    let path = param.replace_all(path, "{$1}").to_string();
+                    operations.insert((method.as_str().to_lowercase(), path));
+                }
+            }
+        }
+        operations
+    }
+
+    fn spec() -> Value {
+        serde_json::to_value(ApiDoc::openapi()).unwrap()
+    }
+
+    fn spec_operations(spec: &Value) -> BTreeSet<(String, String)> {
+        spec["paths"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .flat_map(|(path, item)| {
+                item.as_object()
+                    .unwrap()
+                    .keys()
+                    .map(move |method| (method.clone(), path.clone()))
+            })
+            .collect()
+    }
+
+    fn collect_refs(value: &Value, refs: &mut BTreeSet<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value) {
+                        ("$ref", Value::String(reference)) => {
+                            refs.insert(reference.clone());
+                        }
+                        _ => collect_refs(value, refs),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter().for_each(|value| collect_refs(value, refs)),
+            _ => (),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spec_matches_router() {
+        let routed = router_operations().await;
+        assert!(!routed.is_empty());
+        assert_eq!(routed, spec_operations(&spec()));
+    }
+
+    #[test]
+    fn test_spec_refs_resolve() {
+        let spec = spec();
+        let mut refs = BTreeSet::new();
+        collect_refs(&spec, &mut refs);
+        for reference in refs {
+            let name = reference
+                .strip_prefix("#/components/schemas/")
+                .unwrap_or_else(|| panic!("unexpected reference {reference}"));
+            assert!(
+                spec["components"]["schemas"].get(name).is_some(),
+                "schema {name} is not registered in ApiDoc"
+            );
+        }
+    }
+
+    #[test]
+    fn test_operations_require_bearer_token() {
+        let spec = spec();
+        assert!(spec["components"]["securitySchemes"]["bearer_token"].is_object());
+        for (method, path) in spec_operations(&spec) {
+            let security = &spec["paths"][&path][&method]["security"];
+            assert!(
+                security.to_string().contains("bearer_token"),
+                "{method} {path} is missing bearer token security"
+            );
+        }
+    }
+}
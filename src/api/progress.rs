@@ -0,0 +1,84 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::api_token::scope::ApiScope;
+use crate::auth::model::User;
+use crate::error::{Error, Result};
+use crate::progress::model::{Progress, ProgressQuery};
+use crate::user_privacy::section::PrivacySection;
+
+use super::extractor::{ApiJson, ApiUser};
+use super::model::ProgressCreateRequest;
+
+#[utoipa::path(
+    get,
+    path = "/users/{username}/progress/{date}",
+    tag = "progress",
+    params(("username" = String, Path, description = "Username"), ("date" = NaiveDate, Path, description = "Date as YYYY-MM-DD")),
+    responses(
+        (status = 200, description = "Progress for the date", body = ProgressQuery),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn progress_detail(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Path((username, date)): Path<(String, NaiveDate)>,
+) -> Result<Json<ProgressQuery>> {
+    api_user.require_scope(ApiScope::ProgressRead)?;
+    User::check_view_permission(&pool, &api_user.user, &username, PrivacySection::Progress).await?;
+    let query = ProgressQuery::get_by_username_date(&pool, &username, date)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(Json(query))
+}
+
+#[utoipa::path(
+    post,
+    path = "/progress",
+    tag = "progress",
+    request_body = ProgressCreateRequest,
+    responses(
+        (status = 201, description = "Progress created", body = ProgressQuery),
+        (status = 400, description = "Validation failed", body = ErrorBody),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn progress_create(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    ApiJson(data): ApiJson<ProgressCreateRequest>,
+) -> Result<(StatusCode, Json<ProgressQuery>)> {
+    api_user.require_scope(ApiScope::ProgressWrite)?;
+    let user = api_user.user;
+    Progress::can_create(&user, user.id).await?;
+    Progress::validate(
+        data.date,
+        data.weight_kg,
+        data.energy_burnt,
+        data.notes.clone(),
+    )?;
+
+    Progress::create(
+        &pool,
+        user.id,
+        data.date,
+        data.weight_kg,
+        data.energy_burnt,
+        data.notes,
+        user.id,
+    )
+    .await?;
+    let query = ProgressQuery::get_by_username_date(&pool, &user.username, data.date)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok((StatusCode::CREATED, Json(query)))
+}
@@ -0,0 +1,53 @@
+use axum::routing::{delete, get, post, MethodRouter};
+use axum::{Extension, Router};
+use sqlx::PgPool;
+
+use super::diet::{diet_create, diet_day, diet_delete};
+use super::diet_target::{diet_target_create, diet_target_detail};
+use super::food::{food_detail, food_list};
+use super::openapi::{openapi_json, OPENAPI_PATH};
+use super::progress::{progress_create, progress_detail};
+use super::user::me;
+use super::workout::{workout_create, workout_day};
+
+/// JSON API authenticated with personal access tokens, mounted at `/api/v1`.
+///
+/// Reads are available for any user the token owner can view, while writes always apply to
+/// the token owner. The OpenAPI document describing these routes is served unauthenticated at
+/// `/api/v1/openapi.json`.
+pub fn api_router<S>(pool: PgPool) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    api_routes()
+        .into_iter()
+        .fold(Router::new(), |router, (path, method_router)| {
+            router.route(path, method_router)
+        })
+        .layer(Extension(pool))
+}
+
+/// Every path served by the API, kept as a list so the OpenAPI tests can probe each one.
+pub fn api_routes<S>() -> Vec<(&'static str, MethodRouter<S>)>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    vec![
+        (OPENAPI_PATH, get(openapi_json)),
+        ("/me", get(me)),
+        ("/foods", get(food_list)),
+        ("/foods/:slug", get(food_detail)),
+        ("/diet", post(diet_create)),
+        ("/diet/:id", delete(diet_delete)),
+        ("/users/:username/diet/:date", get(diet_day)),
+        ("/workouts", post(workout_create)),
+        ("/users/:username/workouts/:date", get(workout_day)),
+        ("/progress", post(progress_create)),
+        ("/users/:username/progress/:date", get(progress_detail)),
+        ("/diet-targets", post(diet_target_create)),
+        (
+            "/users/:username/diet-targets/:date",
+            get(diet_target_detail),
+        ),
+    ]
+}
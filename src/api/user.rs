@@ -0,0 +1,22 @@
+use axum::Json;
+
+use super::extractor::ApiUser;
+use super::model::ApiUserResponse;
+
+#[utoipa::path(
+    get,
+    path = "/me",
+    tag = "user",
+    responses(
+        (status = 200, description = "Token owner and granted scopes", body = ApiUserResponse),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn me(api_user: ApiUser) -> Json<ApiUserResponse> {
+    Json(ApiUserResponse {
+        id: api_user.user.id,
+        username: api_user.user.username,
+        scopes: api_user.scopes,
+    })
+}
@@ -0,0 +1,65 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::api_token::scope::ApiScope;
+use crate::auth::model::User;
+use crate::error::{Error, Result};
+use crate::user_privacy::section::PrivacySection;
+use crate::workout::model::{WorkoutBase, WorkoutDayQuery};
+
+use super::extractor::{ApiJson, ApiUser};
+use super::model::WorkoutCreateRequest;
+
+#[utoipa::path(
+    get,
+    path = "/users/{username}/workouts/{date}",
+    tag = "workout",
+    params(("username" = String, Path, description = "Username"), ("date" = NaiveDate, Path, description = "Date as YYYY-MM-DD")),
+    responses(
+        (status = 200, description = "Workouts for the date", body = Vec<WorkoutDayQuery>),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+        (status = 404, description = "Not found", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn workout_day(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    Path((username, date)): Path<(String, NaiveDate)>,
+) -> Result<Json<Vec<WorkoutDayQuery>>> {
+    api_user.require_scope(ApiScope::WorkoutRead)?;
+    User::check_view_permission(&pool, &api_user.user, &username, PrivacySection::Workout).await?;
+    let query = WorkoutDayQuery::all_by_username_date(&pool, &username, date).await?;
+    Ok(Json(query))
+}
+
+#[utoipa::path(
+    post,
+    path = "/workouts",
+    tag = "workout",
+    request_body = WorkoutCreateRequest,
+    responses(
+        (status = 201, description = "Workout created", body = WorkoutBase),
+        (status = 400, description = "Validation failed", body = ErrorBody),
+        (status = 401, description = "Missing, invalid or expired token", body = ErrorBody),
+        (status = 403, description = "Missing scope or no permission", body = ErrorBody),
+    ),
+    security(("bearer_token" = [])),
+)]
+pub async fn workout_create(
+    api_user: ApiUser,
+    Extension(pool): Extension<PgPool>,
+    ApiJson(data): ApiJson<WorkoutCreateRequest>,
+) -> Result<(StatusCode, Json<WorkoutBase>)> {
+    api_user.require_scope(ApiScope::WorkoutWrite)?;
+    let user = User::get_by_id(&pool, api_user.user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    WorkoutBase::can_create(&pool, &user, &api_user.user).await?;
+    let workout = WorkoutBase::create(&pool, user.id, data.date, user.id).await?;
+    Ok((StatusCode::CREATED, Json(workout)))
+}
@@ -0,0 +1,11 @@
+pub mod model;
+pub mod page;
+#[cfg(feature = "ssr")]
+pub mod permission;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod scope;
+#[cfg(feature = "ssr")]
+pub mod service;
+#[cfg(feature = "ssr")]
+pub mod validate;
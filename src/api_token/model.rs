@@ -0,0 +1,53 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use super::scope::ApiScope;
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub token_prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub created_by_id: Uuid,
+}
+
+impl ApiToken {
+    /// Days until a new token expires, where 0 means it never expires.
+    pub const EXPIRY_DAY_OPTIONS: [i64; 4] = [30, 90, 365, 0];
+
+    pub fn expiry_form_options() -> Vec<(&'static str, &'static str)> {
+        let options = [
+            ("30", "30 days"),
+            ("90", "90 days"),
+            ("365", "1 year"),
+            ("0", "No expiry"),
+        ];
+        options.to_vec()
+    }
+
+    pub fn get_scopes(&self) -> Vec<ApiScope> {
+        self.scopes
+            .iter()
+            .filter_map(|scope| ApiScope::from_str(scope).ok())
+            .collect()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Returned once on creation; the plain token is never stored.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiTokenCreated {
+    pub token: String,
+    pub data: ApiToken,
+}
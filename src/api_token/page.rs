@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::component::select::FieldSelect;
+use crate::component::template::{ErrorComponent, ListLoadingComponent, ListNotFoundComponent};
+use crate::util::datetime::format_datetime;
+use crate::util::validation_error::{extract_other_errors, get_field_errors, get_non_field_errors};
+
+use super::model::{ApiToken, ApiTokenCreated};
+use super::scope::ApiScope;
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, error::Error, setup::get_pool};
+
+#[server(endpoint = "api-token-list")]
+pub async fn get_api_token_list() -> Result<Vec<ApiToken>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let query = ApiToken::all_by_user_id(&pool, user.id).await?;
+    Ok(query)
+}
+
+#[server(endpoint = "api-token-create")]
+pub async fn api_token_create(
+    name: String,
+    expires_in_days: i64,
+    scopes: Option<HashSet<String>>,
+) -> Result<ApiTokenCreated, ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+
+    let scopes = scopes.unwrap_or_default();
+    ApiToken::validate(&name, expires_in_days, &scopes)?;
+
+    let expires_at = (expires_in_days > 0)
+        .then(|| chrono::Utc::now() + chrono::TimeDelta::days(expires_in_days));
+    let mut scopes: Vec<String> = scopes.into_iter().collect();
+    scopes.sort();
+
+    let (token, token_prefix, token_hash) = ApiToken::generate_token();
+    let data = ApiToken::create(
+        &pool,
+        user.id,
+        &name,
+        &token_prefix,
+        &token_hash,
+        &scopes,
+        expires_at,
+    )
+    .await?;
+    Ok(ApiTokenCreated { token, data })
+}
+
+#[server(endpoint = "api-token-delete")]
+pub async fn api_token_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let object = ApiToken::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    object.can_delete(&user).await?;
+    ApiToken::delete(&pool, object.id, user.actor_id()).await?;
+    Ok(())
+}
+
+#[component]
+pub fn ApiTokenListPage() -> impl IntoView {
+    let action_create = Action::<ApiTokenCreate, _>::server();
+    let action_delete = Action::<ApiTokenDelete, _>::server();
+
+    let resource = Resource::new(
+        move || (action_create.version().get(), action_delete.version().get()),
+        |_| get_api_token_list(),
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.iter()
+                    .map(|token| view! { <ApiTokenListItem data=token.clone() action_delete/> })
+                    .collect_view()
+            }
+        })
+    };
+
+    let selected_scopes = RwSignal::new(HashSet::<String>::new());
+    let handle_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if let Ok(mut data) = ApiTokenCreate::from_event(&ev) {
+            data.scopes = Some(selected_scopes.get());
+            action_create.dispatch(data)
+        }
+    };
+
+    let action_loading = action_create.pending();
+    let action_value = action_create.value();
+    let action_error =
+        move || extract_other_errors(action_value, &["name", "expires_in_days", "scopes"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let scope_errors =
+        move || get_field_errors(action_value, "scopes").map(|errors| errors.join(" "));
+
+    let created_token = move || {
+        action_value.with(|value| {
+            value.as_ref().and_then(|result| result.as_ref().ok()).map(|created| {
+                view! {
+                    <div class="p-4 mb-4 bg-amber-50 border border-amber-300">
+                        <p class="mb-2 font-bold">
+                            "Copy your new token now. It will not be shown again."
+                        </p>
+                        <code class="block p-2 break-all bg-white border">{created.token.clone()}</code>
+                    </div>
+                }
+            })
+        })
+    };
+
+    let scope_options = ApiScope::to_form_options()
+        .into_iter()
+        .map(|(value, label)| {
+            let on_change = move |ev| {
+                let checked = event_target_checked(&ev);
+                selected_scopes.update(|scopes| {
+                    if checked {
+                        scopes.insert(value.to_string());
+                    } else {
+                        scopes.remove(value);
+                    }
+                });
+            };
+            view! {
+                <label class="flex gap-2 items-center py-1 text-sm">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || selected_scopes.with(|scopes| scopes.contains(value))
+                        on:change=on_change
+                    />
+                    {label}
+                    <span class="text-xs text-gray-400">{value}</span>
+                </label>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <Title text="API Tokens"/>
+        <div class="grid grid-cols-4 gap-4 md:grid-cols-12">
+            <div class="col-span-4 md:col-span-8">
+                <div class="p-4 bg-white border shadow-sm">
+                    <h1 class="mb-2 text-base font-bold">"API Tokens"</h1>
+                    <p class="mb-4 text-sm text-gray-500">
+                        "Personal access tokens authenticate requests to the /api/v1 JSON API. Send them as a bearer token in the Authorization header."
+                    </p>
+                    <div class="grid grid-cols-5">
+                        <div class="p-2 font-bold border-b">"Name"</div>
+                        <div class="p-2 font-bold border-b">"Token"</div>
+                        <div class="p-2 font-bold border-b">"Last Used"</div>
+                        <div class="p-2 font-bold border-b">"Expires"</div>
+                        <div class="p-2 border-b"></div>
+                        <Transition fallback=ListLoadingComponent>
+                            <ErrorBoundary fallback=|errors| {
+                                view! { <ErrorComponent errors/> }
+                            }>{response}</ErrorBoundary>
+                        </Transition>
+                    </div>
+                </div>
+            </div>
+            <div class="col-span-4">
+                <div class="p-4 bg-white border shadow-sm">
+                    <h2 class="mb-4 text-base font-bold">"New Token"</h2>
+                    {created_token}
+                    <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                    <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                    <ActionForm action=action_create on:submit=handle_submit>
+                        <TextInput action_value name="name" placeholder="Mobile app"/>
+                        <FieldSelect
+                            name="expires_in_days"
+                            label="expires"
+                            options=ApiToken::expiry_form_options()
+                        />
+                        <input type="hidden" name="scopes" value=""/>
+                        <fieldset class="mb-4">
+                            <legend class="mb-1 text-sm font-bold">"Scopes"</legend>
+                            {scope_options}
+                            <div class="text-sm text-red-500">{scope_errors}</div>
+                        </fieldset>
+                        <SubmitButton loading=action_loading label="Create Token"/>
+                    </ActionForm>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn ApiTokenListItem(
+    data: ApiToken,
+    action_delete: Action<ApiTokenDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let scopes = data.scopes.join(", ");
+    let last_used_at = format_datetime(&data.last_used_at);
+    let expires_at = data
+        .expires_at
+        .map_or_else(|| "Never".to_string(), |date| format_datetime(&Some(date)));
+    view! {
+        <div class="contents">
+            <div class="p-2 border-b">
+                <div>{data.name}</div>
+                <div class="text-xs text-gray-400">{scopes}</div>
+            </div>
+            <div class="p-2 font-mono text-sm border-b">{data.token_prefix} "..."</div>
+            <div class="p-2 text-sm border-b">{last_used_at}</div>
+            <div class="p-2 text-sm border-b">{expires_at}</div>
+            <div class="p-2 text-right border-b">
+                <ActionForm action=action_delete>
+                    <input type="hidden" name="id" value=data.id.to_string()/>
+                    <button type="submit" class="hover:underline">
+                        "Revoke"
+                    </button>
+                </ActionForm>
+            </div>
+        </div>
+    }
+}
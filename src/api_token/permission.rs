@@ -0,0 +1,13 @@
+use super::model::ApiToken;
+use crate::auth::model::RequestUser;
+use crate::error::{Error, Result};
+
+impl ApiToken {
+    pub async fn can_delete(&self, request_user: &RequestUser) -> Result<()> {
+        if self.user_id == request_user.id || request_user.is_superuser {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+}
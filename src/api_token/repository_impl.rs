@@ -0,0 +1,113 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::{handle_sqlx_contraint_error, Result};
+use crate::util::server::normalize_whitespace;
+
+use super::model::ApiToken;
+
+impl ApiToken {
+    const BASE_NAME: &'static str = "API token";
+
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT id, user_id, name, token_prefix, scopes, expires_at, last_used_at, created_at, created_by_id FROM api_token WHERE id = $1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn get_by_token_hash(pool: &PgPool, token_hash: &str) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT id, user_id, name, token_prefix, scopes, expires_at, last_used_at, created_at, created_by_id FROM api_token WHERE token_hash = $1",
+            token_hash
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT id, user_id, name, token_prefix, scopes, expires_at, last_used_at, created_at, created_by_id FROM api_token WHERE user_id = $1 ORDER BY created_at DESC",
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        name: &str,
+        token_prefix: &str,
+        token_hash: &str,
+        scopes: &[String],
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        let normalized_name = normalize_whitespace(name);
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                api_token (
+                    user_id,
+                    name,
+                    token_prefix,
+                    token_hash,
+                    scopes,
+                    expires_at,
+                    created_by_id
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $1)
+            RETURNING
+                id,
+                user_id,
+                name,
+                token_prefix,
+                scopes,
+                expires_at,
+                last_used_at,
+                created_at,
+                created_by_id
+            ",
+            user_id,
+            normalized_name,
+            token_prefix,
+            token_hash,
+            scopes,
+            expires_at
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|err| {
+            handle_sqlx_contraint_error(err, Self::BASE_NAME, "name", &["user_id_name"])
+        })?;
+        Ok(query)
+    }
+
+    pub async fn update_last_used(pool: &PgPool, id: Uuid) -> Result<()> {
+        sqlx::query!(
+            "UPDATE api_token SET last_used_at = NOW() WHERE id = $1",
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(Self, "DELETE FROM api_token WHERE id = $1 RETURNING id, user_id, name, token_prefix, scopes, expires_at, last_used_at, created_at, created_by_id", id)
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+}
@@ -0,0 +1,127 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Permission granted to a personal access token. Stored in the database by its code, e.g.
+/// `diet:read`, and serialized the same way in API responses.
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[derive(Debug, Display, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ApiScope {
+    #[display(fmt = "Read diet logs")]
+    #[serde(rename = "diet:read")]
+    DietRead,
+    #[display(fmt = "Write diet logs")]
+    #[serde(rename = "diet:write")]
+    DietWrite,
+    #[display(fmt = "Read foods")]
+    #[serde(rename = "food:read")]
+    FoodRead,
+    #[display(fmt = "Read workouts")]
+    #[serde(rename = "workout:read")]
+    WorkoutRead,
+    #[display(fmt = "Write workouts")]
+    #[serde(rename = "workout:write")]
+    WorkoutWrite,
+    #[display(fmt = "Read progress")]
+    #[serde(rename = "progress:read")]
+    ProgressRead,
+    #[display(fmt = "Write progress")]
+    #[serde(rename = "progress:write")]
+    ProgressWrite,
+    #[display(fmt = "Read diet targets")]
+    #[serde(rename = "target:read")]
+    TargetRead,
+    #[display(fmt = "Write diet targets")]
+    #[serde(rename = "target:write")]
+    TargetWrite,
+}
+
+impl FromStr for ApiScope {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "diet:read" => Ok(ApiScope::DietRead),
+            "diet:write" => Ok(ApiScope::DietWrite),
+            "food:read" => Ok(ApiScope::FoodRead),
+            "workout:read" => Ok(ApiScope::WorkoutRead),
+            "workout:write" => Ok(ApiScope::WorkoutWrite),
+            "progress:read" => Ok(ApiScope::ProgressRead),
+            "progress:write" => Ok(ApiScope::ProgressWrite),
+            "target:read" => Ok(ApiScope::TargetRead),
+            "target:write" => Ok(ApiScope::TargetWrite),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<ApiScope> for &str {
+    fn from(value: ApiScope) -> Self {
+        match value {
+            ApiScope::DietRead => "diet:read",
+            ApiScope::DietWrite => "diet:write",
+            ApiScope::FoodRead => "food:read",
+            ApiScope::WorkoutRead => "workout:read",
+            ApiScope::WorkoutWrite => "workout:write",
+            ApiScope::ProgressRead => "progress:read",
+            ApiScope::ProgressWrite => "progress:write",
+            ApiScope::TargetRead => "target:read",
+            ApiScope::TargetWrite => "target:write",
+        }
+    }
+}
+
+impl ApiScope {
+    pub fn all_variants() -> Vec<&'static str> {
+        vec![
+            "diet:read",
+            "diet:write",
+            "food:read",
+            "workout:read",
+            "workout:write",
+            "progress:read",
+            "progress:write",
+            "target:read",
+            "target:write",
+        ]
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        let options = [
+            ("diet:read", "Read diet logs"),
+            ("diet:write", "Write diet logs"),
+            ("food:read", "Read foods"),
+            ("workout:read", "Read workouts"),
+            ("workout:write", "Write workouts"),
+            ("progress:read", "Read progress"),
+            ("progress:write", "Write progress"),
+            ("target:read", "Read diet targets"),
+            ("target:write", "Write diet targets"),
+        ];
+        options.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_variants_round_trip() {
+        for code in ApiScope::all_variants() {
+            let scope = ApiScope::from_str(code).unwrap();
+            let value: &str = scope.into();
+            assert_eq!(value, code);
+        }
+        assert!(ApiScope::from_str("admin").is_err());
+    }
+
+    #[test]
+    fn test_serializes_as_code() {
+        for code in ApiScope::all_variants() {
+            let scope = ApiScope::from_str(code).unwrap();
+            let json = serde_json::to_string(&scope).unwrap();
+            assert_eq!(json, format!("\"{code}\""));
+            assert_eq!(serde_json::from_str::<ApiScope>(&json).unwrap(), scope);
+        }
+    }
+}
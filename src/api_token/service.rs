@@ -0,0 +1,55 @@
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+use sha2::{Digest, Sha256};
+
+use super::model::ApiToken;
+
+impl ApiToken {
+    pub const TOKEN_PREFIX: &'static str = "tfpat_";
+    const TOKEN_LENGTH: usize = 40;
+    const DISPLAY_PREFIX_LENGTH: usize = 12;
+
+    /// Generates a new plain token, returning it along with the prefix shown in the token
+    /// list and the hash that is stored.
+    pub fn generate_token() -> (String, String, String) {
+        let secret: String = thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(Self::TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+        let token = format!("{}{}", Self::TOKEN_PREFIX, secret);
+        let prefix = token[..Self::DISPLAY_PREFIX_LENGTH].to_string();
+        let hash = Self::hash_token(&token);
+        (token, prefix, hash)
+    }
+
+    pub fn hash_token(token: &str) -> String {
+        format!("{:x}", Sha256::digest(token.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_token() {
+        let (token, prefix, hash) = ApiToken::generate_token();
+        assert!(token.starts_with(ApiToken::TOKEN_PREFIX));
+        assert!(token.starts_with(&prefix));
+        assert_eq!(hash, ApiToken::hash_token(&token));
+        assert_eq!(hash.len(), 64);
+    }
+
+    #[test]
+    fn test_hash_token_is_stable() {
+        assert_eq!(
+            ApiToken::hash_token("tfpat_abc"),
+            ApiToken::hash_token("tfpat_abc")
+        );
+        assert_ne!(
+            ApiToken::hash_token("tfpat_abc"),
+            ApiToken::hash_token("tfpat_abd")
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::util::validation_error::ValidationError;
+
+use super::model::ApiToken;
+use super::scope::ApiScope;
+
+impl ApiToken {
+    pub fn validate(
+        name: &str,
+        expires_in_days: i64,
+        scopes: &HashSet<String>,
+    ) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.validate_string("name", name, Some(1), Some(100), None);
+
+        if !Self::EXPIRY_DAY_OPTIONS.contains(&expires_in_days) {
+            errors.add_error("expires_in_days", "Select a valid expiry.".to_string());
+        }
+        if scopes.is_empty() {
+            errors.add_error("scopes", "Select at least one scope.".to_string());
+        } else if scopes
+            .iter()
+            .any(|scope| ApiScope::from_str(scope).is_err())
+        {
+            errors.add_error("scopes", "Select valid scopes.".to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
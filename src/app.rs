@@ -2,9 +2,12 @@ use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
 
+use crate::account_deletion::cancel_page::AccountDeletionCancelPage;
+use crate::account_deletion::requested_page::AccountDeletionRequestedPage;
 use crate::admin::router::AdminRouter;
 use crate::auth::context::RequestUserContext;
 use crate::auth::login_page::{Login, LoginPage};
+use crate::auth::login_two_factor_page::{LoginTwoFactor, LoginTwoFactorPage};
 use crate::auth::logout_page::{Logout, LogoutPage};
 use crate::auth::model::RequestUser;
 use crate::auth::password_reset_complete_page::PasswordResetCompletePage;
@@ -17,8 +20,11 @@ use crate::auth::signup_confirm_page::{SignupConfirm, SignupConfirmPage};
 use crate::auth::signup_done_page::SignupDonePage;
 use crate::auth::signup_page::SignupPage;
 use crate::auth::signup_resend_page::SignupResendPage;
+use crate::challenge::detail_page::ChallengeDetailPage;
+use crate::challenge::list_page::ChallengeListPage;
 use crate::component::navbar::{Navbar, UnauthNavbar};
 use crate::component::template::NotFound;
+use crate::feed::page::FeedPage;
 // use crate::follower::component::pending_follower_request_notification;
 use crate::food::router::FoodRouter;
 use crate::meal_of_day::create_page::MealOfDayCreatePage;
@@ -27,6 +33,9 @@ use crate::meal_of_day::detail_page::MealOfDayDetailPage;
 use crate::meal_of_day::list_page::MealOfDayListPage;
 use crate::meal_of_day::update_page::MealOfDayUpdatePage;
 use crate::movement::router::MovementRouter;
+use crate::notification::list_page::NotificationListPage;
+use crate::shared_template::detail_page::SharedTemplateDetailPage;
+use crate::shared_template::list_page::SharedTemplateListPage;
 // use crate::training_plan::router::TrainingPlanRouter;
 use crate::user::router::UserRouter;
 use crate::user_setting::router::UserSettingsRouter;
@@ -36,7 +45,7 @@ use crate::web::landing_page::LandingPage;
 use crate::web::privacy_policy_page::PrivacyPage;
 use crate::web::terms_of_service_page::TermsPage;
 
-pub type UserResource = Resource<(usize, usize, usize), Result<RequestUser, ServerFnError>>;
+pub type UserResource = Resource<(usize, usize, usize, usize), Result<RequestUser, ServerFnError>>;
 
 #[cfg(feature = "ssr")]
 use crate::auth::service::extract_user_from_request;
@@ -56,6 +65,23 @@ impl FollowerCount {
     }
 }
 
+/// Notified after actions that change the request user's notifications, so the navbar bell
+/// refetches its unread count.
+#[derive(Debug, Copy, Clone)]
+pub struct NotificationRefresh(pub Trigger);
+
+impl NotificationRefresh {
+    pub fn new() -> Self {
+        Self(Trigger::new())
+    }
+}
+
+impl Default for NotificationRefresh {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
@@ -63,6 +89,7 @@ pub fn App() -> impl IntoView {
     provide_context(request_user_ctx);
     let follower_count = FollowerCount::new();
     provide_context(follower_count);
+    provide_context(NotificationRefresh::new());
     // let follower_req = create_action(|&()| pending_follower_request_notification());
     // use_interval(60000, move || {
     //     leptos::logging::log!("Checking pending follower requests.");
@@ -77,12 +104,14 @@ pub fn App() -> impl IntoView {
     let login = Action::<Login, _>::server();
     let logout = Action::<Logout, _>::server();
     let signup_confirm = Action::<SignupConfirm, _>::server();
+    let login_two_factor = Action::<LoginTwoFactor, _>::server();
     let resource: UserResource = Resource::new(
         move || {
             (
                 login.version().get(),
                 logout.version().get(),
                 signup_confirm.version().get(),
+                login_two_factor.version().get(),
             )
         },
         move |_| authenticate(),
@@ -122,6 +151,10 @@ pub fn App() -> impl IntoView {
                 <Route path="/terms-of-service" view=TermsPage/>
                 <Route path="/help" view=HelpPage/>
                 <Route path="/login" view=move || view! { <LoginPage login/> }/>
+                <Route
+                    path="/login/two-factor"
+                    view=move || view! { <LoginTwoFactorPage action=login_two_factor/> }
+                />
                 <Route path="/logout" view=move || view! { <LogoutPage logout/> }/>
                 <Route path="/signup" view=SignupPage/>
                 <Route path="/signup/email-sent" view=SignupDonePage/>
@@ -135,8 +168,16 @@ pub fn App() -> impl IntoView {
                 <Route path="/password-reset/email-sent" view=PasswordResetRequestDonePage/>
                 <Route path="/password-reset/confirm" view=PasswordResetConfirmPage/>
                 <Route path="/password-reset/complete" view=PasswordResetCompletePage/>
+                <Route path="/account-deletion/requested" view=AccountDeletionRequestedPage/>
+                <Route path="/account-deletion/cancel" view=AccountDeletionCancelPage/>
 
                 <Route path="" view=ProtectedRoute>
+                    <Route path="/feed" view=FeedPage/>
+                    <Route path="/notifications" view=NotificationListPage/>
+                    <Route path="/challenges" view=ChallengeListPage/>
+                    <Route path="/challenges/:id" view=ChallengeDetailPage/>
+                    <Route path="/templates" view=SharedTemplateListPage/>
+                    <Route path="/templates/:slug" view=SharedTemplateDetailPage/>
                     <FoodRouter/>
                     <Route path="/meal-of-day" view=MealOfDayListPage/>
                     <Route path="/meal-of-day/create" view=MealOfDayCreatePage/>
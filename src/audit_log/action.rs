@@ -0,0 +1,65 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    ImpersonationStart,
+    ImpersonationStop,
+    Unknown(i32),
+}
+
+impl AuditAction {
+    pub fn to_filter_options() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("", "All"),
+            ("1", "Created"),
+            ("2", "Updated"),
+            ("3", "Deleted"),
+            ("4", "Impersonation started"),
+            ("5", "Impersonation stopped"),
+        ]
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditAction::Create => write!(f, "Created"),
+            AuditAction::Update => write!(f, "Updated"),
+            AuditAction::Delete => write!(f, "Deleted"),
+            AuditAction::ImpersonationStart => write!(f, "Impersonation started"),
+            AuditAction::ImpersonationStop => write!(f, "Impersonation stopped"),
+            AuditAction::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl From<i32> for AuditAction {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => AuditAction::Create,
+            2 => AuditAction::Update,
+            3 => AuditAction::Delete,
+            4 => AuditAction::ImpersonationStart,
+            5 => AuditAction::ImpersonationStop,
+            _ => AuditAction::Unknown(value),
+        }
+    }
+}
+
+impl From<AuditAction> for i32 {
+    fn from(action: AuditAction) -> Self {
+        match action {
+            AuditAction::Create => 1,
+            AuditAction::Update => 2,
+            AuditAction::Delete => 3,
+            AuditAction::ImpersonationStart => 4,
+            AuditAction::ImpersonationStop => 5,
+            AuditAction::Unknown(value) => value,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod action;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
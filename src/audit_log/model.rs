@@ -0,0 +1,26 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::action::AuditAction;
+
+/// A create, update or delete of a row, written by the `audit_log_record` database trigger.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AuditLog {
+    pub id: Uuid,
+    pub entity: String,
+    pub entity_id: Uuid,
+    pub action: i32,
+    pub actor_id: Option<Uuid>,
+    pub actor_username: Option<String>,
+    /// The changed columns as a JSON object of `[old, new]` pairs.
+    pub changes: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditLog {
+    pub fn get_action(&self) -> AuditAction {
+        AuditAction::from(self.action)
+    }
+}
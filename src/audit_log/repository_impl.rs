@@ -0,0 +1,323 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+use crate::util::database::Filter;
+
+use super::action::AuditAction;
+use super::model::AuditLog;
+
+impl AuditLog {
+    /// Records an event that is not a change to a row, such as a superuser impersonating a user.
+    pub async fn create(
+        pool: &PgPool,
+        entity: &str,
+        entity_id: Uuid,
+        action: AuditAction,
+        actor_id: Uuid,
+    ) -> Result<()> {
+        sqlx::query!(
+            "
+            INSERT INTO
+                audit_log (entity, entity_id, action, actor_id, changes)
+            VALUES
+                ($1, $2, $3, $4, '{}')
+            ",
+            entity,
+            entity_id,
+            i32::from(action),
+            actor_id,
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Attributes the changes made in the rest of the transaction to the user. Without it the
+    /// trigger falls back to the user recorded on the row, and deletes have no actor.
+    pub async fn set_actor(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        actor_id: Uuid,
+    ) -> Result<()> {
+        sqlx::query_scalar!(
+            "SELECT set_config('app.actor_id', $1, TRUE)",
+            actor_id.to_string(),
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    /// Marks the transaction as deleting the user's account. Deleted rows keep only their
+    /// delete entry, with every recorded value of the row dropped from the log.
+    pub async fn set_erased_user(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        user_id: Uuid,
+    ) -> Result<()> {
+        sqlx::query_scalar!(
+            "SELECT set_config('app.erased_user_id', $1, TRUE)",
+            user_id.to_string(),
+        )
+        .fetch_one(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes the user as the actor of every entry, leaving the entries themselves in place.
+    pub async fn clear_actor(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        user_id: Uuid,
+    ) -> Result<u64> {
+        let rows_affected = sqlx::query!(
+            "UPDATE audit_log SET actor_id = NULL WHERE actor_id = $1",
+            user_id
+        )
+        .execute(&mut **tx)
+        .await?
+        .rows_affected();
+        Ok(rows_affected)
+    }
+
+    pub async fn count(
+        pool: &PgPool,
+        entity: &str,
+        entity_id: &str,
+        action: &str,
+        actor: &str,
+    ) -> Result<i64> {
+        let mut qb = sqlx::QueryBuilder::new(
+            "
+            SELECT
+                COUNT(*)
+            FROM
+                audit_log t1
+                LEFT JOIN users_user t2 ON t2.id = t1.actor_id
+            WHERE
+                TRUE
+            ",
+        );
+        qb.filter("t1.entity", "ilike", entity);
+        qb.filter("t1.entity_id::TEXT", "=", entity_id);
+        qb.filter("t1.action::TEXT", "=", action);
+        qb.filter("t2.username", "ilike", actor);
+        let query = qb.build_query_scalar().fetch_one(pool).await?;
+        Ok(query)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn filter(
+        pool: &PgPool,
+        entity: &str,
+        entity_id: &str,
+        action: &str,
+        actor: &str,
+        order: &str,
+        size: i64,
+        page: i64,
+    ) -> Result<Vec<Self>> {
+        let order_by_column = match order {
+            "created_at" => "t1.created_at",
+            "entity" => "t1.entity, t1.created_at DESC",
+            "actor_username" => "t2.username NULLS LAST, t1.created_at DESC",
+            _ => "t1.created_at DESC",
+        };
+        let mut qb = sqlx::QueryBuilder::new(
+            "
+            SELECT
+                t1.id,
+                t1.entity,
+                t1.entity_id,
+                t1.action,
+                t1.actor_id,
+                t2.username AS actor_username,
+                t1.changes::TEXT AS changes,
+                t1.created_at
+            FROM
+                audit_log t1
+                LEFT JOIN users_user t2 ON t2.id = t1.actor_id
+            WHERE
+                TRUE
+            ",
+        );
+        qb.filter("t1.entity", "ilike", entity);
+        qb.filter("t1.entity_id::TEXT", "=", entity_id);
+        qb.filter("t1.action::TEXT", "=", action);
+        qb.filter("t2.username", "ilike", actor);
+
+        qb.push(" ORDER BY ");
+        qb.push(order_by_column);
+
+        qb.paginate(size, page);
+        let query = qb.build_query_as().fetch_all(pool).await?;
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::coach::model::CoachClient;
+    use crate::follower::model::Follower;
+    use crate::util::test_fixture::create_user;
+
+    use super::*;
+
+    /// The action, actor and changes of each entry for the entity, oldest first.
+    async fn entries(pool: &PgPool, entity: &str) -> Vec<(i32, Option<Uuid>, Value)> {
+        let rows: Vec<(i32, Option<Uuid>, String)> = sqlx::query_as(
+            "
+            SELECT
+                action,
+                actor_id,
+                changes::TEXT
+            FROM
+                audit_log
+            WHERE
+                entity = $1
+            ORDER BY
+                created_at,
+                action
+            ",
+        )
+        .bind(entity)
+        .fetch_all(pool)
+        .await
+        .unwrap();
+        rows.into_iter()
+            .map(|(action, actor_id, changes)| {
+                (action, actor_id, serde_json::from_str(&changes).unwrap())
+            })
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn test_audit_log_credits_the_acting_user(pool: PgPool) {
+        let user_id = create_user(&pool, "user").await;
+        let other_id = create_user(&pool, "other").await;
+
+        Follower::request(&pool, "user", "other").await.unwrap();
+        Follower::accept(&pool, "other", user_id).await.unwrap();
+        let follows = entries(&pool, "user_follower").await;
+        assert_eq!(follows.len(), 2);
+        assert_eq!(follows[0].1, Some(other_id));
+        assert_eq!((follows[1].0, follows[1].1), (2, Some(user_id)));
+        assert_eq!(follows[1].2, json!({"status": [0, 1]}));
+
+        sqlx::query(
+            "INSERT INTO user_block (blocker_id, blocked_id, blocked_status) VALUES ($1, $2, 1)",
+        )
+        .bind(user_id)
+        .bind(other_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert_eq!(entries(&pool, "user_block").await[0].1, Some(user_id));
+
+        CoachClient::create(&pool, other_id, "user").await.unwrap();
+        assert_eq!(entries(&pool, "coach_client").await[0].1, Some(other_id));
+
+        // A change made on the user's behalf is credited to whoever made it.
+        let mut tx = pool.begin().await.unwrap();
+        AuditLog::set_actor(&mut tx, other_id).await.unwrap();
+        sqlx::query("UPDATE users_user SET name = 'Renamed', updated_at = NOW() WHERE id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        let users = entries(&pool, "users_user").await;
+        assert_eq!(users.last().unwrap().0, 2);
+        assert_eq!(users.last().unwrap().1, Some(other_id));
+    }
+
+    #[sqlx::test]
+    async fn test_audit_log_covers_account_and_plan_tables(pool: PgPool) {
+        let user_id = create_user(&pool, "user").await;
+
+        sqlx::query("INSERT INTO user_totp (user_id, secret) VALUES ($1, 'JBSWY3DPEHPK3PXP')")
+            .bind(user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let totp = entries(&pool, "user_totp").await;
+        assert_eq!(totp[0].1, Some(user_id));
+        assert_eq!(totp[0].2["secret"], json!([null, "[redacted]"]));
+
+        let training_plan_id: Uuid = sqlx::query_scalar(
+            "
+            INSERT INTO
+                training_plan (user_id, name, slug, created_by_id)
+            VALUES
+                ($1, 'Plan', 'plan', $1)
+            RETURNING
+                id
+            ",
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let workout_plan_id: Uuid = sqlx::query_scalar(
+            "
+            INSERT INTO
+                workout_plan (user_id, name, slug, created_by_id)
+            VALUES
+                ($1, 'Day', 'day', $1)
+            RETURNING
+                id
+            ",
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "
+            INSERT INTO
+                training_plan_workout_plan (training_plan_id, workout_plan_id, created_by_id)
+            VALUES
+                ($1, $2, $3)
+            ",
+        )
+        .bind(training_plan_id)
+        .bind(workout_plan_id)
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert_eq!(entries(&pool, "training_plan").await.len(), 1);
+        assert_eq!(entries(&pool, "workout_plan").await.len(), 1);
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM audit_log WHERE entity = 'training_plan_workout_plan' AND entity_id = $1",
+        )
+        .bind(training_plan_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[sqlx::test]
+    async fn test_audit_log_is_append_only(pool: PgPool) {
+        create_user(&pool, "user").await;
+
+        for statement in [
+            "UPDATE audit_log SET changes = '{}'",
+            "DELETE FROM audit_log",
+            "TRUNCATE audit_log",
+        ] {
+            assert!(sqlx::query(statement).execute(&pool).await.is_err());
+        }
+
+        // Deleting an account only allows the recorded values and the actor to be cleared.
+        let mut tx = pool.begin().await.unwrap();
+        AuditLog::set_erased_user(&mut tx, Uuid::nil())
+            .await
+            .unwrap();
+        let result = sqlx::query("UPDATE audit_log SET entity = 'food'")
+            .execute(&mut *tx)
+            .await;
+        assert!(result.is_err());
+    }
+}
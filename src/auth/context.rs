@@ -24,6 +24,9 @@ impl RequestUserContext {
     pub fn is_superuser(&self) -> bool {
         self.user.with(|user| user.is_superuser)
     }
+    pub fn is_impersonating(&self) -> bool {
+        self.user.with(|user| user.impersonator_id.is_some())
+    }
     pub fn is_superuser_or_object_owner(&self, user_id: Uuid) -> bool {
         self.user
             .with(|user| user.is_superuser || user.id == user_id)
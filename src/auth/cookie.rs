@@ -5,38 +5,45 @@ use http::{header, HeaderMap, HeaderValue};
 use crate::config::get_config;
 use crate::error::{Error, Result};
 
+/// Holds the short-lived token for a login that has passed the password check but not yet the
+/// two-factor step.
+pub const TWO_FACTOR_COOKIE_NAME: &str = "two_factor_pending";
+
+/// Holds the refresh token used to renew the auth cookie when it expires.
+pub const REFRESH_COOKIE_NAME: &str = "refresh_token";
+
 pub fn get_cookie(headers: &HeaderMap, name: &str) -> Option<String> {
     headers.get(header::COOKIE).and_then(|cookie_header| {
         cookie_header.to_str().ok().and_then(|cookie_str| {
             cookie_str
                 .split(';')
                 .map(|cookie| cookie.trim())
-                .find(|cookie| cookie.starts_with(name))
-                .and_then(|cookie| {
-                    let parts: Vec<&str> = cookie.splitn(2, '=').collect();
-                    if parts.len() == 2 {
-                        Some(parts[1].to_string())
-                    } else {
-                        None
-                    }
-                })
+                .filter_map(|cookie| cookie.split_once('='))
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
         })
     })
 }
 
+pub fn build_cookie(name: &str, value: &str) -> String {
+    format!("{name}={value}; SameSite=Lax; path=/; HttpOnly")
+}
+
+pub fn build_expired_cookie(name: &str) -> String {
+    format!("{name}=; SameSite=Lax; path=/; expires=Thu, 01 Jan 1970 00:00:00 GMT")
+}
+
 pub fn set_cookie(name: &str, value: &str) -> Result<()> {
     let response = use_context::<leptos_axum::ResponseOptions>().ok_or(Error::InternalServer)?;
-    let cookie = format!("{name}={value}; SameSite=Lax; path=/; HttpOnly");
-    let header_value = HeaderValue::try_from(cookie)?;
-    response.insert_header(header::SET_COOKIE, header_value);
+    let header_value = HeaderValue::try_from(build_cookie(name, value))?;
+    response.append_header(header::SET_COOKIE, header_value);
     Ok(())
 }
 
 pub fn delete_cookie(name: &str) -> Result<()> {
     let response = use_context::<leptos_axum::ResponseOptions>().ok_or(Error::InternalServer)?;
-    let cookie = format!("{name}=; SameSite=Lax; path=/; expires=Thu, 01 Jan 1970 00:00:00 GMT");
-    let header_value = HeaderValue::try_from(cookie)?;
-    response.insert_header(header::SET_COOKIE, header_value);
+    let header_value = HeaderValue::try_from(build_expired_cookie(name))?;
+    response.append_header(header::SET_COOKIE, header_value);
     Ok(())
 }
 
@@ -52,8 +59,26 @@ pub fn set_jwt_cookie(value: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn delete_jwt_cookie() -> Result<()> {
+pub fn set_refresh_cookie(value: &str) -> Result<()> {
+    set_cookie(REFRESH_COOKIE_NAME, value)
+}
+
+/// Removes both the auth and refresh cookies, logging the browser out.
+pub fn delete_auth_cookies() -> Result<()> {
     let config = get_config();
     delete_cookie(&config.auth_cookie_name)?;
+    delete_cookie(REFRESH_COOKIE_NAME)?;
     Ok(())
 }
+
+pub fn set_two_factor_cookie(value: &str) -> Result<()> {
+    set_cookie(TWO_FACTOR_COOKIE_NAME, value)
+}
+
+pub fn get_two_factor_cookie(headers: &HeaderMap) -> Option<String> {
+    get_cookie(headers, TWO_FACTOR_COOKIE_NAME)
+}
+
+pub fn delete_two_factor_cookie() -> Result<()> {
+    delete_cookie(TWO_FACTOR_COOKIE_NAME)
+}
@@ -15,6 +15,7 @@ use crate::{
 #[server(endpoint = "email-change-request")]
 pub async fn email_change_request(email: String) -> Result<(), ServerFnError> {
     let request_user = get_request_user()?;
+    request_user.ensure_not_impersonated()?;
     let pool = get_pool()?;
 
     User::validate_email(&email)?;
@@ -115,4 +115,44 @@ impl EmailService {
             .render(&config.template_email_change, &context)?;
         Self::send_email(recipient, "Confirm your new email address", &body).await
     }
+
+    pub async fn send_account_deletion_email(
+        name: &str,
+        recipient: &str,
+        cancel_link: &str,
+        delete_after: &str,
+    ) -> Result<(), EmailError> {
+        let config = get_config();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert("confirmation_link", cancel_link);
+        context.insert("delete_after", delete_after);
+
+        let body = config
+            .tera
+            .render(&config.template_account_deletion, &context)?;
+        Self::send_email(recipient, "Your account is scheduled for deletion", &body).await
+    }
+
+    pub async fn send_account_lockout_email(
+        name: &str,
+        recipient: &str,
+        duration: &str,
+    ) -> Result<(), EmailError> {
+        let config = get_config();
+
+        let mut context = Context::new();
+        context.insert("name", name);
+        context.insert(
+            "password_reset_link",
+            &format!("{}/password-reset", config.domain),
+        );
+        context.insert("duration", duration);
+
+        let body = config
+            .tera
+            .render(&config.template_account_lockout, &context)?;
+        Self::send_email(recipient, "Your account has been temporarily locked", &body).await
+    }
 }
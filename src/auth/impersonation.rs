@@ -0,0 +1,215 @@
+use leptos::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::app::UserResource;
+use crate::component::button::SubmitButton;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    audit_log::{action::AuditAction, model::AuditLog},
+    auth::{
+        cookie::delete_auth_cookies,
+        model::User,
+        service::{extract_superuser_from_request, get_request_user, AuthService},
+    },
+    error::Error,
+    setup::get_pool,
+    user_session::model::UserSession,
+};
+
+/// Signs the superuser in as the user, ending the superuser's own session until the
+/// impersonation is stopped.
+#[server(endpoint = "impersonation-start")]
+pub async fn impersonation_start(id: Uuid) -> Result<(), ServerFnError> {
+    let superuser = extract_superuser_from_request()?;
+    let pool = get_pool()?;
+    let user = User::get_by_id(&pool, id).await?.ok_or(Error::NotFound)?;
+    user.ensure_can_be_impersonated(superuser.id)?;
+
+    if let Some(session_id) = superuser.session_id {
+        UserSession::delete(&pool, session_id, superuser.id).await?;
+    }
+    AuthService::start_impersonation(&pool, &user, superuser.id).await?;
+    AuditLog::create(
+        &pool,
+        "users_user",
+        user.id,
+        AuditAction::ImpersonationStart,
+        superuser.id,
+    )
+    .await?;
+    leptos_axum::redirect(&format!("/users/{}", user.username));
+    Ok(())
+}
+
+/// Ends the impersonation and signs the superuser back in as themselves.
+#[server(endpoint = "impersonation-stop")]
+pub async fn impersonation_stop() -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let impersonator_id = user.impersonator_id.ok_or(Error::Forbidden)?;
+    let pool = get_pool()?;
+
+    if let Some(session_id) = user.session_id {
+        UserSession::delete(&pool, session_id, user.id).await?;
+    }
+    AuditLog::create(
+        &pool,
+        "users_user",
+        user.id,
+        AuditAction::ImpersonationStop,
+        impersonator_id,
+    )
+    .await?;
+
+    let impersonator = User::get_by_id(&pool, impersonator_id)
+        .await?
+        .filter(|impersonator| impersonator.is_active && impersonator.is_superuser);
+    match impersonator {
+        Some(impersonator) => {
+            AuthService::start_session(&pool, &impersonator).await?;
+            leptos_axum::redirect(&format!("/admin/users/{}", user.id));
+        }
+        None => {
+            delete_auth_cookies()?;
+            leptos_axum::redirect("/login");
+        }
+    }
+    Ok(())
+}
+
+#[component]
+pub fn ImpersonationStartForm<F>(id: F) -> impl IntoView
+where
+    F: Fn() -> Uuid + 'static,
+{
+    let action = Action::<ImpersonationStart, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let user_resource = expect_context::<UserResource>();
+    create_effect(move |_| {
+        if let Some(Ok(())) = action_value.get() {
+            user_resource.refetch();
+        }
+    });
+
+    view! {
+        <p class="mb-4">
+            "View the site as this user. Your session ends until you stop, and the user's password, email and account deletion are unavailable while impersonating."
+        </p>
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action>
+            <input type="hidden" name="id" value=move || id().to_string()/>
+            <SubmitButton loading=action_loading label="Impersonate"/>
+        </ActionForm>
+    }
+}
+
+#[component]
+pub fn ImpersonationStopForm() -> impl IntoView {
+    let action = Action::<ImpersonationStop, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+
+    let user_resource = expect_context::<UserResource>();
+    create_effect(move |_| {
+        if let Some(Ok(())) = action_value.get() {
+            user_resource.refetch();
+        }
+    });
+
+    view! {
+        <ActionForm action class="contents">
+            <button
+                type="submit"
+                class="py-1 px-2 font-bold text-amber-900 bg-amber-200 hover:bg-amber-100"
+                prop:disabled=action_loading
+            >
+                "Stop impersonating"
+            </button>
+        </ActionForm>
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Request;
+    use leptos::{create_runtime, provide_context};
+    use sqlx::PgPool;
+
+    use crate::api_token::page::api_token_create;
+    use crate::auth::model::RequestUser;
+    use crate::coach::page::coach_client_create;
+    use crate::data_export::panel::data_export_create;
+    use crate::user_setting::session_page::user_session_delete;
+    use crate::util::test_fixture::create_user;
+    use crate::workout::create_page::workout_create;
+
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_impersonation_is_shown_to_the_user_and_credits_the_superuser(pool: PgPool) {
+        let superuser_id = create_user(&pool, "admin").await;
+        let user_id = create_user(&pool, "user").await;
+        let own = UserSession::create(&pool, user_id, "Firefox", None, None)
+            .await
+            .unwrap();
+        let impersonation = UserSession::create(&pool, user_id, "Chrome", None, Some(superuser_id))
+            .await
+            .unwrap();
+
+        let runtime = create_runtime();
+        provide_context(pool.clone());
+        let request_user = RequestUser {
+            id: user_id,
+            username: "user".to_string(),
+            is_active: true,
+            session_id: Some(impersonation.id),
+            impersonator_id: Some(superuser_id),
+            ..Default::default()
+        };
+        let (parts, _) = Request::builder()
+            .extension(request_user)
+            .body(())
+            .unwrap()
+            .into_parts();
+        provide_context(parts);
+
+        let errors = [
+            api_token_create("Script".to_string(), 30, None).await.err(),
+            data_export_create().await.err(),
+            user_session_delete(own.id).await.err(),
+            coach_client_create("admin".to_string()).await.err(),
+        ];
+        for error in errors {
+            assert!(error.unwrap().to_string().contains("impersonating"));
+        }
+
+        let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        workout_create("user".to_string(), date).await.unwrap();
+        runtime.dispose();
+
+        let actor_id: Option<Uuid> = sqlx::query_scalar(
+            "SELECT actor_id FROM audit_log WHERE entity = 'workout' AND action = 1",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(actor_id, Some(superuser_id));
+
+        // The user sees the impersonation among their sessions and can end it.
+        let sessions = UserSession::all_by_user_id(&pool, user_id).await.unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert!(UserSession::delete(&pool, impersonation.id, user_id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
@@ -4,41 +4,75 @@ use leptos_router::*;
 
 use crate::component::button::SubmitButton;
 use crate::component::input::TextInput;
+use crate::util::param::extract_param;
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
 use crate::{
-    auth::cookie::set_jwt_cookie, auth::model::User, auth::token::JwtManager, error::Error,
+    account_deletion::model::AccountDeletion,
+    auth::cookie::set_two_factor_cookie,
+    auth::model::User,
+    auth::service::{get_client_ip, AuthService},
+    auth::token::{JwtManager, TokenType},
+    error::Error,
+    login_throttle::model::LoginThrottle,
+    two_factor::model::UserTotp,
 };
 
 #[server(endpoint = "login")]
 pub async fn login(email: String, password: String) -> Result<(), ServerFnError> {
     let pool = crate::setup::get_pool()?;
 
+    let parts = use_context::<http::request::Parts>().ok_or(Error::InternalServer)?;
+    let ip = get_client_ip(&parts)?.to_string();
+
     User::validate_login(&email, &password)?;
+    LoginThrottle::ensure_login_allowed(&pool, &ip, &email).await?;
 
-    let user = User::get_by_email(&pool, &email)
-        .await?
-        .ok_or(Error::InvalidCredentials)?;
+    let user = match User::get_by_email(&pool, &email).await? {
+        Some(user) if user.verify_password(&password).is_ok() => user,
+        user => {
+            LoginThrottle::record_login_failure(&pool, &ip, &email, user.as_ref()).await?;
+            return Err(Error::InvalidCredentials.into());
+        }
+    };
 
-    user.verify_password(&password)?;
+    if !user.is_active
+        && AccountDeletion::get_by_user_id(&pool, user.id)
+            .await?
+            .is_some()
+    {
+        return Err(Error::Other(
+            "This account is scheduled for deletion. Follow the link in the email we sent you to cancel the deletion.".into(),
+        )
+        .into());
+    }
     user.ensure_account_active()?;
     user.ensure_email_verified()?;
 
-    let token = JwtManager::generate_auth_token(
-        user.id,
-        &user.username,
-        user.is_active,
-        user.is_staff,
-        user.is_superuser,
-    )?;
+    if UserTotp::get_enabled_by_user_id(&pool, user.id)
+        .await?
+        .is_some()
+    {
+        let token = JwtManager::create_token(user.id, "", TokenType::TwoFactor)?;
+        set_two_factor_cookie(&token)?;
+        leptos_axum::redirect("/login/two-factor");
+        return Ok(());
+    }
 
-    set_jwt_cookie(&token)?;
+    LoginThrottle::clear_login(&pool, &email).await?;
+    AuthService::start_session(&pool, &user).await?;
 
     leptos_axum::redirect(&format!("/users/{}", user.username));
     Ok(())
 }
 
+#[server(endpoint = "oidc-provider-name")]
+pub async fn get_oidc_provider_name() -> Result<Option<String>, ServerFnError> {
+    let config = crate::config::get_config();
+    Ok(config.oidc.as_ref().map(|oidc| oidc.provider_name.clone()))
+}
+
 #[component]
 pub fn LoginPage(login: Action<Login, Result<(), ServerFnError>>) -> impl IntoView {
     let action_loading = login.pending();
@@ -46,6 +80,27 @@ pub fn LoginPage(login: Action<Login, Result<(), ServerFnError>>) -> impl IntoVi
     let action_error = move || extract_other_errors(action_value, &["email", "password"]);
     let non_field_errors = move || get_non_field_errors(action_value);
 
+    let query = use_query_map();
+    let oidc_error = move || {
+        (extract_param(&query, "error") == "oidc").then_some(
+            "Sign in with your provider failed. Please try again or log in with your password.",
+        )
+    };
+    let oidc_resource = Resource::once(get_oidc_provider_name);
+    let oidc_link = move || {
+        let provider_name = oidc_resource.get().and_then(Result::ok).flatten()?;
+        Some(view! {
+            <a
+                href="/auth/oidc/login"
+                rel="external"
+                class="block p-2 mt-4 text-center border hover:bg-gray-100"
+            >
+                "Sign in with "
+                {provider_name}
+            </a>
+        })
+    };
+
     view! {
         <Title text="Log in"/>
         <main class="p-4 lg:p-8">
@@ -55,6 +110,7 @@ pub fn LoginPage(login: Action<Login, Result<(), ServerFnError>>) -> impl IntoVi
 
                 <div class="mb-4 text-red-500 font-bold">{action_error}</div>
                 <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                <div class="mb-4 text-red-500 font-bold">{oidc_error}</div>
                 <ActionForm action=login>
                     <TextInput
                         name="email"
@@ -71,6 +127,7 @@ pub fn LoginPage(login: Action<Login, Result<(), ServerFnError>>) -> impl IntoVi
                     />
                     <SubmitButton loading=action_loading label="Log in"/>
                 </ActionForm>
+                <Transition>{oidc_link}</Transition>
 
                 <div class="pt-4 space-y-2">
                     <p>
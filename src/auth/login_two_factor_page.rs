@@ -0,0 +1,89 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::cookie::{delete_two_factor_cookie, get_two_factor_cookie},
+    auth::model::User,
+    auth::service::{get_client_ip, AuthService},
+    auth::token::{JwtManager, TokenType},
+    error::Error,
+    login_throttle::model::LoginThrottle,
+    two_factor::model::UserTotp,
+};
+
+#[server(endpoint = "login-two-factor")]
+pub async fn login_two_factor(code: String) -> Result<(), ServerFnError> {
+    let pool = crate::setup::get_pool()?;
+    let parts = use_context::<http::request::Parts>().ok_or(Error::InternalServer)?;
+
+    let token = get_two_factor_cookie(&parts.headers)
+        .ok_or_else(|| Error::Other("Your login has expired. Please log in again.".into()))?;
+    let token = JwtManager::validate_token(&token, TokenType::TwoFactor)
+        .map_err(|_| Error::Other("Your login has expired. Please log in again.".into()))?;
+
+    let user = User::get_by_id(&pool, token.user_id)
+        .await?
+        .ok_or(Error::InvalidCredentials)?;
+    user.ensure_account_active()?;
+
+    let ip = get_client_ip(&parts)?.to_string();
+    LoginThrottle::ensure_login_allowed(&pool, &ip, &user.email).await?;
+    if let Err(err) = UserTotp::verify_login(&pool, user.id, &code).await {
+        LoginThrottle::record_login_failure(&pool, &ip, &user.email, Some(&user)).await?;
+        return Err(err.into());
+    }
+
+    LoginThrottle::clear_login(&pool, &user.email).await?;
+    AuthService::start_session(&pool, &user).await?;
+    delete_two_factor_cookie()?;
+
+    leptos_axum::redirect(&format!("/users/{}", user.username));
+    Ok(())
+}
+
+#[component]
+pub fn LoginTwoFactorPage(
+    action: Action<LoginTwoFactor, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["code"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    view! {
+        <Title text="Two-Factor Authentication"/>
+        <main class="p-4 lg:p-8">
+            <div class="p-4 mx-auto max-w-md bg-white border shadow-md">
+                <h1 class="mb-4 text-xl font-bold">"Two-Factor Authentication"</h1>
+                <p class="mb-4">
+                    "Enter the code from your authenticator app, or one of your recovery codes."
+                </p>
+                <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                <ActionForm action>
+                    <TextInput
+                        action_value
+                        name="code"
+                        label="Authentication code"
+                        autocomplete="one-time-code"
+                        placeholder="Enter your code"
+                    />
+                    <SubmitButton loading=action_loading label="Verify"/>
+                </ActionForm>
+                <div class="pt-4">
+                    <p>
+                        <A href="/login" class="text-blue-500 hover:underline">
+                            "Back to log in"
+                        </A>
+                    </p>
+                </div>
+            </div>
+        </main>
+    }
+}
@@ -7,7 +7,23 @@ use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[server(endpoint = "logout")]
 pub async fn logout() -> Result<(), ServerFnError> {
-    crate::auth::cookie::delete_jwt_cookie()?;
+    if let Some(user) = crate::auth::service::extract_user_from_request()? {
+        let pool = crate::setup::get_pool()?;
+        if let Some(session_id) = user.session_id {
+            crate::user_session::model::UserSession::delete(&pool, session_id, user.id).await?;
+        }
+        if let Some(impersonator_id) = user.impersonator_id {
+            crate::audit_log::model::AuditLog::create(
+                &pool,
+                "users_user",
+                user.id,
+                crate::audit_log::action::AuditAction::ImpersonationStop,
+                impersonator_id,
+            )
+            .await?;
+        }
+    }
+    crate::auth::cookie::delete_auth_cookies()?;
     leptos_axum::redirect("/login");
     Ok(())
 }
@@ -7,7 +7,9 @@ pub mod email_change_done_page;
 pub mod email_change_page;
 #[cfg(feature = "ssr")]
 pub mod email_service;
+pub mod impersonation;
 pub mod login_page;
+pub mod login_two_factor_page;
 pub mod logout_page;
 pub mod model;
 pub mod password_reset_complete_page;
@@ -26,6 +26,14 @@ pub struct User {
 
 #[cfg(feature = "ssr")]
 impl User {
+    /// Stored in place of a password hash for users who signed up through an identity provider
+    /// and have not set a password yet. It never matches a password.
+    pub const UNUSABLE_PASSWORD: &'static str = "!";
+
+    pub fn has_usable_password(&self) -> bool {
+        self.password != Self::UNUSABLE_PASSWORD
+    }
+
     pub fn is_authenticated(&self) -> Result<()> {
         self.is_active.then(|| ()).ok_or(Error::Unauthorized)
     }
@@ -59,6 +67,16 @@ impl User {
             .then(|| ())
             .ok_or_else(|| Error::Other("This email address has already been verified.".into()))
     }
+
+    pub fn ensure_can_be_impersonated(&self, impersonator_id: Uuid) -> Result<()> {
+        (self.is_active && !self.is_superuser && self.id != impersonator_id)
+            .then_some(())
+            .ok_or_else(|| {
+                Error::Other(
+                    "Only active users that are not superusers can be impersonated.".into(),
+                )
+            })
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -68,6 +86,9 @@ pub struct RequestUser {
     pub is_active: bool,
     pub is_staff: bool,
     pub is_superuser: bool,
+    #[serde(skip)]
+    pub session_id: Option<Uuid>,
+    pub impersonator_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -13,7 +13,9 @@ use crate::{
         model::User,
         token::{JwtManager, TokenType},
     },
+    login_throttle::model::LoginThrottle,
     setup::get_pool,
+    user_session::model::UserSession,
 };
 
 #[server(endpoint = "password-reset-confirm")]
@@ -22,7 +24,9 @@ pub async fn password_reset_confirm(token: String, password: String) -> Result<(
     let pool = get_pool()?;
 
     User::validate_password(&password)?;
-    User::update_password(&pool, token.user_id, &password).await?;
+    User::update_password(&pool, token.user_id, &password, token.user_id).await?;
+    UserSession::delete_all_by_user_id(&pool, token.user_id).await?;
+    LoginThrottle::delete_by_user_id(&pool, token.user_id).await?;
 
     leptos_axum::redirect("/password-reset/complete");
     Ok(())
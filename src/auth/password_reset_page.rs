@@ -7,13 +7,23 @@ use crate::component::input::TextInput;
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::AuthService, setup::get_pool};
+use crate::{
+    auth::model::User,
+    auth::service::{get_client_ip, AuthService},
+    error::Error,
+    login_throttle::model::LoginThrottle,
+    setup::get_pool,
+};
 
 #[server(endpoint = "password-reset")]
 pub async fn password_reset_request(email: String) -> Result<(), ServerFnError> {
     let pool = get_pool()?;
+    let parts = use_context::<http::request::Parts>().ok_or(Error::InternalServer)?;
+    let ip = get_client_ip(&parts)?.to_string();
     User::validate_email(&email)?;
     let user = User::get_by_email(&pool, &email).await?;
+    LoginThrottle::record_password_reset(&pool, &ip, &email, user.as_ref().map(|user| user.id))
+        .await?;
     if let Some(user) = user {
         AuthService::send_password_reset_email(user.id, &user.name, &user.email).await?;
     }
@@ -3,9 +3,11 @@ use sqlx::postgres::PgRow;
 use sqlx::{FromRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::component::select::SelectUuidName;
 use crate::error::{handle_sqlx_contraint_error, Error, Result};
 use crate::setup::get_pool;
+use crate::user_privacy::section::PrivacySection;
 use crate::util::server::{normalize_whitespace, slugify};
 
 use super::model::{RequestUser, User, UserRelation};
@@ -134,10 +136,13 @@ impl User {
         is_staff: Option<bool>,
         is_superuser: Option<bool>,
         privacy_level: i32,
+        request_user_id: Uuid,
     ) -> Result<Self> {
         let name = normalize_whitespace(name);
         let username = slugify(username);
-        sqlx::query_as!(
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
             Self,
             r#"
             UPDATE users_user
@@ -166,9 +171,10 @@ impl User {
             privacy_level,
             id
         )
-        .fetch_one(pool)
-        .await
-        .map_err(Error::from)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
     }
 
     pub async fn update_email(pool: &PgPool, user_id: Uuid, email: &str) -> Result<Self> {
@@ -188,9 +194,16 @@ impl User {
         .map_err(Error::from)
     }
 
-    pub async fn update_password(pool: &PgPool, user_id: Uuid, new_password: &str) -> Result<Self> {
+    pub async fn update_password(
+        pool: &PgPool,
+        user_id: Uuid,
+        new_password: &str,
+        request_user_id: Uuid,
+    ) -> Result<Self> {
         let hashed_password = bcrypt::hash(new_password, 8)?;
-        sqlx::query_as!(
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
             Self,
             r#"
             UPDATE users_user
@@ -201,9 +214,42 @@ impl User {
             hashed_password,
             user_id
         )
-        .fetch_one(pool)
-        .await
-        .map_err(Error::from)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+
+    /// Removes the password, leaving the user to sign in through their identity provider until
+    /// they set a new one.
+    pub async fn set_unusable_password(pool: &PgPool, user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user_id).await?;
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            UPDATE users_user
+            SET password = $1, updated_at = NOW()
+            WHERE id = $2
+            RETURNING *
+            "#,
+            Self::UNUSABLE_PASSWORD,
+            user_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+
+    pub async fn update_last_login(pool: &PgPool, user_id: Uuid) -> Result<()> {
+        sqlx::query!(
+            "UPDATE users_user SET last_login = NOW() WHERE id = $1",
+            user_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
     }
 
     pub async fn activate(pool: &PgPool, user_id: Uuid) -> Result<Self> {
@@ -256,6 +302,7 @@ impl User {
         pool: &PgPool,
         user: &RequestUser,
         username: &str,
+        section: PrivacySection,
     ) -> Result<()> {
         if user.is_superuser {
             return Ok(());
@@ -263,7 +310,7 @@ impl User {
         if user.username == username {
             return Ok(());
         }
-        let can_view: bool = sqlx::query_scalar(
+        let query = format!(
             "
                 SELECT
                     EXISTS (
@@ -277,23 +324,30 @@ impl User {
                             LEFT JOIN user_follower t3 ON t3.user_id = t1.id
                             AND t3.follower_id = $1
                             AND t3.status = 1
+                            LEFT JOIN user_privacy_setting t4 ON t4.user_id = t1.id
+                            LEFT JOIN coach_client t5 ON t5.client_id = t1.id
+                            AND t5.coach_id = $1
                         WHERE
                             t1.username = $2
                             AND t2.id IS NULL
                             AND (
-                                t3.id IS NOT NULL
-                                AND t1.privacy_level = 2
-                                OR t1.privacy_level = 1
-                                OR t1.privacy_level = 0
+                                {coach}
+                                OR t3.id IS NOT NULL
+                                AND {level} = 2
+                                OR {level} = 1
+                                OR {level} = 0
                             )
                             AND t1.id <> $1
                     ) AS record_exists
             ",
-        )
-        .bind(user.id)
-        .bind(username)
-        .fetch_one(pool)
-        .await?;
+            level = section.level_sql(),
+            coach = section.coach_sql()
+        );
+        let can_view: bool = sqlx::query_scalar(&query)
+            .bind(user.id)
+            .bind(username)
+            .fetch_one(pool)
+            .await?;
         if !can_view {
             return Err(Error::Forbidden);
         }
@@ -304,6 +358,7 @@ impl User {
         pool: &PgPool,
         user: &RequestUser,
         target_user_id: Uuid,
+        section: PrivacySection,
     ) -> Result<()> {
         if user.is_superuser {
             return Ok(());
@@ -311,7 +366,7 @@ impl User {
         if user.id == target_user_id {
             return Ok(());
         }
-        let can_view: bool = sqlx::query_scalar(
+        let query = format!(
             "
                 SELECT
                     EXISTS (
@@ -325,23 +380,30 @@ impl User {
                             LEFT JOIN user_follower t3 ON t3.user_id = t1.id
                             AND t3.follower_id = $1
                             AND t3.status = 1
+                            LEFT JOIN user_privacy_setting t4 ON t4.user_id = t1.id
+                            LEFT JOIN coach_client t5 ON t5.client_id = t1.id
+                            AND t5.coach_id = $1
                         WHERE
                             t1.id = $2
                             AND t2.id IS NULL
                             AND (
-                                t3.id IS NOT NULL
-                                AND t1.privacy_level = 2
-                                OR t1.privacy_level = 1
-                                OR t1.privacy_level = 0
+                                {coach}
+                                OR t3.id IS NOT NULL
+                                AND {level} = 2
+                                OR {level} = 1
+                                OR {level} = 0
                             )
                             AND t1.id <> $1
                     ) AS record_exists
             ",
-        )
-        .bind(user.id)
-        .bind(target_user_id)
-        .fetch_one(pool)
-        .await?;
+            level = section.level_sql(),
+            coach = section.coach_sql()
+        );
+        let can_view: bool = sqlx::query_scalar(&query)
+            .bind(user.id)
+            .bind(target_user_id)
+            .fetch_one(pool)
+            .await?;
         if !can_view {
             return Err(Error::Forbidden);
         }
@@ -464,6 +526,8 @@ impl From<AuthToken> for RequestUser {
             is_active: auth_token.is_active,
             is_staff: auth_token.is_staff,
             is_superuser: auth_token.is_superuser,
+            session_id: Some(auth_token.session_id),
+            impersonator_id: auth_token.impersonator_id,
         }
     }
 }
@@ -476,6 +540,8 @@ impl From<User> for RequestUser {
             is_active: user.is_active,
             is_staff: user.is_staff,
             is_superuser: user.is_superuser,
+            session_id: None,
+            impersonator_id: None,
         }
     }
 }
@@ -504,4 +570,18 @@ impl RequestUser {
             .ok_or(Error::Unauthorized)?;
         user.is_superuser.then(|| ()).ok_or(Error::Unauthorized)
     }
+
+    /// Changes to the account's sign in details, its credentials, sessions and coaches, its data
+    /// export and import and its deletion are left to the user.
+    pub fn ensure_not_impersonated(&self) -> Result<()> {
+        self.impersonator_id
+            .is_none()
+            .then_some(())
+            .ok_or_else(|| Error::Other("This is not available while impersonating a user.".into()))
+    }
+
+    /// The user credited with the request's writes, which is the superuser while impersonating.
+    pub fn actor_id(&self) -> Uuid {
+        self.impersonator_id.unwrap_or(self.id)
+    }
 }
@@ -1,12 +1,19 @@
-use http::request::Parts;
+use std::net::{IpAddr, SocketAddr};
 
+use axum::extract::ConnectInfo;
+use chrono::{DateTime, Utc};
+use http::request::Parts;
+use http::{header, HeaderMap};
+use sqlx::PgPool;
 use uuid::Uuid;
 
+use super::cookie::{set_jwt_cookie, set_refresh_cookie};
 use super::email_service::EmailService;
-use super::model::RequestUser;
+use super::model::{RequestUser, User};
 use super::token::{JwtManager, TokenType};
 use crate::config::get_config;
 use crate::error::{Error, Result};
+use crate::user_session::model::{RefreshRotation, SessionRefreshToken, UserSession};
 
 pub fn extract_user_from_request() -> Result<Option<RequestUser>> {
     let parts = leptos::use_context::<Parts>().ok_or(Error::InternalServer)?;
@@ -43,9 +50,196 @@ pub fn extract_superuser_from_request() -> Result<RequestUser> {
     Ok(request_user)
 }
 
+/// The address of the connected client. Forwarding headers are only read when the connection
+/// comes from one of the configured trusted proxies, and then the nearest address that is not
+/// another trusted proxy is used.
+pub fn get_client_ip(parts: &Parts) -> Result<IpAddr> {
+    let ConnectInfo(peer) = parts
+        .extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .ok_or(Error::InternalServer)?;
+    Ok(resolve_client_ip(
+        peer.ip(),
+        &parts.headers,
+        &get_config().trusted_proxies,
+    ))
+}
+
+fn resolve_client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpAddr]) -> IpAddr {
+    if !trusted_proxies.contains(&peer) {
+        return peer;
+    }
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|hop| hop.trim().parse::<IpAddr>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .ok()
+        .and_then(|hops| {
+            hops.into_iter()
+                .rev()
+                .find(|hop| !trusted_proxies.contains(hop))
+        })
+        .unwrap_or(peer)
+}
+
+pub struct SessionTokens {
+    pub session: UserSession,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+/// The result of exchanging a refresh token in the middleware.
+pub enum SessionRefresh {
+    /// New tokens were issued and should replace both cookies.
+    Refreshed {
+        user: RequestUser,
+        access_token: String,
+        refresh_token: String,
+    },
+    /// A concurrent request has already refreshed the session and is setting the new cookies.
+    AlreadyRefreshed(RequestUser),
+    Invalid,
+}
+
 pub struct AuthService;
 
 impl AuthService {
+    /// Records a new session for the user's browser and sets the auth cookie for it.
+    pub async fn start_session(pool: &PgPool, user: &User) -> Result<UserSession> {
+        Self::set_session_cookies(pool, user, None).await
+    }
+
+    /// Starts a session as the user for the impersonating superuser, replacing the superuser's
+    /// auth cookies.
+    pub async fn start_impersonation(
+        pool: &PgPool,
+        user: &User,
+        impersonator_id: Uuid,
+    ) -> Result<UserSession> {
+        Self::set_session_cookies(pool, user, Some(impersonator_id)).await
+    }
+
+    async fn set_session_cookies(
+        pool: &PgPool,
+        user: &User,
+        impersonator_id: Option<Uuid>,
+    ) -> Result<UserSession> {
+        let parts = leptos::use_context::<Parts>().ok_or(Error::InternalServer)?;
+        let client_ip = get_client_ip(&parts)?;
+        let tokens =
+            Self::create_session(pool, user, &parts.headers, client_ip, impersonator_id).await?;
+        set_jwt_cookie(&tokens.access_token)?;
+        set_refresh_cookie(&tokens.refresh_token)?;
+        Ok(tokens.session)
+    }
+
+    /// Records a new session and issues its tokens, leaving the caller to set the cookies.
+    pub async fn create_session(
+        pool: &PgPool,
+        user: &User,
+        headers: &HeaderMap,
+        client_ip: IpAddr,
+        impersonator_id: Option<Uuid>,
+    ) -> Result<SessionTokens> {
+        let user_agent = headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        let session = UserSession::create(
+            pool,
+            user.id,
+            user_agent,
+            Some(&client_ip.to_string()),
+            impersonator_id,
+        )
+        .await?;
+        let (refresh_token, token_hash) = SessionRefreshToken::generate_token();
+        SessionRefreshToken::create(
+            pool,
+            session.id,
+            user.id,
+            &token_hash,
+            SessionRefreshToken::expires_at(),
+        )
+        .await?;
+        let access_token = JwtManager::generate_auth_token(
+            user.id,
+            session.id,
+            &user.username,
+            user.is_active,
+            user.is_staff,
+            user.is_superuser,
+            impersonator_id,
+        )?;
+        if impersonator_id.is_none() {
+            User::update_last_login(pool, user.id).await?;
+        }
+        Ok(SessionTokens {
+            session,
+            access_token,
+            refresh_token,
+        })
+    }
+
+    /// Exchanges a refresh token for a new access token, re-reading the user so that changes to
+    /// their account take effect.
+    pub async fn refresh_session(pool: &PgPool, refresh_token: &str) -> Result<SessionRefresh> {
+        let token_hash = SessionRefreshToken::hash_token(refresh_token);
+        let (new_refresh_token, new_token_hash) = SessionRefreshToken::generate_token();
+        let rotation = SessionRefreshToken::rotate(
+            pool,
+            &token_hash,
+            &new_token_hash,
+            SessionRefreshToken::expires_at(),
+        )
+        .await?;
+        let (token, refreshed) = match rotation {
+            RefreshRotation::Rotated(token) => (token, true),
+            RefreshRotation::RecentlyRotated(token) => (token, false),
+            RefreshRotation::Revoked | RefreshRotation::Invalid => {
+                return Ok(SessionRefresh::Invalid)
+            }
+        };
+
+        let Some(user) = User::get_by_id(pool, token.user_id)
+            .await?
+            .filter(|user| user.is_active)
+        else {
+            UserSession::delete(pool, token.session_id, token.user_id).await?;
+            return Ok(SessionRefresh::Invalid);
+        };
+        let impersonator_id = UserSession::get_impersonator_id(pool, token.session_id).await?;
+        let request_user = RequestUser {
+            session_id: Some(token.session_id),
+            impersonator_id,
+            ..RequestUser::from(user.clone())
+        };
+        if !refreshed {
+            return Ok(SessionRefresh::AlreadyRefreshed(request_user));
+        }
+
+        let access_token = JwtManager::generate_auth_token(
+            user.id,
+            token.session_id,
+            &user.username,
+            user.is_active,
+            user.is_staff,
+            user.is_superuser,
+            impersonator_id,
+        )?;
+        if impersonator_id.is_none() {
+            User::update_last_login(pool, user.id).await?;
+        }
+        Ok(SessionRefresh::Refreshed {
+            user: request_user,
+            access_token,
+            refresh_token: new_refresh_token,
+        })
+    }
+
     fn generate_link(token: &str, token_type: TokenType) -> String {
         let config = get_config();
         match token_type {
@@ -54,6 +248,10 @@ impl AuthService {
                 format!("{}/password-reset/confirm?token={}", config.domain, token)
             }
             TokenType::EmailChange => format!("{}/confirm-email?token={}", config.domain, token),
+            TokenType::AccountDeletionCancel => {
+                format!("{}/account-deletion/cancel?token={}", config.domain, token)
+            }
+            TokenType::TwoFactor => format!("{}/login/two-factor", config.domain),
         }
     }
 
@@ -77,4 +275,46 @@ impl AuthService {
         EmailService::send_email_confirmation(name, new_email, &confirmation_link).await?;
         Ok(())
     }
+
+    pub async fn send_account_deletion_email(
+        user_id: Uuid,
+        name: &str,
+        email: &str,
+        delete_after: DateTime<Utc>,
+    ) -> Result<()> {
+        let token = JwtManager::create_token(user_id, email, TokenType::AccountDeletionCancel)?;
+        let cancel_link = Self::generate_link(&token, TokenType::AccountDeletionCancel);
+        let delete_after = delete_after.format("%A %d %B %Y").to_string();
+        EmailService::send_account_deletion_email(name, email, &cancel_link, &delete_after).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_client_ip() {
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        let client: IpAddr = "203.0.113.7".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        assert_eq!(resolve_client_ip(peer, &headers, &[]), peer);
+        assert_eq!(resolve_client_ip(peer, &headers, &[peer]), peer);
+
+        headers.insert(
+            "x-forwarded-for",
+            "198.51.100.1, 203.0.113.7".parse().unwrap(),
+        );
+        assert_eq!(resolve_client_ip(peer, &headers, &[]), peer);
+        assert_eq!(resolve_client_ip(peer, &headers, &[peer]), client);
+
+        // A second proxy in front is skipped when it is trusted too.
+        let proxy: IpAddr = "10.0.0.2".parse().unwrap();
+        headers.insert("x-forwarded-for", "203.0.113.7, 10.0.0.2".parse().unwrap());
+        assert_eq!(resolve_client_ip(peer, &headers, &[peer, proxy]), client);
+
+        headers.insert("x-forwarded-for", "not an address".parse().unwrap());
+        assert_eq!(resolve_client_ip(peer, &headers, &[peer]), peer);
+    }
 }
@@ -10,8 +10,8 @@ use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 #[cfg(feature = "ssr")]
 use crate::{
     auth::{
-        cookie::set_jwt_cookie,
         model::User,
+        service::AuthService,
         token::{JwtManager, TokenType},
     },
     setup::get_pool,
@@ -22,14 +22,7 @@ pub async fn signup_confirm(token: String) -> Result<(), ServerFnError> {
     let token = JwtManager::validate_token(&token, TokenType::Activation)?;
     let pool = get_pool()?;
     let user = User::activate(&pool, token.user_id).await?;
-    let token = JwtManager::generate_auth_token(
-        user.id,
-        &user.username,
-        user.is_active,
-        user.is_staff,
-        user.is_superuser,
-    )?;
-    set_jwt_cookie(&token)?;
+    AuthService::start_session(&pool, &user).await?;
     leptos_axum::redirect(&format!("/users/{}/setup", user.username));
     Ok(())
 }
@@ -6,11 +6,16 @@ use uuid::Uuid;
 use crate::config::get_config;
 use crate::error::{Error, Result};
 
+/// How long a password-verified login has to complete the two-factor step.
+const TWO_FACTOR_TOKEN_MINUTES: i64 = 10;
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub enum TokenType {
     Activation,
     PasswordReset,
     EmailChange,
+    AccountDeletionCancel,
+    TwoFactor,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -25,36 +30,46 @@ pub struct Token {
 pub struct AuthToken {
     pub exp: i64,
     pub user_id: Uuid,
+    pub session_id: Uuid,
     pub username: String,
     pub is_active: bool,
     pub is_staff: bool,
     pub is_superuser: bool,
+    /// The superuser viewing the site as this user.
+    #[serde(default)]
+    pub impersonator_id: Option<Uuid>,
 }
 
 pub struct JwtManager;
 
 impl JwtManager {
+    /// Access tokens are short lived and are renewed by the middleware using the session's
+    /// refresh token.
     pub fn generate_auth_token(
         user_id: Uuid,
+        session_id: Uuid,
         username: &str,
         is_active: bool,
         is_staff: bool,
         is_superuser: bool,
+        impersonator_id: Option<Uuid>,
     ) -> Result<String> {
         let config = get_config();
 
         let expiration = Utc::now()
-            .checked_add_signed(TimeDelta::seconds(config.token_duration_authentication))
+            .checked_add_signed(TimeDelta::seconds(config.token_duration_access))
             .expect("valid timestamp")
             .timestamp();
 
         let claims = AuthToken {
             exp: expiration,
             user_id,
+            session_id,
             username: username.to_string(),
             is_active,
             is_staff,
             is_superuser,
+            impersonator_id,
         };
 
         let token = encode(&Header::default(), &claims, &config.encoding_key)?;
@@ -77,13 +92,15 @@ impl JwtManager {
         let config = get_config();
 
         let duration = match token_type {
-            TokenType::Activation => config.token_duration_account_activation,
-            TokenType::PasswordReset => config.token_duration_password_reset,
-            TokenType::EmailChange => config.token_duration_email_change,
+            TokenType::Activation => TimeDelta::days(config.token_duration_account_activation),
+            TokenType::PasswordReset => TimeDelta::days(config.token_duration_password_reset),
+            TokenType::EmailChange => TimeDelta::days(config.token_duration_email_change),
+            TokenType::AccountDeletionCancel => TimeDelta::days(config.account_deletion_grace_days),
+            TokenType::TwoFactor => TimeDelta::minutes(TWO_FACTOR_TOKEN_MINUTES),
         };
 
         let expiration = Utc::now()
-            .checked_add_signed(TimeDelta::days(duration))
+            .checked_add_signed(duration)
             .expect("valid timestamp")
             .timestamp();
 
@@ -62,6 +62,17 @@ impl User {
         }
     }
 
+    pub fn validate_new_password(new_password: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.validate_string("new_password", new_password, Some(8), Some(255), None);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     pub fn validate_password_change(
         old_password: &str,
         new_password: &str,
@@ -82,6 +93,12 @@ impl User {
     }
 
     pub fn verify_password(&self, password: &str) -> crate::error::Result<()> {
+        if !self.has_usable_password() {
+            return Err(crate::error::Error::Other(
+                "Your account does not have a password yet. Set one on the change password page."
+                    .into(),
+            ));
+        }
         if bcrypt::verify(password, &self.password)? {
             Ok(())
         } else {
@@ -18,7 +18,7 @@ pub async fn brand_create(name: String) -> Result<(), ServerFnError> {
     Brand::can_create(&user).await?;
     Brand::validate(&name)?;
 
-    let object = Brand::create(&pool, &name, user.id).await?;
+    let object = Brand::create(&pool, &name, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/food/brands/{}", object.slug));
     Ok(())
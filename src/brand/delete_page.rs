@@ -21,7 +21,7 @@ pub async fn brand_delete(id: Uuid) -> Result<(), ServerFnError> {
     let object = Brand::get_by_id(&pool, id).await?.ok_or(Error::NotFound)?;
     object.can_delete(&user).await?;
 
-    Brand::delete(&pool, object.id).await?;
+    Brand::delete(&pool, object.id, user.actor_id()).await?;
 
     leptos_axum::redirect("/food/brands");
     Ok(())
@@ -1,6 +1,7 @@
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::brand::model::{Brand, BrandQuery};
 use crate::component::select::{SelectSlugName, SelectUuidName};
 use crate::error::{handle_sqlx_contraint_error, Result};
@@ -78,10 +79,13 @@ impl Brand {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(Self, "DELETE FROM food_brand WHERE id = $1 RETURNING *", id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
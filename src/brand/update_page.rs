@@ -23,7 +23,7 @@ pub async fn brand_update(id: Uuid, name: String) -> Result<(), ServerFnError> {
     object.can_update(&user).await?;
     Brand::validate(&name)?;
 
-    let updated = Brand::update(&pool, object.id, &name, user.id).await?;
+    let updated = Brand::update(&pool, object.id, &name, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/food/brands/{}", updated.slug));
     Ok(())
@@ -67,7 +67,7 @@ pub async fn upload_file_action(data: MultipartData) -> Result<(), ServerFnError
         }
     }
 
-    Brand::update_image_url(&pool, &slug, &new_file_name, user.id).await?;
+    Brand::update_image_url(&pool, &slug, &new_file_name, user.actor_id()).await?;
     Ok(())
 }
 
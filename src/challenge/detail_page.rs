@@ -0,0 +1,202 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use chrono::prelude::*;
+use uuid::Uuid;
+
+use crate::component::button::{Button, ButtonVariant};
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::param::UuidParam;
+use crate::util::validation_error::extract_other_errors;
+
+use super::model::{ChallengeDetail, LeaderboardEntry};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user,
+    challenge::model::{Challenge, ChallengeQuery},
+    error::Error,
+    setup::get_pool,
+};
+
+#[server(endpoint = "challenge-detail", input = GetUrl)]
+pub async fn get_challenge_detail(id: Uuid) -> Result<ChallengeDetail, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let challenge = ChallengeQuery::get_by_id(&pool, id, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    challenge.can_view(&user)?;
+    let leaderboard = LeaderboardEntry::all_by_challenge(&pool, &challenge, &user).await?;
+    let can_join = challenge.can_join(&user, Utc::now().date_naive()).is_ok();
+    let can_delete = challenge.can_delete(&user).is_ok();
+    Ok(ChallengeDetail {
+        challenge,
+        leaderboard,
+        can_join,
+        can_delete,
+    })
+}
+
+#[server(endpoint = "challenge-join")]
+pub async fn challenge_join(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let challenge = ChallengeQuery::get_by_id(&pool, id, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    challenge.can_join(&user, Utc::now().date_naive())?;
+    Challenge::join(&pool, challenge.id, user.id).await?;
+    Ok(())
+}
+
+#[server(endpoint = "challenge-leave")]
+pub async fn challenge_leave(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    Challenge::leave(&pool, id, user.id).await?;
+    Ok(())
+}
+
+#[server(endpoint = "challenge-delete")]
+pub async fn challenge_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let challenge = ChallengeQuery::get_by_id(&pool, id, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    challenge.can_delete(&user)?;
+    Challenge::delete(&pool, challenge.id, user.actor_id()).await?;
+    leptos_axum::redirect("/challenges");
+    Ok(())
+}
+
+#[component]
+pub fn ChallengeDetailPage() -> impl IntoView {
+    let params = use_params::<UuidParam>();
+    let id = move || params.with(|p| p.as_ref().map(|p| p.id).unwrap_or_default());
+
+    let action_join = Action::<ChallengeJoin, _>::server();
+    let action_leave = Action::<ChallengeLeave, _>::server();
+    let action_delete = Action::<ChallengeDelete, _>::server();
+
+    let resource = Resource::new(
+        move || {
+            (
+                id(),
+                action_join.version().get(),
+                action_leave.version().get(),
+            )
+        },
+        |(id, ..)| get_challenge_detail(id),
+    );
+
+    let today = Utc::now().date_naive();
+    let response = move || {
+        resource.and_then(|data| {
+            let challenge = &data.challenge;
+            let id = challenge.id;
+            let metric = challenge.get_metric();
+            let dates = format!(
+                "{} - {}",
+                challenge.start_date.format("%d/%m/%Y"),
+                challenge.end_date.format("%d/%m/%Y")
+            );
+            let join = (data.can_join && !challenge.joined).then(|| {
+                view! {
+                    <ActionForm action=action_join class="contents">
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <Button label="Join"/>
+                    </ActionForm>
+                }
+            });
+            let leave = challenge.joined.then(|| {
+                view! {
+                    <ActionForm action=action_leave class="contents">
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <Button label="Leave"/>
+                    </ActionForm>
+                }
+            });
+            let delete = data.can_delete.then(|| {
+                view! {
+                    <ActionForm action=action_delete class="contents">
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <Button label="Delete" variant=ButtonVariant::Danger/>
+                    </ActionForm>
+                }
+            });
+            view! {
+                <header class="flex flex-wrap gap-2 justify-between mb-4">
+                    <div>
+                        <h1 class="text-xl font-bold">{challenge.name.clone()}</h1>
+                        <p>{metric.to_string()} " by " {challenge.username.clone()}</p>
+                        <p class="text-sm text-gray-500">
+                            {dates} " · " {challenge.status(today).to_string()}
+                        </p>
+                    </div>
+                    <div class="flex gap-2 items-start">{join} {leave} {delete}</div>
+                </header>
+                <Leaderboard entries=data.leaderboard.clone() unit=metric.unit()/>
+            }
+        })
+    };
+
+    let join_error = move || extract_other_errors(action_join.value(), &[]);
+    let delete_error = move || extract_other_errors(action_delete.value(), &[]);
+
+    view! {
+        <Title text="Challenge"/>
+        <main class="p-4 m-4 bg-white border">
+            <div class="mb-4 font-bold text-red-500">{join_error}</div>
+            <div class="mb-4 font-bold text-red-500">{delete_error}</div>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </main>
+    }
+}
+
+#[component]
+pub fn Leaderboard(entries: Vec<LeaderboardEntry>, unit: &'static str) -> impl IntoView {
+    let rows = entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let href = format!("/users/{}", entry.username);
+            let rank = entry.is_visible.then(|| index + 1);
+            let score = match entry.score {
+                Some(score) => format!("{} {}", score.normalize(), unit),
+                None => "Private".to_string(),
+            };
+            view! {
+                <div class="contents group">
+                    <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">
+                        {rank}
+                    </div>
+                    <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">
+                        <A href=href class="hover:underline">
+                            {entry.username}
+                        </A>
+                    </div>
+                    <div class="p-2 border-b text-end group-hover:bg-amber-200 group-odd:bg-gray-50">
+                        {score}
+                    </div>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <section class="grid grid-cols-[auto_1fr_auto]">
+            <div class="p-2 font-bold border-b">"#"</div>
+            <div class="p-2 font-bold border-b">"Participant"</div>
+            <div class="p-2 font-bold border-b text-end">"Score"</div>
+            {rows}
+        </section>
+    }
+}
@@ -0,0 +1,197 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use chrono::prelude::*;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::component::paginator::Paginator;
+use crate::component::select::FieldSelect;
+use crate::component::template::{
+    ErrorComponent, ListLoadingComponent, ListNotFoundComponent, Loading,
+};
+use crate::util::misc::ListResponse;
+use crate::util::param::{extract_page, extract_size};
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::metric::ChallengeMetric;
+use super::model::ChallengeQuery;
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, challenge::model::Challenge, setup::get_pool};
+
+#[server(endpoint = "challenge-list", input = GetUrl)]
+pub async fn get_challenge_list(
+    size: i64,
+    page: i64,
+) -> Result<ListResponse<ChallengeQuery>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let count = ChallengeQuery::count_by_user_id(&pool, user.id).await?;
+    let results = ChallengeQuery::all_by_user_id(&pool, user.id, size, page).await?;
+    Ok(ListResponse { count, results })
+}
+
+#[server(endpoint = "challenge-create")]
+pub async fn challenge_create(
+    name: String,
+    metric: i32,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let name = name.trim();
+    Challenge::validate(name, metric, start_date, end_date, Utc::now().date_naive())?;
+    let challenge = Challenge::create(&pool, user.id, name, metric, start_date, end_date).await?;
+    leptos_axum::redirect(&format!("/challenges/{}", challenge.id));
+    Ok(())
+}
+
+#[component]
+pub fn ChallengeListPage() -> impl IntoView {
+    let action_create = Action::<ChallengeCreate, _>::server();
+
+    let query = use_query_map();
+    let size = move || extract_size(&query);
+    let page = move || extract_page(&query);
+    let resource = Resource::new(
+        move || (size(), page(), action_create.version().get()),
+        |(size, page, _)| get_challenge_list(size, page),
+    );
+
+    let today = Utc::now().date_naive();
+    let response = move || {
+        resource.and_then(|data| {
+            if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.results
+                    .iter()
+                    .map(|data| view! { <ChallengeListItem data=data.clone() today/> })
+                    .collect_view()
+            }
+        })
+    };
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().map(|res| res.count))
+        })
+    };
+
+    view! {
+        <Title text="Challenges"/>
+        <main class="p-4">
+            <div class="grid grid-cols-4 gap-4 md:grid-cols-12">
+                <div class="col-span-4 md:col-span-8">
+                    <div class="p-4 bg-white border shadow-sm">
+                        <header class="mb-4">
+                            <h1 class="text-xl font-bold">"Challenges"</h1>
+                            <p class="text-gray-400">
+                                "Results: " <Transition fallback=Loading>{count}</Transition>
+                            </p>
+                        </header>
+                        <Transition fallback=ListLoadingComponent>
+                            <ErrorBoundary fallback=|errors| {
+                                view! { <ErrorComponent errors/> }
+                            }>{response}</ErrorBoundary>
+                        </Transition>
+                        <Form method="GET" action="" class="contents">
+                            <input type="hidden" name="page" value=page/>
+                            <Transition>
+                                <Paginator count/>
+                            </Transition>
+                        </Form>
+                    </div>
+                </div>
+                <div class="col-span-4">
+                    <div class="p-4 bg-white border shadow-sm">
+                        <header class="mb-4">
+                            <h2 class="mb-2 text-base font-bold">"New Challenge"</h2>
+                            <p>"Your followers will be able to join."</p>
+                        </header>
+                        <ChallengeCreateForm action=action_create/>
+                    </div>
+                </div>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+pub fn ChallengeListItem(data: ChallengeQuery, today: NaiveDate) -> impl IntoView {
+    let href = data.href();
+    let status = data.status(today).to_string();
+    let metric = data.get_metric().to_string();
+    let dates = format!(
+        "{} - {}",
+        data.start_date.format("%d/%m/%Y"),
+        data.end_date.format("%d/%m/%Y")
+    );
+    let participants = format!("{} participants", data.participant_count);
+    let joined = data.joined.then_some("Joined");
+
+    view! {
+        <A href=href class="flex gap-2 justify-between items-start p-2 mb-2 bg-gray-100 hover:bg-amber-200">
+            <div>
+                <div class="font-bold">{data.name}</div>
+                <div class="text-sm">{metric} " by " {data.username}</div>
+                <div class="text-xs text-gray-500">{dates}</div>
+            </div>
+            <div class="text-sm text-end">
+                <div class="font-bold">{status}</div>
+                <div>{participants}</div>
+                <div class="text-green-600">{joined}</div>
+            </div>
+        </A>
+    }
+}
+
+#[component]
+pub fn ChallengeCreateForm(
+    action: Action<ChallengeCreate, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || {
+        extract_other_errors(
+            action_value,
+            &[
+                "non_field_errors",
+                "name",
+                "metric",
+                "start_date",
+                "end_date",
+            ],
+        )
+    };
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let today = Utc::now().date_naive();
+
+    view! {
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action>
+            <TextInput action_value name="name"/>
+            <FieldSelect name="metric" options=ChallengeMetric::to_form_options()/>
+            <TextInput
+                action_value
+                name="start_date"
+                label="Start date"
+                input_type="date"
+                value=today.to_string()
+            />
+            <TextInput
+                action_value
+                name="end_date"
+                label="End date"
+                input_type="date"
+                value=(today + chrono::Days::new(30)).to_string()
+            />
+            <SubmitButton loading=action_loading label="Create Challenge"/>
+        </ActionForm>
+    }
+}
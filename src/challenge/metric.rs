@@ -0,0 +1,142 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::user_privacy::section::PrivacySection;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum ChallengeMetric {
+    Volume,
+    LoggedDays,
+    WeightLoss,
+    Unknown(i32),
+}
+
+impl fmt::Display for ChallengeMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChallengeMetric::Volume => write!(f, "Most Total Volume"),
+            ChallengeMetric::LoggedDays => write!(f, "Most Diet Days Logged"),
+            ChallengeMetric::WeightLoss => write!(f, "Most Weight Lost"),
+            ChallengeMetric::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl ChallengeMetric {
+    /// The part of a participant's data the score is computed from, whose privacy setting
+    /// decides who can see the score.
+    pub fn section(&self) -> PrivacySection {
+        match self {
+            ChallengeMetric::Volume | ChallengeMetric::Unknown(_) => PrivacySection::Workout,
+            ChallengeMetric::LoggedDays => PrivacySection::DietLog,
+            ChallengeMetric::WeightLoss => PrivacySection::Progress,
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            ChallengeMetric::Volume => "kg",
+            ChallengeMetric::LoggedDays => "days",
+            ChallengeMetric::WeightLoss => "%",
+            ChallengeMetric::Unknown(_) => "",
+        }
+    }
+
+    /// A query returning each participant's `user_id` and `score` between `$2` and `$3`, where
+    /// `participant` is a CTE of the challenge's participants.
+    #[cfg(feature = "ssr")]
+    pub fn score_sql(&self) -> &'static str {
+        match self {
+            ChallengeMetric::Volume => {
+                "
+                SELECT
+                    t3.user_id,
+                    SUM(t1.weight * t1.reps) AS score
+                FROM
+                    tracked_set t1
+                    JOIN exercise t2 ON t2.id = t1.exercise_id
+                    JOIN workout t3 ON t3.id = t2.workout_id
+                    JOIN participant t4 ON t4.user_id = t3.user_id
+                WHERE
+                    t3.date BETWEEN $2 AND $3
+                GROUP BY
+                    t3.user_id
+                "
+            }
+            ChallengeMetric::LoggedDays => {
+                "
+                SELECT
+                    t1.user_id,
+                    COUNT(DISTINCT t1.date)::NUMERIC AS score
+                FROM
+                    food_log t1
+                    JOIN participant t2 ON t2.user_id = t1.user_id
+                WHERE
+                    t1.date BETWEEN $2 AND $3
+                GROUP BY
+                    t1.user_id
+                "
+            }
+            ChallengeMetric::WeightLoss => {
+                "
+                SELECT
+                    t1.user_id,
+                    ROUND(
+                        (
+                            (ARRAY_AGG(t1.weight_kg ORDER BY t1.date))[1] - (ARRAY_AGG(t1.weight_kg ORDER BY t1.date DESC))[1]
+                        ) / NULLIF((ARRAY_AGG(t1.weight_kg ORDER BY t1.date))[1], 0) * 100,
+                        2
+                    ) AS score
+                FROM
+                    progress t1
+                    JOIN participant t2 ON t2.user_id = t1.user_id
+                WHERE
+                    t1.date BETWEEN $2 AND $3
+                    AND t1.weight_kg IS NOT NULL
+                GROUP BY
+                    t1.user_id
+                "
+            }
+            ChallengeMetric::Unknown(_) => "SELECT NULL::UUID AS user_id, NULL::NUMERIC AS score",
+        }
+    }
+
+    pub fn all() -> [ChallengeMetric; 3] {
+        [
+            ChallengeMetric::Volume,
+            ChallengeMetric::LoggedDays,
+            ChallengeMetric::WeightLoss,
+        ]
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("1", "Most Total Volume"),
+            ("2", "Most Diet Days Logged"),
+            ("3", "Most Weight Lost"),
+        ]
+    }
+}
+
+impl From<i32> for ChallengeMetric {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ChallengeMetric::Volume,
+            2 => ChallengeMetric::LoggedDays,
+            3 => ChallengeMetric::WeightLoss,
+            _ => ChallengeMetric::Unknown(value),
+        }
+    }
+}
+
+impl From<ChallengeMetric> for i32 {
+    fn from(metric: ChallengeMetric) -> Self {
+        match metric {
+            ChallengeMetric::Volume => 1,
+            ChallengeMetric::LoggedDays => 2,
+            ChallengeMetric::WeightLoss => 3,
+            ChallengeMetric::Unknown(value) => value,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub mod detail_page;
+pub mod list_page;
+pub mod metric;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod permission;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+#[cfg(feature = "ssr")]
+pub mod validate;
@@ -0,0 +1,118 @@
+use std::fmt;
+
+use chrono::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::metric::ChallengeMetric;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum ChallengeStatus {
+    Upcoming,
+    Active,
+    Ended,
+}
+
+impl fmt::Display for ChallengeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChallengeStatus::Upcoming => write!(f, "Upcoming"),
+            ChallengeStatus::Active => write!(f, "Active"),
+            ChallengeStatus::Ended => write!(f, "Ended"),
+        }
+    }
+}
+
+impl ChallengeStatus {
+    pub fn from_dates(start_date: NaiveDate, end_date: NaiveDate, today: NaiveDate) -> Self {
+        if today < start_date {
+            ChallengeStatus::Upcoming
+        } else if today > end_date {
+            ChallengeStatus::Ended
+        } else {
+            ChallengeStatus::Active
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Challenge {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub metric: i32,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ChallengeQuery {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub username: String,
+    pub name: String,
+    pub metric: i32,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub participant_count: i64,
+    /// Whether the request user has joined.
+    pub joined: bool,
+    /// Whether the request user follows the creator.
+    pub following: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ChallengeQuery {
+    pub fn get_metric(&self) -> ChallengeMetric {
+        ChallengeMetric::from(self.metric)
+    }
+
+    pub fn status(&self, today: NaiveDate) -> ChallengeStatus {
+        ChallengeStatus::from_dates(self.start_date, self.end_date, today)
+    }
+
+    pub fn href(&self) -> String {
+        format!("/challenges/{}", self.id)
+    }
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LeaderboardEntry {
+    pub user_id: Uuid,
+    pub username: String,
+    /// Empty when the participant's privacy settings hide the score from the request user.
+    pub score: Option<Decimal>,
+    pub is_visible: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ChallengeDetail {
+    pub challenge: ChallengeQuery,
+    pub leaderboard: Vec<LeaderboardEntry>,
+    pub can_join: bool,
+    pub can_delete: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_from_dates_is_inclusive() {
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 7, 31).unwrap();
+        let status = |day| ChallengeStatus::from_dates(start, end, day);
+
+        assert_eq!(status(start.pred_opt().unwrap()), ChallengeStatus::Upcoming);
+        assert_eq!(status(start), ChallengeStatus::Active);
+        assert_eq!(status(end), ChallengeStatus::Active);
+        assert_eq!(status(end.succ_opt().unwrap()), ChallengeStatus::Ended);
+    }
+}
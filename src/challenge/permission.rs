@@ -0,0 +1,34 @@
+use chrono::prelude::*;
+
+use crate::auth::model::RequestUser;
+use crate::error::{Error, Result};
+
+use super::model::{ChallengeQuery, ChallengeStatus};
+
+impl ChallengeQuery {
+    pub fn can_view(&self, user: &RequestUser) -> Result<()> {
+        if self.user_id == user.id || self.joined || self.following || user.is_superuser {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+
+    /// The creator and their followers can join until the challenge ends.
+    pub fn can_join(&self, user: &RequestUser, today: NaiveDate) -> Result<()> {
+        let is_open = self.status(today) != ChallengeStatus::Ended;
+        if is_open && (self.user_id == user.id || self.following) {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+
+    pub fn can_delete(&self, user: &RequestUser) -> Result<()> {
+        if self.user_id == user.id || user.is_superuser {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+}
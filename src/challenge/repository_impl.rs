@@ -0,0 +1,386 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::auth::model::RequestUser;
+use crate::error::Result;
+
+use super::model::{Challenge, ChallengeQuery, LeaderboardEntry};
+
+impl Challenge {
+    /// Creates the challenge with its creator as the first participant.
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        name: &str,
+        metric: i32,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        let challenge = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                challenge (user_id, name, metric, start_date, end_date)
+            VALUES
+                ($1, $2, $3, $4, $5)
+            RETURNING
+                *
+            ",
+            user_id,
+            name,
+            metric,
+            start_date,
+            end_date,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "INSERT INTO challenge_participant (challenge_id, user_id) VALUES ($1, $2)",
+            challenge.id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(challenge)
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query!("DELETE FROM challenge WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+
+    pub async fn join(pool: &PgPool, id: Uuid, user_id: Uuid) -> Result<u64> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO
+                challenge_participant (challenge_id, user_id)
+            VALUES
+                ($1, $2)
+            ON CONFLICT (challenge_id, user_id) DO NOTHING
+            ",
+            id,
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+
+    pub async fn leave(pool: &PgPool, id: Uuid, user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user_id).await?;
+        let query = sqlx::query!(
+            "DELETE FROM challenge_participant WHERE challenge_id = $1 AND user_id = $2",
+            id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+}
+
+impl ChallengeQuery {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.user_id,
+                t2.username,
+                t1.name,
+                t1.metric,
+                t1.start_date,
+                t1.end_date,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        challenge_participant
+                    WHERE
+                        challenge_id = t1.id
+                ) AS "participant_count!",
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        challenge_participant
+                    WHERE
+                        challenge_id = t1.id
+                        AND user_id = $2
+                ) AS "joined!",
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_follower t3
+                    WHERE
+                        t3.user_id = t1.user_id
+                        AND t3.follower_id = $2
+                        AND t3.status = 1
+                        AND NOT EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                user_block
+                            WHERE
+                                blocker_id = t1.user_id
+                                AND blocked_id = $2
+                                AND blocked_status = 1
+                        )
+                ) AS "following!",
+                t1.created_at
+            FROM
+                challenge t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t1.id = $1
+            "#,
+            id,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Challenges the user created, joined, or can join as a follower of their creator. Those
+    /// still running are listed first.
+    pub async fn all_by_user_id(
+        pool: &PgPool,
+        user_id: Uuid,
+        size: i64,
+        page: i64,
+    ) -> Result<Vec<Self>> {
+        let offset = (page - 1) * size;
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            WITH
+                available AS (
+                    SELECT
+                        t1.id,
+                        t1.user_id,
+                        t2.username,
+                        t1.name,
+                        t1.metric,
+                        t1.start_date,
+                        t1.end_date,
+                        (
+                            SELECT
+                                COUNT(*)
+                            FROM
+                                challenge_participant
+                            WHERE
+                                challenge_id = t1.id
+                        ) AS participant_count,
+                        EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                challenge_participant
+                            WHERE
+                                challenge_id = t1.id
+                                AND user_id = $1
+                        ) AS joined,
+                        EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                user_follower t3
+                            WHERE
+                                t3.user_id = t1.user_id
+                                AND t3.follower_id = $1
+                                AND t3.status = 1
+                                AND NOT EXISTS (
+                                    SELECT
+                                        1
+                                    FROM
+                                        user_block
+                                    WHERE
+                                        blocker_id = t1.user_id
+                                        AND blocked_id = $1
+                                        AND blocked_status = 1
+                                )
+                        ) AS following,
+                        t1.created_at
+                    FROM
+                        challenge t1
+                        JOIN users_user t2 ON t2.id = t1.user_id
+                )
+            SELECT
+                id AS "id!",
+                user_id AS "user_id!",
+                username AS "username!",
+                name AS "name!",
+                metric AS "metric!",
+                start_date AS "start_date!",
+                end_date AS "end_date!",
+                participant_count AS "participant_count!",
+                joined AS "joined!",
+                following AS "following!",
+                created_at AS "created_at!"
+            FROM
+                available
+            WHERE
+                user_id = $1
+                OR joined
+                OR following
+            ORDER BY
+                end_date < CURRENT_DATE,
+                start_date DESC,
+                created_at DESC
+            LIMIT
+                $2
+            OFFSET
+                $3
+            "#,
+            user_id,
+            size,
+            offset,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn count_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<i64> {
+        let query = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                challenge t1
+            WHERE
+                t1.user_id = $1
+                OR EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        challenge_participant
+                    WHERE
+                        challenge_id = t1.id
+                        AND user_id = $1
+                )
+                OR EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_follower t3
+                    WHERE
+                        t3.user_id = t1.user_id
+                        AND t3.follower_id = $1
+                        AND t3.status = 1
+                        AND NOT EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                user_block
+                            WHERE
+                                blocker_id = t1.user_id
+                                AND blocked_id = $1
+                                AND blocked_status = 1
+                        )
+                )
+            "#,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+impl LeaderboardEntry {
+    /// The challenge's participants ranked by score. Scores are only returned for participants
+    /// whose privacy settings for the metric's section let the request user see them.
+    pub async fn all_by_challenge(
+        pool: &PgPool,
+        challenge: &ChallengeQuery,
+        user: &RequestUser,
+    ) -> Result<Vec<Self>> {
+        let metric = challenge.get_metric();
+        let section = metric.section();
+        let query = format!(
+            "
+            WITH
+                participant AS (
+                    SELECT
+                        user_id
+                    FROM
+                        challenge_participant
+                    WHERE
+                        challenge_id = $1
+                ),
+                score AS ({score}),
+                entry AS (
+                    SELECT
+                        t1.id AS user_id,
+                        t1.username,
+                        t6.score,
+                        (
+                            t1.id = $4
+                            OR $5
+                            OR t2.id IS NULL
+                            AND (
+                                {coach}
+                                OR t3.id IS NOT NULL
+                                AND {level} = 2
+                                OR {level} = 1
+                                OR {level} = 0
+                            )
+                        ) AS is_visible
+                    FROM
+                        participant t0
+                        JOIN users_user t1 ON t1.id = t0.user_id
+                        LEFT JOIN user_block t2 ON t2.blocker_id = t1.id
+                        AND t2.blocked_id = $4
+                        AND t2.blocked_status = 1
+                        LEFT JOIN user_follower t3 ON t3.user_id = t1.id
+                        AND t3.follower_id = $4
+                        AND t3.status = 1
+                        LEFT JOIN user_privacy_setting t4 ON t4.user_id = t1.id
+                        LEFT JOIN coach_client t5 ON t5.client_id = t1.id
+                        AND t5.coach_id = $4
+                        LEFT JOIN score t6 ON t6.user_id = t1.id
+                )
+            SELECT
+                user_id,
+                username,
+                CASE
+                    WHEN is_visible THEN COALESCE(score, 0)
+                END AS score,
+                is_visible
+            FROM
+                entry
+            ORDER BY
+                is_visible DESC,
+                score DESC NULLS LAST,
+                username
+            ",
+            score = metric.score_sql(),
+            coach = section.coach_sql(),
+            level = section.level_sql(),
+        );
+        let query = sqlx::query_as::<_, Self>(&query)
+            .bind(challenge.id)
+            .bind(challenge.start_date)
+            .bind(challenge.end_date)
+            .bind(user.id)
+            .bind(user.is_superuser)
+            .fetch_all(pool)
+            .await?;
+        Ok(query)
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::prelude::*;
+
+use crate::util::validation_error::ValidationError;
+use crate::util::validation_field::{validate_max_length, validate_non_empty};
+
+use super::metric::ChallengeMetric;
+use super::model::Challenge;
+
+impl Challenge {
+    pub const MAX_NAME_LENGTH: usize = 100;
+    pub const MAX_DAYS: i64 = 366;
+
+    pub fn validate(
+        name: &str,
+        metric: i32,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        today: NaiveDate,
+    ) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        if let Some(error) = validate_non_empty(name) {
+            errors.add_error("name", error.to_string());
+        } else if let Some(error) = validate_max_length(name, Self::MAX_NAME_LENGTH) {
+            errors.add_error("name", error.to_string());
+        }
+        if !ChallengeMetric::all().contains(&ChallengeMetric::from(metric)) {
+            errors.add_error("metric", "Select a valid metric.".to_string());
+        }
+        if end_date < start_date {
+            errors.add_error(
+                "end_date",
+                "The end date must be on or after the start date.".to_string(),
+            );
+        } else if (end_date - start_date).num_days() >= Self::MAX_DAYS {
+            errors.add_error(
+                "end_date",
+                format!("A challenge can last at most {} days.", Self::MAX_DAYS),
+            );
+        } else if end_date < today {
+            errors.add_error(
+                "end_date",
+                "The end date cannot be in the past.".to_string(),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
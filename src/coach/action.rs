@@ -0,0 +1,44 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum CoachAction {
+    Create,
+    Update,
+    Delete,
+    Unknown(i32),
+}
+
+impl fmt::Display for CoachAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoachAction::Create => write!(f, "Created"),
+            CoachAction::Update => write!(f, "Updated"),
+            CoachAction::Delete => write!(f, "Deleted"),
+            CoachAction::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl From<i32> for CoachAction {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => CoachAction::Create,
+            2 => CoachAction::Update,
+            3 => CoachAction::Delete,
+            _ => CoachAction::Unknown(value),
+        }
+    }
+}
+
+impl From<CoachAction> for i32 {
+    fn from(action: CoachAction) -> Self {
+        match action {
+            CoachAction::Create => 1,
+            CoachAction::Update => 2,
+            CoachAction::Delete => 3,
+            CoachAction::Unknown(value) => value,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod action;
+pub mod model;
+pub mod page;
+#[cfg(feature = "ssr")]
+pub mod permission;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
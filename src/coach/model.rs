@@ -0,0 +1,57 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::util::text::capitalize_and_replace;
+
+use super::action::CoachAction;
+
+/// A client's grant allowing a coach to view and edit their diet log, diet targets, workouts
+/// and meals.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CoachClient {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub client_username: String,
+    pub coach_id: Uuid,
+    pub coach_username: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A change a coach made to one of their client's records, written by the `coach_audit_record`
+/// database trigger.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CoachAudit {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub coach_id: Uuid,
+    pub coach_username: String,
+    pub entity: String,
+    pub entity_id: Option<Uuid>,
+    pub action: i32,
+    /// The changed columns as a JSON object of `[old, new]` pairs.
+    pub changes: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CoachAudit {
+    pub fn get_action(&self) -> CoachAction {
+        CoachAction::from(self.action)
+    }
+
+    pub fn summary(&self) -> String {
+        let entity = capitalize_and_replace(&self.entity);
+        match self.entity_id {
+            Some(entity_id) => format!("{} {} {}", self.get_action(), entity, entity_id),
+            None => format!("{} {}", self.get_action(), entity),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CoachClientList {
+    pub coaches: Vec<CoachClient>,
+    pub clients: Vec<CoachClient>,
+}
@@ -0,0 +1,287 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::component::button::{Button, SubmitButton};
+use crate::component::icon::IconUserMinus;
+use crate::component::input::TextInput;
+use crate::component::paginator::Paginator;
+use crate::component::template::{ErrorComponent, ListLoadingComponent, ListNotFoundComponent};
+use crate::util::datetime::format_datetime;
+use crate::util::misc::ListResponse;
+use crate::util::param::{extract_page, extract_size};
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::model::{CoachAudit, CoachClient, CoachClientList};
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, error::Error, setup::get_pool};
+
+#[server(endpoint = "coach-client-list")]
+pub async fn get_coach_client_list() -> Result<CoachClientList, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let coaches = CoachClient::all_by_client_id(&pool, user.id).await?;
+    let clients = CoachClient::all_by_coach_id(&pool, user.id).await?;
+    Ok(CoachClientList { coaches, clients })
+}
+
+#[server(endpoint = "coach-client-create")]
+pub async fn coach_client_create(username: String) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    if user.username == username {
+        return Err(ServerFnError::new("You cannot be your own coach"));
+    }
+    if CoachClient::create(&pool, user.id, &username).await? == 0 {
+        return Err(ServerFnError::new(
+            "User not found or is already one of your coaches",
+        ));
+    }
+    Ok(())
+}
+
+#[server(endpoint = "coach-client-delete")]
+pub async fn coach_client_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    if CoachClient::delete(&pool, id, user.id).await? == 0 {
+        return Err(Error::NotFound.into());
+    }
+    Ok(())
+}
+
+#[server(endpoint = "coach-audit-list")]
+pub async fn get_coach_audit_list(
+    size: i64,
+    page: i64,
+) -> Result<ListResponse<CoachAudit>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let count = CoachAudit::count_by_client_id(&pool, user.id).await?;
+    let results = CoachAudit::all_by_client_id(&pool, user.id, size, page).await?;
+    Ok(ListResponse { count, results })
+}
+
+#[component]
+pub fn CoachClientPage() -> impl IntoView {
+    let action_create = Action::<CoachClientCreate, _>::server();
+    let action_delete = Action::<CoachClientDelete, _>::server();
+
+    let resource = Resource::new(
+        move || (action_create.version().get(), action_delete.version().get()),
+        |_| get_coach_client_list(),
+    );
+
+    let coaches = move || {
+        resource.and_then(|data| {
+            if data.coaches.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.coaches
+                    .iter()
+                    .map(|data| {
+                        view! {
+                            <CoachClientListItem
+                                id=data.id
+                                username=data.coach_username.clone()
+                                created_at=format_datetime(&Some(data.created_at))
+                                label="Remove"
+                                action=action_delete
+                            />
+                        }
+                    })
+                    .collect_view()
+            }
+        })
+    };
+    let clients = move || {
+        resource.and_then(|data| {
+            if data.clients.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.clients
+                    .iter()
+                    .map(|data| {
+                        view! {
+                            <CoachClientListItem
+                                id=data.id
+                                username=data.client_username.clone()
+                                created_at=format_datetime(&Some(data.created_at))
+                                label="Leave"
+                                action=action_delete
+                            />
+                        }
+                    })
+                    .collect_view()
+            }
+        })
+    };
+
+    let action_value = action_delete.value();
+    let action_error = move || extract_other_errors(action_value, &[]);
+
+    view! {
+        <Title text="Coaching"/>
+        <div class="grid grid-cols-4 gap-4 md:grid-cols-12">
+            <div class="col-span-4 md:col-span-8">
+                <div class="p-4 mb-4 bg-white border shadow-sm">
+                    <header class="mb-4">
+                        <h2 class="mb-2 text-base font-bold">"Your Coaches"</h2>
+                        <p>
+                            "Coaches can view and edit your diet log, diet targets, workouts and meals, whatever your privacy settings."
+                        </p>
+                    </header>
+                    <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                    <Transition fallback=ListLoadingComponent>
+                        <ErrorBoundary fallback=|errors| {
+                            view! { <ErrorComponent errors/> }
+                        }>{coaches}</ErrorBoundary>
+                    </Transition>
+                </div>
+                <div class="p-4 mb-4 bg-white border shadow-sm">
+                    <header class="mb-4">
+                        <h2 class="mb-2 text-base font-bold">"Your Clients"</h2>
+                        <p>"Users who have made you their coach."</p>
+                    </header>
+                    <Transition fallback=ListLoadingComponent>
+                        <ErrorBoundary fallback=|errors| {
+                            view! { <ErrorComponent errors/> }
+                        }>{clients}</ErrorBoundary>
+                    </Transition>
+                </div>
+                <CoachAuditList/>
+            </div>
+            <div class="col-span-4">
+                <div class="p-4 bg-white border shadow-sm">
+                    <header class="mb-4">
+                        <h2 class="mb-2 text-base font-bold">"Add Coach"</h2>
+                    </header>
+                    <div class="max-w-md">
+                        <CoachClientCreateForm action=action_create/>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn CoachClientListItem(
+    id: Uuid,
+    username: String,
+    created_at: String,
+    label: &'static str,
+    action: Action<CoachClientDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let href = format!("/users/{}", username);
+    view! {
+        <div class="flex items-start p-2 mb-4 bg-gray-100">
+            <div class="flex-1">
+                <div>
+                    <A href=href class="text-blue-500 hover:underline">
+                        {username}
+                    </A>
+                </div>
+                <div class="text-xs text-gray-500">{created_at}</div>
+            </div>
+            <ActionForm action class="contents">
+                <input type="hidden" name="id" value=id.to_string()/>
+                <Button label>
+                    <IconUserMinus/>
+                </Button>
+            </ActionForm>
+        </div>
+    }
+}
+
+#[component]
+pub fn CoachClientCreateForm(
+    action: Action<CoachClientCreate, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["username"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    view! {
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action class="contents">
+            <TextInput
+                action_value
+                name="username"
+                label="Username"
+                placeholder="Enter the username of your coach"
+            />
+            <SubmitButton loading=action_loading label="Add Coach"/>
+        </ActionForm>
+    }
+}
+
+#[component]
+pub fn CoachAuditList() -> impl IntoView {
+    let query = use_query_map();
+    let size = move || extract_size(&query);
+    let page = move || extract_page(&query);
+    let resource = Resource::new(
+        move || (size(), page()),
+        |(size, page)| get_coach_audit_list(size, page),
+    );
+    let response = move || {
+        resource.and_then(|data| {
+            if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.results
+                    .iter()
+                    .map(|data| {
+                        let created_at = format_datetime(&Some(data.created_at));
+                        view! {
+                            <div class="flex gap-2 justify-between p-2 border-b">
+                                <div>
+                                    <div>
+                                        <span class="font-bold">{data.coach_username.clone()}</span>
+                                        " "
+                                        {data.summary()}
+                                    </div>
+                                    <code class="text-xs break-all">{data.changes.clone()}</code>
+                                </div>
+                                <div class="text-xs text-gray-500">{created_at}</div>
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            }
+        })
+    };
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().map(|res| res.count))
+        })
+    };
+
+    view! {
+        <div class="p-4 bg-white border shadow-sm">
+            <header class="mb-4">
+                <h2 class="mb-2 text-base font-bold">"Coach Activity"</h2>
+                <p>"Changes your coaches have made to your records."</p>
+            </header>
+            <Transition fallback=ListLoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+            <Form method="GET" action="" class="contents">
+                <input type="hidden" name="page" value=page/>
+                <Transition>
+                    <Paginator count/>
+                </Transition>
+            </Form>
+        </div>
+    }
+}
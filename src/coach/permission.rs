@@ -0,0 +1,27 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::model::RequestUser;
+use crate::error::{Error, Result};
+
+use super::model::CoachClient;
+
+impl CoachClient {
+    /// Checks the request user can write to records owned by `owner_id`: their own, anyone's as a
+    /// superuser, or a client's as one of their coaches. Writes made as a coach are recorded in
+    /// the client's coach audit by the `coach_audit_record` trigger once they succeed.
+    pub async fn authorize(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        owner_id: Uuid,
+    ) -> Result<()> {
+        if owner_id == request_user.id || request_user.is_superuser {
+            return Ok(());
+        }
+        if CoachClient::exists(pool, owner_id, request_user.id).await? {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::Result;
+
+use super::model::{CoachAudit, CoachClient};
+
+impl CoachClient {
+    pub async fn exists(pool: &PgPool, client_id: Uuid, coach_id: Uuid) -> Result<bool> {
+        let query = sqlx::query_scalar!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        coach_client
+                    WHERE
+                        client_id = $1
+                        AND coach_id = $2
+                ) AS "exists!"
+            "#,
+            client_id,
+            coach_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_client_id(pool: &PgPool, client_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.id,
+                t1.client_id,
+                t2.username AS client_username,
+                t1.coach_id,
+                t3.username AS coach_username,
+                t1.created_at
+            FROM
+                coach_client t1
+                JOIN users_user t2 ON t2.id = t1.client_id
+                JOIN users_user t3 ON t3.id = t1.coach_id
+            WHERE
+                t1.client_id = $1
+            ORDER BY
+                t3.username
+            ",
+            client_id,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_coach_id(pool: &PgPool, coach_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.id,
+                t1.client_id,
+                t2.username AS client_username,
+                t1.coach_id,
+                t3.username AS coach_username,
+                t1.created_at
+            FROM
+                coach_client t1
+                JOIN users_user t2 ON t2.id = t1.client_id
+                JOIN users_user t3 ON t3.id = t1.coach_id
+            WHERE
+                t1.coach_id = $1
+            ORDER BY
+                t2.username
+            ",
+            coach_id,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Grants the active user with `coach_username` coach access to the client. Returns the number
+    /// of grants created, which is zero when the user does not exist or is already a coach.
+    pub async fn create(pool: &PgPool, client_id: Uuid, coach_username: &str) -> Result<u64> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO
+                coach_client (client_id, coach_id)
+            SELECT
+                $1,
+                id
+            FROM
+                users_user
+            WHERE
+                username = $2
+                AND id <> $1
+                AND is_active
+            ON CONFLICT (client_id, coach_id) DO NOTHING
+            ",
+            client_id,
+            coach_username,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+
+    /// Deletes the grant when the user is either its client or its coach.
+    pub async fn delete(pool: &PgPool, id: Uuid, user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user_id).await?;
+        let query = sqlx::query!(
+            "DELETE FROM coach_client WHERE id = $1 AND (client_id = $2 OR coach_id = $2)",
+            id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+}
+
+impl CoachAudit {
+    pub async fn all_by_client_id(
+        pool: &PgPool,
+        client_id: Uuid,
+        size: i64,
+        page: i64,
+    ) -> Result<Vec<Self>> {
+        let offset = (page - 1) * size;
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.client_id,
+                t1.coach_id,
+                t2.username AS coach_username,
+                t1.entity,
+                t1.entity_id,
+                t1.action,
+                t1.changes::TEXT AS "changes!",
+                t1.created_at
+            FROM
+                coach_audit t1
+                JOIN users_user t2 ON t2.id = t1.coach_id
+            WHERE
+                t1.client_id = $1
+            ORDER BY
+                t1.created_at DESC
+            LIMIT
+                $2
+            OFFSET
+                $3
+            "#,
+            client_id,
+            size,
+            offset,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn count_by_client_id(pool: &PgPool, client_id: Uuid) -> Result<i64> {
+        let query = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM coach_audit WHERE client_id = $1"#,
+            client_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+
+    use crate::auth::model::RequestUser;
+    use crate::coach::action::CoachAction;
+    use crate::diet::model::Diet;
+    use crate::error::Error;
+    use crate::util::test_fixture::{create_food, create_food_log, create_user};
+
+    use super::*;
+
+    fn request_user(id: Uuid, is_superuser: bool) -> RequestUser {
+        RequestUser {
+            id,
+            is_active: true,
+            is_superuser,
+            ..Default::default()
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_authorize_allows_owner_superuser_and_coaches(pool: PgPool) {
+        let client_id = create_user(&pool, "client").await;
+        let coach_id = create_user(&pool, "coach").await;
+        let stranger_id = create_user(&pool, "stranger").await;
+        CoachClient::create(&pool, client_id, "coach")
+            .await
+            .unwrap();
+
+        for (user, allowed) in [
+            (request_user(client_id, false), true),
+            (request_user(coach_id, false), true),
+            (request_user(stranger_id, true), true),
+            (request_user(stranger_id, false), false),
+        ] {
+            let result = Diet::can_create(&pool, &user, client_id).await;
+            assert_eq!(result.is_ok(), allowed, "{:?}", user.id);
+            if !allowed {
+                assert!(matches!(result, Err(Error::Forbidden)));
+            }
+        }
+        assert!(
+            Diet::can_create(&pool, &request_user(client_id, false), coach_id)
+                .await
+                .is_err()
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_coach_audit_records_committed_coach_writes(pool: PgPool) {
+        let client_id = create_user(&pool, "client").await;
+        let coach_id = create_user(&pool, "coach").await;
+        let stranger_id = create_user(&pool, "stranger").await;
+        CoachClient::create(&pool, client_id, "coach")
+            .await
+            .unwrap();
+        let food_id = create_food(&pool, client_id).await;
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        let id = create_food_log(&pool, client_id, food_id, date, 1).await;
+        let diet = Diet::get_by_id(&pool, id).await.unwrap().unwrap();
+        Diet::update(
+            &pool,
+            id,
+            date,
+            diet.meal_of_day_id,
+            Decimal::from(2),
+            client_id,
+        )
+        .await
+        .unwrap();
+        Diet::update(
+            &pool,
+            id,
+            date,
+            diet.meal_of_day_id,
+            Decimal::from(3),
+            stranger_id,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            CoachAudit::count_by_client_id(&pool, client_id)
+                .await
+                .unwrap(),
+            0
+        );
+
+        Diet::update(
+            &pool,
+            id,
+            date,
+            diet.meal_of_day_id,
+            Decimal::from(4),
+            coach_id,
+        )
+        .await
+        .unwrap();
+        let mut tx = pool.begin().await.unwrap();
+        AuditLog::set_actor(&mut tx, coach_id).await.unwrap();
+        sqlx::query("DELETE FROM food_log WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        tx.rollback().await.unwrap();
+        assert_eq!(
+            CoachAudit::count_by_client_id(&pool, client_id)
+                .await
+                .unwrap(),
+            1
+        );
+
+        Diet::delete(&pool, id, coach_id).await.unwrap();
+        let audits = CoachAudit::all_by_client_id(&pool, client_id, 10, 1)
+            .await
+            .unwrap();
+        assert_eq!(audits.len(), 2);
+        for audit in &audits {
+            assert_eq!(audit.coach_id, coach_id);
+            assert_eq!(audit.entity, "food_log");
+            assert_eq!(audit.entity_id, Some(id));
+        }
+        let actions: Vec<CoachAction> = audits.iter().map(CoachAudit::get_action).collect();
+        assert!(actions.contains(&CoachAction::Update));
+        assert!(actions.contains(&CoachAction::Delete));
+        let update = &audits[actions
+            .iter()
+            .position(|a| *a == CoachAction::Update)
+            .unwrap()];
+        let changes: serde_json::Value = serde_json::from_str(&update.changes).unwrap();
+        assert_eq!(changes["quantity"], serde_json::json!([3.0, 4.0]));
+        assert!(changes.get("updated_by_id").is_none());
+    }
+}
@@ -9,8 +9,8 @@ use crate::component::icon::IconTrash;
 #[cfg(feature = "ssr")]
 use {
     crate::{
-        auth::service::get_request_user, error::Error, setup::get_pool,
-        util::server::parse_uuids_from_strings,
+        audit_log::model::AuditLog, auth::service::get_request_user, coach::model::CoachClient,
+        error::Error, setup::get_pool, util::server::parse_uuids_from_strings,
     },
     sqlx::Row,
     uuid::Uuid,
@@ -22,6 +22,7 @@ pub async fn bulk_delete(
     items: Option<HashSet<String>>,
 ) -> Result<u64, ServerFnError> {
     const ALLOWED_TABLES: [&str; 3] = ["food_log", "progress", "diet_target"];
+    const COACH_TABLES: [&str; 2] = ["food_log", "diet_target"];
 
     let user = get_request_user()?;
     let pool = get_pool()?;
@@ -32,11 +33,14 @@ pub async fn bulk_delete(
 
     if user.is_superuser {
         let sql = format!("DELETE FROM {table} WHERE id = ANY ($1)");
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user.actor_id()).await?;
         let query = sqlx::query(&sql)
             .bind(&uuid_list)
-            .execute(&pool)
+            .execute(&mut *tx)
             .await?
             .rows_affected();
+        tx.commit().await?;
         return Ok(query);
     }
 
@@ -49,24 +53,34 @@ pub async fn bulk_delete(
     let user_id = user.id;
     let check_sql = format!("SELECT user_id FROM {table} WHERE id = ANY ($1)");
 
-    let all_owner_ids_match_user = sqlx::query(&check_sql)
+    // Items owned by someone else can only be deleted by one of the owner's coaches.
+    let other_owner_ids: HashSet<Uuid> = sqlx::query(&check_sql)
         .bind(&uuid_list)
         .fetch_all(&pool)
         .await?
         .iter()
         .map(|row| row.get::<Uuid, _>("user_id"))
-        .all(|owner_id| owner_id == user_id);
+        .filter(|owner_id| *owner_id != user_id)
+        .collect();
 
-    if !user.is_superuser && !all_owner_ids_match_user {
-        return Err(Error::Forbidden)?;
+    if !other_owner_ids.is_empty() && !COACH_TABLES.contains(&table.as_str()) {
+        return Err(Error::Forbidden.into());
+    }
+    for owner_id in other_owner_ids {
+        if !CoachClient::exists(&pool, owner_id, user_id).await? {
+            return Err(Error::Forbidden.into());
+        }
     }
 
     let sql = format!("DELETE FROM {table} WHERE id = ANY ($1)");
+    let mut tx = pool.begin().await?;
+    AuditLog::set_actor(&mut tx, user.actor_id()).await?;
     let query = sqlx::query(&sql)
         .bind(&uuid_list)
-        .execute(&pool)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
+    tx.commit().await?;
 
     if query == 0 {
         return Err(ServerFnError::new("Nothing deleted"));
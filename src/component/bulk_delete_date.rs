@@ -9,8 +9,8 @@ use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
 use crate::{
-    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
-    util::server::parse_dates_from_strings,
+    audit_log::model::AuditLog, auth::model::User, auth::service::get_request_user, error::Error,
+    setup::get_pool, util::server::parse_dates_from_strings,
 };
 
 #[server]
@@ -40,14 +40,17 @@ pub async fn bulk_delete_date_range(
         return Err(Error::Forbidden)?;
     };
 
+    let mut tx = pool.begin().await?;
+    AuditLog::set_actor(&mut tx, user.actor_id()).await?;
     let query = sqlx::query(&format!(
         "DELETE FROM {table} WHERE user_id = $1 AND date = ANY ($2)"
     ))
     .bind(user.id)
     .bind(date_list)
-    .execute(&pool)
+    .execute(&mut *tx)
     .await?
     .rows_affected();
+    tx.commit().await?;
 
     if query == 0 {
         return Err(ServerFnError::new("Nothing deleted"));
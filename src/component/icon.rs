@@ -114,6 +114,19 @@ pub fn ChevronsRight(
     }
 }
 
+#[component]
+pub fn IconBell(
+    #[prop(default = 20)] size: usize,
+    #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
+) -> impl IntoView {
+    view! {
+        <SvgWrapper size attrs>
+            <path d="M18 8A6 6 0 0 0 6 8c0 7-3 9-3 9h18s-3-2-3-9"></path>
+            <path d="M13.73 21a2 2 0 0 1-3.46 0"></path>
+        </SvgWrapper>
+    }
+}
+
 #[component]
 pub fn IconCalendar(
     #[prop(default = 20)] size: usize,
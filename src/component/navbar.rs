@@ -2,14 +2,17 @@ use leptos::*;
 use leptos_router::*;
 
 use crate::auth::context::RequestUserContext;
+use crate::auth::impersonation::ImpersonationStopForm;
 use crate::component::icon::{IconClose, IconMenu};
 use crate::component::link::{CircularIconLink, Link, LinkVariant, NotificationLink};
 use crate::component::template::Backdrop;
+use crate::notification::bell::NotificationBell;
 
 #[component]
 pub fn Navbar(username: String) -> impl IntoView {
     let initial = username.chars().next().unwrap_or_default();
     let top_nav = vec![
+        ("/feed".to_string(), "Feed"),
         (format!("/users/{}/diet", username), "Diet"),
         (format!("/users/{}/workouts", username), "Workouts"),
         (format!("/users/{}/week", username), "Week"),
@@ -19,6 +22,8 @@ pub fn Navbar(username: String) -> impl IntoView {
     ];
     let side_nav = vec![
         (format!("/users/{}", username), "Profile"),
+        ("/feed".to_string(), "Feed"),
+        ("/challenges".to_string(), "Challenges"),
         (format!("/users/{}/diet", username), "Diet"),
         (format!("/users/{}/workouts", username), "Workouts"),
         (format!("/users/{}/progress", username), "Progress"),
@@ -29,6 +34,7 @@ pub fn Navbar(username: String) -> impl IntoView {
         ("/food".to_string(), "Food"),
         ("/food/brands".to_string(), "Brands"),
         ("/food/meals".to_string(), "Meals"),
+        ("/templates".to_string(), "Templates"),
         ("/exercises".to_string(), "Exercises"),
         ("/exercises/muscle-groups".to_string(), "Muscle Groups"),
     ];
@@ -56,6 +62,9 @@ pub fn Navbar(username: String) -> impl IntoView {
     view! {
         <Backdrop show_menu/>
 
+        <Show when=move || request_user.is_impersonating()>
+            <ImpersonationBanner username=username.clone()/>
+        </Show>
         <nav class="flex overflow-hidden sticky top-0 z-10 justify-between p-2 bg-zinc-900 text-zinc-100">
             <section class="flex bg-zinc-800">
                 <button
@@ -82,6 +91,7 @@ pub fn Navbar(username: String) -> impl IntoView {
                 </Show>
                 <div class="hidden md:flex">{top_nav_view}</div>
                 <div class="flex gap-x-2 items-center">
+                    <NotificationBell/>
                     <NotificationLink href="/settings/followers"/>
                     <CircularIconLink href=user_href initial/>
                 </div>
@@ -109,6 +119,17 @@ pub fn Navbar(username: String) -> impl IntoView {
     }
 }
 
+/// Shown on every page while a superuser is viewing the site as another user.
+#[component]
+fn ImpersonationBanner(username: String) -> impl IntoView {
+    view! {
+        <div class="flex flex-wrap gap-2 justify-center items-center p-2 font-bold text-amber-900 bg-amber-300">
+            <span>"You are viewing the site as " {username} "."</span>
+            <ImpersonationStopForm/>
+        </div>
+    }
+}
+
 #[component]
 pub fn UnauthNavbar() -> impl IntoView {
     view! {
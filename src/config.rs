@@ -1,4 +1,5 @@
 use std::env;
+use std::net::IpAddr;
 use std::sync::OnceLock;
 
 use dotenvy::dotenv;
@@ -11,6 +12,32 @@ use crate::error::Result;
 // pub const DATE_FORMAT_LONG: &str = "%A %d %B %Y";
 // pub const DATE_FORMAT_SHORT: &str = "%a %d %b %Y";
 
+/// An OpenID Connect provider offered as "Sign in with ..." on the login page.
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    pub provider_name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_url: String,
+}
+
+impl OidcConfig {
+    /// The provider is enabled when its issuer and client credentials are all set.
+    fn from_env(domain: &str) -> Option<Self> {
+        let issuer = env::var("OIDC_ISSUER").ok()?;
+        let client_id = env::var("OIDC_CLIENT_ID").ok()?;
+        let client_secret = env::var("OIDC_CLIENT_SECRET").ok()?;
+        Some(Self {
+            provider_name: env::var("OIDC_PROVIDER_NAME").unwrap_or_else(|_| "SSO".into()),
+            issuer,
+            client_id,
+            client_secret,
+            redirect_url: format!("{}/auth/oidc/callback", domain),
+        })
+    }
+}
+
 pub struct Config {
     pub database_url: String,
     pub domain: String,
@@ -25,15 +52,23 @@ pub struct Config {
     pub template_account_activation: String,
     pub template_password_reset: String,
     pub template_email_change: String,
+    pub template_account_deletion: String,
+    pub template_account_lockout: String,
     pub token_duration_authentication: i64,
+    pub token_duration_access: i64,
     pub token_duration_account_activation: i64,
     pub token_duration_password_reset: i64,
     pub token_duration_email_change: i64,
+    pub account_deletion_grace_days: i64,
+    /// Reverse proxies whose `X-Forwarded-For` header is trusted, from `TRUSTED_PROXIES` as a
+    /// comma separated list of addresses.
+    pub trusted_proxies: Vec<IpAddr>,
     pub encoding_key: EncodingKey,
     pub decoding_key: DecodingKey,
     pub slug_regex: Regex,
     pub email_regex: Regex,
     pub access_code: String,
+    pub oidc: Option<OidcConfig>,
 }
 
 impl Config {
@@ -51,8 +86,11 @@ impl Config {
         let email_regex = Regex::new(r"^(?i)[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}$").unwrap();
         let slug_regex = Regex::new(r"[^a-z0-9]+").expect("valid regex");
 
+        let domain = env::var("DOMAIN").unwrap();
+        let oidc = OidcConfig::from_env(&domain);
+
         let config = Config {
-            domain: env::var("DOMAIN").unwrap(),
+            domain,
             database_url: env::var("DATABASE_URL").unwrap(),
             auth_cookie_name: env::var("AUTH_COOKIE_NAME").unwrap(),
             access_code: env::var("ACCESS_CODE").unwrap(),
@@ -71,11 +109,18 @@ impl Config {
             template_account_activation: env::var("TEMPLATE_ACCOUNT_ACTIVATION").unwrap(),
             template_password_reset: env::var("TEMPLATE_PASSWORD_RESET").unwrap(),
             template_email_change: env::var("TEMPLATE_EMAIL_CHANGE").unwrap(),
+            template_account_deletion: env::var("TEMPLATE_ACCOUNT_DELETION")
+                .unwrap_or_else(|_| "account_deletion_email.html".into()),
+            template_account_lockout: env::var("TEMPLATE_ACCOUNT_LOCKOUT")
+                .unwrap_or_else(|_| "account_lockout_email.html".into()),
 
             token_duration_authentication: env::var("TOKEN_DURATION_AUTHENTICATION")
                 .unwrap()
                 .parse()
                 .unwrap(),
+            token_duration_access: env::var("TOKEN_DURATION_ACCESS")
+                .map(|seconds| seconds.parse().unwrap())
+                .unwrap_or(900),
             token_duration_account_activation: env::var("TOKEN_DURATION_ACCOUNT_ACTIVATION")
                 .unwrap()
                 .parse()
@@ -88,9 +133,27 @@ impl Config {
                 .unwrap()
                 .parse()
                 .unwrap(),
+            account_deletion_grace_days: env::var("ACCOUNT_DELETION_GRACE_DAYS")
+                .map(|days| days.parse().unwrap())
+                .unwrap_or(14),
+            trusted_proxies: env::var("TRUSTED_PROXIES")
+                .map(|proxies| {
+                    proxies
+                        .split(',')
+                        .filter(|proxy| !proxy.trim().is_empty())
+                        .map(|proxy| {
+                            proxy
+                                .trim()
+                                .parse()
+                                .expect("invalid TRUSTED_PROXIES address")
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
 
             email_regex,
             slug_regex,
+            oidc,
         };
 
         Ok(config)
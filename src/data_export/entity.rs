@@ -0,0 +1,167 @@
+/// A set of rows belonging to the exported user, written to the archive as `<name>.csv` and
+/// `<name>.json`. Each query takes the user id as its only parameter.
+pub struct ExportEntity {
+    pub name: &'static str,
+    pub query: &'static str,
+}
+
+/// Foods, meals of day and movements are referenced by slug and food code alongside their
+/// ids so an export can be matched up against another instance's reference data.
+pub const EXPORT_ENTITIES: [ExportEntity; 14] = [
+    ExportEntity {
+        name: "user",
+        query: "
+            SELECT
+                id, name, username, email, is_active, is_staff, is_superuser, email_verified,
+                privacy_level, created_at, updated_at, last_login
+            FROM
+                users_user
+            WHERE
+                id = $1
+        ",
+    },
+    ExportEntity {
+        name: "profile",
+        query: "SELECT * FROM user_profile WHERE user_id = $1",
+    },
+    ExportEntity {
+        name: "diet",
+        query: "
+            SELECT
+                t1.*,
+                t2.slug AS food_slug,
+                t2.food_code,
+                t3.slug AS meal_of_day_slug
+            FROM
+                food_log t1
+                JOIN food t2 ON t2.id = t1.food_id
+                JOIN meal_of_day t3 ON t3.id = t1.meal_of_day_id
+            WHERE
+                t1.user_id = $1
+            ORDER BY
+                t1.date,
+                t3.ordering,
+                t1.created_at
+        ",
+    },
+    ExportEntity {
+        name: "diet_target",
+        query: "SELECT * FROM diet_target WHERE user_id = $1 ORDER BY date",
+    },
+    ExportEntity {
+        name: "progress",
+        query: "SELECT * FROM progress WHERE user_id = $1 ORDER BY date",
+    },
+    ExportEntity {
+        name: "workout",
+        query: "SELECT * FROM workout WHERE user_id = $1 ORDER BY date, created_at",
+    },
+    ExportEntity {
+        name: "exercise",
+        query: "
+            SELECT
+                t1.*,
+                t3.slug AS movement_slug
+            FROM
+                exercise t1
+                JOIN workout t2 ON t2.id = t1.workout_id
+                JOIN movement t3 ON t3.id = t1.movement_id
+            WHERE
+                t2.user_id = $1
+            ORDER BY
+                t2.date,
+                t1.order
+        ",
+    },
+    ExportEntity {
+        name: "set",
+        query: "
+            SELECT
+                t1.*
+            FROM
+                tracked_set t1
+                JOIN exercise t2 ON t2.id = t1.exercise_id
+                JOIN workout t3 ON t3.id = t2.workout_id
+            WHERE
+                t3.user_id = $1
+            ORDER BY
+                t3.date,
+                t2.order,
+                t1.order
+        ",
+    },
+    ExportEntity {
+        name: "meal",
+        query: "SELECT * FROM meal WHERE user_id = $1 ORDER BY name",
+    },
+    ExportEntity {
+        name: "meal_food",
+        query: "
+            SELECT
+                t1.*,
+                t3.slug AS food_slug,
+                t3.food_code
+            FROM
+                meal_food t1
+                JOIN meal t2 ON t2.id = t1.meal_id
+                JOIN food t3 ON t3.id = t1.food_id
+            WHERE
+                t2.user_id = $1
+            ORDER BY
+                t2.name,
+                t1.created_at
+        ",
+    },
+    ExportEntity {
+        name: "food",
+        query: "
+            SELECT
+                t1.*,
+                t2.slug AS brand_slug
+            FROM
+                food t1
+                JOIN food_brand t2 ON t2.id = t1.brand_id
+            WHERE
+                t1.created_by_id = $1
+            ORDER BY
+                t1.name
+        ",
+    },
+    ExportEntity {
+        name: "brand",
+        query: "SELECT * FROM food_brand WHERE created_by_id = $1 ORDER BY name",
+    },
+    ExportEntity {
+        name: "follower",
+        query: "
+            SELECT
+                t1.*,
+                t2.username,
+                t3.username AS follower_username
+            FROM
+                user_follower t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+                JOIN users_user t3 ON t3.id = t1.follower_id
+            WHERE
+                t1.user_id = $1
+                OR t1.follower_id = $1
+            ORDER BY
+                t1.created_at
+        ",
+    },
+    ExportEntity {
+        name: "block",
+        query: "
+            SELECT
+                t1.*,
+                t2.username AS blocked_username
+            FROM
+                user_block t1
+                JOIN users_user t2 ON t2.id = t1.blocked_id
+            WHERE
+                t1.blocker_id = $1
+            ORDER BY
+                t1.blocked_at
+        ",
+    },
+];
@@ -0,0 +1,60 @@
+use axum::extract::Path;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::model::RequestUser;
+use crate::error::{Error, Result};
+
+use super::model::DataExport;
+
+/// Serves a completed data export archive to the user it belongs to, but not to a superuser
+/// impersonating them.
+pub async fn data_export_handler(
+    Extension(pool): Extension<PgPool>,
+    request_user: Option<Extension<RequestUser>>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    let request_user = request_user.map(|Extension(user)| user).unwrap_or_default();
+    match read_data_export(&pool, &request_user, id).await {
+        Ok((file_name, bytes)) => (
+            [
+                (header::CONTENT_TYPE, "application/zip".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", file_name),
+                ),
+                (header::CACHE_CONTROL, "private, no-store".to_string()),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Err(Error::Unauthorized) => StatusCode::UNAUTHORIZED.into_response(),
+        Err(Error::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(Error::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+async fn read_data_export(
+    pool: &PgPool,
+    request_user: &RequestUser,
+    id: Uuid,
+) -> Result<(String, Vec<u8>)> {
+    if !request_user.is_active {
+        return Err(Error::Unauthorized);
+    }
+    if request_user.impersonator_id.is_some() {
+        return Err(Error::Forbidden);
+    }
+    let export = DataExport::get_by_id(pool, id)
+        .await?
+        .filter(|export| export.user_id == request_user.id)
+        .ok_or(Error::NotFound)?;
+    let file_name = export.file_name.as_deref().ok_or(Error::NotFound)?;
+    let export_dir = DataExport::export_dir().map_err(|_| Error::InternalServer)?;
+    let bytes = std::fs::read(export_dir.join(file_name)).map_err(|_| Error::NotFound)?;
+    Ok((export.download_filename(), bytes))
+}
@@ -0,0 +1,11 @@
+#[cfg(feature = "ssr")]
+pub mod entity;
+#[cfg(feature = "ssr")]
+pub mod handler;
+pub mod model;
+pub mod panel;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+#[cfg(feature = "ssr")]
+pub mod service;
+pub mod status;
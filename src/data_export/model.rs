@@ -0,0 +1,94 @@
+use chrono::prelude::*;
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::status::ExportStatus;
+
+#[cfg(feature = "ssr")]
+use std::{env, path::PathBuf};
+
+#[cfg(feature = "ssr")]
+pub const DATA_EXPORT_DIR: &str = "uploads/exports";
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DataExport {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: i32,
+    #[serde(skip)]
+    pub file_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_by_id: Uuid,
+}
+
+impl DataExport {
+    /// Minutes after which a pending export is treated as failed, so an export interrupted by
+    /// a restart does not stop the user from requesting another.
+    pub const PENDING_TIMEOUT_MINUTES: i64 = 30;
+
+    pub fn get_status(&self) -> ExportStatus {
+        self.get_status_at(Utc::now())
+    }
+
+    pub fn get_status_at(&self, now: DateTime<Utc>) -> ExportStatus {
+        match ExportStatus::from(self.status) {
+            ExportStatus::Pending
+                if now - self.created_at > TimeDelta::minutes(Self::PENDING_TIMEOUT_MINUTES) =>
+            {
+                ExportStatus::Failed
+            }
+            status => status,
+        }
+    }
+
+    pub fn download_url(&self) -> String {
+        format!("/settings/export/{}/download", self.id)
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl DataExport {
+    /// Exports are written outside of the public site root and are only served to their owner.
+    pub fn export_dir() -> std::io::Result<PathBuf> {
+        Ok(env::current_dir()?.join(DATA_EXPORT_DIR))
+    }
+
+    pub fn generate_filename(user_id: Uuid) -> String {
+        let timestamp = Utc::now().format("%Y%m%dT%H%M%S").to_string();
+        format!("export_{}_{}.zip", user_id, timestamp)
+    }
+
+    pub fn download_filename(&self) -> String {
+        format!(
+            "trackedfitness-export-{}.zip",
+            self.created_at.format("%Y-%m-%d")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_export_times_out() {
+        let now = Utc::now();
+        let export = DataExport {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            status: 1,
+            file_name: None,
+            created_at: now - TimeDelta::minutes(DataExport::PENDING_TIMEOUT_MINUTES),
+            completed_at: None,
+            created_by_id: Uuid::nil(),
+        };
+        assert_eq!(export.get_status_at(now), ExportStatus::Pending);
+        assert_eq!(
+            export.get_status_at(now + TimeDelta::minutes(1)),
+            ExportStatus::Failed
+        );
+    }
+}
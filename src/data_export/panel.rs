@@ -0,0 +1,114 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::component::button::SubmitButton;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::datetime::format_datetime;
+use crate::util::validation_error::get_non_field_errors;
+
+use super::model::DataExport;
+use super::status::ExportStatus;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user, error::Error, setup::get_pool,
+    util::validation_error::ValidationError,
+};
+
+#[server(endpoint = "data-export-latest")]
+pub async fn get_latest_data_export() -> Result<Option<DataExport>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let query = DataExport::get_latest_by_user_id(&pool, user.id).await?;
+    Ok(query)
+}
+
+#[server(endpoint = "data-export-create")]
+pub async fn data_export_create() -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+
+    let latest = DataExport::get_latest_by_user_id(&pool, user.id).await?;
+    if latest.is_some_and(|export| export.get_status() == ExportStatus::Pending) {
+        let mut errors = ValidationError::new();
+        errors.add_non_field_error("An export is already being prepared.");
+        return Err(Error::Validation(errors).into());
+    }
+
+    let export = DataExport::create(&pool, user.id, user.actor_id()).await?;
+    let export_dir = DataExport::export_dir().map_err(|_| Error::InternalServer)?;
+    for previous in DataExport::delete_previous(&pool, user.id, export.id).await? {
+        if let Some(file_name) = previous.file_name {
+            let _ = std::fs::remove_file(export_dir.join(file_name));
+        }
+    }
+    tokio::spawn(super::service::run_export(pool, export));
+    Ok(())
+}
+
+#[component]
+pub fn DataExportPanel() -> impl IntoView {
+    let action = Action::<DataExportCreate, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let resource = Resource::new(move || action.version().get(), |_| get_latest_data_export());
+    let response = move || {
+        resource.and_then(|data| match data {
+            Some(export) => view! { <DataExportStatus export=export.clone()/> }.into_view(),
+            None => {
+                view! { <p class="mb-4 text-sm text-gray-500">"No exports yet."</p> }.into_view()
+            }
+        })
+    };
+
+    view! {
+        <div class="p-4 mt-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Export Your Data"</h2>
+            <p class="mb-4">
+                "Download a zip archive of your account, profile, diet logs, targets, progress, workouts, meals, created foods and brands, followers and blocked users, with a CSV and JSON file for each."
+            </p>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+            <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+            <ActionForm action>
+                <SubmitButton loading=action_loading label="Request Export"/>
+            </ActionForm>
+        </div>
+    }
+}
+
+#[component]
+pub fn DataExportStatus(export: DataExport) -> impl IntoView {
+    let requested = format_datetime(&Some(export.created_at));
+    let status = export.get_status();
+    let detail = match status {
+        ExportStatus::Complete => view! {
+            <a href=export.download_url() rel="external" class="text-blue-500 hover:underline">
+                "Download"
+            </a>
+        }
+        .into_view(),
+        ExportStatus::Pending => view! {
+            <span class="text-gray-500">"Being prepared, refresh the page shortly."</span>
+        }
+        .into_view(),
+        _ => view! { <span class="text-red-500">"The export failed, please try again."</span> }
+            .into_view(),
+    };
+    view! {
+        <div class="flex flex-wrap gap-2 mb-4 text-sm">
+            <span class="font-bold">"Last export:"</span>
+            <span>{requested}</span>
+            <span>"-"</span>
+            <span>{status.to_string()}</span>
+            <span>"-"</span>
+            {detail}
+        </div>
+    }
+}
@@ -0,0 +1,107 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+use super::model::DataExport;
+use super::status::ExportStatus;
+
+impl DataExport {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM data_export WHERE id = $1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn get_latest_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM data_export WHERE user_id = $1 ORDER BY created_at DESC LIMIT 1",
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create(pool: &PgPool, user_id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                data_export (user_id, status, created_by_id)
+            VALUES
+                ($1, $2, $3)
+            RETURNING
+                *
+            ",
+            user_id,
+            i32::from(ExportStatus::Pending),
+            request_user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn complete(pool: &PgPool, id: Uuid, file_name: &str) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            UPDATE
+                data_export
+            SET
+                status = $2,
+                file_name = $3,
+                completed_at = NOW()
+            WHERE
+                id = $1
+            RETURNING
+                *
+            ",
+            id,
+            i32::from(ExportStatus::Complete),
+            file_name,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn fail(pool: &PgPool, id: Uuid) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            UPDATE
+                data_export
+            SET
+                status = $2,
+                completed_at = NOW()
+            WHERE
+                id = $1
+            RETURNING
+                *
+            ",
+            id,
+            i32::from(ExportStatus::Failed),
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Removes every export for the user other than `keep_id`, returning the removed rows so
+    /// their archives can be deleted from disk.
+    pub async fn delete_previous(pool: &PgPool, user_id: Uuid, keep_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "DELETE FROM data_export WHERE user_id = $1 AND id != $2 RETURNING *",
+            user_id,
+            keep_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::{Cursor, Write};
+
+use serde_json::{Map, Value};
+use sqlx::PgPool;
+use uuid::Uuid;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::error::{Error, Result};
+
+use super::entity::{ExportEntity, EXPORT_ENTITIES};
+use super::model::DataExport;
+
+pub type ExportRows = Vec<Map<String, Value>>;
+
+/// Fetches the entity's rows as JSON objects keyed by column name, so every table can be
+/// exported without a dedicated struct.
+async fn fetch_rows(pool: &PgPool, entity: &ExportEntity, user_id: Uuid) -> Result<ExportRows> {
+    let sql = format!(
+        "SELECT COALESCE(json_agg(t), '[]')::text FROM ({}) t",
+        entity.query
+    );
+    let json: String = sqlx::query_scalar(&sql)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+    serde_json::from_str(&json).map_err(|err| Error::Other(err.to_string()))
+}
+
+fn to_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Writes rows as CSV with a header of every column seen, in the order first seen.
+pub fn rows_to_csv(rows: &[Map<String, Value>]) -> Result<Vec<u8>> {
+    let mut columns: Vec<&String> = Vec::new();
+    for key in rows.iter().flat_map(|row| row.keys()) {
+        if !columns.contains(&key) {
+            columns.push(key);
+        }
+    }
+    if columns.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let to_error = |err: csv::Error| Error::Other(err.to_string());
+    writer.write_record(&columns).map_err(to_error)?;
+    for row in rows {
+        let record = columns
+            .iter()
+            .map(|column| row.get(*column).map(to_cell).unwrap_or_default());
+        writer.write_record(record).map_err(to_error)?;
+    }
+    writer
+        .into_inner()
+        .map_err(|err| Error::Other(err.to_string()))
+}
+
+/// Builds a zip archive containing `<name>.csv` and `<name>.json` for each entity.
+pub fn build_archive(entities: &[(&str, ExportRows)]) -> Result<Vec<u8>> {
+    let to_error = |err: zip::result::ZipError| Error::Other(err.to_string());
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, rows) in entities {
+        let json = serde_json::to_vec_pretty(rows).map_err(|err| Error::Other(err.to_string()))?;
+        zip.start_file(format!("{}.json", name), options)
+            .map_err(to_error)?;
+        zip.write_all(&json)
+            .map_err(|err| Error::Other(err.to_string()))?;
+
+        let csv = rows_to_csv(rows)?;
+        zip.start_file(format!("{}.csv", name), options)
+            .map_err(to_error)?;
+        zip.write_all(&csv)
+            .map_err(|err| Error::Other(err.to_string()))?;
+    }
+    let cursor = zip.finish().map_err(to_error)?;
+    Ok(cursor.into_inner())
+}
+
+async fn write_export(pool: &PgPool, export: &DataExport) -> Result<String> {
+    let mut entities = Vec::with_capacity(EXPORT_ENTITIES.len());
+    for entity in EXPORT_ENTITIES.iter() {
+        let rows = fetch_rows(pool, entity, export.user_id).await?;
+        entities.push((entity.name, rows));
+    }
+    let archive = build_archive(&entities)?;
+
+    let export_dir = DataExport::export_dir().map_err(|_| Error::InternalServer)?;
+    std::fs::create_dir_all(&export_dir).map_err(|_| Error::InternalServer)?;
+    let file_name = DataExport::generate_filename(export.user_id);
+    std::fs::write(export_dir.join(&file_name), archive).map_err(|_| Error::InternalServer)?;
+    Ok(file_name)
+}
+
+/// Generates the archive for a pending export and records the outcome. Intended to be run
+/// in the background with `tokio::spawn`.
+pub async fn run_export(pool: PgPool, export: DataExport) {
+    let result = match write_export(&pool, &export).await {
+        Ok(file_name) => DataExport::complete(&pool, export.id, &file_name).await,
+        Err(err) => {
+            tracing::error!("data export {} failed: {:?}", export.id, err);
+            DataExport::fail(&pool, export.id).await
+        }
+    };
+    if let Err(err) = result {
+        tracing::error!("data export {} status update failed: {:?}", export.id, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn rows(value: Value) -> ExportRows {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_rows_to_csv() {
+        let rows = rows(json!([
+            {"date": "2024-07-01", "notes": "felt, good", "weight_kg": 80.5},
+            {"date": "2024-07-02", "notes": null, "weight_kg": 80.1, "energy_burnt": 300},
+        ]));
+        let csv = String::from_utf8(rows_to_csv(&rows).unwrap()).unwrap();
+        assert_eq!(
+            csv,
+            "date,notes,weight_kg,energy_burnt\n\
+             2024-07-01,\"felt, good\",80.5,\n\
+             2024-07-02,,80.1,300\n"
+        );
+    }
+
+    #[test]
+    fn test_rows_to_csv_empty() {
+        assert!(rows_to_csv(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_build_archive() {
+        let entities = [
+            ("progress", rows(json!([{"date": "2024-07-01"}]))),
+            ("meal", Vec::new()),
+        ];
+        let archive = build_archive(&entities).unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+        let mut names: Vec<&str> = zip.file_names().collect();
+        names.sort();
+        assert_eq!(
+            names,
+            ["meal.csv", "meal.json", "progress.csv", "progress.json"]
+        );
+
+        let mut json = String::new();
+        zip.by_name("progress.json")
+            .unwrap()
+            .read_to_string(&mut json)
+            .unwrap();
+        assert_eq!(rows(serde_json::from_str(&json).unwrap()), entities[0].1);
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ExportStatus {
+    Pending,
+    Complete,
+    Failed,
+    Unknown(i32),
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportStatus::Pending => write!(f, "Pending"),
+            ExportStatus::Complete => write!(f, "Complete"),
+            ExportStatus::Failed => write!(f, "Failed"),
+            ExportStatus::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl From<i32> for ExportStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ExportStatus::Pending,
+            2 => ExportStatus::Complete,
+            3 => ExportStatus::Failed,
+            _ => ExportStatus::Unknown(value),
+        }
+    }
+}
+
+impl From<ExportStatus> for i32 {
+    fn from(status: ExportStatus) -> Self {
+        match status {
+            ExportStatus::Pending => 1,
+            ExportStatus::Complete => 2,
+            ExportStatus::Failed => 3,
+            ExportStatus::Unknown(value) => value,
+        }
+    }
+}
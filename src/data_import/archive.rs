@@ -0,0 +1,186 @@
+use std::io::{Cursor, Read};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Rows of an archive produced by the data export, limited to the columns needed to recreate
+/// them. Ids are only used to relate rows to each other and are replaced on import.
+#[derive(Debug, Default)]
+pub struct ExportArchive {
+    pub diet: Vec<ArchiveDiet>,
+    pub diet_target: Vec<ArchiveDietTarget>,
+    pub progress: Vec<ArchiveProgress>,
+    pub workout: Vec<ArchiveWorkout>,
+    pub exercise: Vec<ArchiveExercise>,
+    pub set: Vec<ArchiveSet>,
+    pub meal: Vec<ArchiveMeal>,
+    pub meal_food: Vec<ArchiveMealFood>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveDiet {
+    pub date: NaiveDate,
+    pub quantity: Decimal,
+    pub food_code: String,
+    pub food_slug: String,
+    pub meal_of_day_slug: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveDietTarget {
+    pub date: NaiveDate,
+    pub weight: Decimal,
+    pub energy: i32,
+    pub fat: Decimal,
+    pub saturates: Decimal,
+    pub carbohydrate: Decimal,
+    pub sugars: Decimal,
+    pub fibre: Decimal,
+    pub protein: Decimal,
+    pub salt: Decimal,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveProgress {
+    pub date: NaiveDate,
+    pub weight_kg: Option<Decimal>,
+    pub energy_burnt: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveWorkout {
+    pub id: Uuid,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveExercise {
+    pub id: Uuid,
+    pub workout_id: Uuid,
+    pub movement_slug: String,
+    pub order: i32,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveSet {
+    pub exercise_id: Uuid,
+    pub weight: Decimal,
+    pub reps: i32,
+    pub rest: i32,
+    pub notes: Option<String>,
+    pub order: i32,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveMeal {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ArchiveMealFood {
+    pub meal_id: Uuid,
+    pub quantity: Decimal,
+    pub food_code: String,
+    pub food_slug: String,
+}
+
+fn read_entity<T: DeserializeOwned>(
+    zip: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Result<Vec<T>, String> {
+    let file_name = format!("{}.json", name);
+    let mut file = match zip.by_name(&file_name) {
+        Ok(file) => file,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(Vec::new()),
+        Err(err) => return Err(err.to_string()),
+    };
+    let mut json = String::new();
+    file.read_to_string(&mut json)
+        .map_err(|err| err.to_string())?;
+    serde_json::from_str(&json).map_err(|err| format!("{}: {}", file_name, err))
+}
+
+/// Reads the JSON files of an export archive. Entity files missing from the archive are
+/// treated as empty.
+pub fn read_archive(bytes: &[u8]) -> Result<ExportArchive, String> {
+    let mut zip = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|_| "The file is not a Trackedfitness export.".to_string())?;
+    Ok(ExportArchive {
+        diet: read_entity(&mut zip, "diet")?,
+        diet_target: read_entity(&mut zip, "diet_target")?,
+        progress: read_entity(&mut zip, "progress")?,
+        workout: read_entity(&mut zip, "workout")?,
+        exercise: read_entity(&mut zip, "exercise")?,
+        set: read_entity(&mut zip, "set")?,
+        meal: read_entity(&mut zip, "meal")?,
+        meal_food: read_entity(&mut zip, "meal_food")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::data_export::service::{build_archive, ExportRows};
+
+    use super::*;
+
+    fn rows(value: serde_json::Value) -> ExportRows {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_read_archive_round_trip() {
+        let workout_id = Uuid::from_u128(1);
+        let entities = [
+            (
+                "progress",
+                rows(json!([{
+                    "id": Uuid::from_u128(2),
+                    "user_id": Uuid::from_u128(3),
+                    "date": "2024-07-01",
+                    "weight_kg": 80.5,
+                    "energy_burnt": null,
+                    "notes": "morning",
+                }])),
+            ),
+            (
+                "workout",
+                rows(json!([{"id": workout_id, "date": "2024-07-02"}])),
+            ),
+            (
+                "exercise",
+                rows(json!([{
+                    "id": Uuid::from_u128(4),
+                    "workout_id": workout_id,
+                    "movement_slug": "squat",
+                    "order": 1,
+                }])),
+            ),
+        ];
+        let archive = read_archive(&build_archive(&entities).unwrap()).unwrap();
+
+        assert_eq!(
+            archive.progress,
+            vec![ArchiveProgress {
+                date: NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+                weight_kg: Some(Decimal::new(805, 1)),
+                energy_burnt: None,
+                notes: Some("morning".to_string()),
+            }]
+        );
+        assert_eq!(archive.workout[0].id, workout_id);
+        assert_eq!(archive.exercise[0].workout_id, workout_id);
+        assert!(archive.diet.is_empty());
+    }
+
+    #[test]
+    fn test_read_archive_rejects_other_files() {
+        assert!(read_archive(b"date,weight\n").is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use super::model::{CsvPreview, DATE_FORMAT_OPTIONS};
+
+const PREVIEW_ROWS: usize = 5;
+
+/// Maps import fields (e.g. `date`, `quantity`) to the CSV header they are read from.
+#[derive(Debug, Clone, Default)]
+pub struct CsvColumnMapping {
+    pub columns: HashMap<String, String>,
+    pub date_format: String,
+}
+
+impl CsvColumnMapping {
+    /// Form fields are named `column_<field>`; an empty value leaves the field unmapped.
+    pub fn set_from_form(&mut self, name: &str, value: String) {
+        if name == "date_format" {
+            self.date_format = value;
+        } else if let Some(field) = name.strip_prefix("column_") {
+            if !value.is_empty() {
+                self.columns.insert(field.to_string(), value);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvDietRow {
+    pub line: usize,
+    pub date: NaiveDate,
+    pub meal: String,
+    pub food: String,
+    pub quantity: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvWeighInRow {
+    pub line: usize,
+    pub date: NaiveDate,
+    pub weight: Option<Decimal>,
+    pub energy_burnt: Option<i32>,
+    pub notes: Option<String>,
+}
+
+pub type CsvRowResult<T> = Result<T, String>;
+
+fn reader(bytes: &[u8]) -> csv::Reader<&[u8]> {
+    csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(bytes)
+}
+
+pub fn read_preview(bytes: &[u8]) -> Result<CsvPreview, String> {
+    let mut reader = reader(bytes);
+    let headers = reader
+        .headers()
+        .map_err(|err| err.to_string())?
+        .iter()
+        .map(String::from)
+        .collect();
+    let rows = reader
+        .records()
+        .take(PREVIEW_ROWS)
+        .filter_map(|record| record.ok())
+        .map(|record| record.iter().map(String::from).collect())
+        .collect();
+    Ok(CsvPreview { headers, rows })
+}
+
+/// A CSV record with its values looked up by mapped field name.
+struct MappedRecord<'a> {
+    line: usize,
+    values: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> MappedRecord<'a> {
+    fn get(&self, field: &str) -> Option<&'a str> {
+        self.values
+            .get(field)
+            .copied()
+            .filter(|value| !value.is_empty())
+    }
+
+    fn required(&self, field: &str) -> CsvRowResult<&'a str> {
+        self.get(field)
+            .ok_or_else(|| format!("line {}: missing {}", self.line, field))
+    }
+
+    fn parse<T: FromStr>(&self, field: &str, value: &str) -> CsvRowResult<T> {
+        value
+            .parse()
+            .map_err(|_| format!("line {}: invalid {} \"{}\"", self.line, field, value))
+    }
+
+    fn date(&self, date_format: &str) -> CsvRowResult<NaiveDate> {
+        let value = self.required("date")?;
+        NaiveDate::parse_from_str(value, date_format)
+            .map_err(|_| format!("line {}: invalid date \"{}\"", self.line, value))
+    }
+
+    fn decimal(&self, field: &str) -> CsvRowResult<Option<Decimal>> {
+        self.get(field)
+            .map(|value| self.parse(field, &value.replace(',', ".")))
+            .transpose()
+    }
+}
+
+/// Reads every record, resolving the mapped columns by header. Fails as a whole only when the
+/// file itself can't be read or a mapped column is missing from the header.
+fn read_mapped<T>(
+    bytes: &[u8],
+    mapping: &CsvColumnMapping,
+    required: &[&str],
+    parse: impl Fn(&MappedRecord) -> CsvRowResult<T>,
+) -> Result<Vec<CsvRowResult<T>>, String> {
+    if !DATE_FORMAT_OPTIONS
+        .iter()
+        .any(|(format, _)| *format == mapping.date_format)
+    {
+        return Err("Select a date format.".to_string());
+    }
+    for field in required {
+        if !mapping.columns.contains_key(*field) {
+            return Err(format!("Select a column for {}.", field));
+        }
+    }
+
+    let mut reader = reader(bytes);
+    let headers = reader.headers().map_err(|err| err.to_string())?.clone();
+    let mut indexes = Vec::new();
+    for (field, column) in mapping.columns.iter() {
+        let index = headers
+            .iter()
+            .position(|header| header == column)
+            .ok_or_else(|| format!("Column \"{}\" not found.", column))?;
+        indexes.push((field.as_str(), index));
+    }
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        // The header is line 1.
+        let line = index + 2;
+        let row = match record {
+            Ok(record) => {
+                let values = indexes
+                    .iter()
+                    .map(|(field, index)| (*field, record.get(*index).unwrap_or_default()))
+                    .collect();
+                parse(&MappedRecord { line, values })
+            }
+            Err(err) => Err(format!("line {}: {}", line, err)),
+        };
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+pub fn parse_diet_rows(
+    bytes: &[u8],
+    mapping: &CsvColumnMapping,
+) -> Result<Vec<CsvRowResult<CsvDietRow>>, String> {
+    read_mapped(
+        bytes,
+        mapping,
+        &["date", "meal", "food", "quantity"],
+        |record| {
+            let quantity = record
+                .decimal("quantity")?
+                .ok_or_else(|| format!("line {}: missing quantity", record.line))?;
+            if quantity <= Decimal::ZERO || quantity > Decimal::from(10000) {
+                return Err(format!("line {}: quantity out of range", record.line));
+            }
+            Ok(CsvDietRow {
+                line: record.line,
+                date: record.date(&mapping.date_format)?,
+                meal: record.required("meal")?.to_string(),
+                food: record.required("food")?.to_string(),
+                quantity,
+            })
+        },
+    )
+}
+
+pub fn parse_weigh_in_rows(
+    bytes: &[u8],
+    mapping: &CsvColumnMapping,
+) -> Result<Vec<CsvRowResult<CsvWeighInRow>>, String> {
+    read_mapped(bytes, mapping, &["date"], |record| {
+        let weight = record.decimal("weight")?;
+        if weight.is_some_and(|weight| weight < Decimal::from(20) || weight > Decimal::from(500)) {
+            return Err(format!("line {}: weight out of range", record.line));
+        }
+        let energy_burnt = record
+            .get("energy_burnt")
+            .map(|value| record.parse::<i32>("energy_burnt", value))
+            .transpose()?;
+        if energy_burnt.is_some_and(|energy| !(0..=10000).contains(&energy)) {
+            return Err(format!("line {}: energy burnt out of range", record.line));
+        }
+        let notes = record.get("notes").map(String::from);
+        if weight.is_none() && energy_burnt.is_none() && notes.is_none() {
+            return Err(format!("line {}: nothing to import", record.line));
+        }
+        Ok(CsvWeighInRow {
+            line: record.line,
+            date: record.date(&mapping.date_format)?,
+            weight,
+            energy_burnt,
+            notes,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(columns: &[(&str, &str)], date_format: &str) -> CsvColumnMapping {
+        let mut mapping = CsvColumnMapping::default();
+        for (field, column) in columns {
+            mapping.set_from_form(&format!("column_{}", field), column.to_string());
+        }
+        mapping.set_from_form("date_format", date_format.to_string());
+        mapping
+    }
+
+    #[test]
+    fn test_read_preview() {
+        let csv = "Date,Weight (kg)\n2024-07-01,80.5\n2024-07-02,80.1\n";
+        let preview = read_preview(csv.as_bytes()).unwrap();
+        assert_eq!(preview.headers, vec!["Date", "Weight (kg)"]);
+        assert_eq!(preview.rows.len(), 2);
+        assert_eq!(preview.rows[0], vec!["2024-07-01", "80.5"]);
+    }
+
+    #[test]
+    fn test_parse_weigh_in_rows() {
+        let csv = "Day,Weight (kg),Notes\n\
+                   01/07/2024,\"80,5\",morning\n\
+                   02/07/2024,,\n\
+                   31/02/2024,80.1,\n\
+                   03/07/2024,heavy,\n";
+        let mapping = mapping(
+            &[
+                ("date", "Day"),
+                ("weight", "Weight (kg)"),
+                ("notes", "Notes"),
+            ],
+            "%d/%m/%Y",
+        );
+        let rows = parse_weigh_in_rows(csv.as_bytes(), &mapping).unwrap();
+        assert_eq!(
+            rows[0],
+            Ok(CsvWeighInRow {
+                line: 2,
+                date: NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+                weight: Some(Decimal::new(805, 1)),
+                energy_burnt: None,
+                notes: Some("morning".to_string()),
+            })
+        );
+        assert_eq!(rows[1], Err("line 3: nothing to import".to_string()));
+        assert_eq!(
+            rows[2],
+            Err("line 4: invalid date \"31/02/2024\"".to_string())
+        );
+        assert_eq!(rows[3], Err("line 5: invalid weight \"heavy\"".to_string()));
+    }
+
+    #[test]
+    fn test_parse_diet_rows() {
+        let csv = "date,meal,food,grams\n2024-07-01,Breakfast,oats,40\n2024-07-01,Lunch,,100\n";
+        let mapping = mapping(
+            &[
+                ("date", "date"),
+                ("meal", "meal"),
+                ("food", "food"),
+                ("quantity", "grams"),
+            ],
+            "%Y-%m-%d",
+        );
+        let rows = parse_diet_rows(csv.as_bytes(), &mapping).unwrap();
+        assert_eq!(
+            rows[0],
+            Ok(CsvDietRow {
+                line: 2,
+                date: NaiveDate::from_ymd_opt(2024, 7, 1).unwrap(),
+                meal: "Breakfast".to_string(),
+                food: "oats".to_string(),
+                quantity: Decimal::from(40),
+            })
+        );
+        assert_eq!(rows[1], Err("line 3: missing food".to_string()));
+    }
+
+    #[test]
+    fn test_parse_rejects_incomplete_mapping() {
+        let csv = "date,meal\n";
+        let unmapped = mapping(&[("date", "date"), ("meal", "meal")], "%Y-%m-%d");
+        assert_eq!(
+            parse_diet_rows(csv.as_bytes(), &unmapped),
+            Err("Select a column for food.".to_string())
+        );
+        let missing = mapping(&[("date", "when")], "%Y-%m-%d");
+        assert_eq!(
+            parse_weigh_in_rows(csv.as_bytes(), &missing),
+            Err("Column \"when\" not found.".to_string())
+        );
+    }
+}
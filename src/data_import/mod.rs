@@ -0,0 +1,8 @@
+#[cfg(feature = "ssr")]
+pub mod archive;
+#[cfg(feature = "ssr")]
+pub mod csv_parse;
+pub mod model;
+pub mod page;
+#[cfg(feature = "ssr")]
+pub mod service;
@@ -0,0 +1,206 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Row-level messages beyond this are counted but not listed in the report.
+pub const MAX_REPORT_MESSAGES: usize = 100;
+
+pub const DATE_FORMAT_OPTIONS: [(&str, &str); 3] = [
+    ("%Y-%m-%d", "YYYY-MM-DD"),
+    ("%d/%m/%Y", "DD/MM/YYYY"),
+    ("%m/%d/%Y", "MM/DD/YYYY"),
+];
+
+#[derive(Debug, Display, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum CsvImportKind {
+    #[display(fmt = "Diet Entries")]
+    Diet,
+    #[display(fmt = "Weigh-ins")]
+    WeighIn,
+}
+
+impl FromStr for CsvImportKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "diet" => Ok(CsvImportKind::Diet),
+            "weigh_in" => Ok(CsvImportKind::WeighIn),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<CsvImportKind> for &str {
+    fn from(kind: CsvImportKind) -> Self {
+        match kind {
+            CsvImportKind::Diet => "diet",
+            CsvImportKind::WeighIn => "weigh_in",
+        }
+    }
+}
+
+impl CsvImportKind {
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        vec![("diet", "Diet Entries"), ("weigh_in", "Weigh-ins")]
+    }
+
+    /// The fields a CSV column can be mapped to, with whether the field is required.
+    pub fn fields(&self) -> &'static [(&'static str, bool)] {
+        match self {
+            CsvImportKind::Diet => &[
+                ("date", true),
+                ("meal", true),
+                ("food", true),
+                ("quantity", true),
+            ],
+            CsvImportKind::WeighIn => &[
+                ("date", true),
+                ("weight", false),
+                ("energy_burnt", false),
+                ("notes", false),
+            ],
+        }
+    }
+}
+
+/// Picks the first header containing the field name, ignoring case and separators, e.g.
+/// `Weight (kg)` for `weight` or `Energy Burnt` for `energy_burnt`.
+pub fn guess_column(field: &str, headers: &[String]) -> Option<String> {
+    let normalise = |value: &str| {
+        value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let field = normalise(field);
+    headers
+        .iter()
+        .find(|header| normalise(header).contains(&field))
+        .cloned()
+}
+
+/// Header and first rows of an uploaded CSV, used to build the column mapping form.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct CsvPreview {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ImportReportLine {
+    pub entity: String,
+    pub created: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub lines: Vec<ImportReportLine>,
+    pub messages: Vec<String>,
+    pub message_count: usize,
+}
+
+impl ImportReport {
+    pub fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            ..Default::default()
+        }
+    }
+
+    fn line_mut(&mut self, entity: &str) -> &mut ImportReportLine {
+        let index = match self.lines.iter().position(|line| line.entity == entity) {
+            Some(index) => index,
+            None => {
+                self.lines.push(ImportReportLine {
+                    entity: entity.to_string(),
+                    created: 0,
+                    skipped: 0,
+                });
+                self.lines.len() - 1
+            }
+        };
+        &mut self.lines[index]
+    }
+
+    pub fn created(&mut self, entity: &str) {
+        self.line_mut(entity).created += 1;
+    }
+
+    pub fn skipped(&mut self, entity: &str, message: Option<String>) {
+        self.line_mut(entity).skipped += 1;
+        if let Some(message) = message {
+            self.message_count += 1;
+            if self.messages.len() < MAX_REPORT_MESSAGES {
+                self.messages.push(message);
+            }
+        }
+    }
+
+    pub fn hidden_message_count(&self) -> usize {
+        self.message_count - self.messages.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_report_counts() {
+        let mut report = ImportReport::new(true);
+        report.created("progress");
+        report.created("progress");
+        report.skipped("progress", None);
+        report.skipped("diet", Some("line 2: unknown food".to_string()));
+
+        assert_eq!(
+            report.lines,
+            vec![
+                ImportReportLine {
+                    entity: "progress".to_string(),
+                    created: 2,
+                    skipped: 1,
+                },
+                ImportReportLine {
+                    entity: "diet".to_string(),
+                    created: 0,
+                    skipped: 1,
+                },
+            ]
+        );
+        assert_eq!(report.messages, vec!["line 2: unknown food"]);
+        assert_eq!(report.hidden_message_count(), 0);
+    }
+
+    #[test]
+    fn test_guess_column() {
+        let headers = vec![
+            "Day".to_string(),
+            "Weight (kg)".to_string(),
+            "Energy Burnt".to_string(),
+        ];
+        assert_eq!(
+            guess_column("weight", &headers),
+            Some("Weight (kg)".to_string())
+        );
+        assert_eq!(
+            guess_column("energy_burnt", &headers),
+            Some("Energy Burnt".to_string())
+        );
+        assert_eq!(guess_column("date", &headers), None);
+    }
+
+    #[test]
+    fn test_import_report_caps_messages() {
+        let mut report = ImportReport::new(false);
+        for line in 0..MAX_REPORT_MESSAGES + 5 {
+            report.skipped("diet", Some(format!("line {}", line)));
+        }
+        assert_eq!(report.messages.len(), MAX_REPORT_MESSAGES);
+        assert_eq!(report.hidden_message_count(), 5);
+    }
+}
@@ -0,0 +1,393 @@
+use leptos::*;
+use leptos_meta::*;
+use server_fn::codec::{MultipartData, MultipartFormData};
+
+use wasm_bindgen::JsCast;
+use web_sys::{FormData, HtmlFormElement, SubmitEvent};
+
+use crate::component::button::SubmitButton;
+use crate::component::checkbox::CheckboxInput;
+use crate::component::select::FieldSelect;
+use crate::util::text::capitalize_and_replace;
+use crate::util::validation_error::get_non_field_errors;
+
+use super::model::{guess_column, CsvImportKind, CsvPreview, ImportReport, DATE_FORMAT_OPTIONS};
+
+#[cfg(feature = "ssr")]
+use {
+    super::{archive::read_archive, csv_parse, csv_parse::CsvColumnMapping, service},
+    crate::{
+        auth::service::get_request_user, error::Error, setup::get_pool,
+        util::validation_error::ValidationError,
+    },
+    std::collections::HashMap,
+};
+
+#[cfg(feature = "ssr")]
+const MAX_IMPORT_BYTES: usize = 20 * 1024 * 1024;
+
+#[cfg(feature = "ssr")]
+fn import_error(message: impl Into<String>) -> ServerFnError {
+    let mut errors = ValidationError::new();
+    errors.add_non_field_error(message);
+    Error::Validation(errors).into()
+}
+
+/// Reads the text fields and the `file_upload` contents of an import form.
+#[cfg(feature = "ssr")]
+async fn read_import_form(
+    data: MultipartData,
+) -> Result<(HashMap<String, String>, Vec<u8>), ServerFnError> {
+    let mut data = data.into_inner().unwrap();
+    let mut fields = HashMap::new();
+    let mut file = Vec::new();
+    while let Ok(Some(mut field)) = data.next_field().await {
+        let field_name = field.name().unwrap_or_default().to_string();
+        if field_name == "file_upload" {
+            while let Ok(Some(chunk)) = field.chunk().await {
+                file.extend_from_slice(&chunk);
+                if file.len() > MAX_IMPORT_BYTES {
+                    return Err(import_error("The file is larger than 20 MB."));
+                }
+            }
+        } else {
+            fields.insert(field_name, field.text().await.unwrap_or_default());
+        }
+    }
+    if file.is_empty() {
+        return Err(import_error("Select a file to import."));
+    }
+    Ok((fields, file))
+}
+
+#[cfg(feature = "ssr")]
+fn is_dry_run(fields: &HashMap<String, String>) -> bool {
+    fields.get("dry_run").map(String::as_str) == Some("true")
+}
+
+#[server(input = MultipartFormData)]
+pub async fn data_import_archive(data: MultipartData) -> Result<ImportReport, ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let (fields, file) = read_import_form(data).await?;
+    let archive = read_archive(&file).map_err(import_error)?;
+    let report = service::import_archive(&pool, user.id, archive, is_dry_run(&fields)).await?;
+    Ok(report)
+}
+
+#[server(input = MultipartFormData)]
+pub async fn data_import_csv_preview(data: MultipartData) -> Result<CsvPreview, ServerFnError> {
+    get_request_user()?;
+    let (_, file) = read_import_form(data).await?;
+    let preview = csv_parse::read_preview(&file).map_err(import_error)?;
+    Ok(preview)
+}
+
+#[server(input = MultipartFormData)]
+pub async fn data_import_csv(data: MultipartData) -> Result<ImportReport, ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let (fields, file) = read_import_form(data).await?;
+
+    let mut mapping = CsvColumnMapping::default();
+    for (name, value) in fields.iter() {
+        mapping.set_from_form(name, value.clone());
+    }
+    let dry_run = is_dry_run(&fields);
+    let kind = fields
+        .get("kind")
+        .and_then(|kind| kind.parse::<CsvImportKind>().ok())
+        .ok_or_else(|| import_error("Select what the file contains."))?;
+
+    let report = match kind {
+        CsvImportKind::Diet => {
+            let rows = csv_parse::parse_diet_rows(&file, &mapping).map_err(import_error)?;
+            service::import_csv_diet(&pool, user.id, rows, dry_run).await?
+        }
+        CsvImportKind::WeighIn => {
+            let rows = csv_parse::parse_weigh_in_rows(&file, &mapping).map_err(import_error)?;
+            service::import_csv_weigh_in(&pool, user.id, rows, dry_run).await?
+        }
+    };
+    Ok(report)
+}
+
+const FILE_INPUT_CSS: &str = "block w-full rounded border px-3 py-1.5 shadow-sm focus:border-blue-500 focus:outline-none focus:ring-2 focus:ring-blue-500";
+const SELECT_CSS: &str = "block py-2 px-3 w-full bg-white rounded border focus:border-blue-500 focus:ring-2 focus:ring-blue-500 focus:outline-none";
+
+fn form_data(ev: &SubmitEvent) -> FormData {
+    let target = ev.target().unwrap().unchecked_into::<HtmlFormElement>();
+    FormData::new_with_form(&target).unwrap()
+}
+
+#[component]
+pub fn DataImportPage() -> impl IntoView {
+    view! {
+        <Title text="Import Data"/>
+        <div class="grid grid-cols-4 gap-4 md:grid-cols-12">
+            <div class="col-span-4 md:col-span-6">
+                <ArchiveImportForm/>
+            </div>
+            <div class="col-span-4 md:col-span-6">
+                <CsvImportForm/>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn ArchiveImportForm() -> impl IntoView {
+    let action = create_action(|data: &FormData| data_import_archive(data.clone().into()));
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let report = move || match action_value.get() {
+        Some(Ok(report)) => view! { <ImportReportTable report/> }.into_view(),
+        _ => ().into_view(),
+    };
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        action.dispatch(form_data(&ev));
+    };
+
+    view! {
+        <div class="p-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Import a Trackedfitness Export"</h2>
+            <p class="mb-4 text-sm text-gray-500">
+                "Adds the diet logs, targets, progress, workouts and meals from an export zip to your account. Days you already have progress, targets, diet logs or workouts for are skipped."
+            </p>
+            <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+            <form on:submit=on_submit>
+                <label class="block mb-4">
+                    <span class="block mb-1 text-sm font-bold">"Export File"</span>
+                    <input name="file_upload" type="file" accept=".zip" class=FILE_INPUT_CSS/>
+                </label>
+                <CheckboxInput name="dry_run" checked=true/>
+                <SubmitButton loading=action_loading label="Import"/>
+            </form>
+            {report}
+        </div>
+    }
+}
+
+#[component]
+pub fn CsvImportForm() -> impl IntoView {
+    let kind = RwSignal::new(CsvImportKind::Diet);
+
+    let action_preview =
+        create_action(|data: &FormData| data_import_csv_preview(data.clone().into()));
+    let action_import = create_action(|data: &FormData| data_import_csv(data.clone().into()));
+    let loading =
+        Signal::derive(move || action_preview.pending().get() || action_import.pending().get());
+    let preview_value = action_preview.value();
+    let import_value = action_import.value();
+    let has_preview = move || preview_value.with(|value| matches!(value, Some(Ok(_))));
+
+    let non_field_errors = move || {
+        if has_preview() {
+            get_non_field_errors(import_value)
+        } else {
+            get_non_field_errors(preview_value)
+        }
+    };
+    let report = move || match import_value.get() {
+        Some(Ok(report)) if has_preview() => view! { <ImportReportTable report/> }.into_view(),
+        _ => ().into_view(),
+    };
+    // Changing the file or the kind of data requires mapping the columns again.
+    let reset = move || {
+        preview_value.set(None);
+        import_value.set(None);
+    };
+
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let data = form_data(&ev);
+        if has_preview() {
+            action_import.dispatch(data);
+        } else {
+            action_preview.dispatch(data);
+        }
+    };
+
+    let mapping = move || {
+        preview_value.get().and_then(Result::ok).map(|preview| {
+            let fields = kind.get().fields();
+            let columns = fields
+                .iter()
+                .map(|(field, required)| {
+                    let guessed = guess_column(field, &preview.headers).unwrap_or_default();
+                    view! {
+                        <CsvColumnSelect
+                            field=*field
+                            required=*required
+                            headers=preview.headers.clone()
+                            selected=guessed
+                        />
+                    }
+                })
+                .collect_view();
+            view! {
+                <CsvPreviewTable preview=preview.clone()/>
+                {columns}
+                <FieldSelect name="date_format" options=DATE_FORMAT_OPTIONS.to_vec()/>
+                <CheckboxInput name="dry_run" checked=true/>
+            }
+        })
+    };
+    view! {
+        <div class="p-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Import a CSV From Another App"</h2>
+            <p class="mb-4 text-sm text-gray-500">
+                "Import daily diet entries or weigh-ins. Foods are matched by food code or slug and meals by name, and diet quantities are grams, millilitres or servings. Run a dry run first to see what will be imported."
+            </p>
+            <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+            <form on:submit=on_submit>
+                <label class="block mb-4">
+                    <span class="block mb-1 text-sm font-bold">"Contains"</span>
+                    <select
+                        name="kind"
+                        class=SELECT_CSS
+                        on:change=move |ev| {
+                            kind.set(event_target_value(&ev).parse().unwrap_or(CsvImportKind::Diet));
+                            reset();
+                        }
+                    >
+                        {CsvImportKind::to_form_options()
+                            .into_iter()
+                            .map(|(value, label)| view! { <option value=value>{label}</option> })
+                            .collect_view()}
+                    </select>
+                </label>
+                <label class="block mb-4">
+                    <span class="block mb-1 text-sm font-bold">"CSV File"</span>
+                    <input
+                        name="file_upload"
+                        type="file"
+                        accept=".csv,text/csv"
+                        class=FILE_INPUT_CSS
+                        on:change=move |_| reset()
+                    />
+                </label>
+                {mapping}
+                <Show
+                    when=has_preview
+                    fallback=move || view! { <SubmitButton loading label="Preview Columns"/> }
+                >
+                    <SubmitButton loading label="Import"/>
+                </Show>
+            </form>
+            {report}
+        </div>
+    }
+}
+
+#[component]
+pub fn CsvColumnSelect(
+    field: &'static str,
+    required: bool,
+    headers: Vec<String>,
+    selected: String,
+) -> impl IntoView {
+    let label = if required {
+        format!("{} Column", capitalize_and_replace(field))
+    } else {
+        format!("{} Column (optional)", capitalize_and_replace(field))
+    };
+    let options = headers
+        .into_iter()
+        .map(|header| {
+            let is_selected = header == selected;
+            view! {
+                <option value=header.clone() selected=is_selected>
+                    {header}
+                </option>
+            }
+        })
+        .collect_view();
+    view! {
+        <label class="block mb-4">
+            <span class="block mb-1 text-sm font-bold">{label}</span>
+            <select name=format!("column_{}", field) class=SELECT_CSS>
+                <option value="">"-"</option>
+                {options}
+            </select>
+        </label>
+    }
+}
+
+#[component]
+pub fn CsvPreviewTable(preview: CsvPreview) -> impl IntoView {
+    let header = preview
+        .headers
+        .iter()
+        .map(|header| view! { <th class="p-1 text-left border">{header.clone()}</th> })
+        .collect_view();
+    let rows = preview
+        .rows
+        .iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .map(|cell| view! { <td class="p-1 border">{cell.clone()}</td> })
+                .collect_view();
+            view! { <tr>{cells}</tr> }
+        })
+        .collect_view();
+    view! {
+        <div class="overflow-x-auto mb-4">
+            <table class="text-xs whitespace-nowrap border-collapse">
+                <thead>
+                    <tr>{header}</tr>
+                </thead>
+                <tbody>{rows}</tbody>
+            </table>
+        </div>
+    }
+}
+
+#[component]
+pub fn ImportReportTable(report: ImportReport) -> impl IntoView {
+    let (summary, created_label) = if report.dry_run {
+        (
+            "Dry run complete. Nothing has been saved, untick dry run to import.",
+            "Would Create",
+        )
+    } else {
+        ("Import complete.", "Created")
+    };
+    let lines = report
+        .lines
+        .iter()
+        .map(|line| {
+            view! {
+                <div class="p-1 border-b">{capitalize_and_replace(&line.entity)}</div>
+                <div class="p-1 text-right border-b">{line.created}</div>
+                <div class="p-1 text-right border-b">{line.skipped}</div>
+            }
+        })
+        .collect_view();
+    let messages = report
+        .messages
+        .iter()
+        .map(|message| view! { <li>{message.clone()}</li> })
+        .collect_view();
+    let hidden = report.hidden_message_count();
+    view! {
+        <div class="mt-4 text-sm">
+            <p class="mb-2 font-bold">{summary}</p>
+            <div class="grid grid-cols-3 mb-2">
+                <div class="p-1 font-bold border-b">"Data"</div>
+                <div class="p-1 font-bold text-right border-b">{created_label}</div>
+                <div class="p-1 font-bold text-right border-b">"Skipped"</div>
+                {lines}
+            </div>
+            <ul class="list-disc list-inside text-gray-500">{messages}</ul>
+            <Show when=move || hidden != 0>
+                <p class="text-gray-500">{format!("and {} more", hidden)}</p>
+            </Show>
+        </div>
+    }
+}
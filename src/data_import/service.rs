@@ -0,0 +1,570 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::diet::model::Diet;
+use crate::diet_target::model::{DietTarget, DietTargetInput, DietTargetQuery};
+use crate::error::Result;
+use crate::exercise::model::ExerciseBase;
+use crate::food::data_measurement::DataMeasurement;
+use crate::meal::model::Meal;
+use crate::meal_food::model::MealFood;
+use crate::progress::model::Progress;
+use crate::set::model::SetModel;
+use crate::util::server::normalize_whitespace;
+use crate::workout::model::WorkoutBase;
+
+use super::archive::ExportArchive;
+use super::csv_parse::{CsvDietRow, CsvRowResult, CsvWeighInRow};
+use super::model::ImportReport;
+
+/// Foods matched by food code first, falling back to slug, so imports work across instances
+/// whose food ids differ.
+struct FoodLookup {
+    by_code: HashMap<String, (Uuid, DataMeasurement)>,
+    by_slug: HashMap<String, (Uuid, DataMeasurement)>,
+}
+
+impl FoodLookup {
+    async fn load(conn: &mut PgConnection, keys: Vec<String>) -> Result<Self> {
+        let rows = sqlx::query!(
+            "
+            SELECT
+                id,
+                slug,
+                food_code,
+                data_measurement
+            FROM
+                food
+            WHERE
+                food_code = ANY ($1)
+                OR slug = ANY ($1)
+            ",
+            &keys
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+        let mut lookup = Self {
+            by_code: HashMap::new(),
+            by_slug: HashMap::new(),
+        };
+        for row in rows {
+            let value = (row.id, DataMeasurement::from(row.data_measurement));
+            lookup.by_code.insert(row.food_code, value.clone());
+            lookup.by_slug.insert(row.slug, value);
+        }
+        Ok(lookup)
+    }
+
+    fn resolve(&self, food_code: &str, slug: &str) -> Option<&(Uuid, DataMeasurement)> {
+        self.by_code
+            .get(food_code)
+            .or_else(|| self.by_slug.get(slug))
+    }
+}
+
+/// Meals of day matched by slug or case-insensitive name.
+struct MealOfDayLookup(HashMap<String, Uuid>);
+
+impl MealOfDayLookup {
+    async fn load(conn: &mut PgConnection) -> Result<Self> {
+        let rows = sqlx::query!("SELECT id, name, slug FROM meal_of_day")
+            .fetch_all(&mut *conn)
+            .await?;
+        let mut lookup = HashMap::new();
+        for row in rows {
+            lookup.insert(row.name.to_lowercase(), row.id);
+            lookup.insert(row.slug, row.id);
+        }
+        Ok(Self(lookup))
+    }
+
+    fn resolve(&self, value: &str) -> Option<Uuid> {
+        self.0
+            .get(value)
+            .or_else(|| self.0.get(&value.to_lowercase()))
+            .copied()
+    }
+}
+
+/// Dates the user already has diet logs on. Imported diet entries for these dates are skipped
+/// so importing the same file twice does not double up a day.
+async fn existing_diet_dates(conn: &mut PgConnection, user_id: Uuid) -> Result<HashSet<NaiveDate>> {
+    let dates = sqlx::query_scalar!(
+        "SELECT DISTINCT date FROM food_log WHERE user_id = $1",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(dates.into_iter().collect())
+}
+
+async fn existing_workout_dates(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<HashSet<NaiveDate>> {
+    let dates = sqlx::query_scalar!(
+        "SELECT DISTINCT date FROM workout WHERE user_id = $1",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(dates.into_iter().collect())
+}
+
+async fn existing_progress_dates(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<HashSet<NaiveDate>> {
+    let dates = sqlx::query_scalar!("SELECT date FROM progress WHERE user_id = $1", user_id)
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(dates.into_iter().collect())
+}
+
+async fn existing_diet_target_dates(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<HashSet<NaiveDate>> {
+    let dates = sqlx::query_scalar!("SELECT date FROM diet_target WHERE user_id = $1", user_id)
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(dates.into_iter().collect())
+}
+
+/// Meal names are unique per user, so meals whose name is already taken are skipped rather
+/// than aborting the import on the constraint.
+async fn existing_meal_names(conn: &mut PgConnection, user_id: Uuid) -> Result<HashSet<String>> {
+    let names = sqlx::query_scalar!("SELECT name FROM meal WHERE user_id = $1", user_id)
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(names.into_iter().collect())
+}
+
+/// Imports an archive produced by the data export into the user's account. New ids are
+/// generated for every row, with workouts, exercises and meals remapped so their children
+/// follow. Progress and diet targets already recorded on a date are left untouched.
+pub async fn import_archive(
+    pool: &PgPool,
+    user_id: Uuid,
+    archive: ExportArchive,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    let mut report = ImportReport::new(dry_run);
+    let mut tx = pool.begin().await?;
+
+    let mut food_keys: Vec<String> = Vec::new();
+    for (code, slug) in archive
+        .diet
+        .iter()
+        .map(|row| (&row.food_code, &row.food_slug))
+        .chain(
+            archive
+                .meal_food
+                .iter()
+                .map(|row| (&row.food_code, &row.food_slug)),
+        )
+    {
+        food_keys.push(code.clone());
+        food_keys.push(slug.clone());
+    }
+    let foods = FoodLookup::load(&mut tx, food_keys).await?;
+    let meals_of_day = MealOfDayLookup::load(&mut tx).await?;
+
+    let mut progress_dates = existing_progress_dates(&mut tx, user_id).await?;
+    for row in archive.progress {
+        if progress_dates.contains(&row.date) {
+            report.skipped("progress", None);
+            continue;
+        }
+        if let Err(errors) =
+            Progress::validate(row.date, row.weight_kg, row.energy_burnt, row.notes.clone())
+        {
+            report.skipped("progress", Some(format!("{}: {}", row.date, errors)));
+            continue;
+        }
+        Progress::create(
+            &mut *tx,
+            user_id,
+            row.date,
+            row.weight_kg,
+            row.energy_burnt,
+            row.notes,
+            user_id,
+        )
+        .await?;
+        progress_dates.insert(row.date);
+        report.created("progress");
+    }
+
+    let mut diet_target_dates = existing_diet_target_dates(&mut tx, user_id).await?;
+    for row in archive.diet_target {
+        if diet_target_dates.contains(&row.date) {
+            report.skipped("diet_target", None);
+            continue;
+        }
+        if let Err(errors) = DietTargetQuery::validate_date(row.date) {
+            report.skipped("diet_target", Some(format!("{}: {}", row.date, errors)));
+            continue;
+        }
+        let input = DietTargetInput {
+            user_id,
+            date: row.date,
+            weight: row.weight,
+            energy: row.energy,
+            fat: row.fat,
+            saturates: row.saturates,
+            carbohydrate: row.carbohydrate,
+            sugars: row.sugars,
+            fibre: row.fibre,
+            protein: row.protein,
+            salt: row.salt,
+        };
+        DietTarget::create(&mut *tx, input, user_id).await?;
+        diet_target_dates.insert(row.date);
+        report.created("diet_target");
+    }
+
+    let diet_dates = existing_diet_dates(&mut tx, user_id).await?;
+    for row in archive.diet {
+        if diet_dates.contains(&row.date) {
+            report.skipped("diet", None);
+            continue;
+        }
+        let Some((food_id, _)) = foods.resolve(&row.food_code, &row.food_slug) else {
+            report.skipped(
+                "diet",
+                Some(format!(
+                    "{}: food \"{}\" not found",
+                    row.date, row.food_slug
+                )),
+            );
+            continue;
+        };
+        let Some(meal_of_day_id) = meals_of_day.resolve(&row.meal_of_day_slug) else {
+            report.skipped(
+                "diet",
+                Some(format!(
+                    "{}: meal \"{}\" not found",
+                    row.date, row.meal_of_day_slug
+                )),
+            );
+            continue;
+        };
+        if let Err(errors) = Diet::validate(row.date, row.quantity) {
+            report.skipped("diet", Some(format!("{}: {}", row.date, errors)));
+            continue;
+        }
+        Diet::create(
+            &mut *tx,
+            row.date,
+            user_id,
+            meal_of_day_id,
+            *food_id,
+            row.quantity,
+            user_id,
+        )
+        .await?;
+        report.created("diet");
+    }
+
+    let workout_dates = existing_workout_dates(&mut tx, user_id).await?;
+    let mut workout_ids = HashMap::new();
+    for row in archive.workout {
+        if workout_dates.contains(&row.date) {
+            report.skipped("workout", None);
+            continue;
+        }
+        let workout = WorkoutBase::create(&mut *tx, user_id, row.date, user_id).await?;
+        workout_ids.insert(row.id, workout.id);
+        report.created("workout");
+    }
+
+    let movement_slugs: Vec<String> = archive
+        .exercise
+        .iter()
+        .map(|row| row.movement_slug.clone())
+        .collect();
+    let movements: HashMap<String, Uuid> = sqlx::query!(
+        "SELECT id, slug FROM movement WHERE slug = ANY ($1)",
+        &movement_slugs
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|row| (row.slug, row.id))
+    .collect();
+
+    let mut exercise_ids = HashMap::new();
+    for row in archive.exercise {
+        let Some(workout_id) = workout_ids.get(&row.workout_id) else {
+            report.skipped("exercise", None);
+            continue;
+        };
+        let Some(movement_id) = movements.get(&row.movement_slug) else {
+            report.skipped(
+                "exercise",
+                Some(format!("exercise \"{}\" not found", row.movement_slug)),
+            );
+            continue;
+        };
+        let exercise =
+            ExerciseBase::create(&mut *tx, *workout_id, *movement_id, row.order, user_id).await?;
+        exercise_ids.insert(row.id, exercise.id);
+        report.created("exercise");
+    }
+
+    for row in archive.set {
+        let Some(exercise_id) = exercise_ids.get(&row.exercise_id) else {
+            report.skipped("set", None);
+            continue;
+        };
+        if let Err(errors) = SetModel::validate(row.order, row.weight, row.reps, row.rest) {
+            report.skipped("set", Some(errors.to_string()));
+            continue;
+        }
+        SetModel::create(
+            &mut *tx,
+            *exercise_id,
+            row.order,
+            row.weight,
+            row.reps,
+            row.rest,
+            row.notes,
+            user_id,
+        )
+        .await?;
+        report.created("set");
+    }
+
+    let mut meal_names = existing_meal_names(&mut tx, user_id).await?;
+    let mut meal_ids = HashMap::new();
+    for row in archive.meal {
+        let name = normalize_whitespace(&row.name);
+        if meal_names.contains(&name) {
+            report.skipped("meal", None);
+            continue;
+        }
+        if let Err(errors) = Meal::validate(&name) {
+            report.skipped("meal", Some(format!("meal \"{}\": {}", name, errors)));
+            continue;
+        }
+        let meal = Meal::create(&mut *tx, user_id, &name, user_id).await?;
+        meal_ids.insert(row.id, meal.id);
+        meal_names.insert(name);
+        report.created("meal");
+    }
+
+    for row in archive.meal_food {
+        let Some(meal_id) = meal_ids.get(&row.meal_id) else {
+            report.skipped("meal_food", None);
+            continue;
+        };
+        let Some((food_id, _)) = foods.resolve(&row.food_code, &row.food_slug) else {
+            report.skipped(
+                "meal_food",
+                Some(format!("meal food \"{}\" not found", row.food_slug)),
+            );
+            continue;
+        };
+        if let Err(errors) = MealFood::validate(row.quantity) {
+            report.skipped(
+                "meal_food",
+                Some(format!("meal food \"{}\": {}", row.food_slug, errors)),
+            );
+            continue;
+        }
+        MealFood::create_and_return_meal_id(&mut *tx, *meal_id, *food_id, row.quantity, user_id)
+            .await?;
+        report.created("meal_food");
+    }
+
+    finish(tx, dry_run).await?;
+    Ok(report)
+}
+
+/// Imports diet entries from another app. Quantities are grams, millilitres or servings as
+/// shown when adding food, and foods are matched by food code or slug.
+pub async fn import_csv_diet(
+    pool: &PgPool,
+    user_id: Uuid,
+    rows: Vec<CsvRowResult<CsvDietRow>>,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    let mut report = ImportReport::new(dry_run);
+    let mut tx = pool.begin().await?;
+
+    let food_keys = rows
+        .iter()
+        .filter_map(|row| row.as_ref().ok())
+        .map(|row| row.food.clone())
+        .collect();
+    let foods = FoodLookup::load(&mut tx, food_keys).await?;
+    let meals_of_day = MealOfDayLookup::load(&mut tx).await?;
+    let diet_dates = existing_diet_dates(&mut tx, user_id).await?;
+
+    for row in rows {
+        let row = match row {
+            Ok(row) => row,
+            Err(message) => {
+                report.skipped("diet", Some(message));
+                continue;
+            }
+        };
+        if diet_dates.contains(&row.date) {
+            report.skipped(
+                "diet",
+                Some(format!(
+                    "line {}: {} already has diet entries",
+                    row.line, row.date
+                )),
+            );
+            continue;
+        }
+        let Some((food_id, data_measurement)) = foods.resolve(&row.food, &row.food) else {
+            report.skipped(
+                "diet",
+                Some(format!(
+                    "line {}: food \"{}\" not found",
+                    row.line, row.food
+                )),
+            );
+            continue;
+        };
+        let Some(meal_of_day_id) = meals_of_day.resolve(&row.meal) else {
+            report.skipped(
+                "diet",
+                Some(format!(
+                    "line {}: meal \"{}\" not found",
+                    row.line, row.meal
+                )),
+            );
+            continue;
+        };
+        let quantity = data_measurement.to_quantity_modifier(&row.quantity);
+        if let Err(errors) = Diet::validate(row.date, quantity) {
+            report.skipped("diet", Some(format!("line {}: {}", row.line, errors)));
+            continue;
+        }
+        Diet::create(
+            &mut *tx,
+            row.date,
+            user_id,
+            meal_of_day_id,
+            *food_id,
+            quantity,
+            user_id,
+        )
+        .await?;
+        report.created("diet");
+    }
+
+    finish(tx, dry_run).await?;
+    Ok(report)
+}
+
+/// Imports weigh-ins from another app into progress, skipping dates already recorded.
+pub async fn import_csv_weigh_in(
+    pool: &PgPool,
+    user_id: Uuid,
+    rows: Vec<CsvRowResult<CsvWeighInRow>>,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    let mut report = ImportReport::new(dry_run);
+    let mut tx = pool.begin().await?;
+    let mut progress_dates = existing_progress_dates(&mut tx, user_id).await?;
+
+    for row in rows {
+        let row = match row {
+            Ok(row) => row,
+            Err(message) => {
+                report.skipped("progress", Some(message));
+                continue;
+            }
+        };
+        if progress_dates.contains(&row.date) {
+            report.skipped(
+                "progress",
+                Some(format!("line {}: {} already recorded", row.line, row.date)),
+            );
+            continue;
+        }
+        if let Err(errors) =
+            Progress::validate(row.date, row.weight, row.energy_burnt, row.notes.clone())
+        {
+            report.skipped("progress", Some(format!("line {}: {}", row.line, errors)));
+            continue;
+        }
+        Progress::create(
+            &mut *tx,
+            user_id,
+            row.date,
+            row.weight,
+            row.energy_burnt,
+            row.notes,
+            user_id,
+        )
+        .await?;
+        progress_dates.insert(row.date);
+        report.created("progress");
+    }
+
+    finish(tx, dry_run).await?;
+    Ok(report)
+}
+
+/// A dry run performs every insert so constraint failures show up in the report, then rolls
+/// them back.
+async fn finish(tx: sqlx::Transaction<'_, sqlx::Postgres>, dry_run: bool) -> Result<()> {
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    use crate::util::test_fixture::create_user;
+
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_import_csv_weigh_in_validates_rows(pool: PgPool) {
+        let user_id = create_user(&pool, "importer").await;
+        let date = Utc::now().date_naive();
+        let row = |line, weight| CsvWeighInRow {
+            line,
+            date,
+            weight: Some(Decimal::from(weight)),
+            energy_burnt: None,
+            notes: None,
+        };
+        let rows = vec![Ok(row(2, 5)), Ok(row(3, 80)), Ok(row(4, 81))];
+
+        let report = import_csv_weigh_in(&pool, user_id, rows, false)
+            .await
+            .unwrap();
+
+        assert_eq!(report.lines[0].created, 1);
+        assert_eq!(report.lines[0].skipped, 2);
+        assert!(report.messages[0].starts_with("line 2: weight"));
+        assert_eq!(
+            report.messages[1],
+            format!("line 4: {} already recorded", date)
+        );
+        let weight: Decimal =
+            sqlx::query_scalar("SELECT weight_kg FROM progress WHERE user_id = $1")
+                .bind(user_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(weight, Decimal::from(80));
+    }
+}
@@ -76,7 +76,7 @@ pub async fn diet_add_food(
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    Diet::can_create(&user, target_user.id)?;
+    Diet::can_create(&pool, &user, target_user.id).await?;
     Diet::validate(date, quantity)?;
     let food = FoodQuery::get_by_slug(&pool, &food_slug)
         .await?
@@ -89,15 +89,15 @@ pub async fn diet_add_food(
     Diet::create(
         &pool,
         date,
-        user.id,
+        target_user.id,
         meal_of_day.id,
         food.id,
         quantity,
-        user.id,
+        user.actor_id(),
     )
     .await?;
 
-    leptos_axum::redirect(&format!("/users/{}/diet/{}", user.username, date));
+    leptos_axum::redirect(&format!("/users/{}/diet/{}", target_user.username, date));
     Ok(())
 }
 
@@ -38,7 +38,7 @@ pub async fn diet_add_meal(
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    Diet::can_create(&user, user.id)?;
+    Diet::can_create(&pool, &user, target_user.id).await?;
     let meal_of_day = MealOfDay::get_by_slug(&pool, &meal_of_day_slug)
         .await?
         .ok_or(Error::NotFound)?;
@@ -49,10 +49,10 @@ pub async fn diet_add_meal(
         date,
         meal_of_day.id,
         &meal_food,
-        user.id,
+        user.actor_id(),
     )
     .await?;
-    leptos_axum::redirect(&format!("/users/{}/diet/{}", user.username, date));
+    leptos_axum::redirect(&format!("/users/{}/diet/{}", target_user.username, date));
     Ok(())
 }
 
@@ -26,14 +26,21 @@ pub async fn diet_copy_previous_day(
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    Diet::can_create(&user, target_user.id)?;
+    Diet::can_create(&pool, &user, target_user.id).await?;
     let previous_date = date.checked_sub_days(Days::new(1)).expect("valid date");
-    let previous_date_diet_logs = Diet::all_by_user_id_date(&pool, user.id, previous_date).await?;
+    let previous_date_diet_logs =
+        Diet::all_by_user_id_date(&pool, target_user.id, previous_date).await?;
     if previous_date_diet_logs.is_empty() {
         return Err(ServerFnError::new("Nothing to add"));
     }
-    Diet::bulk_create_from_previous_day(&pool, user.id, &date, &previous_date_diet_logs, user.id)
-        .await?;
+    Diet::bulk_create_from_previous_day(
+        &pool,
+        target_user.id,
+        &date,
+        &previous_date_diet_logs,
+        user.actor_id(),
+    )
+    .await?;
     Ok(())
 }
 
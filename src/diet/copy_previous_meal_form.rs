@@ -27,7 +27,7 @@ pub async fn diet_copy_previous(
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    Diet::can_create(&user, target_user.id)?;
+    Diet::can_create(&pool, &user, target_user.id).await?;
     let meal_of_day = MealOfDay::get_by_slug(&pool, &meal_of_day_slug)
         .await?
         .ok_or(Error::NotFound)?;
@@ -44,7 +44,7 @@ pub async fn diet_copy_previous(
         date,
         meal_of_day.id,
         &previous_date_diet_logs,
-        user.id,
+        user.actor_id(),
     )
     .await?;
     Ok(())
@@ -15,6 +15,8 @@ use crate::component::icon::IconFilePlus;
 use crate::component::template::{ErrorComponent, LoadingSpinner};
 use crate::diet_target::model::DietTargetQuery;
 use crate::food::model::Nutrition;
+use crate::social::component::SocialPanel;
+use crate::social::target::SocialTarget;
 use crate::util::param::{get_date, get_username};
 
 use super::component::{DietFoodGridHeader, DietMealGridHeader};
@@ -26,7 +28,8 @@ use super::week_navigation::DietWeekNavComponent;
 
 #[cfg(feature = "ssr")]
 use crate::{
-    auth::model::User, auth::service::get_request_user, diet::service::DietService, setup::get_pool,
+    auth::model::User, auth::service::get_request_user, diet::service::DietService,
+    setup::get_pool, user_privacy::section::PrivacySection,
 };
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -40,7 +43,7 @@ pub struct DietDayResponse {
 async fn get_diet_day(username: String, date: NaiveDate) -> Result<DietDayResponse, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietLog).await?;
     let response = DietService::aggregate_diet_day_data(&pool, &username, date).await?;
     Ok(response)
 }
@@ -90,9 +93,13 @@ pub fn DietDayPage() -> impl IntoView {
             });
             let remain_view = data.remaining.map(|remaining| {
                 let total = remaining.format();
+                let meal_targets_href = format!("/users/{}/diet-targets/meals", username());
                 view! {
-                    <section class="flex col-span-4 items-center p-2 font-bold bg-gray-200">
+                    <section class="flex col-span-4 justify-between items-center p-2 font-bold bg-gray-200">
                         "Remaining"
+                        <a class="text-xs font-semibold hover:underline" href=meal_targets_href>
+                            "Meal Targets"
+                        </a>
                     </section>
                     <DietDayTotalComponent formatted=total/>
                 }
@@ -105,6 +112,8 @@ pub fn DietDayPage() -> impl IntoView {
         })
     };
 
+    let social_target = Signal::derive(move || SocialTarget::diet_day(username(), date()));
+
     let show_save_modal = RwSignal::new(false);
     let toggle_save_modal = move |_| show_save_modal.update(|value| *value = !*value);
     let disabled_save_modal = Signal::derive(move || checked_items.with(HashSet::is_empty));
@@ -139,6 +148,7 @@ pub fn DietDayPage() -> impl IntoView {
             <section class="max-w-sm border p-4 m-4">
                 <DietToMealForm action=action_diet_save_to_meal checked_items/>
             </section>
+            <SocialPanel target=social_target/>
         </main>
     }
 }
@@ -186,6 +196,8 @@ const MEAL_TOTAL_ROW_CSS: &str =
     "flex items-center justify-end p-2 bg-gray-200/75 font-bold text-xs";
 const SECONDARY_MEAL_TOTAL_ROW_CSS: &str =
     "hidden md:flex items-center justify-end p-2 bg-gray-200/75 font-bold text-xs";
+const MEAL_REMAINING_ROW_CSS: &str =
+    "flex items-center justify-end p-2 text-xs font-bold text-gray-600";
 
 #[component]
 pub fn DietMealComponent(
@@ -196,6 +208,20 @@ pub fn DietMealComponent(
     let add_food_url = data.diet_add_food_url();
     let add_meal_url = data.diet_add_meal_url();
 
+    let remaining_view = data.remaining.map(|remaining| {
+        let remaining = remaining.format();
+        view! {
+            <div class="flex items-center p-2 text-xs font-bold text-gray-600 col-span-4">
+                "Remaining for meal"
+            </div>
+            <div class=MEAL_REMAINING_ROW_CSS>{remaining.energy}</div>
+            <div class=MEAL_REMAINING_ROW_CSS>{remaining.protein}</div>
+            <div class=MEAL_REMAINING_ROW_CSS>{remaining.carbohydrate}</div>
+            <div class=MEAL_REMAINING_ROW_CSS>{remaining.fat}</div>
+            <div class="hidden md:block col-span-4"></div>
+        }
+    });
+
     let food_list = data.food_list;
     let all_items = RwSignal::new(DietFoodQuery::ids_as_set(&food_list));
 
@@ -242,6 +268,7 @@ pub fn DietMealComponent(
         <div class=SECONDARY_MEAL_TOTAL_ROW_CSS>{total.sugars}</div>
         <div class=SECONDARY_MEAL_TOTAL_ROW_CSS>{total.fibre}</div>
         <div class=SECONDARY_MEAL_TOTAL_ROW_CSS>{total.salt}</div>
+        {remaining_view}
         <section class="col-span-full h-2"></section>
     }
 }
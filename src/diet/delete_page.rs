@@ -20,9 +20,9 @@ pub async fn diet_delete(diet_id: Uuid, redirect_to: Option<String>) -> Result<(
     let diet = Diet::get_by_id(&pool, diet_id)
         .await?
         .ok_or(Error::NotFound)?;
-    diet.can_delete(&user)?;
+    diet.can_delete(&pool, &user).await?;
 
-    Diet::delete(&pool, diet.id).await?;
+    Diet::delete(&pool, diet.id, user.actor_id()).await?;
 
     if let Some(redirect_url) = redirect_to {
         leptos_axum::redirect(&redirect_url);
@@ -13,7 +13,10 @@ use super::component::NutritionInformation;
 use super::model::DietFoodQuery;
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server]
 pub async fn get_diet_detail(id: Uuid) -> Result<DietFoodQuery, ServerFnError> {
@@ -25,7 +28,8 @@ pub async fn get_diet_detail(id: Uuid) -> Result<DietFoodQuery, ServerFnError> {
     let target_user = User::get_by_id(&pool, object.user_id)
         .await?
         .ok_or(Error::NotFound)?;
-    User::check_view_permission(&pool, &user, &target_user.username).await?;
+    User::check_view_permission(&pool, &user, &target_user.username, PrivacySection::DietLog)
+        .await?;
     Ok(object)
 }
 
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::diet_target::model::DietTargetQuery;
+use crate::food::model::Nutrition;
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Diet {
@@ -31,6 +32,7 @@ pub struct DietDaySummary {
     pub fat: Decimal,
 }
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DietFoodQuery {
     pub id: Uuid,
@@ -119,6 +121,7 @@ pub struct DietMealDTO {
     pub carbohydrate_pct: Decimal,
     pub fat_pct: Decimal,
     pub food_list: Vec<DietFoodQuery>,
+    pub remaining: Option<Nutrition>,
 }
 
 impl DietMealDTO {
@@ -1,30 +1,24 @@
 use super::model::Diet;
 use crate::auth::model::RequestUser;
-use crate::error::{Error, Result};
+use crate::coach::model::CoachClient;
+use crate::error::Result;
+use sqlx::PgPool;
 use uuid::Uuid;
 
 impl Diet {
-    pub fn can_create(request_user: &RequestUser, user_id: Uuid) -> Result<()> {
-        if user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Unauthorized)
-        }
+    pub async fn can_create(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        user_id: Uuid,
+    ) -> Result<()> {
+        CoachClient::authorize(pool, request_user, user_id).await
     }
 
-    pub fn can_update(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Unauthorized)
-        }
+    pub async fn can_update(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 
-    pub fn can_delete(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Unauthorized)
-        }
+    pub async fn can_delete(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 }
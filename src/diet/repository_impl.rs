@@ -1,9 +1,10 @@
 use chrono::prelude::*;
 use rust_decimal::Decimal;
 use sqlx::postgres::PgQueryResult;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::meal_food::model::MealFoodModel;
 use crate::util::datetime::{get_week_end, get_week_start};
@@ -19,7 +20,7 @@ impl Diet {
     }
 
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         date: NaiveDate,
         user_id: Uuid,
         meal_of_day_id: Uuid,
@@ -50,7 +51,7 @@ impl Diet {
             quantity,
             created_by_id,
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(query)
     }
@@ -87,10 +88,13 @@ impl Diet {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(Self, "DELETE FROM food_log WHERE id = $1 RETURNING *", id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
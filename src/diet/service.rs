@@ -1,11 +1,11 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use chrono::prelude::*;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::diet_target::model::DietTargetQuery;
+use crate::diet_target::model::{DietMealTarget, DietTargetQuery};
 use crate::error::Result;
 use crate::food::model::Nutrition;
 use crate::meal_of_day::model::MealOfDay;
@@ -47,6 +47,18 @@ impl DietMealDTO {
             self.fat_pct = (fat_energy / total_energy) * Decimal::new(100, 0);
         }
     }
+
+    fn calculate_remaining(&self, target: &Nutrition) -> Nutrition {
+        let mut remaining = Nutrition {
+            energy: target.energy - self.energy,
+            protein: target.protein - self.protein,
+            carbohydrate: target.carbohydrate - self.carbohydrate,
+            fat: target.fat - self.fat,
+            ..Default::default()
+        };
+        remaining.calculate_percentages();
+        remaining
+    }
 }
 
 impl DietDayDTO {
@@ -89,6 +101,14 @@ impl DietService {
         let diet_target =
             DietTargetQuery::get_latest_by_username_date(pool, username, date).await?;
         let diet_food = DietFoodQuery::all_by_username_date(pool, username, date).await?;
+        let meal_targets: HashMap<Uuid, Nutrition> = match &diet_target {
+            Some(target) => DietMealTarget::all_by_username(pool, username)
+                .await?
+                .into_iter()
+                .map(|meal_target| (meal_target.meal_of_day_id, meal_target.to_nutrition(target)))
+                .collect(),
+            None => HashMap::new(),
+        };
 
         let mut diet_day = DietDayDTO {
             username: username.to_string(),
@@ -132,6 +152,9 @@ impl DietService {
                     meal_dto.food_list = grouped_diet_food.remove(&meal.id).unwrap_or_default();
                     meal_dto.calculate_percentages();
                 }
+                meal_dto.remaining = meal_targets
+                    .get(&meal.id)
+                    .map(|target| meal_dto.calculate_remaining(target));
                 meal_dto
             })
             .collect();
@@ -32,7 +32,7 @@ pub async fn save_to_meal(
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    Meal::can_create(&target_user.clone().into()).await?;
+    Meal::can_create(&pool, &user, target_user.id).await?;
     let diet_uuids = parse_uuids_from_strings(&items)?;
     Meal::validate(&meal_name)?;
     let diet_food = Diet::all_by_ids(&pool, &diet_uuids).await?;
@@ -40,8 +40,8 @@ pub async fn save_to_meal(
         .is_empty()
         .then(|| ())
         .ok_or(ServerFnError::new("No diet logs found."))?;
-    let meal = Meal::create(&pool, target_user.id, &meal_name, user.id).await?;
-    MealFoodModel::bulk_create_from_diet(&pool, meal.id, &diet_food, user.id).await?;
+    let meal = Meal::create(&pool, target_user.id, &meal_name, user.actor_id()).await?;
+    MealFoodModel::bulk_create_from_diet(&pool, meal.id, &diet_food, user.actor_id()).await?;
     leptos_axum::redirect(&format!("/food/meals/{}", meal.id));
     Ok(())
 }
@@ -32,7 +32,7 @@ pub async fn diet_update(
     let pool = get_pool()?;
 
     let diet = Diet::get_by_id(&pool, id).await?.ok_or(Error::NotFound)?;
-    diet.can_update(&user)?;
+    diet.can_update(&pool, &user).await?;
     Diet::validate(date, quantity)?;
 
     let food = FoodQuery::get_by_id(&pool, food_id)
@@ -41,7 +41,15 @@ pub async fn diet_update(
 
     let quantity = food.data_measurement.to_quantity_modifier(&quantity);
 
-    Diet::update(&pool, diet.id, date, meal_of_day_id, quantity, user.id).await?;
+    Diet::update(
+        &pool,
+        diet.id,
+        date,
+        meal_of_day_id,
+        quantity,
+        user.actor_id(),
+    )
+    .await?;
     leptos_axum::redirect(&format!("/users/{username}/diet/{date}"));
     Ok(())
 }
@@ -9,7 +9,10 @@ use crate::util::param::{get_date, get_username};
 use super::model::DietDaySummary;
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server(endpoint = "user-diet-day-week-navigation")]
 pub async fn get_diet_week_nav(
@@ -18,7 +21,7 @@ pub async fn get_diet_week_nav(
 ) -> Result<Vec<DietDaySummary>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietLog).await?;
     let query = DietDaySummary::get_by_username_date(&pool, &username, date).await?;
     Ok(query)
 }
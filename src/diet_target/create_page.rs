@@ -34,7 +34,7 @@ pub async fn diet_target_create(
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    DietTarget::can_create(&user, target_user.id).await?;
+    DietTarget::can_create(&pool, &user, target_user.id).await?;
 
     let data = DietTargetGramKg {
         user_id: target_user.id,
@@ -46,7 +46,7 @@ pub async fn diet_target_create(
     };
     data.validate()?;
     let input = DietTargetInput::from(data);
-    DietTarget::create(&pool, input, user.id).await?;
+    DietTarget::create(&pool, input, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/users/{}/{}", username, date));
     Ok(())
@@ -23,9 +23,9 @@ pub async fn diet_target_delete(id: Uuid, username: String) -> Result<(), Server
     let object = DietTarget::get_by_id(&pool, id)
         .await?
         .ok_or(Error::NotFound)?;
-    object.can_delete(&user).await?;
+    object.can_delete(&pool, &user).await?;
 
-    DietTarget::delete(&pool, object.id).await?;
+    DietTarget::delete(&pool, object.id, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/users/{}/{}", username, object.date));
     Ok(())
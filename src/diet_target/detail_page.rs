@@ -11,7 +11,10 @@ use crate::component::template::{
 use crate::util::param::{get_date, get_username};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server(endpoint = "diet-target-detail-latest")]
 pub async fn get_diet_target_detail_latest(
@@ -20,7 +23,7 @@ pub async fn get_diet_target_detail_latest(
 ) -> Result<Option<DietTargetQuery>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietTarget).await?;
     let object = DietTargetQuery::get_latest_by_username_date(&pool, &username, date).await?;
     Ok(object)
 }
@@ -32,7 +35,7 @@ pub async fn get_diet_target_detail(
 ) -> Result<DietTargetQuery, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietTarget).await?;
     let object = DietTargetQuery::get_by_username_date(&pool, &username, date)
         .await?
         .ok_or(Error::NotFound)?;
@@ -31,10 +31,10 @@ pub async fn target_from_profile_create(
         .await?
         .ok_or(Error::NotFound)?;
 
-    DietTargetQuery::can_create(&user, target_user.id).await?;
+    DietTargetQuery::can_create(&pool, &user, target_user.id).await?;
     DietTargetQuery::validate_date(date)?;
 
-    let profile = ProfileQuery::get_latest_by_username(&pool, &user.username, date)
+    let profile = ProfileQuery::get_latest_by_username(&pool, &username, date)
         .await?
         .ok_or(Error::NotFound)?;
 
@@ -49,12 +49,13 @@ pub async fn target_from_profile_create(
         .parse::<FitnessGoal>()
         .map_err(|_| Error::InternalServer)?;
     let modifier: TargetModifier = fitness_goal.into();
+    // Uses the profile's selected BMR formula, or the manual TDEE override when set.
     let tdee = profile.get_total_daily_energy_expenditure();
 
     let database_input =
         DietTargetInput::calculate_nutrients(modifier, tdee, target_user.id, date, latest_weight);
 
-    DietTarget::create(&pool, database_input, user.id).await?;
+    DietTarget::create(&pool, database_input, user.actor_id()).await?;
     Ok(())
 }
 
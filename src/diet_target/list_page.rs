@@ -18,7 +18,7 @@ use crate::util::param::{extract_page, extract_param, extract_size, get_username
 #[cfg(feature = "ssr")]
 use crate::{
     auth::model::User, auth::service::get_request_user, diet_target::model::DietTargetQuery,
-    setup::get_pool,
+    setup::get_pool, user_privacy::section::PrivacySection,
 };
 
 #[server(endpoint = "diet-target-list")]
@@ -31,7 +31,7 @@ pub async fn get_diet_target_list(
 ) -> Result<Vec<UserDaySummary>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietTarget).await?;
 
     let results =
         DietTargetQuery::filter_by_username(&pool, &username, &search, &order, size, page).await?;
@@ -46,7 +46,7 @@ pub async fn get_diet_target_list_count(
 ) -> Result<i64, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietTarget).await?;
 
     let count = DietTargetQuery::count_by_username(&pool, &username, &search).await?;
 
@@ -0,0 +1,114 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::food::model::Nutrition;
+
+use super::model::{DietMealTarget, DietTargetQuery};
+
+/// How a per-meal target is expressed: as a share of the daily diet target, or as fixed grams.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum MealSplit {
+    Percentage,
+    Grams,
+    Unknown(i32),
+}
+
+impl fmt::Display for MealSplit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MealSplit::Percentage => write!(f, "Percentage"),
+            MealSplit::Grams => write!(f, "Grams"),
+            MealSplit::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl MealSplit {
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        let options = [("1", "Percentage of daily target"), ("2", "Grams")];
+        options.to_vec()
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            MealSplit::Percentage => "%",
+            _ => "g",
+        }
+    }
+
+    pub fn resolve(&self, value: Decimal, daily_total: Decimal) -> Decimal {
+        match self {
+            MealSplit::Percentage => daily_total * value / Decimal::new(100, 0),
+            _ => value,
+        }
+    }
+}
+
+impl From<i32> for MealSplit {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => MealSplit::Percentage,
+            2 => MealSplit::Grams,
+            _ => MealSplit::Unknown(value),
+        }
+    }
+}
+
+impl From<MealSplit> for i32 {
+    fn from(split: MealSplit) -> Self {
+        match split {
+            MealSplit::Percentage => 1,
+            MealSplit::Grams => 2,
+            MealSplit::Unknown(value) => value,
+        }
+    }
+}
+
+impl DietMealTarget {
+    /// Resolves the meal target into grams against the daily diet target. Energy is derived from
+    /// the resolved macronutrients.
+    pub fn to_nutrition(&self, daily: &DietTargetQuery) -> Nutrition {
+        let split = self.get_split_type();
+        let protein = split.resolve(self.protein, daily.protein);
+        let carbohydrate = split.resolve(self.carbohydrate, daily.carbohydrate);
+        let fat = split.resolve(self.fat, daily.fat);
+        let energy = protein * Decimal::new(4, 0)
+            + carbohydrate * Decimal::new(4, 0)
+            + fat * Decimal::new(9, 0);
+        let mut nutrition = Nutrition {
+            energy,
+            protein,
+            carbohydrate,
+            fat,
+            ..Default::default()
+        };
+        nutrition.calculate_percentages();
+        nutrition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_percentage() {
+        let value = MealSplit::Percentage.resolve(Decimal::from(25), Decimal::from(200));
+        assert_eq!(value, Decimal::from(50));
+    }
+
+    #[test]
+    fn test_resolve_grams() {
+        let value = MealSplit::Grams.resolve(Decimal::from(40), Decimal::from(200));
+        assert_eq!(value, Decimal::from(40));
+    }
+
+    #[test]
+    fn test_split_type_round_trip() {
+        for split in [MealSplit::Percentage, MealSplit::Grams] {
+            assert_eq!(MealSplit::from(i32::from(split)), split);
+        }
+        assert_eq!(MealSplit::from(7), MealSplit::Unknown(7));
+    }
+}
@@ -0,0 +1,195 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::NumberInput;
+use crate::component::select::FieldSelect;
+use crate::component::template::{ErrorComponent, ListLoadingComponent, ListNotFoundComponent};
+use crate::meal_of_day::select::MealOfDaySelect;
+use crate::util::param::get_username;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::meal_split::MealSplit;
+use super::model::DietMealTargetQuery;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::model::User,
+    auth::service::get_request_user,
+    diet_target::model::{DietMealTarget, DietMealTargetInput},
+    error::Error,
+    setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
+
+#[server(endpoint = "diet-meal-target-list")]
+pub async fn get_diet_meal_target_list(
+    username: String,
+) -> Result<Vec<DietMealTargetQuery>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietTarget).await?;
+    let query = DietMealTargetQuery::all_by_username(&pool, &username).await?;
+    Ok(query)
+}
+
+#[server(endpoint = "diet-meal-target-update-or-create")]
+pub async fn diet_meal_target_update_or_create(
+    username: String,
+    meal_of_day_id: Uuid,
+    split_type: i32,
+    protein: Decimal,
+    carbohydrate: Decimal,
+    fat: Decimal,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let target_user = User::get_by_username(&pool, &username)
+        .await?
+        .ok_or(Error::NotFound)?;
+    DietMealTarget::can_create(&pool, &user, target_user.id).await?;
+
+    let data = DietMealTargetInput {
+        user_id: target_user.id,
+        meal_of_day_id,
+        split_type,
+        protein,
+        carbohydrate,
+        fat,
+    };
+    data.validate()?;
+    let existing = DietMealTarget::all_by_username(&pool, &username).await?;
+    data.validate_percentage_total(&existing)?;
+
+    DietMealTarget::create_or_update(&pool, &data, user.actor_id()).await?;
+    Ok(())
+}
+
+#[server(endpoint = "diet-meal-target-delete")]
+pub async fn diet_meal_target_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let object = DietMealTarget::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    object.can_delete(&pool, &user).await?;
+
+    DietMealTarget::delete(&pool, object.id, user.actor_id()).await?;
+    Ok(())
+}
+
+#[component]
+pub fn DietMealTargetPage() -> impl IntoView {
+    let params = use_params_map();
+    let username = move || get_username(&params);
+
+    let action_update = Action::<DietMealTargetUpdateOrCreate, _>::server();
+    let action_delete = Action::<DietMealTargetDelete, _>::server();
+
+    let action_loading = action_update.pending();
+    let action_value = action_update.value();
+    let action_error = move || {
+        extract_other_errors(
+            action_value,
+            &["split_type", "protein", "carbohydrate", "fat"],
+        )
+    };
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let resource = Resource::new(
+        move || {
+            (
+                username(),
+                action_update.version().get(),
+                action_delete.version().get(),
+            )
+        },
+        |(username, ..)| get_diet_meal_target_list(username),
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.iter()
+                    .map(|item| {
+                        view! { <DietMealTargetListItem data=item.clone() action_delete/> }
+                    })
+                    .collect_view()
+            }
+        })
+    };
+
+    view! {
+        <Title text="Meal Targets"/>
+        <main class="p-4 space-y-4 bg-white border md:m-4">
+            <h1 class="text-xl font-bold">"Meal Targets"</h1>
+            <p class="text-gray-500">
+                "Split your daily diet target across meals, either as a percentage of the daily target or as fixed grams. The amount remaining for each meal is shown on the diet day page."
+            </p>
+            <div class="grid grid-cols-5">
+                <div class="p-2 font-bold border-b">"Meal"</div>
+                <div class="p-2 font-bold text-right border-b">"Protein"</div>
+                <div class="p-2 font-bold text-right border-b">"Carbs"</div>
+                <div class="p-2 font-bold text-right border-b">"Fat"</div>
+                <div class="p-2 border-b"></div>
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+            </div>
+            <section class="p-4 max-w-md border">
+                <h2 class="mb-4 text-base font-bold">"Set Meal Target"</h2>
+                <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                <ActionForm action=action_update>
+                    <input type="hidden" name="username" value=username/>
+                    <MealOfDaySelect/>
+                    <FieldSelect
+                        name="split_type"
+                        label="split"
+                        options=MealSplit::to_form_options()
+                    />
+                    <NumberInput action_value step="0.1" name="protein" placeholder="25"/>
+                    <NumberInput action_value step="0.1" name="carbohydrate" placeholder="25"/>
+                    <NumberInput action_value step="0.1" name="fat" placeholder="25"/>
+                    <SubmitButton loading=action_loading label="Save Meal Target"/>
+                </ActionForm>
+            </section>
+        </main>
+    }
+}
+
+#[component]
+fn DietMealTargetListItem(
+    data: DietMealTargetQuery,
+    action_delete: Action<DietMealTargetDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let unit = data.get_split_type().unit();
+    view! {
+        <div class="contents">
+            <div class="p-2 border-b">{data.meal_of_day_name}</div>
+            <div class="p-2 text-right border-b">{format!("{:.1}{}", data.protein, unit)}</div>
+            <div class="p-2 text-right border-b">
+                {format!("{:.1}{}", data.carbohydrate, unit)}
+            </div>
+            <div class="p-2 text-right border-b">{format!("{:.1}{}", data.fat, unit)}</div>
+            <div class="p-2 text-right border-b">
+                <ActionForm action=action_delete>
+                    <input type="hidden" name="id" value=data.id.to_string()/>
+                    <button type="submit" class="hover:underline">
+                        "Remove"
+                    </button>
+                </ActionForm>
+            </div>
+        </div>
+    }
+}
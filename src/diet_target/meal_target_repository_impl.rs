@@ -0,0 +1,123 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::Result;
+
+use super::model::{DietMealTarget, DietMealTargetInput, DietMealTargetQuery};
+
+impl DietMealTarget {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM diet_meal_target WHERE id = $1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_username(pool: &PgPool, username: &str) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.*
+            FROM
+                diet_meal_target t1
+                LEFT JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t2.username = $1
+            ",
+            username
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create_or_update(
+        pool: &PgPool,
+        data: &DietMealTargetInput,
+        request_user_id: Uuid,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                diet_meal_target (
+                    user_id,
+                    meal_of_day_id,
+                    split_type,
+                    protein,
+                    carbohydrate,
+                    fat,
+                    created_by_id
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, meal_of_day_id) DO UPDATE
+            SET
+                split_type = EXCLUDED.split_type,
+                protein = EXCLUDED.protein,
+                carbohydrate = EXCLUDED.carbohydrate,
+                fat = EXCLUDED.fat,
+                updated_at = NOW(),
+                updated_by_id = EXCLUDED.created_by_id
+            RETURNING
+                *
+            ",
+            data.user_id,
+            data.meal_of_day_id,
+            data.split_type,
+            data.protein,
+            data.carbohydrate,
+            data.fat,
+            request_user_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
+            Self,
+            "DELETE FROM diet_meal_target WHERE id = $1 RETURNING *",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+}
+
+impl DietMealTargetQuery {
+    pub async fn all_by_username(pool: &PgPool, username: &str) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.id,
+                t2.name AS meal_of_day_name,
+                t2.ordering AS meal_of_day_ordering,
+                t1.split_type,
+                t1.protein,
+                t1.carbohydrate,
+                t1.fat
+            FROM
+                diet_meal_target t1
+                JOIN meal_of_day t2 ON t2.id = t1.meal_of_day_id
+                LEFT JOIN users_user t3 ON t3.id = t1.user_id
+            WHERE
+                t3.username = $1
+            ORDER BY
+                t2.ordering
+            ",
+            username
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
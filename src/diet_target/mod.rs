@@ -5,6 +5,10 @@ pub mod detail_page;
 pub mod detail_table;
 pub mod from_profile_form;
 pub mod list_page;
+pub mod meal_split;
+pub mod meal_target_page;
+#[cfg(feature = "ssr")]
+pub mod meal_target_repository_impl;
 pub mod model;
 #[cfg(feature = "ssr")]
 pub mod permission;
@@ -12,6 +16,11 @@ pub mod permission;
 pub mod repository_impl;
 #[cfg(feature = "ssr")]
 pub mod service;
+pub mod template_apply_form;
+pub mod template_day;
+pub mod template_page;
+#[cfg(feature = "ssr")]
+pub mod template_repository_impl;
 pub mod update_or_create_form;
 pub mod update_page;
 #[cfg(feature = "ssr")]
@@ -3,6 +3,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::meal_split::MealSplit;
+use super::template_day::TemplateDay;
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DietTarget {
     pub id: Uuid,
@@ -23,6 +26,7 @@ pub struct DietTarget {
     pub updated_by_id: Option<Uuid>,
 }
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DietTargetQuery {
     pub id: Uuid,
@@ -75,3 +79,88 @@ pub struct DietTargetInput {
     pub protein: Decimal,
     pub salt: Decimal,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DietTargetTemplate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub created_by_id: Uuid,
+    pub updated_by_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DietTargetTemplateDay {
+    pub id: Uuid,
+    pub template_id: Uuid,
+    pub day_type: i32,
+    pub protein_per_kg: Decimal,
+    pub carbohydrate_per_kg: Decimal,
+    pub fat_per_kg: Decimal,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub created_by_id: Uuid,
+    pub updated_by_id: Option<Uuid>,
+}
+
+impl DietTargetTemplateDay {
+    pub fn get_day_type(&self) -> TemplateDay {
+        TemplateDay::from(self.day_type)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DietTargetTemplateQuery {
+    pub template: DietTargetTemplate,
+    pub days: Vec<DietTargetTemplateDay>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DietMealTarget {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub meal_of_day_id: Uuid,
+    pub split_type: i32,
+    pub protein: Decimal,
+    pub carbohydrate: Decimal,
+    pub fat: Decimal,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub created_by_id: Uuid,
+    pub updated_by_id: Option<Uuid>,
+}
+
+impl DietMealTarget {
+    pub fn get_split_type(&self) -> MealSplit {
+        MealSplit::from(self.split_type)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DietMealTargetInput {
+    pub user_id: Uuid,
+    pub meal_of_day_id: Uuid,
+    pub split_type: i32,
+    pub protein: Decimal,
+    pub carbohydrate: Decimal,
+    pub fat: Decimal,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DietMealTargetQuery {
+    pub id: Uuid,
+    pub meal_of_day_name: String,
+    pub meal_of_day_ordering: i32,
+    pub split_type: i32,
+    pub protein: Decimal,
+    pub carbohydrate: Decimal,
+    pub fat: Decimal,
+}
+
+impl DietMealTargetQuery {
+    pub fn get_split_type(&self) -> MealSplit {
+        MealSplit::from(self.split_type)
+    }
+}
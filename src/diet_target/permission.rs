@@ -1,57 +1,75 @@
+use sqlx::PgPool;
 use uuid::Uuid;
 
-use super::model::{DietTarget, DietTargetQuery};
+use super::model::{DietMealTarget, DietTarget, DietTargetQuery, DietTargetTemplate};
 use crate::auth::model::RequestUser;
-use crate::error::{Error, Result};
+use crate::coach::model::CoachClient;
+use crate::error::Result;
 
 impl DietTarget {
-    pub async fn can_create(request_user: &RequestUser, target_user_id: Uuid) -> Result<()> {
-        if target_user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_create(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        target_user_id: Uuid,
+    ) -> Result<()> {
+        CoachClient::authorize(pool, request_user, target_user_id).await
     }
 
-    pub async fn can_update(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_update(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 
-    pub async fn can_delete(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_delete(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 }
 
 impl DietTargetQuery {
-    pub async fn can_create(request_user: &RequestUser, target_user_id: Uuid) -> Result<()> {
-        if target_user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
-    }
-
-    pub async fn can_update(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
-    }
-
-    pub async fn can_delete(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_create(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        target_user_id: Uuid,
+    ) -> Result<()> {
+        CoachClient::authorize(pool, request_user, target_user_id).await
+    }
+
+    pub async fn can_update(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
+    }
+
+    pub async fn can_delete(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
+    }
+}
+
+impl DietTargetTemplate {
+    pub async fn can_create(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        target_user_id: Uuid,
+    ) -> Result<()> {
+        CoachClient::authorize(pool, request_user, target_user_id).await
+    }
+
+    pub async fn can_update(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
+    }
+
+    pub async fn can_delete(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
+    }
+}
+
+impl DietMealTarget {
+    pub async fn can_create(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        target_user_id: Uuid,
+    ) -> Result<()> {
+        CoachClient::authorize(pool, request_user, target_user_id).await
+    }
+
+    pub async fn can_delete(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 }
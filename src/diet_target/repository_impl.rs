@@ -1,7 +1,8 @@
 use chrono::prelude::*;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::summary::model::UserDaySummary;
 use crate::util::database::Filter;
@@ -35,7 +36,7 @@ impl DietTarget {
     }
 
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         data: DietTargetInput,
         request_user_id: Uuid,
     ) -> Result<Self> {
@@ -71,7 +72,7 @@ impl DietTarget {
             data.salt,
             request_user_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(query)
     }
@@ -118,14 +119,17 @@ impl DietTarget {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(
             Self,
             "DELETE FROM diet_target WHERE id = $1 RETURNING *",
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
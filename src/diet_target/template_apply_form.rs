@@ -0,0 +1,171 @@
+use leptos::*;
+use leptos_router::*;
+
+use chrono::prelude::*;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::{NumberInput, TextInput};
+use crate::component::template::OptionLoading;
+use crate::util::datetime::{get_week_end, get_week_start};
+use crate::util::param::{get_date, get_username};
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::template_page::get_diet_target_template_list;
+
+#[cfg(feature = "ssr")]
+use {
+    crate::{
+        auth::model::User,
+        auth::service::get_request_user,
+        diet_target::model::{
+            DietTarget, DietTargetGramKg, DietTargetInput, DietTargetTemplate,
+            DietTargetTemplateDay,
+        },
+        diet_target::template_day::TemplateDay,
+        error::Error,
+        progress::model::Progress,
+        setup::get_pool,
+    },
+    std::collections::{BTreeMap, HashSet},
+};
+
+#[server(endpoint = "diet-target-template-apply")]
+pub async fn diet_target_template_apply(
+    username: String,
+    template_id: Uuid,
+    start: NaiveDate,
+    end: NaiveDate,
+    weight: Option<Decimal>,
+    use_workouts: Option<String>,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let target_user = User::get_by_username(&pool, &username)
+        .await?
+        .ok_or(Error::NotFound)?;
+    DietTarget::can_create(&pool, &user, target_user.id).await?;
+
+    let template = DietTargetTemplate::get_by_id(&pool, template_id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    if template.user_id != target_user.id {
+        return Err(Error::Forbidden.into());
+    }
+    DietTargetTemplate::validate_apply(start, end)?;
+
+    let weight = match weight {
+        Some(weight) => weight,
+        None => Progress::get_latest_weight(&pool, target_user.id)
+            .await?
+            .ok_or_else(|| {
+                ServerFnError::new("Enter a weight or log one in progress to apply a template.")
+            })?,
+    };
+
+    let days = DietTargetTemplateDay::all_by_template_ids(&pool, &[template.id]).await?;
+    let workout_dates: Option<HashSet<NaiveDate>> = if use_workouts.is_some() {
+        let dates = DietTargetTemplate::workout_dates(&pool, target_user.id, start, end).await?;
+        Some(dates.into_iter().collect())
+    } else {
+        None
+    };
+
+    // Group the dates by template day so each day's values are written in a single query.
+    let mut grouped: BTreeMap<Uuid, Vec<NaiveDate>> = BTreeMap::new();
+    for date in start.iter_days().take_while(|date| *date <= end) {
+        let is_training_day = workout_dates.as_ref().map(|dates| dates.contains(&date));
+        if let Some(day) = TemplateDay::select_for_date(&days, date, is_training_day) {
+            grouped.entry(day.id).or_default().push(date);
+        }
+    }
+
+    for day in days.iter() {
+        let Some(date_list) = grouped.get(&day.id) else {
+            continue;
+        };
+        let data = DietTargetGramKg {
+            user_id: target_user.id,
+            date: start,
+            weight,
+            protein_per_kg: day.protein_per_kg,
+            carbohydrate_per_kg: day.carbohydrate_per_kg,
+            fat_per_kg: day.fat_per_kg,
+        };
+        data.validate()?;
+        let database_input = DietTargetInput::from(data);
+        DietTarget::bulk_create_update(&pool, database_input, date_list, user.actor_id()).await?;
+    }
+    Ok(())
+}
+
+#[component]
+pub fn DietTargetTemplateApplyForm(
+    action: Action<DietTargetTemplateApply, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let params = use_params_map();
+    let username = move || get_username(&params);
+    let date = move || get_date(&params);
+    let start = move || get_week_start(date()).to_string();
+    let end = move || get_week_end(date()).to_string();
+
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || {
+        extract_other_errors(
+            action_value,
+            &["non_field_errors", "start", "end", "weight", "template_id"],
+        )
+    };
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let resource = Resource::new(username, get_diet_target_template_list);
+    let template_options = move || {
+        resource.and_then(|data| {
+            data.iter()
+                .map(|item| {
+                    view! {
+                        <option value=item.template.id.to_string()>{&item.template.name}</option>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action class="contents">
+            <input type="hidden" name="username" value=username/>
+            <label class="block mb-4">
+                <span class="block mb-1 text-sm font-bold">"Template"</span>
+                <select
+                    name="template_id"
+                    class="block py-2 px-3 w-full bg-white rounded border focus:border-blue-500 focus:ring-2 focus:ring-blue-500 focus:outline-none"
+                >
+                    <Transition fallback=OptionLoading>
+                        <ErrorBoundary fallback=|_| {
+                            view! { <option>"Error loading templates"</option> }
+                        }>{template_options}</ErrorBoundary>
+                    </Transition>
+                </select>
+            </label>
+            <TextInput action_value name="start" input_type="date" value=Signal::derive(start)/>
+            <TextInput action_value name="end" input_type="date" value=Signal::derive(end)/>
+            <NumberInput
+                action_value
+                step="0.01"
+                name="weight"
+                label="Weight (kg)"
+                placeholder="Defaults to your latest weight"
+            />
+            <label class="flex gap-2 items-center mb-4">
+                <input type="checkbox" name="use_workouts" value="on"/>
+                <span class="text-sm">"Use training/rest days based on logged workouts"</span>
+            </label>
+            <SubmitButton loading=action_loading label="Apply Template"/>
+        </ActionForm>
+    }
+}
@@ -0,0 +1,173 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::model::DietTargetTemplateDay;
+
+/// Which days a template row applies to. Weekdays are matched by the date, while training and
+/// rest days are matched by whether a workout has been logged on the date.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateDay {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+    TrainingDay,
+    RestDay,
+    Unknown(i32),
+}
+
+impl fmt::Display for TemplateDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateDay::Monday => write!(f, "Monday"),
+            TemplateDay::Tuesday => write!(f, "Tuesday"),
+            TemplateDay::Wednesday => write!(f, "Wednesday"),
+            TemplateDay::Thursday => write!(f, "Thursday"),
+            TemplateDay::Friday => write!(f, "Friday"),
+            TemplateDay::Saturday => write!(f, "Saturday"),
+            TemplateDay::Sunday => write!(f, "Sunday"),
+            TemplateDay::TrainingDay => write!(f, "Training Day"),
+            TemplateDay::RestDay => write!(f, "Rest Day"),
+            TemplateDay::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl TemplateDay {
+    pub fn from_weekday(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Mon => TemplateDay::Monday,
+            Weekday::Tue => TemplateDay::Tuesday,
+            Weekday::Wed => TemplateDay::Wednesday,
+            Weekday::Thu => TemplateDay::Thursday,
+            Weekday::Fri => TemplateDay::Friday,
+            Weekday::Sat => TemplateDay::Saturday,
+            Weekday::Sun => TemplateDay::Sunday,
+        }
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        let options = [
+            ("8", "Training Day"),
+            ("9", "Rest Day"),
+            ("1", "Monday"),
+            ("2", "Tuesday"),
+            ("3", "Wednesday"),
+            ("4", "Thursday"),
+            ("5", "Friday"),
+            ("6", "Saturday"),
+            ("7", "Sunday"),
+        ];
+        options.to_vec()
+    }
+
+    /// Picks the template row to use for a date. When `is_training_day` is given, a matching
+    /// training/rest row takes precedence over the weekday row.
+    pub fn select_for_date(
+        days: &[DietTargetTemplateDay],
+        date: NaiveDate,
+        is_training_day: Option<bool>,
+    ) -> Option<&DietTargetTemplateDay> {
+        let by_day_type =
+            |day_type: TemplateDay| days.iter().find(|day| day.get_day_type() == day_type);
+        let training_or_rest = is_training_day.and_then(|is_training_day| {
+            if is_training_day {
+                by_day_type(TemplateDay::TrainingDay)
+            } else {
+                by_day_type(TemplateDay::RestDay)
+            }
+        });
+        training_or_rest.or_else(|| by_day_type(TemplateDay::from_weekday(date.weekday())))
+    }
+}
+
+impl From<i32> for TemplateDay {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => TemplateDay::Monday,
+            2 => TemplateDay::Tuesday,
+            3 => TemplateDay::Wednesday,
+            4 => TemplateDay::Thursday,
+            5 => TemplateDay::Friday,
+            6 => TemplateDay::Saturday,
+            7 => TemplateDay::Sunday,
+            8 => TemplateDay::TrainingDay,
+            9 => TemplateDay::RestDay,
+            _ => TemplateDay::Unknown(value),
+        }
+    }
+}
+
+impl From<TemplateDay> for i32 {
+    fn from(day: TemplateDay) -> Self {
+        match day {
+            TemplateDay::Monday => 1,
+            TemplateDay::Tuesday => 2,
+            TemplateDay::Wednesday => 3,
+            TemplateDay::Thursday => 4,
+            TemplateDay::Friday => 5,
+            TemplateDay::Saturday => 6,
+            TemplateDay::Sunday => 7,
+            TemplateDay::TrainingDay => 8,
+            TemplateDay::RestDay => 9,
+            TemplateDay::Unknown(value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    fn template_day(day_type: TemplateDay, carbohydrate_per_kg: i64) -> DietTargetTemplateDay {
+        DietTargetTemplateDay {
+            id: Uuid::nil(),
+            template_id: Uuid::nil(),
+            day_type: day_type.into(),
+            protein_per_kg: Decimal::from(2),
+            carbohydrate_per_kg: Decimal::from(carbohydrate_per_kg),
+            fat_per_kg: Decimal::from(1),
+            created_at: Utc::now(),
+            updated_at: None,
+            created_by_id: Uuid::nil(),
+            updated_by_id: None,
+        }
+    }
+
+    #[test]
+    fn test_select_for_date_by_weekday() {
+        let days = vec![
+            template_day(TemplateDay::Monday, 5),
+            template_day(TemplateDay::Tuesday, 2),
+        ];
+        let tuesday = NaiveDate::from_ymd_opt(2024, 7, 2).unwrap();
+        let selected = TemplateDay::select_for_date(&days, tuesday, None).unwrap();
+        assert_eq!(selected.get_day_type(), TemplateDay::Tuesday);
+
+        let wednesday = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+        assert!(TemplateDay::select_for_date(&days, wednesday, None).is_none());
+    }
+
+    #[test]
+    fn test_select_for_date_by_workout() {
+        let days = vec![
+            template_day(TemplateDay::TrainingDay, 5),
+            template_day(TemplateDay::RestDay, 2),
+            template_day(TemplateDay::Monday, 3),
+        ];
+        let monday = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let selected = TemplateDay::select_for_date(&days, monday, Some(true)).unwrap();
+        assert_eq!(selected.get_day_type(), TemplateDay::TrainingDay);
+        let selected = TemplateDay::select_for_date(&days, monday, Some(false)).unwrap();
+        assert_eq!(selected.get_day_type(), TemplateDay::RestDay);
+        let selected = TemplateDay::select_for_date(&days, monday, None).unwrap();
+        assert_eq!(selected.get_day_type(), TemplateDay::Monday);
+    }
+}
@@ -0,0 +1,294 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::{NumberInput, TextInput};
+use crate::component::select::FieldSelect;
+use crate::component::template::{ErrorComponent, ListLoadingComponent, ListNotFoundComponent};
+use crate::util::param::get_username;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::model::DietTargetTemplateQuery;
+use super::template_day::TemplateDay;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::model::User,
+    auth::service::get_request_user,
+    diet_target::model::{DietTargetTemplate, DietTargetTemplateDay},
+    error::Error,
+    setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
+
+#[server(endpoint = "diet-target-template-list")]
+pub async fn get_diet_target_template_list(
+    username: String,
+) -> Result<Vec<DietTargetTemplateQuery>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietTarget).await?;
+
+    let templates = DietTargetTemplate::all_by_username(&pool, &username).await?;
+    let template_ids: Vec<Uuid> = templates.iter().map(|template| template.id).collect();
+    let mut days = DietTargetTemplateDay::all_by_template_ids(&pool, &template_ids).await?;
+
+    let results = templates
+        .into_iter()
+        .map(|template| {
+            let (template_days, other_days) = days
+                .drain(..)
+                .partition(|day| day.template_id == template.id);
+            days = other_days;
+            DietTargetTemplateQuery {
+                template,
+                days: template_days,
+            }
+        })
+        .collect();
+    Ok(results)
+}
+
+#[server(endpoint = "diet-target-template-create")]
+pub async fn diet_target_template_create(
+    username: String,
+    name: String,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let target_user = User::get_by_username(&pool, &username)
+        .await?
+        .ok_or(Error::NotFound)?;
+    DietTargetTemplate::can_create(&pool, &user, target_user.id).await?;
+    DietTargetTemplate::validate(&name)?;
+
+    DietTargetTemplate::create(&pool, target_user.id, &name, user.actor_id()).await?;
+    Ok(())
+}
+
+#[server(endpoint = "diet-target-template-delete")]
+pub async fn diet_target_template_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let object = DietTargetTemplate::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    object.can_delete(&pool, &user).await?;
+
+    DietTargetTemplate::delete(&pool, object.id, user.actor_id()).await?;
+    Ok(())
+}
+
+#[server(endpoint = "diet-target-template-day-update-or-create")]
+pub async fn diet_target_template_day_update_or_create(
+    template_id: Uuid,
+    day_type: i32,
+    protein_per_kg: Decimal,
+    carbohydrate_per_kg: Decimal,
+    fat_per_kg: Decimal,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let template = DietTargetTemplate::get_by_id(&pool, template_id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    template.can_update(&pool, &user).await?;
+    DietTargetTemplateDay::validate(day_type, protein_per_kg, carbohydrate_per_kg, fat_per_kg)?;
+
+    DietTargetTemplateDay::create_or_update(
+        &pool,
+        template.id,
+        day_type,
+        protein_per_kg,
+        carbohydrate_per_kg,
+        fat_per_kg,
+        user.actor_id(),
+    )
+    .await?;
+    Ok(())
+}
+
+#[server(endpoint = "diet-target-template-day-delete")]
+pub async fn diet_target_template_day_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let object = DietTargetTemplateDay::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let template = DietTargetTemplate::get_by_id(&pool, object.template_id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    template.can_update(&pool, &user).await?;
+
+    DietTargetTemplateDay::delete(&pool, object.id, user.actor_id()).await?;
+    Ok(())
+}
+
+#[component]
+pub fn DietTargetTemplatePage() -> impl IntoView {
+    let params = use_params_map();
+    let username = move || get_username(&params);
+
+    let action_create = Action::<DietTargetTemplateCreate, _>::server();
+    let action_delete = Action::<DietTargetTemplateDelete, _>::server();
+    let action_day_update = Action::<DietTargetTemplateDayUpdateOrCreate, _>::server();
+    let action_day_delete = Action::<DietTargetTemplateDayDelete, _>::server();
+
+    let action_loading = action_create.pending();
+    let action_value = action_create.value();
+    let action_error = move || extract_other_errors(action_value, &["name"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let resource = Resource::new(
+        move || {
+            (
+                username(),
+                action_create.version().get(),
+                action_delete.version().get(),
+                action_day_update.version().get(),
+                action_day_delete.version().get(),
+            )
+        },
+        |(username, ..)| get_diet_target_template_list(username),
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.iter()
+                    .map(|item| {
+                        view! {
+                            <DietTargetTemplateItem
+                                data=item.clone()
+                                action_delete
+                                action_day_update
+                                action_day_delete
+                            />
+                        }
+                    })
+                    .collect_view()
+            }
+        })
+    };
+
+    view! {
+        <Title text="Diet Target Templates"/>
+        <main class="p-4 space-y-4 bg-white border md:m-4">
+            <h1 class="text-xl font-bold">"Diet Target Templates"</h1>
+            <p class="text-gray-500">
+                "Set grams per kg for each weekday, or for training and rest days, then apply the template over a date range from the week summary."
+            </p>
+            <Transition fallback=ListLoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+            <section class="p-4 max-w-md border">
+                <h2 class="mb-4 text-base font-bold">"New Template"</h2>
+                <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                <ActionForm action=action_create>
+                    <input type="hidden" name="username" value=username/>
+                    <TextInput action_value name="name" placeholder="Carb Cycling"/>
+                    <SubmitButton loading=action_loading label="Create Template"/>
+                </ActionForm>
+            </section>
+        </main>
+    }
+}
+
+#[component]
+fn DietTargetTemplateItem(
+    data: DietTargetTemplateQuery,
+    action_delete: Action<DietTargetTemplateDelete, Result<(), ServerFnError>>,
+    action_day_update: Action<DietTargetTemplateDayUpdateOrCreate, Result<(), ServerFnError>>,
+    action_day_delete: Action<DietTargetTemplateDayDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let template_id = data.template.id;
+    let action_value = action_day_update.value();
+    let action_loading = action_day_update.pending();
+
+    let day_view = data
+        .days
+        .iter()
+        .map(|day| {
+            let id = day.id;
+            view! {
+                <div class="contents">
+                    <div class="p-2 border-b">{day.get_day_type().to_string()}</div>
+                    <div class="p-2 text-right border-b">
+                        {format!("{:.2}g", day.protein_per_kg)}
+                    </div>
+                    <div class="p-2 text-right border-b">
+                        {format!("{:.2}g", day.carbohydrate_per_kg)}
+                    </div>
+                    <div class="p-2 text-right border-b">{format!("{:.2}g", day.fat_per_kg)}</div>
+                    <div class="p-2 text-right border-b">
+                        <ActionForm action=action_day_delete>
+                            <input type="hidden" name="id" value=id.to_string()/>
+                            <button type="submit" class="hover:underline">
+                                "Remove"
+                            </button>
+                        </ActionForm>
+                    </div>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <section class="p-4 border">
+            <header class="flex justify-between items-center mb-2">
+                <h2 class="text-base font-bold">{data.template.name}</h2>
+                <ActionForm action=action_delete>
+                    <input type="hidden" name="id" value=template_id.to_string()/>
+                    <SubmitButton label="Delete Template"/>
+                </ActionForm>
+            </header>
+            <div class="grid grid-cols-5 mb-4">
+                <div class="p-2 font-bold border-b">"Day"</div>
+                <div class="p-2 font-bold text-right border-b">"Protein/kg"</div>
+                <div class="p-2 font-bold text-right border-b">"Carbs/kg"</div>
+                <div class="p-2 font-bold text-right border-b">"Fat/kg"</div>
+                <div class="p-2 border-b"></div>
+                {day_view}
+            </div>
+            <ActionForm action=action_day_update class="grid grid-cols-2 gap-2 md:grid-cols-5">
+                <input type="hidden" name="template_id" value=template_id.to_string()/>
+                <FieldSelect name="day_type" label="day" options=TemplateDay::to_form_options()/>
+                <NumberInput
+                    action_value
+                    step="0.01"
+                    name="protein_per_kg"
+                    label="Protein/kg"
+                    placeholder="2.50"
+                />
+                <NumberInput
+                    action_value
+                    step="0.01"
+                    name="carbohydrate_per_kg"
+                    label="Carbs/kg"
+                    placeholder="5.00"
+                />
+                <NumberInput
+                    action_value
+                    step="0.01"
+                    name="fat_per_kg"
+                    label="Fat/kg"
+                    placeholder="1.00"
+                />
+                <SubmitButton loading=action_loading label="Save Day"/>
+            </ActionForm>
+        </section>
+    }
+}
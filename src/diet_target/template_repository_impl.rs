@@ -0,0 +1,202 @@
+use chrono::prelude::*;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::{handle_sqlx_contraint_error, Result};
+use crate::util::server::normalize_whitespace;
+
+use super::model::{DietTargetTemplate, DietTargetTemplateDay};
+
+impl DietTargetTemplate {
+    const BASE_NAME: &'static str = "Diet target template";
+
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM diet_target_template WHERE id = $1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_username(pool: &PgPool, username: &str) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.*
+            FROM
+                diet_target_template t1
+                LEFT JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t2.username = $1
+            ORDER BY
+                t1.name
+            ",
+            username
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        name: &str,
+        request_user_id: Uuid,
+    ) -> Result<Self> {
+        let normalized_name = normalize_whitespace(name);
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                diet_target_template (user_id, name, created_by_id)
+            VALUES
+                ($1, $2, $3)
+            RETURNING
+                *
+            ",
+            user_id,
+            normalized_name,
+            request_user_id
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|err| {
+            handle_sqlx_contraint_error(err, Self::BASE_NAME, "name", &["user_id_name"])
+        })?;
+        Ok(query)
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
+            Self,
+            "DELETE FROM diet_target_template WHERE id = $1 RETURNING *",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+
+    pub async fn workout_dates(
+        pool: &PgPool,
+        user_id: Uuid,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<NaiveDate>> {
+        let query: Vec<NaiveDate> = sqlx::query_scalar(
+            "
+            SELECT DISTINCT
+                date
+            FROM
+                workout
+            WHERE
+                user_id = $1
+                AND date BETWEEN $2 AND $3
+            ",
+        )
+        .bind(user_id)
+        .bind(start)
+        .bind(end)
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+impl DietTargetTemplateDay {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM diet_target_template_day WHERE id = $1",
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_template_ids(pool: &PgPool, template_ids: &[Uuid]) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                *
+            FROM
+                diet_target_template_day
+            WHERE
+                template_id = ANY($1)
+            ORDER BY
+                day_type
+            ",
+            template_ids
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create_or_update(
+        pool: &PgPool,
+        template_id: Uuid,
+        day_type: i32,
+        protein_per_kg: Decimal,
+        carbohydrate_per_kg: Decimal,
+        fat_per_kg: Decimal,
+        request_user_id: Uuid,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                diet_target_template_day (
+                    template_id,
+                    day_type,
+                    protein_per_kg,
+                    carbohydrate_per_kg,
+                    fat_per_kg,
+                    created_by_id
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (template_id, day_type) DO UPDATE
+            SET
+                protein_per_kg = EXCLUDED.protein_per_kg,
+                carbohydrate_per_kg = EXCLUDED.carbohydrate_per_kg,
+                fat_per_kg = EXCLUDED.fat_per_kg,
+                updated_at = NOW(),
+                updated_by_id = EXCLUDED.created_by_id
+            RETURNING
+                *
+            ",
+            template_id,
+            day_type,
+            protein_per_kg,
+            carbohydrate_per_kg,
+            fat_per_kg,
+            request_user_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
+            Self,
+            "DELETE FROM diet_target_template_day WHERE id = $1 RETURNING *",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+}
@@ -38,7 +38,7 @@ pub async fn diet_target_update(
     let object = DietTarget::get_by_id(&pool, id)
         .await?
         .ok_or(Error::NotFound)?;
-    object.can_update(&user).await?;
+    object.can_update(&pool, &user).await?;
 
     let data = DietTargetGramKg {
         user_id: object.user_id,
@@ -50,7 +50,7 @@ pub async fn diet_target_update(
     };
     data.validate()?;
     let database_input = DietTargetInput::from(data);
-    DietTarget::update(&pool, object.id, database_input, user.id).await?;
+    DietTarget::update(&pool, object.id, database_input, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/users/{}/{}", username, date));
     Ok(())
@@ -3,7 +3,12 @@ use rust_decimal::Decimal;
 
 use crate::util::validation_error::ValidationError;
 
-use super::model::{DietTargetGramKg, DietTargetQuery};
+use super::meal_split::MealSplit;
+use super::model::{
+    DietMealTarget, DietMealTargetInput, DietTargetGramKg, DietTargetQuery, DietTargetTemplate,
+    DietTargetTemplateDay,
+};
+use super::template_day::TemplateDay;
 
 impl DietTargetGramKg {
     pub fn validate(&self) -> Result<(), ValidationError> {
@@ -94,3 +99,213 @@ impl DietTargetQuery {
         }
     }
 }
+
+impl DietTargetTemplate {
+    pub fn validate(name: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.validate_string("name", name, Some(1), Some(100), None);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Applying a template is limited to a quarter of a year at a time.
+    pub fn validate_apply(start: NaiveDate, end: NaiveDate) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        errors.validate_date("start", start, 365, 365);
+        errors.validate_date("end", end, 365, 365);
+        if end < start {
+            errors.add_error(
+                "end",
+                "End date must be on or after the start date.".to_string(),
+            );
+        } else if (end - start).num_days() > 92 {
+            errors.add_error(
+                "end",
+                "Templates can be applied to 93 days at most.".to_string(),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl DietTargetTemplateDay {
+    pub fn validate(
+        day_type: i32,
+        protein_per_kg: Decimal,
+        carbohydrate_per_kg: Decimal,
+        fat_per_kg: Decimal,
+    ) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        let min_per_kg = Decimal::from(0);
+        let max_per_kg = Decimal::from(10);
+
+        if matches!(TemplateDay::from(day_type), TemplateDay::Unknown(_)) {
+            errors.add_error("day_type", "Select a valid day.".to_string());
+        }
+        errors.validate_decimal(
+            "protein_per_kg",
+            protein_per_kg,
+            Some(min_per_kg),
+            Some(max_per_kg),
+        );
+        errors.validate_decimal(
+            "carbohydrate_per_kg",
+            carbohydrate_per_kg,
+            Some(min_per_kg),
+            Some(max_per_kg),
+        );
+        errors.validate_decimal("fat_per_kg", fat_per_kg, Some(min_per_kg), Some(max_per_kg));
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl DietMealTargetInput {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        let min_value = Decimal::from(0);
+        let max_value = match MealSplit::from(self.split_type) {
+            MealSplit::Percentage => Decimal::from(100),
+            MealSplit::Grams => Decimal::from(1000),
+            MealSplit::Unknown(_) => {
+                errors.add_error("split_type", "Select a valid split.".to_string());
+                Decimal::from(1000)
+            }
+        };
+        errors.validate_decimal("protein", self.protein, Some(min_value), Some(max_value));
+        errors.validate_decimal(
+            "carbohydrate",
+            self.carbohydrate,
+            Some(min_value),
+            Some(max_value),
+        );
+        errors.validate_decimal("fat", self.fat, Some(min_value), Some(max_value));
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Percentage targets across the user's meals cannot add up to more than the daily target.
+    /// The existing target for the same meal is replaced, so it is left out of the total.
+    pub fn validate_percentage_total(
+        &self,
+        existing: &[DietMealTarget],
+    ) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        if MealSplit::from(self.split_type) != MealSplit::Percentage {
+            return Ok(());
+        }
+        let others: Vec<&DietMealTarget> = existing
+            .iter()
+            .filter(|target| target.meal_of_day_id != self.meal_of_day_id)
+            .filter(|target| target.get_split_type() == MealSplit::Percentage)
+            .collect();
+        let fields = [
+            (
+                "protein",
+                self.protein,
+                others.iter().map(|t| t.protein).sum::<Decimal>(),
+            ),
+            (
+                "carbohydrate",
+                self.carbohydrate,
+                others.iter().map(|t| t.carbohydrate).sum::<Decimal>(),
+            ),
+            (
+                "fat",
+                self.fat,
+                others.iter().map(|t| t.fat).sum::<Decimal>(),
+            ),
+        ];
+        for (field, value, allocated) in fields {
+            if value + allocated > Decimal::from(100) {
+                errors.add_error(
+                    field,
+                    format!(
+                        "Meal targets cannot add up to more than 100%. {}% is already set for other meals.",
+                        allocated.normalize()
+                    ),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn meal_target(meal_of_day_id: Uuid, split: MealSplit, value: i64) -> DietMealTarget {
+        DietMealTarget {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            meal_of_day_id,
+            split_type: split.into(),
+            protein: Decimal::from(value),
+            carbohydrate: Decimal::from(value),
+            fat: Decimal::from(value),
+            created_at: Utc::now(),
+            updated_at: None,
+            created_by_id: Uuid::nil(),
+            updated_by_id: None,
+        }
+    }
+
+    fn input(meal_of_day_id: Uuid, value: i64) -> DietMealTargetInput {
+        DietMealTargetInput {
+            user_id: Uuid::nil(),
+            meal_of_day_id,
+            split_type: MealSplit::Percentage.into(),
+            protein: Decimal::from(value),
+            carbohydrate: Decimal::from(value),
+            fat: Decimal::from(value),
+        }
+    }
+
+    #[test]
+    fn test_validate_percentage_total() {
+        let breakfast = Uuid::from_u128(1);
+        let lunch = Uuid::from_u128(2);
+        let existing = vec![
+            meal_target(breakfast, MealSplit::Percentage, 60),
+            meal_target(Uuid::from_u128(3), MealSplit::Grams, 500),
+        ];
+        assert!(input(lunch, 40)
+            .validate_percentage_total(&existing)
+            .is_ok());
+        assert!(input(lunch, 41)
+            .validate_percentage_total(&existing)
+            .is_err());
+        // Updating breakfast replaces its own share.
+        assert!(input(breakfast, 100)
+            .validate_percentage_total(&existing)
+            .is_ok());
+    }
+}
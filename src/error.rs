@@ -19,6 +19,7 @@ pub enum Error {
     Other(String),
     InvalidToken,
     FileUpload,
+    TooManyRequests(String),
 }
 
 impl From<ValidationError> for Error {
@@ -48,6 +49,13 @@ impl From<http::header::InvalidHeaderValue> for Error {
     }
 }
 
+#[cfg(feature = "ssr")]
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Other(error.to_string())
+    }
+}
+
 #[cfg(feature = "ssr")]
 impl From<jsonwebtoken::errors::Error> for Error {
     fn from(error: jsonwebtoken::errors::Error) -> Self {
@@ -93,10 +101,114 @@ impl From<Error> for ServerFnError {
             Error::Other(msg) => ServerFnError::ServerError(msg),
             Error::InvalidToken => ServerFnError::new("Invalid token"),
             Error::FileUpload => ServerFnError::new("File upload error"),
+            Error::TooManyRequests(msg) => {
+                #[cfg(feature = "ssr")]
+                if let Some(res) = use_context::<leptos_axum::ResponseOptions>() {
+                    res.set_status(StatusCode::TOO_MANY_REQUESTS);
+                }
+                ServerFnError::ServerError(msg)
+            }
         }
     }
 }
 
+/// JSON error body returned by the `/api/v1` router.
+#[cfg(feature = "ssr")]
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct ErrorBody {
+    pub error: &'static str,
+    pub message: String,
+    /// Field errors, present when `error` is `validation`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub fields: Option<ValidationError>,
+}
+
+#[cfg(feature = "ssr")]
+impl Error {
+    pub fn into_status_and_body(self) -> (StatusCode, ErrorBody) {
+        let (status, error, message, fields) = match self {
+            Error::Validation(val_error) => (
+                StatusCode::BAD_REQUEST,
+                "validation",
+                "Validation failed".to_string(),
+                Some(val_error),
+            ),
+            Error::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "unauthorized",
+                "Unauthorized".to_string(),
+                None,
+            ),
+            Error::InvalidCredentials => (
+                StatusCode::UNAUTHORIZED,
+                "invalid_credentials",
+                "Invalid credentials".to_string(),
+                None,
+            ),
+            Error::InvalidToken => (
+                StatusCode::UNAUTHORIZED,
+                "invalid_token",
+                "Invalid token".to_string(),
+                None,
+            ),
+            Error::Forbidden => (
+                StatusCode::FORBIDDEN,
+                "forbidden",
+                "You do not have permission to perform this action".to_string(),
+                None,
+            ),
+            Error::NotFound => (
+                StatusCode::NOT_FOUND,
+                "not_found",
+                "Not found".to_string(),
+                None,
+            ),
+            Error::FileUpload => (
+                StatusCode::BAD_REQUEST,
+                "file_upload",
+                "File upload error".to_string(),
+                None,
+            ),
+            Error::TooManyRequests(msg) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "too_many_requests",
+                msg,
+                None,
+            ),
+            Error::Email(msg) | Error::Other(msg) => {
+                tracing::error!("api error: {}", msg);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal_server_error",
+                    "Internal server error".to_string(),
+                    None,
+                )
+            }
+            Error::InternalServer => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal_server_error",
+                "Internal server error".to_string(),
+                None,
+            ),
+        };
+        let body = ErrorBody {
+            error,
+            message,
+            fields,
+        };
+        (status, body)
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl axum::response::IntoResponse for Error {
+    fn into_response(self) -> axum::response::Response {
+        let (status, body) = self.into_status_and_body();
+        (status, axum::Json(body)).into_response()
+    }
+}
+
 #[cfg(feature = "ssr")]
 impl From<sqlx::Error> for Error {
     fn from(err: sqlx::Error) -> Self {
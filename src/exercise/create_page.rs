@@ -24,9 +24,9 @@ pub async fn exercise_create(
     let workout = WorkoutBase::get_by_id(&pool, workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_update(&pool, &user).await?;
 
-    ExerciseBase::create(&pool, workout.id, movement_id, user.id).await?;
+    ExerciseBase::create(&pool, workout.id, movement_id, 1, user.actor_id()).await?;
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
     }
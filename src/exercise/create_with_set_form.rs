@@ -11,7 +11,7 @@ use crate::component::input::SetInput;
 #[cfg(feature = "ssr")]
 use crate::{
     auth::service::get_request_user, error::Error, exercise::model::ExerciseBase,
-    set::model::SetModel, setup::get_pool, workout::model::WorkoutBase,
+    notification::service, set::model::SetModel, setup::get_pool, workout::model::WorkoutBase,
 };
 
 #[server(endpoint = "exercise-set-create")]
@@ -29,9 +29,19 @@ pub async fn exercise_set_create(
     let workout = WorkoutBase::get_by_id(&pool, workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
-    let exercise = ExerciseBase::create(&pool, workout.id, movement_id, user.id).await?;
-    SetModel::bulk_create(&pool, exercise.id, weight, reps, rest, set_count, user.id).await?;
+    workout.can_update(&pool, &user).await?;
+    let exercise = ExerciseBase::create(&pool, workout.id, movement_id, 1, user.actor_id()).await?;
+    SetModel::bulk_create(
+        &pool,
+        exercise.id,
+        weight,
+        reps,
+        rest,
+        set_count,
+        user.actor_id(),
+    )
+    .await?;
+    service::notify_personal_record(&pool, exercise.id).await;
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
     }
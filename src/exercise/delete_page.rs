@@ -30,9 +30,9 @@ pub async fn exercise_delete(
     let workout = WorkoutBase::get_by_id(&pool, exercise.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_delete(&user).await?;
+    workout.can_delete(&pool, &user).await?;
 
-    ExerciseBase::delete(&pool, exercise.id).await?;
+    ExerciseBase::delete(&pool, exercise.id, user.actor_id()).await?;
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
     }
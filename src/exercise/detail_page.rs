@@ -14,7 +14,7 @@ use crate::workout::router::ExerciseDetailParam;
 #[cfg(feature = "ssr")]
 use crate::{
     auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
-    workout::model::WorkoutBase,
+    user_privacy::section::PrivacySection, workout::model::WorkoutBase,
 };
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -34,7 +34,8 @@ pub async fn get_exercise_detail(exercise_id: Uuid) -> Result<ExerciseBase, Serv
     let workout = WorkoutBase::get_by_id(&pool, exercise.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    User::check_view_permission_by_user_id(&pool, &user, workout.user_id).await?;
+    User::check_view_permission_by_user_id(&pool, &user, workout.user_id, PrivacySection::Workout)
+        .await?;
 
     Ok(exercise)
 }
@@ -52,7 +53,9 @@ pub async fn get_exercise_detail_with_previous(
     let workout = WorkoutBase::get_by_id(&pool, current.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_view(&user).await?;
+    User::check_view_permission_by_user_id(&pool, &user, workout.user_id, PrivacySection::Workout)
+        .await?;
 
     let previous_exercise_id =
         ExerciseBase::try_get_previous_exercise_id(&pool, exercise_id).await?;
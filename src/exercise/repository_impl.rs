@@ -1,8 +1,9 @@
 use indexmap::IndexMap;
 use sqlx::postgres::PgRow;
-use sqlx::{FromRow, PgPool, Row};
+use sqlx::{FromRow, PgExecutor, PgPool, Row};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::exercise::model::{ExerciseBase, ExerciseQuery};
 use crate::set::model::SetQuery;
@@ -55,26 +56,28 @@ impl ExerciseBase {
     }
 
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         workout_id: Uuid,
         movement_id: Uuid,
+        order: i32,
         request_user_id: Uuid,
     ) -> Result<Self> {
         let query = sqlx::query_as!(
             Self,
-            "
+            r#"
             INSERT INTO
-                exercise (workout_id, movement_id, created_by_id)
+                exercise (workout_id, movement_id, "order", created_by_id)
             VALUES
-                ($1, $2, $3)
+                ($1, $2, $3, $4)
             RETURNING
                 *
-            ",
+            "#,
             workout_id,
             movement_id,
+            order,
             request_user_id,
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(query)
     }
@@ -109,10 +112,13 @@ impl ExerciseBase {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(Self, "DELETE FROM exercise WHERE id = $1 RETURNING *", id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
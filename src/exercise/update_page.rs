@@ -32,9 +32,9 @@ pub async fn exercise_update(
     let workout = WorkoutBase::get_by_id(&pool, exercise.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_update(&pool, &user).await?;
 
-    ExerciseBase::update(&pool, exercise.id, workout.id, movement_id, user.id).await?;
+    ExerciseBase::update(&pool, exercise.id, workout.id, movement_id, user.actor_id()).await?;
 
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
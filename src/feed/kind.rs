@@ -0,0 +1,48 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum FeedItemKind {
+    Workout,
+    PersonalRecord,
+    DietDay,
+    Progress,
+    Unknown(i32),
+}
+
+impl fmt::Display for FeedItemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeedItemKind::Workout => write!(f, "Workout"),
+            FeedItemKind::PersonalRecord => write!(f, "Personal Record"),
+            FeedItemKind::DietDay => write!(f, "Diet"),
+            FeedItemKind::Progress => write!(f, "Progress"),
+            FeedItemKind::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl From<i32> for FeedItemKind {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => FeedItemKind::Workout,
+            2 => FeedItemKind::PersonalRecord,
+            3 => FeedItemKind::DietDay,
+            4 => FeedItemKind::Progress,
+            _ => FeedItemKind::Unknown(value),
+        }
+    }
+}
+
+impl From<FeedItemKind> for i32 {
+    fn from(kind: FeedItemKind) -> Self {
+        match kind {
+            FeedItemKind::Workout => 1,
+            FeedItemKind::PersonalRecord => 2,
+            FeedItemKind::DietDay => 3,
+            FeedItemKind::Progress => 4,
+            FeedItemKind::Unknown(value) => value,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod kind;
+pub mod model;
+pub mod page;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
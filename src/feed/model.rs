@@ -0,0 +1,138 @@
+use chrono::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::kind::FeedItemKind;
+
+/// An entry in the activity feed. Only the fields relevant to the item's kind are set: the
+/// weight is the lifted weight of a personal record or the weight of a progress entry, and the
+/// count is the number of exercises in a workout or foods logged on a diet day. Diet days are
+/// only included once they meet the user's diet targets.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct FeedItem {
+    pub id: Uuid,
+    pub kind: i32,
+    pub user_id: Uuid,
+    pub username: String,
+    pub date: NaiveDate,
+    pub occurred_at: DateTime<Utc>,
+    pub workout_id: Option<Uuid>,
+    pub exercise_id: Option<Uuid>,
+    pub movement_name: Option<String>,
+    pub weight: Option<Decimal>,
+    pub reps: Option<i32>,
+    pub count: Option<i64>,
+}
+
+impl FeedItem {
+    pub fn get_kind(&self) -> FeedItemKind {
+        FeedItemKind::from(self.kind)
+    }
+
+    pub fn href(&self) -> String {
+        let base = format!("/users/{}", self.username);
+        match (self.get_kind(), self.workout_id, self.exercise_id) {
+            (FeedItemKind::PersonalRecord, Some(workout_id), Some(exercise_id)) => format!(
+                "{}/workouts/{}/{}/{}",
+                base, self.date, workout_id, exercise_id
+            ),
+            (FeedItemKind::Workout, Some(workout_id), _) => {
+                format!("{}/workouts/{}/{}", base, self.date, workout_id)
+            }
+            (FeedItemKind::DietDay, _, _) => format!("{}/diet/{}", base, self.date),
+            (FeedItemKind::Progress, _, _) => format!("{}/progress/{}", base, self.date),
+            _ => base,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let count = self.count.unwrap_or_default();
+        match self.get_kind() {
+            FeedItemKind::Workout => format!(
+                "Logged a workout with {} exercise{}",
+                count,
+                if count == 1 { "" } else { "s" }
+            ),
+            FeedItemKind::PersonalRecord => format!(
+                "New personal record on {}: {}kg x {}",
+                self.movement_name.as_deref().unwrap_or_default(),
+                self.weight.unwrap_or_default().normalize(),
+                self.reps.unwrap_or_default()
+            ),
+            FeedItemKind::DietDay => format!(
+                "Hit their diet targets with {} food{} logged",
+                count,
+                if count == 1 { "" } else { "s" }
+            ),
+            FeedItemKind::Progress => match self.weight {
+                Some(weight) => format!("Logged progress at {}kg", weight.normalize()),
+                None => "Logged progress".to_string(),
+            },
+            FeedItemKind::Unknown(_) => String::new(),
+        }
+    }
+
+    pub fn cursor(&self) -> FeedCursor {
+        FeedCursor {
+            occurred_at: self.occurred_at,
+            id: self.id,
+        }
+    }
+}
+
+/// The position of the last item on a page of the feed. The next page starts with the items
+/// that sort after it, so newly added items do not shift the pages being read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeedCursor {
+    pub occurred_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl FeedCursor {
+    pub fn to_param(self) -> String {
+        format!("{}_{}", self.occurred_at.timestamp_micros(), self.id)
+    }
+
+    pub fn from_param(param: &str) -> Option<Self> {
+        let (micros, id) = param.split_once('_')?;
+        let micros: i64 = micros.parse().ok()?;
+        let occurred_at = Utc
+            .timestamp_opt(
+                micros.div_euclid(1_000_000),
+                (micros.rem_euclid(1_000_000) * 1_000) as u32,
+            )
+            .single()?;
+        let id = Uuid::parse_str(id).ok()?;
+        Some(Self { occurred_at, id })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct FeedList {
+    pub results: Vec<FeedItem>,
+    pub next_cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_cursor_round_trip() {
+        let cursor = FeedCursor {
+            occurred_at: Utc.with_ymd_and_hms(2024, 7, 3, 10, 5, 0).unwrap()
+                + chrono::Duration::microseconds(123_456),
+            id: Uuid::from_u128(42),
+        };
+        assert_eq!(FeedCursor::from_param(&cursor.to_param()), Some(cursor));
+    }
+
+    #[test]
+    fn test_feed_cursor_invalid() {
+        assert_eq!(FeedCursor::from_param(""), None);
+        assert_eq!(FeedCursor::from_param("abc_def"), None);
+        assert_eq!(FeedCursor::from_param("1720001100000000"), None);
+    }
+}
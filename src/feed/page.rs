@@ -0,0 +1,114 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::template::{ErrorComponent, ListLoadingComponent, ListNotFoundComponent};
+use crate::util::datetime::format_datetime;
+use crate::util::param::extract_param;
+
+use super::model::{FeedItem, FeedList};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user, error::Error, feed::model::FeedCursor, setup::get_pool,
+};
+
+const FEED_PAGE_SIZE: i64 = 25;
+
+#[server(endpoint = "feed", input = GetUrl)]
+pub async fn get_feed(cursor: String) -> Result<FeedList, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let cursor = if cursor.is_empty() {
+        None
+    } else {
+        Some(FeedCursor::from_param(&cursor).ok_or(Error::NotFound)?)
+    };
+    let mut results =
+        FeedItem::all_by_follower_id(&pool, user.id, cursor, FEED_PAGE_SIZE + 1).await?;
+    let next_cursor = if results.len() as i64 > FEED_PAGE_SIZE {
+        results.truncate(FEED_PAGE_SIZE as usize);
+        results.last().map(|item| item.cursor().to_param())
+    } else {
+        None
+    };
+    Ok(FeedList {
+        results,
+        next_cursor,
+    })
+}
+
+#[component]
+pub fn FeedPage() -> impl IntoView {
+    let query = use_query_map();
+    let cursor = move || extract_param(&query, "cursor");
+    let resource = Resource::new(cursor, get_feed);
+
+    let response = move || {
+        resource.and_then(|data| {
+            let results = if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.results
+                    .iter()
+                    .map(|item| view! { <FeedListItem data=item.clone()/> })
+                    .collect_view()
+            };
+            let next = data.next_cursor.clone().map(|next_cursor| {
+                view! {
+                    <A href=format!("?cursor={}", next_cursor) class="hover:underline">
+                        "Older"
+                    </A>
+                }
+            });
+            view! {
+                {results}
+                <div class="flex gap-4 justify-end p-2">
+                    <Show when=move || !cursor().is_empty()>
+                        <A href="/feed" class="hover:underline">
+                            "Latest"
+                        </A>
+                    </Show>
+                    {next}
+                </div>
+            }
+        })
+    };
+
+    view! {
+        <Title text="Feed"/>
+        <main class="p-4">
+            <div class="p-4 bg-white border">
+                <h1 class="mb-2 text-base font-bold">"Feed"</h1>
+                <p class="mb-4">"Recent activity from the people you follow."</p>
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+pub fn FeedListItem(data: FeedItem) -> impl IntoView {
+    let href = data.href();
+    let summary = data.summary();
+    let kind = data.get_kind().to_string();
+    let occurred_at = format_datetime(&Some(data.occurred_at));
+    let user_href = format!("/users/{}", data.username);
+    view! {
+        <div class="flex flex-wrap gap-2 items-baseline py-2 border-b">
+            <A href=user_href class="font-bold hover:underline">
+                {data.username}
+            </A>
+            <span class="px-2 text-xs bg-gray-100 rounded">{kind}</span>
+            <A href=href class="flex-1 hover:underline">
+                {summary}
+            </A>
+            <span class="text-xs text-gray-500">{occurred_at}</span>
+        </div>
+    }
+}
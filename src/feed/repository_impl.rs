@@ -0,0 +1,333 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::adherence::model::DEFAULT_TOLERANCE_PCT;
+use crate::error::Result;
+
+use super::model::{FeedCursor, FeedItem};
+
+impl FeedItem {
+    /// How far back the feed goes, which keeps each page to a bounded range of each user's
+    /// history.
+    pub const FEED_DAYS: i32 = 90;
+
+    /// Recent workouts, personal records, diet days that met their targets and progress entries
+    /// of the users the user follows, newest first, starting after the cursor. Each kind is
+    /// paged on its own before they are merged, so no more than a page of each is read. Users
+    /// who have blocked the user or been blocked or muted by them are left out, as is any section
+    /// their privacy settings hide from followers.
+    pub async fn all_by_follower_id(
+        pool: &PgPool,
+        follower_id: Uuid,
+        cursor: Option<FeedCursor>,
+        size: i64,
+    ) -> Result<Vec<Self>> {
+        let occurred_at: Option<DateTime<Utc>> = cursor.map(|cursor| cursor.occurred_at);
+        let id: Option<Uuid> = cursor.map(|cursor| cursor.id);
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            WITH
+                following AS (
+                    SELECT
+                        t1.id,
+                        t1.username,
+                        COALESCE(t4.progress, t1.privacy_level) AS progress_level,
+                        COALESCE(t4.diet_log, t1.privacy_level) AS diet_log_level,
+                        COALESCE(t4.workout, t1.privacy_level) AS workout_level
+                    FROM
+                        user_follower t2
+                        JOIN users_user t1 ON t1.id = t2.user_id
+                        LEFT JOIN user_privacy_setting t4 ON t4.user_id = t1.id
+                    WHERE
+                        t2.follower_id = $1
+                        AND t2.status = 1
+                        AND t1.is_active
+                        AND NOT EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                user_block t3
+                            WHERE
+                                t3.blocked_status = 1
+                                AND (
+                                    t3.blocker_id = t1.id
+                                    AND t3.blocked_id = $1
+                                    OR t3.blocker_id = $1
+                                    AND t3.blocked_id = t1.id
+                                )
+                        )
+                        AND NOT EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                user_mute t5
+                            WHERE
+                                t5.muter_id = $1
+                                AND t5.muted_id = t1.id
+                        )
+                ),
+                workout_item AS (
+                    SELECT
+                        t1.id,
+                        1 AS kind,
+                        t1.user_id,
+                        t1.date,
+                        t1.created_at AS occurred_at,
+                        t1.id AS workout_id,
+                        NULL::UUID AS exercise_id,
+                        NULL::TEXT AS movement_name,
+                        NULL::NUMERIC AS weight,
+                        NULL::INT AS reps,
+                        (
+                            SELECT
+                                COUNT(*)
+                            FROM
+                                exercise
+                            WHERE
+                                workout_id = t1.id
+                        ) AS count
+                    FROM
+                        workout t1
+                        JOIN following t2 ON t2.id = t1.user_id
+                    WHERE
+                        t2.workout_level IN (0, 1, 2)
+                        AND t1.created_at >= NOW() - MAKE_INTERVAL(days => $5)
+                        AND (
+                            $2::TIMESTAMPTZ IS NULL
+                            OR (t1.created_at, t1.id) < ($2, $3)
+                        )
+                    ORDER BY
+                        t1.created_at DESC,
+                        t1.id DESC
+                    LIMIT
+                        $4
+                ),
+                record_item AS (
+                    SELECT
+                        t1.id,
+                        2 AS kind,
+                        t1.user_id,
+                        t1.date,
+                        t1.created_at AS occurred_at,
+                        t1.workout_id,
+                        t1.exercise_id,
+                        t3.name::TEXT AS movement_name,
+                        t1.weight::NUMERIC AS weight,
+                        t1.reps,
+                        NULL::BIGINT AS count
+                    FROM
+                        personal_record t1
+                        JOIN following t2 ON t2.id = t1.user_id
+                        JOIN movement t3 ON t3.id = t1.movement_id
+                    WHERE
+                        t2.workout_level IN (0, 1, 2)
+                        AND t1.created_at >= NOW() - MAKE_INTERVAL(days => $5)
+                        AND (
+                            $2::TIMESTAMPTZ IS NULL
+                            OR (t1.created_at, t1.id) < ($2, $3)
+                        )
+                    ORDER BY
+                        t1.created_at DESC,
+                        t1.id DESC
+                    LIMIT
+                        $4
+                ),
+                diet_item AS (
+                    SELECT
+                        t3.id,
+                        3 AS kind,
+                        t1.user_id,
+                        t1.date,
+                        t3.created_at AS occurred_at,
+                        NULL::UUID AS workout_id,
+                        NULL::UUID AS exercise_id,
+                        NULL::TEXT AS movement_name,
+                        NULL::NUMERIC AS weight,
+                        NULL::INT AS reps,
+                        t3.count
+                    FROM
+                        diet_adherence_day t1
+                        JOIN following t2 ON t2.id = t1.user_id
+                        CROSS JOIN LATERAL (
+                            SELECT
+                                id,
+                                created_at,
+                                COUNT(*) OVER () AS count
+                            FROM
+                                food_log
+                            WHERE
+                                user_id = t1.user_id
+                                AND date = t1.date
+                            ORDER BY
+                                created_at DESC,
+                                id DESC
+                            LIMIT
+                                1
+                        ) t3
+                    WHERE
+                        t2.diet_log_level IN (0, 1, 2)
+                        AND t1.date >= CURRENT_DATE - $5
+                        AND diet_day_is_adherent (t1, $6)
+                        AND (
+                            $2::TIMESTAMPTZ IS NULL
+                            OR (t3.created_at, t3.id) < ($2, $3)
+                        )
+                    ORDER BY
+                        t3.created_at DESC,
+                        t3.id DESC
+                    LIMIT
+                        $4
+                ),
+                progress_item AS (
+                    SELECT
+                        t1.id,
+                        4 AS kind,
+                        t1.user_id,
+                        t1.date,
+                        t1.created_at AS occurred_at,
+                        NULL::UUID AS workout_id,
+                        NULL::UUID AS exercise_id,
+                        NULL::TEXT AS movement_name,
+                        t1.weight_kg::NUMERIC AS weight,
+                        NULL::INT AS reps,
+                        NULL::BIGINT AS count
+                    FROM
+                        progress t1
+                        JOIN following t2 ON t2.id = t1.user_id
+                    WHERE
+                        t2.progress_level IN (0, 1, 2)
+                        AND t1.created_at >= NOW() - MAKE_INTERVAL(days => $5)
+                        AND (
+                            $2::TIMESTAMPTZ IS NULL
+                            OR (t1.created_at, t1.id) < ($2, $3)
+                        )
+                    ORDER BY
+                        t1.created_at DESC,
+                        t1.id DESC
+                    LIMIT
+                        $4
+                ),
+                feed AS (
+                    SELECT
+                        *
+                    FROM
+                        workout_item
+                    UNION ALL
+                    SELECT
+                        *
+                    FROM
+                        record_item
+                    UNION ALL
+                    SELECT
+                        *
+                    FROM
+                        diet_item
+                    UNION ALL
+                    SELECT
+                        *
+                    FROM
+                        progress_item
+                )
+            SELECT
+                t1.id AS "id!",
+                t1.kind AS "kind!",
+                t1.user_id AS "user_id!",
+                t2.username AS "username!",
+                t1.date AS "date!",
+                t1.occurred_at AS "occurred_at!",
+                t1.workout_id,
+                t1.exercise_id,
+                t1.movement_name,
+                t1.weight,
+                t1.reps,
+                t1.count
+            FROM
+                feed t1
+                JOIN following t2 ON t2.id = t1.user_id
+            ORDER BY
+                t1.occurred_at DESC,
+                t1.id DESC
+            LIMIT
+                $4
+            "#,
+            follower_id,
+            occurred_at,
+            id,
+            size,
+            Self::FEED_DAYS,
+            DEFAULT_TOLERANCE_PCT,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::util::test_fixture::{
+        create_diet_target, create_follower, create_food, create_food_log, create_user,
+    };
+
+    use super::*;
+    use crate::feed::kind::FeedItemKind;
+
+    #[sqlx::test]
+    async fn test_feed_pages_and_only_includes_completed_diet_days(pool: PgPool) {
+        let leader_id = create_user(&pool, "leader").await;
+        let follower_id = create_user(&pool, "follower").await;
+        create_follower(&pool, leader_id, follower_id).await;
+        create_diet_target(&pool, leader_id).await;
+        let food_id = create_food(&pool, leader_id).await;
+
+        let today = Utc::now().date_naive();
+        create_food_log(&pool, leader_id, food_id, today, 20).await;
+        create_food_log(&pool, leader_id, food_id, today - Duration::days(1), 5).await;
+        for days_ago in [2, 3] {
+            sqlx::query(
+                "INSERT INTO progress (user_id, date, weight_kg, created_by_id) VALUES ($1, $2, 80, $1)",
+            )
+            .bind(leader_id)
+            .bind(today - Duration::days(days_ago))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let first = FeedItem::all_by_follower_id(&pool, follower_id, None, 2)
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 2);
+        let cursor = first.last().map(FeedItem::cursor);
+        let second = FeedItem::all_by_follower_id(&pool, follower_id, cursor, 2)
+            .await
+            .unwrap();
+        assert_eq!(second.len(), 1);
+
+        let items: Vec<FeedItem> = first.into_iter().chain(second).collect();
+        let diet_days: Vec<&FeedItem> = items
+            .iter()
+            .filter(|item| item.get_kind() == FeedItemKind::DietDay)
+            .collect();
+        assert_eq!(diet_days.len(), 1);
+        assert_eq!(diet_days[0].date, today);
+        assert_eq!(diet_days[0].count, Some(1));
+        assert_eq!(
+            items
+                .iter()
+                .filter(|item| item.get_kind() == FeedItemKind::Progress)
+                .count(),
+            2
+        );
+
+        let unrelated_id = create_user(&pool, "unrelated").await;
+        let feed = FeedItem::all_by_follower_id(&pool, unrelated_id, None, 10)
+            .await
+            .unwrap();
+        assert!(feed.is_empty());
+    }
+}
@@ -6,7 +6,10 @@ use crate::component::icon::{IconUserMinus, IconUserPlus};
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::service::get_request_user, follower::model::Follower, setup::get_pool};
+use crate::{
+    auth::service::get_request_user, follower::model::Follower, notification::service,
+    setup::get_pool,
+};
 
 #[server(endpoint = "follower-request")]
 pub async fn follower_request(username: String) -> Result<(), ServerFnError> {
@@ -15,7 +18,9 @@ pub async fn follower_request(username: String) -> Result<(), ServerFnError> {
     if user.username == username {
         return Err(ServerFnError::new("You cannot follow yourself"));
     }
-    Follower::request(&pool, &username, &user.username).await?;
+    if Follower::request(&pool, &username, &user.username).await? > 0 {
+        service::notify_follow_request(&pool, &user, &username).await;
+    }
     Ok(())
 }
 
@@ -26,7 +31,9 @@ pub async fn follower_accept(username: String) -> Result<(), ServerFnError> {
     if user.username == username {
         return Err(ServerFnError::new("You cannot follow yourself"));
     }
-    Follower::accept(&pool, &username, &user.username).await?;
+    if Follower::accept(&pool, &username, user.actor_id()).await? > 0 {
+        service::notify_follow_accept(&pool, &user, &username).await;
+    }
     Ok(())
 }
 
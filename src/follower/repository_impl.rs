@@ -2,6 +2,7 @@ use sqlx::postgres::PgRow;
 use sqlx::{FromRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::follower::model::Follower;
 use crate::follower::status::FollowerStatus;
@@ -52,7 +53,10 @@ impl Follower {
         user_id: Uuid,
         follower_id: Uuid,
         status: i32,
-    ) -> sqlx::Result<u64> {
+        request_user_id: Uuid,
+    ) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query(
             "
             INSERT INTO
@@ -64,9 +68,10 @@ impl Follower {
         .bind(user_id)
         .bind(follower_id)
         .bind(status)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
+        tx.commit().await?;
         Ok(query)
     }
 
@@ -95,7 +100,10 @@ impl Follower {
         Ok(query)
     }
 
-    pub async fn accept(pool: &PgPool, username: &str, request_user: &str) -> sqlx::Result<u64> {
+    /// Accepts the follow request of `username`, made to the request user.
+    pub async fn accept(pool: &PgPool, username: &str, request_user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query(
             "
             UPDATE user_follower
@@ -103,20 +111,19 @@ impl Follower {
                 status = 1,
                 updated_at = NOW()
             FROM
-                users_user t2,
                 users_user t3
             WHERE
-                user_follower.user_id = t2.id
+                user_follower.user_id = $1
                 AND user_follower.follower_id = t3.id
-                AND t2.username = $1
                 AND t3.username = $2
             ",
         )
-        .bind(request_user)
+        .bind(request_user_id)
         .bind(username)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
+        tx.commit().await?;
         Ok(query)
     }
 
@@ -166,7 +173,10 @@ impl Follower {
         user_id: Uuid,
         follower_id: Uuid,
         status: i32,
-    ) -> sqlx::Result<u64> {
+        request_user_id: Uuid,
+    ) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query(
             "
             UPDATE user_follower
@@ -183,9 +193,10 @@ impl Follower {
         .bind(follower_id)
         .bind(status)
         .bind(id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
+        tx.commit().await?;
         Ok(query)
     }
 
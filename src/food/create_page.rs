@@ -72,7 +72,7 @@ pub async fn food_create(
         fibre,
         protein,
         salt,
-        user.id,
+        user.actor_id(),
     )
     .await?;
 
@@ -21,7 +21,7 @@ pub async fn food_delete(slug: String) -> Result<(), ServerFnError> {
         .ok_or(Error::NotFound)?;
     food.can_delete(&user).await?;
 
-    Food::delete(&pool, food.id).await?;
+    Food::delete(&pool, food.id, user.actor_id()).await?;
 
     leptos_axum::redirect("/food");
     Ok(())
@@ -34,6 +34,7 @@ pub struct Food {
     pub data_value_numeric: Decimal,
 }
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct FoodQuery {
     pub id: Uuid,
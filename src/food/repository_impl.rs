@@ -3,6 +3,7 @@ use sqlx::postgres::PgRow;
 use sqlx::{FromRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::util::database::Filter;
 use crate::util::server::{normalize_whitespace, slugify};
@@ -222,10 +223,13 @@ impl Food {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(Self, "DELETE FROM food WHERE id = $1 RETURNING *", id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(query)
     }
 }
@@ -26,7 +26,7 @@ pub async fn food_to_diet_create(
     let user = get_request_user()?;
     let pool = get_pool()?;
 
-    Diet::can_create(&user, user.id)?;
+    Diet::can_create(&pool, &user, user.id).await?;
     Diet::validate(date, quantity)?;
 
     let food = FoodQuery::get_by_id(&pool, food_id)
@@ -41,7 +41,7 @@ pub async fn food_to_diet_create(
         meal_of_day_id,
         food.id,
         quantity,
-        user.id,
+        user.actor_id(),
     )
     .await?;
 
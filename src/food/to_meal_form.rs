@@ -26,9 +26,9 @@ pub async fn food_to_meal_create(
     let meal = Meal::get_by_id(&pool, meal_id)
         .await?
         .ok_or(Error::NotFound)?;
-    meal.can_update(&user).await?;
+    meal.can_update(&pool, &user).await?;
     MealFood::validate(quantity)?;
-    MealFood::create_and_return_meal_id(&pool, meal.id, food_id, quantity, user.id).await?;
+    MealFood::create_and_return_meal_id(&pool, meal.id, food_id, quantity, user.actor_id()).await?;
     leptos_axum::redirect(&format!("/food/meals/{}", meal.id));
     Ok(())
 }
@@ -78,7 +78,7 @@ pub async fn food_update(
         fibre,
         protein,
         salt,
-        user.id,
+        user.actor_id(),
     )
     .await?;
 
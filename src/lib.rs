@@ -1,32 +1,54 @@
+mod account_deletion;
+mod adherence;
 mod admin;
+mod api_token;
 pub mod app;
+mod audit_log;
 mod auth;
 mod brand;
+mod challenge;
 mod chart;
+mod coach;
 mod component;
+mod data_export;
+mod data_import;
 mod diet;
 mod diet_target;
 mod error;
 mod exercise;
+mod feed;
 mod follower;
 mod food;
+mod login_throttle;
 mod meal;
 mod meal_food;
 mod meal_of_day;
 mod movement;
 mod muscle_group;
+mod notification;
+mod oidc;
 mod profile;
 mod progress;
+mod progress_photo;
 mod set;
+mod shared_template;
+mod social;
 mod summary;
+mod two_factor;
 mod user;
 mod user_block;
+mod user_mute;
+mod user_privacy;
+mod user_report;
+mod user_session;
 mod user_setting;
 mod user_statistic;
 mod util;
 mod web;
 mod workout;
 
+#[cfg(feature = "ssr")]
+pub mod api;
 #[cfg(feature = "ssr")]
 pub mod config;
 #[cfg(feature = "ssr")]
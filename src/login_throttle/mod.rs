@@ -0,0 +1,6 @@
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod scope;
+#[cfg(feature = "ssr")]
+pub mod service;
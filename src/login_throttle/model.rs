@@ -0,0 +1,192 @@
+use chrono::prelude::*;
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::scope::ThrottleScope;
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LoginThrottle {
+    pub id: Uuid,
+    pub scope: i32,
+    pub key: String,
+    pub user_id: Option<Uuid>,
+    pub failure_count: i32,
+    pub last_failure_at: DateTime<Utc>,
+    pub blocked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginThrottle {
+    pub fn get_scope(&self) -> ThrottleScope {
+        ThrottleScope::from(self.scope)
+    }
+
+    pub fn is_blocked(&self, now: DateTime<Utc>) -> bool {
+        self.blocked_until.is_some_and(|until| until > now)
+    }
+
+    pub fn is_locked_out(&self, now: DateTime<Utc>) -> bool {
+        self.is_blocked(now) && self.failure_count >= self.get_scope().policy().lockout_threshold
+    }
+
+    /// The message shown while the key is blocked.
+    pub fn retry_message(&self, now: DateTime<Utc>) -> String {
+        let seconds = self
+            .blocked_until
+            .map(|until| (until - now).num_seconds())
+            .unwrap_or_default();
+        format!(
+            "Too many attempts. Please try again in {}.",
+            format_wait(seconds)
+        )
+    }
+}
+
+/// How repeated failures for a scope are slowed down and eventually locked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThrottlePolicy {
+    /// Failures allowed before any delay is applied.
+    pub free_attempts: i32,
+    /// Delay after the first failure past the free attempts, doubled for each failure after it.
+    pub base_delay_seconds: i64,
+    pub max_delay_seconds: i64,
+    /// Failures after which the key is locked out rather than delayed.
+    pub lockout_threshold: i32,
+    pub lockout_seconds: i64,
+    /// Failures older than this no longer count towards the total.
+    pub window_seconds: i64,
+}
+
+impl ThrottleScope {
+    pub fn policy(&self) -> ThrottlePolicy {
+        match self {
+            ThrottleScope::LoginIp => ThrottlePolicy {
+                free_attempts: 20,
+                base_delay_seconds: 1,
+                max_delay_seconds: 300,
+                lockout_threshold: 100,
+                lockout_seconds: 3600,
+                window_seconds: 3600,
+            },
+            ThrottleScope::LoginAccount | ThrottleScope::Unknown(_) => ThrottlePolicy {
+                free_attempts: 3,
+                base_delay_seconds: 2,
+                max_delay_seconds: 300,
+                lockout_threshold: 10,
+                lockout_seconds: 1800,
+                window_seconds: 3600,
+            },
+            ThrottleScope::PasswordResetIp => ThrottlePolicy {
+                free_attempts: 5,
+                base_delay_seconds: 10,
+                max_delay_seconds: 600,
+                lockout_threshold: 20,
+                lockout_seconds: 3600,
+                window_seconds: 3600,
+            },
+            ThrottleScope::PasswordResetAccount => ThrottlePolicy {
+                free_attempts: 2,
+                base_delay_seconds: 60,
+                max_delay_seconds: 900,
+                lockout_threshold: 5,
+                lockout_seconds: 3600,
+                window_seconds: 3600,
+            },
+        }
+    }
+}
+
+impl ThrottlePolicy {
+    /// Seconds to wait before the next attempt, if any, after the given number of failures.
+    pub fn delay_seconds(&self, failure_count: i32) -> Option<i64> {
+        if failure_count >= self.lockout_threshold {
+            return Some(self.lockout_seconds);
+        }
+        let over = failure_count - self.free_attempts;
+        if over <= 0 {
+            return None;
+        }
+        let exponent = (over - 1).min(30) as u32;
+        let delay = self
+            .base_delay_seconds
+            .saturating_mul(2_i64.pow(exponent))
+            .min(self.max_delay_seconds);
+        Some(delay)
+    }
+
+    pub fn blocked_until(&self, failure_count: i32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.delay_seconds(failure_count)
+            .map(|seconds| now + TimeDelta::seconds(seconds))
+    }
+
+    /// Whether this failure is the one that locked the key out.
+    pub fn is_lockout(&self, failure_count: i32) -> bool {
+        failure_count == self.lockout_threshold
+    }
+}
+
+pub fn format_wait(seconds: i64) -> String {
+    let seconds = seconds.max(1);
+    if seconds < 60 {
+        let unit = if seconds == 1 { "second" } else { "seconds" };
+        format!("{} {}", seconds, unit)
+    } else {
+        let minutes = (seconds + 59) / 60;
+        let unit = if minutes == 1 { "minute" } else { "minutes" };
+        format!("{} {}", minutes, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> ThrottlePolicy {
+        ThrottlePolicy {
+            free_attempts: 3,
+            base_delay_seconds: 2,
+            max_delay_seconds: 30,
+            lockout_threshold: 10,
+            lockout_seconds: 1800,
+            window_seconds: 3600,
+        }
+    }
+
+    #[test]
+    fn test_delay_seconds_backs_off_exponentially() {
+        let policy = policy();
+        let delays: Vec<Option<i64>> = (1..=10).map(|n| policy.delay_seconds(n)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                None,
+                None,
+                None,
+                Some(2),
+                Some(4),
+                Some(8),
+                Some(16),
+                Some(30),
+                Some(30),
+                Some(1800),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_lockout_only_at_threshold() {
+        let policy = policy();
+        assert!(!policy.is_lockout(9));
+        assert!(policy.is_lockout(10));
+        assert!(!policy.is_lockout(11));
+    }
+
+    #[test]
+    fn test_format_wait() {
+        assert_eq!(format_wait(0), "1 second");
+        assert_eq!(format_wait(45), "45 seconds");
+        assert_eq!(format_wait(60), "1 minute");
+        assert_eq!(format_wait(61), "2 minutes");
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+use super::model::LoginThrottle;
+use super::scope::ThrottleScope;
+
+impl LoginThrottle {
+    pub async fn get(pool: &PgPool, scope: ThrottleScope, key: &str) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM login_throttle WHERE scope = $1 AND key = $2",
+            i32::from(scope),
+            key,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn all_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM login_throttle WHERE user_id = $1 ORDER BY scope",
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Counts a failed attempt, starting the count again when the previous failure was before
+    /// `window_start`.
+    pub async fn increment(
+        pool: &PgPool,
+        scope: ThrottleScope,
+        key: &str,
+        user_id: Option<Uuid>,
+        window_start: DateTime<Utc>,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                login_throttle (scope, key, user_id, failure_count)
+            VALUES
+                ($1, $2, $3, 1)
+            ON CONFLICT (scope, key) DO UPDATE
+            SET
+                failure_count = CASE
+                    WHEN login_throttle.last_failure_at > $4 THEN login_throttle.failure_count + 1
+                    ELSE 1
+                END,
+                user_id = COALESCE(EXCLUDED.user_id, login_throttle.user_id),
+                last_failure_at = NOW()
+            RETURNING
+                *
+            ",
+            i32::from(scope),
+            key,
+            user_id,
+            window_start,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn update_blocked_until(
+        pool: &PgPool,
+        id: Uuid,
+        blocked_until: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "UPDATE login_throttle SET blocked_until = $2 WHERE id = $1 RETURNING *",
+            id,
+            blocked_until,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn delete(pool: &PgPool, scope: ThrottleScope, key: &str) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM login_throttle WHERE scope = $1 AND key = $2",
+            i32::from(scope),
+            key,
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Clears every throttle tied to the user's account. IP address throttles are left in place.
+    pub async fn delete_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<u64> {
+        let query = sqlx::query!("DELETE FROM login_throttle WHERE user_id = $1", user_id)
+            .execute(pool)
+            .await?;
+        Ok(query.rows_affected())
+    }
+
+    /// Removes throttles that are no longer blocking and whose last failure is before
+    /// `window_start`.
+    pub async fn delete_expired(pool: &PgPool, window_start: DateTime<Utc>) -> Result<u64> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM login_throttle
+            WHERE
+                last_failure_at < $1
+                AND (
+                    blocked_until IS NULL
+                    OR blocked_until < NOW()
+                )
+            ",
+            window_start,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+}
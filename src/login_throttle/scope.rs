@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleScope {
+    LoginIp,
+    LoginAccount,
+    PasswordResetIp,
+    PasswordResetAccount,
+    Unknown(i32),
+}
+
+impl fmt::Display for ThrottleScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThrottleScope::LoginIp => write!(f, "Log in (IP address)"),
+            ThrottleScope::LoginAccount => write!(f, "Log in (account)"),
+            ThrottleScope::PasswordResetIp => write!(f, "Password reset (IP address)"),
+            ThrottleScope::PasswordResetAccount => write!(f, "Password reset (account)"),
+            ThrottleScope::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl From<i32> for ThrottleScope {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ThrottleScope::LoginIp,
+            2 => ThrottleScope::LoginAccount,
+            3 => ThrottleScope::PasswordResetIp,
+            4 => ThrottleScope::PasswordResetAccount,
+            _ => ThrottleScope::Unknown(value),
+        }
+    }
+}
+
+impl From<ThrottleScope> for i32 {
+    fn from(scope: ThrottleScope) -> Self {
+        match scope {
+            ThrottleScope::LoginIp => 1,
+            ThrottleScope::LoginAccount => 2,
+            ThrottleScope::PasswordResetIp => 3,
+            ThrottleScope::PasswordResetAccount => 4,
+            ThrottleScope::Unknown(value) => value,
+        }
+    }
+}
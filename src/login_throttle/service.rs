@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use chrono::prelude::*;
+use chrono::TimeDelta;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::email_service::EmailService;
+use crate::auth::model::User;
+use crate::error::{Error, Result};
+
+use super::model::{format_wait, LoginThrottle};
+use super::scope::ThrottleScope;
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+impl LoginThrottle {
+    /// Throttles for an account are keyed on the email address entered, so that attempts against
+    /// addresses without an account are slowed down in the same way.
+    pub fn account_key(email: &str) -> String {
+        email.trim().to_lowercase()
+    }
+
+    /// Returns an error while the key is blocked by an earlier failure.
+    pub async fn ensure_allowed(pool: &PgPool, scope: ThrottleScope, key: &str) -> Result<()> {
+        let now = Utc::now();
+        match Self::get(pool, scope, key).await? {
+            Some(throttle) if throttle.is_blocked(now) => {
+                Err(Error::TooManyRequests(throttle.retry_message(now)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Counts a failed attempt and blocks the key for as long as the scope's policy requires.
+    pub async fn record_failure(
+        pool: &PgPool,
+        scope: ThrottleScope,
+        key: &str,
+        user_id: Option<Uuid>,
+    ) -> Result<Self> {
+        let policy = scope.policy();
+        let now = Utc::now();
+        let window_start = now - TimeDelta::seconds(policy.window_seconds);
+        let throttle = Self::increment(pool, scope, key, user_id, window_start).await?;
+        match policy.blocked_until(throttle.failure_count, now) {
+            Some(blocked_until) => {
+                Self::update_blocked_until(pool, throttle.id, Some(blocked_until)).await
+            }
+            None => Ok(throttle),
+        }
+    }
+
+    pub async fn ensure_login_allowed(pool: &PgPool, ip: &str, email: &str) -> Result<()> {
+        Self::ensure_allowed(pool, ThrottleScope::LoginIp, ip).await?;
+        Self::ensure_allowed(pool, ThrottleScope::LoginAccount, &Self::account_key(email)).await
+    }
+
+    /// Records a failed log in against the client address and the account, emailing the account
+    /// owner when the failure locks the account.
+    pub async fn record_login_failure(
+        pool: &PgPool,
+        ip: &str,
+        email: &str,
+        user: Option<&User>,
+    ) -> Result<()> {
+        Self::record_failure(pool, ThrottleScope::LoginIp, ip, None).await?;
+        let throttle = Self::record_failure(
+            pool,
+            ThrottleScope::LoginAccount,
+            &Self::account_key(email),
+            user.map(|user| user.id),
+        )
+        .await?;
+
+        let policy = ThrottleScope::LoginAccount.policy();
+        if let Some(user) = user.filter(|_| policy.is_lockout(throttle.failure_count)) {
+            tracing::warn!("Account locked after failed log in attempts: {}", user.id);
+            let duration = format_wait(policy.lockout_seconds);
+            if let Err(err) =
+                EmailService::send_account_lockout_email(&user.name, &user.email, &duration).await
+            {
+                tracing::error!("account lockout email failed for {}: {:?}", user.id, err);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn clear_login(pool: &PgPool, email: &str) -> Result<()> {
+        Self::delete(pool, ThrottleScope::LoginAccount, &Self::account_key(email)).await
+    }
+
+    /// Password reset requests are counted whether or not they succeed, as each one sends an
+    /// email.
+    pub async fn record_password_reset(
+        pool: &PgPool,
+        ip: &str,
+        email: &str,
+        user_id: Option<Uuid>,
+    ) -> Result<()> {
+        let key = Self::account_key(email);
+        Self::ensure_allowed(pool, ThrottleScope::PasswordResetIp, ip).await?;
+        Self::ensure_allowed(pool, ThrottleScope::PasswordResetAccount, &key).await?;
+        Self::record_failure(pool, ThrottleScope::PasswordResetIp, ip, None).await?;
+        Self::record_failure(pool, ThrottleScope::PasswordResetAccount, &key, user_id).await?;
+        Ok(())
+    }
+}
+
+/// Periodically removes throttles that have expired.
+pub async fn run_login_throttle_sweeper(pool: PgPool) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        let window_start = Utc::now() - TimeDelta::days(1);
+        if let Err(err) = LoginThrottle::delete_expired(&pool, window_start).await {
+            tracing::error!("login throttle sweep failed: {:?}", err);
+        }
+    }
+}
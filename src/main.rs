@@ -3,7 +3,7 @@
 async fn main() {
     use trackedfitness::config::get_config;
     use trackedfitness::setup::{
-        initialize_db_pool, load_leptos_config, setup_router, start_server,
+        initialize_db_pool, load_leptos_config, setup_router, spawn_background_tasks, start_server,
     };
 
     tracing_subscriber::fmt().with_thread_ids(true).init();
@@ -12,6 +12,8 @@ async fn main() {
 
     let pool = initialize_db_pool(&config.database_url).await;
 
+    spawn_background_tasks(&pool);
+
     let leptos_options = load_leptos_config().await;
 
     let app = setup_router(pool, leptos_options.clone()).await;
@@ -38,13 +38,14 @@ pub async fn meal_add_food(
     let object = Meal::get_by_id(&pool, meal_id)
         .await?
         .ok_or(Error::NotFound)?;
-    object.can_update(&user).await?;
+    object.can_update(&pool, &user).await?;
     let food = FoodQuery::get_by_id(&pool, food_id)
         .await?
         .ok_or(Error::NotFound)?;
     let quantity = food.data_measurement.to_quantity_modifier(&quantity);
     MealFood::validate(quantity)?;
-    MealFood::create_and_return_meal_id(&pool, object.id, food_id, quantity, user.id).await?;
+    MealFood::create_and_return_meal_id(&pool, object.id, food_id, quantity, user.actor_id())
+        .await?;
     Ok(())
 }
 
@@ -14,10 +14,10 @@ pub async fn meal_create(name: String) -> Result<(), ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
 
-    Meal::can_create(&user).await?;
+    Meal::can_create(&pool, &user, user.id).await?;
     Meal::validate(&name)?;
 
-    let object = Meal::create(&pool, user.id, &name, user.id).await?;
+    let object = Meal::create(&pool, user.id, &name, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/food/meals/{}", object.id));
     Ok(())
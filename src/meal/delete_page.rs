@@ -18,9 +18,9 @@ pub async fn meal_delete(id: Uuid) -> Result<(), ServerFnError> {
     let pool = get_pool()?;
 
     let object = Meal::get_by_id(&pool, id).await?.ok_or(Error::NotFound)?;
-    object.can_delete(&user).await?;
+    object.can_delete(&pool, &user).await?;
 
-    Meal::delete(&pool, id).await?;
+    Meal::delete(&pool, id, user.actor_id()).await?;
 
     leptos_axum::redirect("/food/meals");
     Ok(())
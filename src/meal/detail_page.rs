@@ -18,6 +18,8 @@ use crate::component::template::{
 use crate::food::nutrition_row::NutritionRow;
 use crate::meal::add_food_page::MealAddFood;
 use crate::meal_food::model::MealFood;
+use crate::shared_template::component::SharedTemplatePanel;
+use crate::shared_template::kind::TemplateKind;
 use crate::util::param::UuidParam;
 
 #[cfg(feature = "ssr")]
@@ -90,6 +92,13 @@ pub fn MealDetailPage() -> impl IntoView {
             }
         })
     };
+    let share_response = move || {
+        resource.and_then(|data| {
+            view! {
+                <SharedTemplatePanel kind=TemplateKind::Meal source_id=data.id name=data.name.clone()/>
+            }
+        })
+    };
     let meal_food_response = move || {
         meal_food_resource.and_then(|results| {
             if results.is_empty() {
@@ -147,6 +156,7 @@ pub fn MealDetailPage() -> impl IntoView {
                     <Transition>{meal_total_response}</Transition>
                 </section>
                 <BulkDeleteForm table="meal_food" action=action_bulk_delete checked_items/>
+                <Transition>{share_response}</Transition>
             </section>
 
             <section class="p-4 bg-white border">
@@ -1,4 +1,8 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
 use crate::auth::model::RequestUser;
+use crate::coach::model::CoachClient;
 use crate::error::{Error, Result};
 
 use super::model::{Meal, MealQuery};
@@ -12,28 +16,20 @@ impl Meal {
         }
     }
 
-    pub async fn can_create(user: &RequestUser) -> Result<()> {
-        if user.is_active {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_create(
+        pool: &PgPool,
+        request_user: &RequestUser,
+        owner_id: Uuid,
+    ) -> Result<()> {
+        CoachClient::authorize(pool, request_user, owner_id).await
     }
 
-    pub async fn can_update(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_update(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 
-    pub async fn can_delete(&self, request_user: &RequestUser) -> Result<()> {
-        if self.user_id == request_user.id || request_user.is_superuser {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_delete(&self, pool: &PgPool, request_user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, request_user, self.user_id).await
     }
 }
 
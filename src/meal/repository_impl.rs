@@ -1,9 +1,10 @@
 use sqlx::postgres::PgRow;
-use sqlx::{FromRow, PgPool, Row};
+use sqlx::{FromRow, PgExecutor, PgPool, Row};
 
 use uuid::Uuid;
 
 use super::model::MealQuery;
+use crate::audit_log::model::AuditLog;
 use crate::component::select::SelectUuidName;
 use crate::error::{handle_sqlx_contraint_error, Result};
 use crate::food::model::Nutrition;
@@ -40,7 +41,7 @@ impl Meal {
     }
 
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         user_id: Uuid,
         name: &str,
         created_by_id: Uuid,
@@ -57,7 +58,7 @@ impl Meal {
             normalised_name,
             created_by_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
         .map_err(|err| {
             handle_sqlx_contraint_error(err, Self::BASE_NAME, "name", &["slug_key", "name_key"])
@@ -87,10 +88,13 @@ impl Meal {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(Self, "DELETE FROM meal WHERE id = $1 RETURNING *", id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(query)
     }
 }
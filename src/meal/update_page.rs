@@ -19,11 +19,11 @@ pub async fn meal_update(id: Uuid, name: String) -> Result<(), ServerFnError> {
     let pool = get_pool()?;
 
     let object = Meal::get_by_id(&pool, id).await?.ok_or(Error::NotFound)?;
-    object.can_update(&user).await?;
+    object.can_update(&pool, &user).await?;
 
     Meal::validate(&name)?;
 
-    let meal = Meal::update(&pool, object.id, &name, user.id).await?;
+    let meal = Meal::update(&pool, object.id, &name, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/food/meals/{}", meal.id));
     Ok(())
@@ -22,9 +22,9 @@ pub async fn meal_food_delete(meal_id: Uuid, meal_food_id: Uuid) -> Result<(), S
     let meal = Meal::get_by_id(&pool, meal_id)
         .await?
         .ok_or(Error::NotFound)?;
-    meal.can_update(&user).await?;
+    meal.can_update(&pool, &user).await?;
     let meal_food = MealFood::get_object_or_404(&pool, meal_food_id).await?;
-    MealFood::delete(&pool, meal_food.id).await?;
+    MealFood::delete(&pool, meal_food.id, user.actor_id()).await?;
     leptos_axum::redirect("/food/meals");
     Ok(())
 }
@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use sqlx::postgres::PgRow;
-use sqlx::{FromRow, PgPool, Row};
+use sqlx::{FromRow, PgExecutor, PgPool, Row};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::diet::model::Diet;
 use crate::error::{Error, Result};
 use crate::food::model::Nutrition;
@@ -89,7 +90,7 @@ impl MealFood {
     }
 
     pub async fn create_and_return_meal_id(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         meal_id: Uuid,
         food_id: Uuid,
         quantity: Decimal,
@@ -114,7 +115,7 @@ impl MealFood {
             quantity,
             created_by_id,
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(query)
     }
@@ -147,11 +148,14 @@ impl MealFood {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<u64> {
-        Ok(sqlx::query!("DELETE FROM meal_food WHERE id = $1", id)
-            .execute(pool)
-            .await?
-            .rows_affected())
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query!("DELETE FROM meal_food WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
     }
 
     pub async fn all_by_meal_id(pool: &PgPool, meal_id: Uuid) -> Result<Vec<Self>> {
@@ -36,11 +36,11 @@ async fn meal_food_update(
     let meal = Meal::get_by_id(&pool, meal_id)
         .await?
         .ok_or(Error::NotFound)?;
-    meal.can_update(&user).await?;
+    meal.can_update(&pool, &user).await?;
 
     MealFood::validate(quantity)?;
 
-    MealFood::update_and_return_meal_id(&pool, meal_food_id, quantity, user.id).await?;
+    MealFood::update_and_return_meal_id(&pool, meal_food_id, quantity, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/food/meals/{}", meal.id));
     Ok(())
@@ -17,7 +17,7 @@ pub async fn meal_of_day_create(name: String, ordering: i32) -> Result<(), Serve
     MealOfDay::can_create(&user).await?;
     MealOfDay::validate(&name, ordering)?;
 
-    let object = MealOfDay::create(&pool, &name, ordering, user.id).await?;
+    let object = MealOfDay::create(&pool, &name, ordering, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/meal-of-day/{}", object.slug));
     Ok(())
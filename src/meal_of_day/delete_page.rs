@@ -22,7 +22,7 @@ pub async fn meal_of_day_delete(slug: String) -> Result<(), ServerFnError> {
         .ok_or(Error::NotFound)?;
     object.can_delete(&user).await?;
 
-    MealOfDay::delete(&pool, object.id).await?;
+    MealOfDay::delete(&pool, object.id, user.actor_id()).await?;
     leptos_axum::redirect("/meal-of-day");
     Ok(())
 }
@@ -1,6 +1,7 @@
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::component::select::SelectUuidName;
 use crate::error::Result;
 use crate::util::database::Filter;
@@ -85,14 +86,17 @@ impl MealOfDay {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(
             Self,
             "DELETE FROM meal_of_day WHERE id = $1 RETURNING *",
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
@@ -30,7 +30,7 @@ pub async fn meal_of_day_update(
 
     MealOfDay::validate(&name, ordering)?;
 
-    let updated = MealOfDay::update(&pool, object.id, &name, ordering, user.id).await?;
+    let updated = MealOfDay::update(&pool, object.id, &name, ordering, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/meal-of-day/{}", updated.slug));
     Ok(())
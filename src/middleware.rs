@@ -2,18 +2,26 @@
 // use leptos::*;
 
 use axum::body::Body;
-use axum::extract::Request;
+use axum::extract::{Request, State};
 use axum::middleware::Next;
 use axum::response::Response;
-// use http::header;
+use http::{header, HeaderValue};
 // use http::status::StatusCode;
 
-use crate::auth::cookie::get_cookie;
+use sqlx::PgPool;
+
+use crate::auth::cookie::{build_cookie, build_expired_cookie, get_cookie, REFRESH_COOKIE_NAME};
 use crate::auth::model::RequestUser;
+use crate::auth::service::{AuthService, SessionRefresh};
 use crate::auth::token::JwtManager;
 use crate::config::get_config;
+use crate::user_session::model::UserSession;
 
-pub async fn auth_token_middleware(mut req: Request<Body>, next: Next) -> Response {
+pub async fn auth_token_middleware(
+    State(pool): State<PgPool>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
     let path = req.uri().path();
     // tracing::info!("Running middleware: {}", path);
     // Skip middleware for static assets
@@ -26,12 +34,87 @@ pub async fn auth_token_middleware(mut req: Request<Body>, next: Next) -> Respon
     if let Some(auth_cookie) = get_cookie(headers, &config.auth_cookie_name) {
         // tracing::info!("auth cookie found: {}", auth_cookie);
         if let Ok(auth_token) = JwtManager::validate_auth_token(&auth_cookie) {
-            let user = RequestUser::from(auth_token);
-            // tracing::info!("User added to request extension: {:?}", user);
+            // Revoked sessions are treated as logged out even though the token is still valid.
+            let session_active =
+                UserSession::touch(&pool, auth_token.session_id, auth_token.user_id)
+                    .await
+                    .unwrap_or(false);
+            if session_active {
+                let user = RequestUser::from(auth_token);
+                // tracing::info!("User added to request extension: {:?}", user);
+                req.extensions_mut().insert(user);
+                return next.run(req).await;
+            }
+        }
+    }
+
+    // The access token is missing, expired or revoked, so try to renew it with the refresh token.
+    let Some(refresh_cookie) = get_cookie(req.headers(), REFRESH_COOKIE_NAME) else {
+        return next.run(req).await;
+    };
+    let refresh = AuthService::refresh_session(&pool, &refresh_cookie)
+        .await
+        .unwrap_or_else(|err| {
+            tracing::error!("session refresh failed: {:?}", err);
+            SessionRefresh::Invalid
+        });
+    match refresh {
+        SessionRefresh::Refreshed {
+            user,
+            access_token,
+            refresh_token,
+        } => {
+            req.extensions_mut().insert(user);
+            let mut response = next.run(req).await;
+            append_cookie(
+                &mut response,
+                &config.auth_cookie_name,
+                build_cookie(&config.auth_cookie_name, &access_token),
+            );
+            append_cookie(
+                &mut response,
+                REFRESH_COOKIE_NAME,
+                build_cookie(REFRESH_COOKIE_NAME, &refresh_token),
+            );
+            response
+        }
+        SessionRefresh::AlreadyRefreshed(user) => {
             req.extensions_mut().insert(user);
+            next.run(req).await
+        }
+        SessionRefresh::Invalid => {
+            let mut response = next.run(req).await;
+            append_cookie(
+                &mut response,
+                &config.auth_cookie_name,
+                build_expired_cookie(&config.auth_cookie_name),
+            );
+            append_cookie(
+                &mut response,
+                REFRESH_COOKIE_NAME,
+                build_expired_cookie(REFRESH_COOKIE_NAME),
+            );
+            response
         }
     }
-    next.run(req).await
+}
+
+/// Adds a cookie to the response unless the handler has already set one with the same name, such
+/// as when logging in or out.
+fn append_cookie(response: &mut Response, name: &str, cookie: String) {
+    let prefix = format!("{}=", name);
+    let already_set = response
+        .headers()
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.starts_with(&prefix));
+    if already_set {
+        return;
+    }
+    if let Ok(value) = HeaderValue::try_from(cookie) {
+        response.headers_mut().append(header::SET_COOKIE, value);
+    }
 }
 
 // pub async fn auth_token_middleware(mut req: Request<Body>, next: Next) -> Response {
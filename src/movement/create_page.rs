@@ -19,7 +19,7 @@ pub async fn movement_create(muscle_group_id: Uuid, name: String) -> Result<(),
 
     Movement::can_create(&user).await?;
     Movement::validate(&name)?;
-    let object = Movement::create(&pool, muscle_group_id, &name, user.id).await?;
+    let object = Movement::create(&pool, muscle_group_id, &name, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/exercises/{}", object.slug));
     Ok(())
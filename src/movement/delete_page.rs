@@ -25,7 +25,7 @@ pub async fn movement_delete(id: Uuid) -> Result<(), ServerFnError> {
         .ok_or(Error::NotFound)?;
     object.can_delete(&user).await?;
 
-    Movement::delete(&pool, object.id).await?;
+    Movement::delete(&pool, object.id, user.actor_id()).await?;
 
     leptos_axum::redirect("/movement");
     Ok(())
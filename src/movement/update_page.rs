@@ -28,7 +28,7 @@ pub async fn movement_update(
         .ok_or(Error::NotFound)?;
     object.can_update(&user).await?;
     Movement::validate(&name)?;
-    Movement::update(&pool, object.id, &name, muscle_group_id, user.id).await?;
+    Movement::update(&pool, object.id, &name, muscle_group_id, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/exercises/{}", object.slug));
     Ok(())
@@ -15,7 +15,7 @@ async fn muscle_group_create(name: String) -> Result<(), ServerFnError> {
     let pool = expect_context::<sqlx::PgPool>();
     MuscleGroup::can_create(&user).await?;
     MuscleGroup::validate(&name)?;
-    let object = MuscleGroup::create(&pool, &name, user.id).await?;
+    let object = MuscleGroup::create(&pool, &name, user.actor_id()).await?;
     leptos_axum::redirect(&format!("/exercises/muscle-groups/{}", object.slug));
     Ok(())
 }
@@ -23,7 +23,7 @@ async fn muscle_group_delete(id: Uuid) -> Result<(), ServerFnError> {
 
     object.can_delete(&user).await?;
 
-    MuscleGroup::delete(&pool, id).await?;
+    MuscleGroup::delete(&pool, id, user.actor_id()).await?;
 
     leptos_axum::redirect("/exercises/muscle-groups");
     Ok(())
@@ -1,4 +1,5 @@
 use super::model::{MuscleGroup, MuscleGroupQuery};
+use crate::audit_log::model::AuditLog;
 use crate::component::select::{SelectSlugName, SelectUuidName};
 use crate::error::{handle_sqlx_contraint_error, Result};
 use crate::util::database::Filter;
@@ -71,14 +72,17 @@ impl MuscleGroup {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(
             Self,
             "DELETE FROM muscle_group WHERE id = $1 RETURNING *",
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
+        tx.commit().await?;
         Ok(query)
     }
 }
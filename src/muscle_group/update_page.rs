@@ -25,7 +25,7 @@ async fn muscle_group_update(id: Uuid, name: String) -> Result<(), ServerFnError
         .ok_or(Error::NotFound)?;
     object.can_update(&user).await?;
     MuscleGroup::validate(&name)?;
-    let updated = MuscleGroup::update(&pool, object.id, &name, user.id).await?;
+    let updated = MuscleGroup::update(&pool, object.id, &name, user.actor_id()).await?;
     leptos_axum::redirect(&format!("/exercises/muscle-groups/{}", updated.slug));
     Ok(())
 }
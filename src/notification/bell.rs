@@ -0,0 +1,54 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_router::*;
+
+use crate::app::NotificationRefresh;
+use crate::component::icon::IconBell;
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, notification::model::Notification, setup::get_pool};
+
+#[server(endpoint = "notification-unread-count", input = GetUrl)]
+pub async fn get_notification_unread_count() -> Result<i64, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let count = Notification::count_by_user_id(&pool, user.id, true).await?;
+    Ok(count)
+}
+
+#[component]
+pub fn NotificationBell() -> impl IntoView {
+    let refresh = expect_context::<NotificationRefresh>().0;
+    let location = use_location();
+    let resource = Resource::new(
+        move || {
+            refresh.track();
+            location.pathname.get()
+        },
+        |_| get_notification_unread_count(),
+    );
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().copied())
+                .unwrap_or_default()
+        })
+    };
+
+    view! {
+        <A
+            class="block relative p-2 rounded bg-zinc-900 text-zinc-100 hover:bg-zinc-700"
+            href="/notifications"
+        >
+            <IconBell/>
+            <Transition>
+                <span
+                    class="inline-flex absolute justify-center items-center w-5 h-5 text-xs font-bold leading-none text-red-100 bg-red-600 rounded-full top-[-6px] right-[-6px]"
+                    class=("hidden", move || count() == 0)
+                >
+                    {count}
+                </span>
+            </Transition>
+        </A>
+    }
+}
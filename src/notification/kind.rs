@@ -0,0 +1,64 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum NotificationKind {
+    FollowRequest,
+    FollowAccept,
+    Comment,
+    PersonalRecord,
+    System,
+    Unknown(i32),
+}
+
+impl fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationKind::FollowRequest => write!(f, "Follow Request"),
+            NotificationKind::FollowAccept => write!(f, "Follow Accepted"),
+            NotificationKind::Comment => write!(f, "Comment"),
+            NotificationKind::PersonalRecord => write!(f, "Personal Record"),
+            NotificationKind::System => write!(f, "System"),
+            NotificationKind::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl NotificationKind {
+    /// The kinds a user can turn off. System messages are always delivered.
+    pub fn configurable() -> [NotificationKind; 4] {
+        [
+            NotificationKind::FollowRequest,
+            NotificationKind::FollowAccept,
+            NotificationKind::Comment,
+            NotificationKind::PersonalRecord,
+        ]
+    }
+}
+
+impl From<i32> for NotificationKind {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => NotificationKind::FollowRequest,
+            2 => NotificationKind::FollowAccept,
+            3 => NotificationKind::Comment,
+            4 => NotificationKind::PersonalRecord,
+            5 => NotificationKind::System,
+            _ => NotificationKind::Unknown(value),
+        }
+    }
+}
+
+impl From<NotificationKind> for i32 {
+    fn from(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::FollowRequest => 1,
+            NotificationKind::FollowAccept => 2,
+            NotificationKind::Comment => 3,
+            NotificationKind::PersonalRecord => 4,
+            NotificationKind::System => 5,
+            NotificationKind::Unknown(value) => value,
+        }
+    }
+}
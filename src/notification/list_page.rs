@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::app::NotificationRefresh;
+use crate::component::button::Button;
+use crate::component::checkbox::CheckboxListItem;
+use crate::component::icon::IconCheck;
+use crate::component::paginator::Paginator;
+use crate::component::select::FilterSelect;
+use crate::component::template::{
+    AutoListHeader, ErrorComponent, ListLoadingComponent, ListNotFoundComponent, Loading,
+};
+use crate::util::datetime::format_datetime;
+use crate::util::misc::ListResponse;
+use crate::util::param::{extract_page, extract_param, extract_size};
+use crate::util::validation_error::extract_other_errors;
+
+use super::model::Notification;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user, setup::get_pool, util::server::parse_uuids_from_strings,
+};
+
+#[server(endpoint = "notification-list", input = GetUrl)]
+pub async fn get_notification_list(
+    status: String,
+    size: i64,
+    page: i64,
+) -> Result<ListResponse<Notification>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let unread = status == "unread";
+    let count = Notification::count_by_user_id(&pool, user.id, unread).await?;
+    let results = Notification::all_by_user_id(&pool, user.id, unread, size, page).await?;
+    Ok(ListResponse { count, results })
+}
+
+#[server(endpoint = "notification-mark-read")]
+pub async fn notification_mark_read(items: Option<HashSet<String>>) -> Result<u64, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let items = items.ok_or_else(|| ServerFnError::new("Nothing selected"))?;
+    let uuid_list =
+        parse_uuids_from_strings(&items).map_err(|_| ServerFnError::new("Invalid id selection"))?;
+    let query = Notification::mark_read(&pool, user.id, &uuid_list).await?;
+    Ok(query)
+}
+
+#[server(endpoint = "notification-mark-all-read")]
+pub async fn notification_mark_all_read() -> Result<u64, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let query = Notification::mark_all_read(&pool, user.id).await?;
+    Ok(query)
+}
+
+#[component]
+pub fn NotificationListPage() -> impl IntoView {
+    let refresh = expect_context::<NotificationRefresh>().0;
+    let action_mark_read = Action::<NotificationMarkRead, _>::server();
+    let action_mark_all_read = Action::<NotificationMarkAllRead, _>::server();
+
+    let query = use_query_map();
+    let status = move || extract_param(&query, "status");
+    let size = move || extract_size(&query);
+    let page = move || extract_page(&query);
+    let resource = Resource::new(
+        move || {
+            (
+                status(),
+                size(),
+                page(),
+                action_mark_read.version().get(),
+                action_mark_all_read.version().get(),
+            )
+        },
+        |(status, size, page, ..)| get_notification_list(status, size, page),
+    );
+
+    create_effect(move |_| {
+        if action_mark_read.version().get() > 0 || action_mark_all_read.version().get() > 0 {
+            refresh.notify();
+        }
+    });
+
+    let all_items = RwSignal::new(HashSet::<String>::new());
+    let checked_items = RwSignal::new(HashSet::<String>::new());
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                let ids: HashSet<String> = data
+                    .results
+                    .iter()
+                    .map(|item| item.id.to_string())
+                    .collect();
+                all_items.update(|set| set.extend(ids));
+                data.results
+                    .iter()
+                    .cloned()
+                    .map(|data| view! { <NotificationListItem data checked_items/> })
+                    .collect_view()
+            }
+        })
+    };
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().map(|res| res.count))
+        })
+    };
+
+    let checked_item_count = move || checked_items.with(|items| items.len());
+    let status_options = vec![("", "All"), ("unread", "Unread")];
+
+    let action_value = action_mark_read.value();
+    let action_error = move || extract_other_errors(action_value, &[]);
+    let handle_mark_read = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if let Ok(mut data) = NotificationMarkRead::from_event(&ev) {
+            data.items = Some(checked_items.get());
+            checked_items.update(|v| v.clear());
+            action_mark_read.dispatch(data)
+        }
+    };
+
+    view! {
+        <Title text="Notifications"/>
+        <main class="p-4 m-4 bg-white border">
+
+            <header class="flex justify-between mb-2">
+                <div>
+                    <h1 class="text-xl font-bold">"Notifications"</h1>
+                    <p class="text-gray-400">
+                        "Results: " <Transition fallback=Loading>{count}</Transition>
+                    </p>
+                </div>
+                <div class="flex gap-2 items-center">
+                    <div class=(
+                        "hidden",
+                        move || checked_item_count() == 0,
+                    )>{checked_item_count} " selected"</div>
+                    <A href="/settings/notifications" class="hover:underline">
+                        "Preferences"
+                    </A>
+                </div>
+            </header>
+
+            <section class="flex flex-wrap gap-2 mb-4 lg:mb-2">
+                <Form method="GET" action="" class="contents">
+                    <input type="hidden" name="size" value=size/>
+                    <input type="hidden" name="page" value=1/>
+                    <FilterSelect
+                        name="status"
+                        value=Signal::derive(status)
+                        options=status_options
+                    />
+                </Form>
+            </section>
+
+            <div class="mb-4 font-bold text-red-500">{action_error}</div>
+
+            <section class="grid overflow-auto mb-4 grid-cols-checkbox-4">
+                <AutoListHeader all_items checked_items>
+                    "Notification"
+                    "Type"
+                    "From"
+                    "Received"
+                </AutoListHeader>
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+            </section>
+
+            <section class="flex flex-wrap gap-2">
+                <ActionForm action=action_mark_read on:submit=handle_mark_read>
+                    <input type="hidden" name="items" value=""/>
+                    <Button
+                        label="Mark as read"
+                        loading=action_mark_read.pending()
+                        disabled=Signal::derive(move || checked_items.with(HashSet::is_empty))
+                    >
+                        <IconCheck/>
+                    </Button>
+                </ActionForm>
+                <ActionForm action=action_mark_all_read>
+                    <Button label="Mark all as read" loading=action_mark_all_read.pending()/>
+                </ActionForm>
+                <div class="flex-1">
+                    <Form method="GET" action="" class="contents">
+                        <input type="hidden" name="status" value=status/>
+                        <input type="hidden" name="page" value=page/>
+                        <Transition>
+                            <Paginator count/>
+                        </Transition>
+                    </Form>
+                </div>
+            </section>
+
+        </main>
+    }
+}
+
+#[component]
+pub fn NotificationListItem(
+    data: Notification,
+    checked_items: RwSignal<HashSet<String>>,
+) -> impl IntoView {
+    let id = data.id.to_string();
+    let kind = data.get_kind().to_string();
+    let created_at = format_datetime(&Some(data.created_at));
+    let link_class = if data.is_read() {
+        "flex-1 p-2"
+    } else {
+        "flex-1 p-2 font-bold"
+    };
+    let href = data.link.unwrap_or_else(|| "/notifications".to_string());
+    let actor = data.actor_username.unwrap_or_else(|| "-".to_string());
+    let message = data.message;
+
+    view! {
+        <div class="contents group">
+            <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">
+                <CheckboxListItem id checked_items/>
+            </div>
+            <div class="flex border-b group-hover:bg-amber-200 group-odd:bg-gray-50">
+                <A href=href class=link_class>
+                    {message}
+                </A>
+            </div>
+            <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">{kind}</div>
+            <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">{actor}</div>
+            <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">
+                {created_at}
+            </div>
+        </div>
+    }
+}
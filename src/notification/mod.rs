@@ -0,0 +1,11 @@
+pub mod bell;
+pub mod kind;
+pub mod list_page;
+pub mod model;
+pub mod preference_page;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+#[cfg(feature = "ssr")]
+pub mod service;
+#[cfg(feature = "ssr")]
+pub mod validate;
@@ -0,0 +1,88 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::kind::NotificationKind;
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub actor_username: Option<String>,
+    pub kind: i32,
+    pub message: String,
+    pub link: Option<String>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Notification {
+    pub fn get_kind(&self) -> NotificationKind {
+        NotificationKind::from(self.kind)
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct NotificationPreference {
+    pub kind: i32,
+    pub enabled: bool,
+}
+
+impl NotificationPreference {
+    /// A preference for every kind a user can turn off, enabled unless they have saved otherwise.
+    pub fn with_defaults(saved: &[NotificationPreference]) -> Vec<NotificationPreference> {
+        NotificationKind::configurable()
+            .into_iter()
+            .map(|kind| {
+                let kind = i32::from(kind);
+                let enabled = saved
+                    .iter()
+                    .filter(|preference| preference.kind == kind)
+                    .all(|preference| preference.enabled);
+                NotificationPreference { kind, enabled }
+            })
+            .collect()
+    }
+
+    pub fn is_enabled(preferences: &[NotificationPreference], kind: NotificationKind) -> bool {
+        let kind = i32::from(kind);
+        preferences
+            .iter()
+            .filter(|preference| preference.kind == kind)
+            .all(|preference| preference.enabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preferences_default_to_enabled() {
+        let saved = vec![NotificationPreference {
+            kind: NotificationKind::Comment.into(),
+            enabled: false,
+        }];
+        let preferences = NotificationPreference::with_defaults(&saved);
+        assert_eq!(preferences.len(), NotificationKind::configurable().len());
+        assert!(!NotificationPreference::is_enabled(
+            &preferences,
+            NotificationKind::Comment
+        ));
+        assert!(NotificationPreference::is_enabled(
+            &preferences,
+            NotificationKind::FollowRequest
+        ));
+        assert!(NotificationPreference::is_enabled(
+            &preferences,
+            NotificationKind::System
+        ));
+    }
+}
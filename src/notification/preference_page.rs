@@ -0,0 +1,113 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::button::SubmitButton;
+use crate::component::checkbox::CheckboxInput;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::kind::NotificationKind;
+use super::model::NotificationPreference;
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, setup::get_pool};
+
+#[server(endpoint = "notification-preference-list")]
+pub async fn get_notification_preference_list() -> Result<Vec<NotificationPreference>, ServerFnError>
+{
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let saved = NotificationPreference::all_by_user_id(&pool, user.id).await?;
+    Ok(NotificationPreference::with_defaults(&saved))
+}
+
+#[server(endpoint = "notification-preference-update")]
+pub async fn notification_preference_update(
+    follow_request: bool,
+    follow_accept: bool,
+    comment: bool,
+    personal_record: bool,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let preferences = [
+        (NotificationKind::FollowRequest, follow_request),
+        (NotificationKind::FollowAccept, follow_accept),
+        (NotificationKind::Comment, comment),
+        (NotificationKind::PersonalRecord, personal_record),
+    ];
+    for (kind, enabled) in preferences {
+        NotificationPreference::upsert(&pool, user.id, kind, enabled).await?;
+    }
+    Ok(())
+}
+
+#[component]
+pub fn NotificationPreferencePage() -> impl IntoView {
+    let action = Action::<NotificationPreferenceUpdate, _>::server();
+    let resource = Resource::new(
+        move || action.version().get(),
+        |_| get_notification_preference_list(),
+    );
+    let response = move || {
+        resource.and_then(|data| view! { <NotificationPreferenceForm data=data.clone() action/> })
+    };
+
+    view! {
+        <Title text="Notifications"/>
+        <div class="p-4 bg-white border">
+            <h1 class="mb-2 text-base font-bold">"Notifications"</h1>
+            <p class="mb-4">
+                "Choose which notifications you receive. Messages from the site are always delivered."
+            </p>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+pub fn NotificationPreferenceForm(
+    data: Vec<NotificationPreference>,
+    action: Action<NotificationPreferenceUpdate, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let checked = move |kind| NotificationPreference::is_enabled(&data, kind);
+
+    view! {
+        <div class="max-w-sm">
+            <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+            <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+            <ActionForm action>
+                <CheckboxInput
+                    name="follow_request"
+                    label="follow_requests"
+                    checked=checked(NotificationKind::FollowRequest)
+                />
+                <CheckboxInput
+                    name="follow_accept"
+                    label="accepted_follow_requests"
+                    checked=checked(NotificationKind::FollowAccept)
+                />
+                <CheckboxInput
+                    name="comment"
+                    label="comments_and_replies"
+                    checked=checked(NotificationKind::Comment)
+                />
+                <CheckboxInput
+                    name="personal_record"
+                    label="personal_records_from_people_you_follow"
+                    checked=checked(NotificationKind::PersonalRecord)
+                />
+                <SubmitButton loading=action_loading label="Update Notifications"/>
+            </ActionForm>
+        </div>
+    }
+}
@@ -0,0 +1,301 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+use super::kind::NotificationKind;
+use super::model::{Notification, NotificationPreference};
+
+impl Notification {
+    /// Creates the notification unless the user has turned off notifications of its kind.
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        actor_id: Option<Uuid>,
+        kind: NotificationKind,
+        message: &str,
+        link: Option<&str>,
+    ) -> Result<u64> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO
+                notification (user_id, actor_id, kind, message, link)
+            SELECT
+                $1,
+                $2,
+                $3,
+                $4,
+                $5
+            WHERE
+                NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        notification_preference
+                    WHERE
+                        user_id = $1
+                        AND kind = $3
+                        AND NOT enabled
+                )
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_mute
+                    WHERE
+                        muter_id = $1
+                        AND muted_id = $2
+                )
+            ",
+            user_id,
+            actor_id,
+            i32::from(kind),
+            message,
+            link,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+
+    pub async fn all_by_user_id(
+        pool: &PgPool,
+        user_id: Uuid,
+        unread: bool,
+        size: i64,
+        page: i64,
+    ) -> Result<Vec<Self>> {
+        let offset = (page - 1) * size;
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.user_id,
+                t1.actor_id,
+                t2.username AS "actor_username?",
+                t1.kind,
+                t1.message,
+                t1.link,
+                t1.read_at,
+                t1.created_at
+            FROM
+                notification t1
+                LEFT JOIN users_user t2 ON t2.id = t1.actor_id
+            WHERE
+                t1.user_id = $1
+                AND (
+                    NOT $2
+                    OR t1.read_at IS NULL
+                )
+            ORDER BY
+                t1.created_at DESC,
+                t1.id DESC
+            LIMIT
+                $3
+            OFFSET
+                $4
+            "#,
+            user_id,
+            unread,
+            size,
+            offset,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn count_by_user_id(pool: &PgPool, user_id: Uuid, unread: bool) -> Result<i64> {
+        let query = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                notification
+            WHERE
+                user_id = $1
+                AND (
+                    NOT $2
+                    OR read_at IS NULL
+                )
+            "#,
+            user_id,
+            unread,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn mark_read(pool: &PgPool, user_id: Uuid, ids: &[Uuid]) -> Result<u64> {
+        let query = sqlx::query!(
+            "
+            UPDATE notification
+            SET
+                read_at = NOW()
+            WHERE
+                user_id = $1
+                AND id = ANY ($2)
+                AND read_at IS NULL
+            ",
+            user_id,
+            ids,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+
+    pub async fn mark_all_read(pool: &PgPool, user_id: Uuid) -> Result<u64> {
+        let query = sqlx::query!(
+            "UPDATE notification SET read_at = NOW() WHERE user_id = $1 AND read_at IS NULL",
+            user_id,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+
+    /// Notifies the followers of the exercise's owner when the exercise holds a personal
+    /// record. Followers who cannot see the owner's workouts, or who were already told about a
+    /// record in the exercise, are skipped.
+    pub async fn create_personal_record(pool: &PgPool, exercise_id: Uuid) -> Result<u64> {
+        let query = sqlx::query!(
+            "
+            WITH
+                target AS (
+                    SELECT
+                        t1.id AS exercise_id,
+                        t2.id AS workout_id,
+                        t2.user_id,
+                        t2.date,
+                        t3.username,
+                        COALESCE(
+                            t3.privacy_level,
+                            0
+                        ) AS profile_level,
+                        t5.workout AS workout_level,
+                        t4.name AS movement_name
+                    FROM
+                        exercise t1
+                        JOIN workout t2 ON t2.id = t1.workout_id
+                        JOIN users_user t3 ON t3.id = t2.user_id
+                        JOIN movement t4 ON t4.id = t1.movement_id
+                        LEFT JOIN user_privacy_setting t5 ON t5.user_id = t2.user_id
+                    WHERE
+                        t1.id = $1
+                        AND EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                personal_record
+                            WHERE
+                                exercise_id = t1.id
+                        )
+                )
+            INSERT INTO
+                notification (user_id, actor_id, kind, message, link)
+            SELECT
+                t3.follower_id,
+                t1.user_id,
+                $2,
+                t1.username || ' set a personal record on ' || t1.movement_name || '.',
+                '/users/' || t1.username || '/workouts/' || t1.date || '/' || t1.workout_id || '/' || t1.exercise_id
+            FROM
+                target t1
+                JOIN user_follower t3 ON t3.user_id = t1.user_id
+                AND t3.status = 1
+            WHERE
+                COALESCE(t1.workout_level, t1.profile_level) IN (0, 1, 2)
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_block
+                    WHERE
+                        blocked_status = 1
+                        AND (
+                            blocker_id = t1.user_id
+                            AND blocked_id = t3.follower_id
+                            OR blocker_id = t3.follower_id
+                            AND blocked_id = t1.user_id
+                        )
+                )
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        notification_preference
+                    WHERE
+                        user_id = t3.follower_id
+                        AND kind = $2
+                        AND NOT enabled
+                )
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_mute
+                    WHERE
+                        muter_id = t3.follower_id
+                        AND muted_id = t1.user_id
+                )
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        notification
+                    WHERE
+                        user_id = t3.follower_id
+                        AND kind = $2
+                        AND link = '/users/' || t1.username || '/workouts/' || t1.date || '/' || t1.workout_id || '/' || t1.exercise_id
+                )
+            ",
+            exercise_id,
+            i32::from(NotificationKind::PersonalRecord),
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+}
+
+impl NotificationPreference {
+    pub async fn all_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT kind, enabled FROM notification_preference WHERE user_id = $1",
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn upsert(
+        pool: &PgPool,
+        user_id: Uuid,
+        kind: NotificationKind,
+        enabled: bool,
+    ) -> Result<()> {
+        sqlx::query!(
+            "
+            INSERT INTO
+                notification_preference (user_id, kind, enabled)
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (user_id, kind) DO UPDATE
+            SET
+                enabled = EXCLUDED.enabled,
+                updated_at = NOW()
+            ",
+            user_id,
+            i32::from(kind),
+            enabled,
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::model::{RequestUser, User};
+use crate::social::model::Comment;
+use crate::social::target::SocialTarget;
+use crate::user_report::model::UserReportQuery;
+use crate::user_report::status::ReportStatus;
+
+use super::kind::NotificationKind;
+use super::model::Notification;
+
+// Notifications are a side effect of the action that triggers them, so failures are logged
+// rather than returned to the user.
+
+async fn send(
+    pool: &PgPool,
+    user_id: Uuid,
+    actor_id: Option<Uuid>,
+    kind: NotificationKind,
+    message: &str,
+    link: Option<&str>,
+) {
+    if let Err(err) = Notification::create(pool, user_id, actor_id, kind, message, link).await {
+        tracing::error!("notification failed for {}: {:?}", user_id, err);
+    }
+}
+
+pub async fn notify_follow_request(pool: &PgPool, actor: &RequestUser, username: &str) {
+    match User::get_by_username(pool, username).await {
+        Ok(Some(user)) => {
+            let message = format!("{} has requested to follow you.", actor.username);
+            send(
+                pool,
+                user.id,
+                Some(actor.id),
+                NotificationKind::FollowRequest,
+                &message,
+                Some("/settings/followers"),
+            )
+            .await;
+        }
+        Ok(None) => (),
+        Err(err) => tracing::error!("notification failed for {}: {:?}", username, err),
+    }
+}
+
+pub async fn notify_follow_accept(pool: &PgPool, actor: &RequestUser, username: &str) {
+    match User::get_by_username(pool, username).await {
+        Ok(Some(user)) => {
+            let message = format!("{} accepted your follow request.", actor.username);
+            let link = format!("/users/{}", actor.username);
+            send(
+                pool,
+                user.id,
+                Some(actor.id),
+                NotificationKind::FollowAccept,
+                &message,
+                Some(&link),
+            )
+            .await;
+        }
+        Ok(None) => (),
+        Err(err) => tracing::error!("notification failed for {}: {:?}", username, err),
+    }
+}
+
+/// Notifies the owner of the commented content and, for a reply, the author of the parent
+/// comment. Nobody is notified of their own comment.
+pub async fn notify_comment(
+    pool: &PgPool,
+    actor: &RequestUser,
+    owner_id: Uuid,
+    target: &SocialTarget,
+    parent: Option<&Comment>,
+) {
+    let link = target.href();
+    if owner_id != actor.id {
+        let message = format!(
+            "{} commented on your {}.",
+            actor.username,
+            target_noun(target)
+        );
+        send(
+            pool,
+            owner_id,
+            Some(actor.id),
+            NotificationKind::Comment,
+            &message,
+            Some(&link),
+        )
+        .await;
+    }
+    if let Some(parent) = parent {
+        if parent.user_id != actor.id && parent.user_id != owner_id {
+            let message = format!("{} replied to your comment.", actor.username);
+            send(
+                pool,
+                parent.user_id,
+                Some(actor.id),
+                NotificationKind::Comment,
+                &message,
+                Some(&link),
+            )
+            .await;
+        }
+    }
+}
+
+pub async fn notify_personal_record(pool: &PgPool, exercise_id: Uuid) {
+    if let Err(err) = Notification::create_personal_record(pool, exercise_id).await {
+        tracing::error!(
+            "personal record notification failed for {}: {:?}",
+            exercise_id,
+            err
+        );
+    }
+}
+
+/// Moderators are not named in notifications about reports.
+pub async fn notify_report_resolved(pool: &PgPool, report: &UserReportQuery, action: ReportStatus) {
+    let message = format!(
+        "Your report about {} has been reviewed: {}.",
+        report.reported_username,
+        action.outcome()
+    );
+    send_system(pool, report.reporter_id, &message).await;
+}
+
+pub async fn notify_warning(pool: &PgPool, user_id: Uuid, note: &str) {
+    let message = format!("Moderator warning: {}", note);
+    send_system(pool, user_id, &message).await;
+}
+
+async fn send_system(pool: &PgPool, user_id: Uuid, message: &str) {
+    send(pool, user_id, None, NotificationKind::System, message, None).await;
+}
+
+fn target_noun(target: &SocialTarget) -> &'static str {
+    match target.workout_id {
+        Some(_) => "workout",
+        None => "diet log",
+    }
+}
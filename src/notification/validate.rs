@@ -0,0 +1,24 @@
+use crate::util::validation_error::ValidationError;
+use crate::util::validation_field::{validate_max_length, validate_non_empty};
+
+use super::model::Notification;
+
+impl Notification {
+    pub const MAX_MESSAGE_LENGTH: usize = 255;
+
+    pub fn validate(message: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        if let Some(error) = validate_non_empty(message) {
+            errors.add_error("message", error.to_string());
+        } else if let Some(error) = validate_max_length(message, Self::MAX_MESSAGE_LENGTH) {
+            errors.add_error("message", error.to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
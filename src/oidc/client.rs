@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use data_encoding::BASE64URL_NOPAD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+use reqwest::Url;
+use serde::{Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
+
+use crate::config::OidcConfig;
+use crate::error::{Error, Result};
+
+/// The parts of the provider's discovery document used for the authorization code flow.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_email_verified")]
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+/// Some providers send `email_verified` as a string.
+fn deserialize_email_verified<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrString {
+        Bool(bool),
+        String(String),
+    }
+    Ok(match BoolOrString::deserialize(deserializer)? {
+        BoolOrString::Bool(value) => value,
+        BoolOrString::String(value) => value.eq_ignore_ascii_case("true"),
+    })
+}
+
+/// Discovery documents change rarely, so they are reused across logins for this long.
+const DISCOVERY_TTL: Duration = Duration::from_secs(60 * 60);
+
+static DISCOVERY_CACHE: OnceLock<Mutex<HashMap<String, (Instant, ProviderMetadata)>>> =
+    OnceLock::new();
+
+pub struct OidcClient {
+    config: OidcConfig,
+    metadata: ProviderMetadata,
+    http: reqwest::Client,
+}
+
+impl OidcClient {
+    const RANDOM_LENGTH: usize = 43;
+
+    /// Loads the provider's discovery document from the issuer, reusing the last one fetched
+    /// while it is fresh.
+    pub async fn discover(config: &OidcConfig) -> Result<Self> {
+        let http = reqwest::Client::new();
+        let cache = DISCOVERY_CACHE.get_or_init(Default::default);
+        let cached = cache
+            .lock()
+            .map_err(|_| Error::InternalServer)?
+            .get(&config.issuer)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < DISCOVERY_TTL)
+            .map(|(_, metadata)| metadata.clone());
+        let metadata = match cached {
+            Some(metadata) => metadata,
+            None => {
+                let metadata = Self::fetch_metadata(&http, config).await?;
+                cache
+                    .lock()
+                    .map_err(|_| Error::InternalServer)?
+                    .insert(config.issuer.clone(), (Instant::now(), metadata.clone()));
+                metadata
+            }
+        };
+        Ok(Self {
+            config: config.clone(),
+            metadata,
+            http,
+        })
+    }
+
+    async fn fetch_metadata(
+        http: &reqwest::Client,
+        config: &OidcConfig,
+    ) -> Result<ProviderMetadata> {
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            config.issuer.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = http
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if metadata.issuer.trim_end_matches('/') != config.issuer.trim_end_matches('/') {
+            return Err(Error::Other(
+                "The provider's issuer does not match the configured issuer.".into(),
+            ));
+        }
+        Ok(metadata)
+    }
+
+    /// A random value for the state, nonce and PKCE verifier.
+    pub fn generate_random() -> String {
+        thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(Self::RANDOM_LENGTH)
+            .map(char::from)
+            .collect()
+    }
+
+    pub fn code_challenge(code_verifier: &str) -> String {
+        BASE64URL_NOPAD.encode(&Sha256::digest(code_verifier.as_bytes()))
+    }
+
+    pub fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_verifier: &str,
+    ) -> Result<String> {
+        let url = Url::parse_with_params(
+            &self.metadata.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", self.config.client_id.as_str()),
+                ("redirect_uri", self.config.redirect_url.as_str()),
+                ("scope", "openid email profile"),
+                ("state", state),
+                ("nonce", nonce),
+                ("code_challenge", &Self::code_challenge(code_verifier)),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|err| Error::Other(err.to_string()))?;
+        Ok(url.into())
+    }
+
+    /// Exchanges the authorization code for the verified claims of the user's ID token.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<IdTokenClaims> {
+        let response: TokenResponse = self
+            .http
+            .post(&self.metadata.token_endpoint)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", self.config.redirect_url.as_str()),
+                ("client_id", self.config.client_id.as_str()),
+                ("client_secret", self.config.client_secret.as_str()),
+                ("code_verifier", code_verifier),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        self.verify_id_token(&response.id_token, nonce).await
+    }
+
+    /// ID tokens signed with HMAC use the client secret as the key. Otherwise the key is taken
+    /// from the provider's published key set.
+    pub async fn verify_id_token(&self, id_token: &str, nonce: &str) -> Result<IdTokenClaims> {
+        let header = decode_header(id_token)?;
+        let key = match header.alg {
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
+                DecodingKey::from_secret(self.config.client_secret.as_bytes())
+            }
+            _ => {
+                let jwks = self.fetch_jwks().await?;
+                let jwk = match &header.kid {
+                    Some(kid) => jwks.find(kid),
+                    None => jwks.keys.first(),
+                }
+                .ok_or_else(|| Error::Other("No matching key for the ID token.".into()))?;
+                DecodingKey::from_jwk(jwk)?
+            }
+        };
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_issuer(&[&self.metadata.issuer]);
+        let claims = decode::<IdTokenClaims>(id_token, &key, &validation)?.claims;
+
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(Error::Other("The ID token nonce does not match.".into()));
+        }
+        Ok(claims)
+    }
+
+    async fn fetch_jwks(&self) -> Result<JwkSet> {
+        let jwks_uri = self
+            .metadata
+            .jwks_uri
+            .as_ref()
+            .ok_or_else(|| Error::Other("The provider does not publish a key set.".into()))?;
+        let jwks = self
+            .http
+            .get(jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(jwks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const CLIENT_ID: &str = "trackedfitness";
+    const CLIENT_SECRET: &str = "client-secret";
+
+    fn config(issuer: &str) -> OidcConfig {
+        OidcConfig {
+            provider_name: "Mock".into(),
+            issuer: issuer.into(),
+            client_id: CLIENT_ID.into(),
+            client_secret: CLIENT_SECRET.into(),
+            redirect_url: "http://localhost:3000/auth/oidc/callback".into(),
+        }
+    }
+
+    fn id_token(issuer: &str, audience: &str, nonce: &str) -> String {
+        let exp = chrono::Utc::now().timestamp() + 300;
+        let claims = json!({
+            "iss": issuer,
+            "sub": "user-123",
+            "aud": audience,
+            "exp": exp,
+            "iat": exp - 300,
+            "nonce": nonce,
+            "email": "jane@example.com",
+            "email_verified": "true",
+            "name": "Jane Doe",
+        });
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(CLIENT_SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    async fn mock_provider(audience: &str) -> MockServer {
+        let server = MockServer::start().await;
+        let issuer = server.uri();
+        Mock::given(method("GET"))
+            .and(path("/.well-known/openid-configuration"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "issuer": issuer,
+                "authorization_endpoint": format!("{}/authorize", issuer),
+                "token_endpoint": format!("{}/token", issuer),
+                "jwks_uri": format!("{}/jwks", issuer),
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(body_string_contains("code=auth-code"))
+            .and(body_string_contains("code_verifier=verifier"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "access",
+                "token_type": "Bearer",
+                "id_token": id_token(&issuer, audience, "nonce"),
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code() {
+        let server = mock_provider(CLIENT_ID).await;
+        let client = OidcClient::discover(&config(&server.uri())).await.unwrap();
+        let claims = client
+            .exchange_code("auth-code", "verifier", "nonce")
+            .await
+            .unwrap();
+        assert_eq!(claims.sub, "user-123");
+        assert_eq!(claims.email.as_deref(), Some("jane@example.com"));
+        assert!(claims.email_verified);
+    }
+
+    #[tokio::test]
+    async fn test_discover_reuses_metadata() {
+        let server = mock_provider(CLIENT_ID).await;
+        let config = config(&server.uri());
+        let discovery_requests = || async {
+            server
+                .received_requests()
+                .await
+                .unwrap()
+                .iter()
+                .filter(|request| request.url.path() == "/.well-known/openid-configuration")
+                .count()
+        };
+        OidcClient::discover(&config).await.unwrap();
+        let fetched = discovery_requests().await;
+        assert!(fetched <= 1);
+        OidcClient::discover(&config).await.unwrap();
+        assert_eq!(discovery_requests().await, fetched);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_rejects_nonce_mismatch() {
+        let server = mock_provider(CLIENT_ID).await;
+        let client = OidcClient::discover(&config(&server.uri())).await.unwrap();
+        let result = client.exchange_code("auth-code", "verifier", "other").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_rejects_wrong_audience() {
+        let server = mock_provider("another-client").await;
+        let client = OidcClient::discover(&config(&server.uri())).await.unwrap();
+        let result = client.exchange_code("auth-code", "verifier", "nonce").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_authorization_url() {
+        let server = mock_provider(CLIENT_ID).await;
+        let client = OidcClient::discover(&config(&server.uri())).await.unwrap();
+        let url = client
+            .authorization_url("state", "nonce", "verifier")
+            .unwrap();
+        assert!(url.starts_with(&format!("{}/authorize?", server.uri())));
+        assert!(url.contains("state=state"));
+        assert!(url.contains(&format!(
+            "code_challenge={}",
+            OidcClient::code_challenge("verifier")
+        )));
+    }
+
+    #[test]
+    fn test_code_challenge() {
+        assert_eq!(
+            OidcClient::code_challenge("verifier"),
+            "iMnq5o6zALKXGivsnlom_0F5_WYda32GHkxlV7mq7hQ"
+        );
+    }
+}
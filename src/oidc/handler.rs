@@ -0,0 +1,165 @@
+use axum::extract::Query;
+use axum::http::request::Parts;
+use axum::http::{header, HeaderValue};
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::Extension;
+use serde::Deserialize;
+use sqlx::PgPool;
+
+use crate::auth::cookie::{
+    build_cookie, build_expired_cookie, get_cookie, REFRESH_COOKIE_NAME, TWO_FACTOR_COOKIE_NAME,
+};
+use crate::auth::service::{get_client_ip, AuthService};
+use crate::auth::token::{JwtManager, TokenType};
+use crate::config::get_config;
+use crate::error::{Error, Result};
+use crate::login_throttle::model::LoginThrottle;
+use crate::login_throttle::scope::ThrottleScope;
+use crate::two_factor::model::UserTotp;
+
+use super::client::{IdTokenClaims, OidcClient};
+use super::model::UserIdentity;
+use super::service::{OidcLoginState, OIDC_COOKIE_NAME};
+
+const FAILED_REDIRECT: &str = "/login?error=oidc";
+
+#[derive(Debug, Deserialize)]
+pub struct OidcCallbackParams {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+/// Sends the browser to the provider, remembering the state needed to complete the sign in.
+pub async fn oidc_login_handler() -> Response {
+    match start_login().await {
+        Ok((url, login_state)) => with_cookies(
+            Redirect::to(&url).into_response(),
+            &[build_cookie(OIDC_COOKIE_NAME, &login_state)],
+        ),
+        Err(err) => {
+            tracing::error!("oidc login failed: {:?}", err);
+            Redirect::to(FAILED_REDIRECT).into_response()
+        }
+    }
+}
+
+async fn start_login() -> Result<(String, String)> {
+    let config = get_config().oidc.as_ref().ok_or(Error::NotFound)?;
+    let client = OidcClient::discover(config).await?;
+    let login_state = OidcLoginState::generate();
+    let url = client.authorization_url(
+        &login_state.state,
+        &login_state.nonce,
+        &login_state.code_verifier,
+    )?;
+    Ok((url, login_state.encode()?))
+}
+
+/// Completes the sign in when the provider redirects back.
+pub async fn oidc_callback_handler(
+    Extension(pool): Extension<PgPool>,
+    Query(params): Query<OidcCallbackParams>,
+    parts: Parts,
+) -> Response {
+    let mut cookies = vec![build_expired_cookie(OIDC_COOKIE_NAME)];
+    let location = match complete_login(&pool, &parts, params).await {
+        Ok(OidcLogin::LoggedIn {
+            username,
+            access_token,
+            refresh_token,
+        }) => {
+            let config = get_config();
+            cookies.push(build_cookie(&config.auth_cookie_name, &access_token));
+            cookies.push(build_cookie(REFRESH_COOKIE_NAME, &refresh_token));
+            format!("/users/{}", username)
+        }
+        Ok(OidcLogin::TwoFactor(token)) => {
+            cookies.push(build_cookie(TWO_FACTOR_COOKIE_NAME, &token));
+            "/login/two-factor".to_string()
+        }
+        Ok(OidcLogin::ActivationRequired) => "/signup/email-sent".to_string(),
+        Err(err) => {
+            tracing::warn!("oidc callback failed: {:?}", err);
+            FAILED_REDIRECT.to_string()
+        }
+    };
+    with_cookies(Redirect::to(&location).into_response(), &cookies)
+}
+
+enum OidcLogin {
+    LoggedIn {
+        username: String,
+        access_token: String,
+        refresh_token: String,
+    },
+    TwoFactor(String),
+    ActivationRequired,
+}
+
+async fn complete_login(
+    pool: &PgPool,
+    parts: &Parts,
+    params: OidcCallbackParams,
+) -> Result<OidcLogin> {
+    let client_ip = get_client_ip(parts)?;
+    let ip = client_ip.to_string();
+    LoginThrottle::ensure_allowed(pool, ThrottleScope::LoginIp, &ip).await?;
+    let claims = match verify_callback(parts, params).await {
+        Ok(claims) => claims,
+        Err(err) => {
+            LoginThrottle::record_failure(pool, ThrottleScope::LoginIp, &ip, None).await?;
+            return Err(err);
+        }
+    };
+    let user = UserIdentity::resolve_user(pool, &claims).await?;
+    LoginThrottle::ensure_login_allowed(pool, &ip, &user.email).await?;
+
+    if !user.email_verified {
+        return Ok(OidcLogin::ActivationRequired);
+    }
+    user.ensure_account_active()?;
+
+    if UserTotp::get_enabled_by_user_id(pool, user.id)
+        .await?
+        .is_some()
+    {
+        let token = JwtManager::create_token(user.id, "", TokenType::TwoFactor)?;
+        return Ok(OidcLogin::TwoFactor(token));
+    }
+
+    let tokens = AuthService::create_session(pool, &user, &parts.headers, client_ip, None).await?;
+    Ok(OidcLogin::LoggedIn {
+        username: user.username,
+        access_token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+    })
+}
+
+/// Checks the callback belongs to the login this browser started and exchanges its code.
+async fn verify_callback(parts: &Parts, params: OidcCallbackParams) -> Result<IdTokenClaims> {
+    if let Some(error) = params.error {
+        return Err(Error::Other(error));
+    }
+    let login_state = get_cookie(&parts.headers, OIDC_COOKIE_NAME).ok_or(Error::InvalidToken)?;
+    let login_state = OidcLoginState::decode(&login_state)?;
+    if params.state.as_deref() != Some(login_state.state.as_str()) {
+        return Err(Error::InvalidToken);
+    }
+    let code = params.code.ok_or(Error::InvalidToken)?;
+
+    let config = get_config().oidc.as_ref().ok_or(Error::NotFound)?;
+    let client = OidcClient::discover(config).await?;
+    client
+        .exchange_code(&code, &login_state.code_verifier, &login_state.nonce)
+        .await
+}
+
+fn with_cookies(mut response: Response, cookies: &[String]) -> Response {
+    for cookie in cookies {
+        if let Ok(value) = HeaderValue::try_from(cookie) {
+            response.headers_mut().append(header::SET_COOKIE, value);
+        }
+    }
+    response
+}
@@ -0,0 +1,9 @@
+#[cfg(feature = "ssr")]
+pub mod client;
+#[cfg(feature = "ssr")]
+pub mod handler;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+#[cfg(feature = "ssr")]
+pub mod service;
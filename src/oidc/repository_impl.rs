@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+use super::model::UserIdentity;
+
+impl UserIdentity {
+    pub async fn get_by_subject(
+        pool: &PgPool,
+        issuer: &str,
+        subject: &str,
+    ) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM user_identity WHERE issuer = $1 AND subject = $2",
+            issuer,
+            subject,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        issuer: &str,
+        subject: &str,
+        email: Option<&str>,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                user_identity (user_id, issuer, subject, email, last_login_at)
+            VALUES
+                ($1, $2, $3, $4, NOW())
+            RETURNING
+                *
+            ",
+            user_id,
+            issuer,
+            subject,
+            email,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn update_last_login(pool: &PgPool, id: Uuid, email: Option<&str>) -> Result<()> {
+        sqlx::query!(
+            "UPDATE user_identity SET last_login_at = NOW(), email = $2 WHERE id = $1",
+            id,
+            email,
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
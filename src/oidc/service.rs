@@ -0,0 +1,112 @@
+use chrono::{TimeDelta, Utc};
+use jsonwebtoken::{decode, encode, Header, Validation};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::auth::model::User;
+use crate::auth::privacy_level::PrivacyLevel;
+use crate::auth::service::AuthService;
+use crate::config::get_config;
+use crate::error::{Error, Result};
+
+use super::client::{IdTokenClaims, OidcClient};
+use super::model::UserIdentity;
+
+/// Name of the cookie holding the state of a sign in that has been sent to the provider.
+pub const OIDC_COOKIE_NAME: &str = "oidc_login";
+
+/// The values checked when the provider redirects back, signed so they cannot be altered.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OidcLoginState {
+    pub exp: i64,
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+}
+
+impl OidcLoginState {
+    const DURATION_MINUTES: i64 = 10;
+
+    pub fn generate() -> Self {
+        let exp = (Utc::now() + TimeDelta::minutes(Self::DURATION_MINUTES)).timestamp();
+        Self {
+            exp,
+            state: OidcClient::generate_random(),
+            nonce: OidcClient::generate_random(),
+            code_verifier: OidcClient::generate_random(),
+        }
+    }
+
+    pub fn encode(&self) -> Result<String> {
+        let token = encode(&Header::default(), self, &get_config().encoding_key)?;
+        Ok(token)
+    }
+
+    pub fn decode(token: &str) -> Result<Self> {
+        let token_data = decode::<Self>(token, &get_config().decoding_key, &Validation::default())?;
+        Ok(token_data.claims)
+    }
+}
+
+impl UserIdentity {
+    /// Finds the user linked to the provider account. Unknown accounts are linked to the user
+    /// with the same email address when the provider has verified it, or otherwise signed up.
+    /// New users whose email the provider has not verified are sent the usual activation email.
+    pub async fn resolve_user(pool: &PgPool, claims: &IdTokenClaims) -> Result<User> {
+        let email = claims.email.as_deref();
+        if let Some(identity) = Self::get_by_subject(pool, &claims.iss, &claims.sub).await? {
+            Self::update_last_login(pool, identity.id, email).await?;
+            let user = User::get_by_id(pool, identity.user_id)
+                .await?
+                .ok_or(Error::NotFound)?;
+            return Ok(user);
+        }
+
+        let email = email
+            .ok_or_else(|| Error::Other("The provider did not share an email address.".into()))?;
+        if let Some(user) = User::get_by_email(pool, email).await? {
+            if !claims.email_verified {
+                return Err(Error::Other(
+                    "An account already uses this email address. Log in with your password.".into(),
+                ));
+            }
+            Self::create(pool, user.id, &claims.iss, &claims.sub, Some(email)).await?;
+            if !user.email_verified {
+                // The password of an unverified account may have been set by someone else
+                // using this address, so it is removed before the account is activated.
+                User::set_unusable_password(pool, user.id).await?;
+                return User::activate(pool, user.id).await;
+            }
+            return Ok(user);
+        }
+
+        User::validate_email(email)?;
+        let name = claims
+            .name
+            .as_deref()
+            .filter(|name| name.trim().len() >= 3)
+            .unwrap_or_else(|| email.split('@').next().unwrap_or(email));
+        let name: String = name.chars().take(255).collect();
+        let username = User::generate_username_from_email(email)?;
+        let password = OidcClient::generate_random();
+        let user = User::create(
+            pool,
+            &name,
+            &username,
+            &password,
+            email,
+            claims.email_verified,
+            claims.email_verified,
+            false,
+            false,
+            PrivacyLevel::Followers.into(),
+        )
+        .await?;
+        let user = User::set_unusable_password(pool, user.id).await?;
+        Self::create(pool, user.id, &claims.iss, &claims.sub, Some(email)).await?;
+        if !claims.email_verified {
+            AuthService::send_activation_email(user.id, &user.name, &user.email).await?;
+        }
+        Ok(user)
+    }
+}
@@ -0,0 +1,141 @@
+use derive_more::Display;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+use super::sex::Sex;
+
+#[derive(Debug, Display, Default, Clone, PartialEq)]
+pub enum BmrFormula {
+    #[display(fmt = "Mifflin-St Jeor")]
+    MifflinStJeor,
+    #[default]
+    #[display(fmt = "Harris-Benedict")]
+    HarrisBenedict,
+    #[display(fmt = "Katch-McArdle")]
+    KatchMcArdle,
+}
+
+impl FromStr for BmrFormula {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "MS" => Ok(BmrFormula::MifflinStJeor),
+            "HB" => Ok(BmrFormula::HarrisBenedict),
+            "KM" => Ok(BmrFormula::KatchMcArdle),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<BmrFormula> for &str {
+    fn from(value: BmrFormula) -> Self {
+        match value {
+            BmrFormula::MifflinStJeor => "MS",
+            BmrFormula::HarrisBenedict => "HB",
+            BmrFormula::KatchMcArdle => "KM",
+        }
+    }
+}
+
+impl BmrFormula {
+    /// Basal metabolic rate for the given formula. Katch-McArdle is based on lean body mass
+    /// and falls back to Mifflin-St Jeor when no body fat percentage has been recorded.
+    pub fn calculate(
+        &self,
+        sex: &Sex,
+        weight: Decimal,
+        height: Decimal,
+        age: u32,
+        body_fat_percentage: Option<Decimal>,
+    ) -> Decimal {
+        let age_decimal = Decimal::from(age);
+        match self {
+            BmrFormula::HarrisBenedict => {
+                let modifier = sex.to_bmr_modifier();
+                modifier.sex_modifier
+                    + (weight * modifier.weight_modifier)
+                    + (height * modifier.height_modifier)
+                    - (modifier.age_modifier * age_decimal)
+            }
+            BmrFormula::MifflinStJeor => {
+                let sex_modifier = match sex {
+                    Sex::Male => Decimal::from(5),
+                    Sex::Female => Decimal::from(-161),
+                    Sex::Default => Decimal::from(0),
+                };
+                (Decimal::from(10) * weight) + (Decimal::new(625, 2) * height)
+                    - (Decimal::from(5) * age_decimal)
+                    + sex_modifier
+            }
+            BmrFormula::KatchMcArdle => match body_fat_percentage {
+                Some(body_fat) => {
+                    let lean_mass = weight * (Decimal::from(1) - body_fat / Decimal::from(100));
+                    Decimal::from(370) + (Decimal::new(216, 1) * lean_mass)
+                }
+                None => BmrFormula::MifflinStJeor.calculate(sex, weight, height, age, None),
+            },
+        }
+    }
+
+    pub fn all_variants() -> Vec<&'static str> {
+        vec!["MS", "HB", "KM"]
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        let options = [
+            ("MS", "Mifflin-St Jeor"),
+            ("HB", "Harris-Benedict"),
+            ("KM", "Katch-McArdle - requires body fat percentage"),
+        ];
+        options.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mifflin_st_jeor() {
+        let bmr = BmrFormula::MifflinStJeor.calculate(
+            &Sex::Male,
+            Decimal::from(80),
+            Decimal::from(180),
+            30,
+            None,
+        );
+        assert_eq!(bmr, Decimal::from(1780));
+        let bmr = BmrFormula::MifflinStJeor.calculate(
+            &Sex::Female,
+            Decimal::from(60),
+            Decimal::from(165),
+            30,
+            None,
+        );
+        assert_eq!(bmr, Decimal::new(132025, 2));
+    }
+
+    #[test]
+    fn test_katch_mcardle() {
+        let bmr = BmrFormula::KatchMcArdle.calculate(
+            &Sex::Male,
+            Decimal::from(80),
+            Decimal::from(180),
+            30,
+            Some(Decimal::from(20)),
+        );
+        assert_eq!(bmr, Decimal::new(17524, 1));
+    }
+
+    #[test]
+    fn test_katch_mcardle_without_body_fat_falls_back() {
+        let bmr = BmrFormula::KatchMcArdle.calculate(
+            &Sex::Male,
+            Decimal::from(80),
+            Decimal::from(180),
+            30,
+            None,
+        );
+        assert_eq!(bmr, Decimal::from(1780));
+    }
+}
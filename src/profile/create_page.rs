@@ -12,6 +12,7 @@ use crate::util::param::get_username;
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 use super::activity_level::ActivityLevel;
+use super::bmr_formula::BmrFormula;
 use super::fitness_goal::FitnessGoal;
 use super::sex::Sex;
 
@@ -26,14 +27,18 @@ use crate::{
 };
 
 #[server(endpoint = "profile-create")]
+#[allow(clippy::too_many_arguments)]
 pub async fn profile_create(
     username: String,
     fitness_goal: String,
+    bmr_formula: String,
     activity_level: String,
     sex: String,
     height: Decimal,
     weight: Decimal,
     date_of_birth: NaiveDate,
+    body_fat_percentage: Option<Decimal>,
+    tdee_override: Option<i32>,
 ) -> Result<(), ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
@@ -47,9 +52,12 @@ pub async fn profile_create(
         &sex,
         &activity_level,
         &fitness_goal,
+        &bmr_formula,
         height,
         weight,
         date_of_birth,
+        body_fat_percentage,
+        tdee_override,
     )?;
 
     let latest_weight = Progress::get_latest_weight(&pool, user.id).await?;
@@ -63,7 +71,7 @@ pub async fn profile_create(
             Some(weight),
             None,
             None,
-            user.id,
+            user.actor_id(),
         )
         .await?;
     }
@@ -76,7 +84,10 @@ pub async fn profile_create(
         date_of_birth,
         &activity_level,
         &fitness_goal,
-        user.id,
+        &bmr_formula,
+        body_fat_percentage,
+        tdee_override,
+        user.actor_id(),
     )
     .await?;
 
@@ -98,6 +109,8 @@ pub fn ProfileCreatePage() -> impl IntoView {
     let goal_options = FitnessGoal::to_form_options();
     let activity_options = ActivityLevel::to_form_options();
     let sex_options = Sex::to_form_options();
+    let bmr_formula_options = BmrFormula::to_form_options();
+    let default_bmr_formula: &str = BmrFormula::default().into();
 
     view! {
         <DetailPageTemplate title="Set up Profile">
@@ -128,6 +141,26 @@ pub fn ProfileCreatePage() -> impl IntoView {
                     label="Date of Birth"
                     input_type="date"
                 />
+                <FieldSelect
+                    name="bmr_formula"
+                    label="BMR formula"
+                    options=bmr_formula_options
+                    value=default_bmr_formula.to_string()
+                />
+                <NumberInput
+                    action_value
+                    name="body_fat_percentage"
+                    label="Body Fat %"
+                    step="0.1"
+                    placeholder="Required for Katch-McArdle"
+                />
+                <NumberInput
+                    action_value
+                    name="tdee_override"
+                    label="TDEE Override"
+                    step="1"
+                    placeholder="Leave blank to calculate from activity level"
+                />
                 <SubmitButton loading=action_loading label="Create Profile"/>
             </ActionForm>
         </DetailPageTemplate>
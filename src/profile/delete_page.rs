@@ -22,7 +22,7 @@ pub async fn profile_delete(username: String) -> Result<(), ServerFnError> {
         .ok_or(Error::NotFound)?;
     object.can_delete(&user).await?;
 
-    Profile::delete(&pool, object.id).await?;
+    Profile::delete(&pool, object.id, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/users/{username}"));
     Ok(())
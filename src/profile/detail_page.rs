@@ -13,7 +13,7 @@ use crate::util::param::{get_date, get_username};
 #[cfg(feature = "ssr")]
 use crate::{
     auth::model::User, auth::service::get_request_user, error::Error, profile::model::ProfileQuery,
-    setup::get_pool,
+    setup::get_pool, user_privacy::section::PrivacySection,
 };
 
 #[server(endpoint = "profile-detail-latest")]
@@ -23,7 +23,7 @@ pub async fn get_profile_detail_latest(
 ) -> Result<Option<ProfileMetric>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Profile).await?;
 
     let object = ProfileQuery::get_latest_by_username(&pool, &username, date)
         .await?
@@ -39,7 +39,7 @@ pub async fn get_profile_detail(
 ) -> Result<ProfileMetric, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Profile).await?;
 
     let object = ProfileQuery::get_latest_by_username(&pool, &username, date)
         .await?
@@ -20,6 +20,15 @@ pub fn ProfileDetailTable(data: ProfileMetric) -> impl IntoView {
         |date| format!("/users/{}/progress/{}", data.username, date),
     );
 
+    let body_fat_percentage = data
+        .body_fat_percentage
+        .map_or_else(|| "-".to_string(), |value| format!("{:.1}%", value));
+    let tdee_source = if data.tdee_override.is_some() {
+        "Manual override"
+    } else {
+        "Calculated"
+    };
+
     view! {
         <div>
             <table class="w-full border-collapse table-fixed">
@@ -47,6 +56,10 @@ pub fn ProfileDetailTable(data: ProfileMetric) -> impl IntoView {
                         <td class="p-2 w-1/2 text-left border">"Age"</td>
                         <td class="p-2 w-1/2 text-right border">{data.age}</td>
                     </tr>
+                    <tr>
+                        <td class="p-2 w-1/2 text-left border">"Body Fat"</td>
+                        <td class="p-2 w-1/2 text-right border">{body_fat_percentage}</td>
+                    </tr>
                     <tr>
                         <td class="p-2 w-1/2 text-left border">
                             <a href="/help#bmi" class="text-blue-500 §hover:underline">
@@ -67,6 +80,10 @@ pub fn ProfileDetailTable(data: ProfileMetric) -> impl IntoView {
                             {format!("{:.*} kcal", 0, data.basal_metabolic_rate)}
                         </td>
                     </tr>
+                    <tr>
+                        <td class="p-2 w-1/2 text-left border">"BMR Formula"</td>
+                        <td class="p-2 w-1/2 text-right border">{data.bmr_formula_display}</td>
+                    </tr>
                     <tr>
                         <td class="p-2 w-1/2 text-left border">"Activity Level"</td>
                         <td class="p-2 w-1/2 text-right border">{data.activity_level_display}</td>
@@ -79,6 +96,7 @@ pub fn ProfileDetailTable(data: ProfileMetric) -> impl IntoView {
                         </td>
                         <td class="p-2 w-1/2 text-right border">
                             {format!("{:.*} kcal", 0, data.total_daily_energy_expenditure)}
+                            <div class="text-xs text-gray-500">{tdee_source}</div>
                         </td>
                     </tr>
                     <tr>
@@ -1,4 +1,5 @@
 pub mod activity_level;
+pub mod bmr_formula;
 pub mod component;
 pub mod create_page;
 pub mod delete_page;
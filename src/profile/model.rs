@@ -5,6 +5,7 @@ use std::str::FromStr;
 use uuid::Uuid;
 
 use super::activity_level::ActivityLevel;
+use super::bmr_formula::BmrFormula;
 use super::fitness_goal::FitnessGoal;
 use super::sex::Sex;
 
@@ -24,6 +25,9 @@ pub struct Profile {
     pub created_by_id: Uuid,
     pub updated_by_id: Option<Uuid>,
     pub image_location: Option<String>,
+    pub bmr_formula: String,
+    pub body_fat_percentage: Option<Decimal>,
+    pub tdee_override: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -46,6 +50,9 @@ pub struct ProfileQuery {
     pub created_by_id: Uuid,
     pub updated_by_id: Option<Uuid>,
     pub image_location: Option<String>,
+    pub bmr_formula: String,
+    pub body_fat_percentage: Option<Decimal>,
+    pub tdee_override: Option<i32>,
     //
     pub latest_weight: Option<Decimal>,
     pub latest_weight_date: Option<NaiveDate>,
@@ -66,6 +73,9 @@ pub struct ProfileMetric {
     pub created_by_id: Uuid,
     pub updated_by_id: Option<Uuid>,
     pub image_location: Option<String>,
+    pub bmr_formula: String,
+    pub body_fat_percentage: Option<Decimal>,
+    pub tdee_override: Option<i32>,
     //
     pub latest_weight: Option<Decimal>,
     pub latest_weight_date: Option<NaiveDate>,
@@ -74,6 +84,7 @@ pub struct ProfileMetric {
     pub age: u32,
     pub activity_level_display: String,
     pub basal_metabolic_rate: Decimal,
+    pub bmr_formula_display: String,
     pub body_mass_index: Decimal,
     pub fitness_goal_display: String,
     pub sex_display: String,
@@ -98,6 +109,7 @@ impl From<ProfileQuery> for ProfileMetric {
             .unwrap_or_default()
             .to_string();
         let sex_display = Sex::from_str(&data.sex).unwrap_or_default().to_string();
+        let bmr_formula_display = data.get_bmr_formula().to_string();
 
         let basal_metabolic_rate = data.get_basal_metabolic_rate();
         let body_mass_index = data.get_body_mass_index();
@@ -117,11 +129,15 @@ impl From<ProfileQuery> for ProfileMetric {
             created_by_id: data.created_by_id,
             updated_by_id: data.updated_by_id,
             image_location: data.image_location,
+            bmr_formula: data.bmr_formula,
+            body_fat_percentage: data.body_fat_percentage,
+            tdee_override: data.tdee_override,
             latest_weight: data.latest_weight,
             latest_weight_date: data.latest_weight_date,
             username: data.username,
             activity_level_display,
             basal_metabolic_rate,
+            bmr_formula_display,
             body_mass_index,
             fitness_goal_display,
             sex_display,
@@ -149,25 +165,31 @@ impl ProfileQuery {
         body_mass_index
     }
 
-    /// Basal metabolic rate, calculated using the Harris-Benedict Equation
+    pub fn get_bmr_formula(&self) -> BmrFormula {
+        BmrFormula::from_str(&self.bmr_formula).unwrap_or_default()
+    }
+
+    /// Basal metabolic rate, calculated using the formula selected on the profile.
     pub fn get_basal_metabolic_rate(&self) -> Decimal {
         let Some(weight) = self.latest_weight else {
             return Decimal::from(0);
         };
         let sex = Sex::from_str(&self.sex).unwrap_or(Sex::Default);
-        let bmr_modifier = sex.to_bmr_modifier();
-        let age_decimal = Decimal::from(self.get_age());
-        let height = self.height;
-        let basal_metabolic_rate = bmr_modifier.sex_modifier
-            + (weight * bmr_modifier.weight_modifier)
-            + (height * bmr_modifier.height_modifier)
-            - (bmr_modifier.age_modifier * age_decimal);
-        basal_metabolic_rate
+        self.get_bmr_formula().calculate(
+            &sex,
+            weight,
+            self.height,
+            self.get_age(),
+            self.body_fat_percentage,
+        )
     }
 
     /// Estimation of daily energy burnt taking into account life-sustaining functions and physical activity;
-    /// A pretty accurate estimataion.
+    /// A pretty accurate estimataion. A manual override on the profile takes precedence.
     pub fn get_total_daily_energy_expenditure(&self) -> Decimal {
+        if let Some(tdee_override) = self.tdee_override {
+            return Decimal::from(tdee_override);
+        }
         let activity_level =
             ActivityLevel::from_str(&self.activity_level).unwrap_or(ActivityLevel::Sedentary);
         let activity_level_modifier = activity_level.to_tdee_modifier();
@@ -3,6 +3,7 @@ use rust_decimal::prelude::*;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 
 use super::model::{Profile, ProfileImage, ProfileQuery};
@@ -22,6 +23,9 @@ impl Profile {
         date_of_birth: NaiveDate,
         activity_level: &str,
         fitness_goal: &str,
+        bmr_formula: &str,
+        body_fat_percentage: Option<Decimal>,
+        tdee_override: Option<i32>,
         request_user_id: Uuid,
     ) -> Result<Self> {
         let query = sqlx::query_as!(
@@ -33,10 +37,13 @@ impl Profile {
                     date_of_birth,
                     activity_level,
                     fitness_goal,
+                    bmr_formula,
+                    body_fat_percentage,
+                    tdee_override,
                     created_by_id
                 )
             VALUES
-                ($1, $2, $3, $4, $5, $6, $7)
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING *
             ",
             user_id,
@@ -45,6 +52,9 @@ impl Profile {
             date_of_birth,
             activity_level,
             fitness_goal,
+            bmr_formula,
+            body_fat_percentage,
+            tdee_override,
             request_user_id
         )
         .fetch_one(pool)
@@ -60,6 +70,9 @@ impl Profile {
         date_of_birth: NaiveDate,
         activity_level: &str,
         fitness_goal: &str,
+        bmr_formula: &str,
+        body_fat_percentage: Option<Decimal>,
+        tdee_override: Option<i32>,
         request_user_id: Uuid,
     ) -> Result<Self> {
         let query = sqlx::query_as!(
@@ -71,10 +84,13 @@ impl Profile {
                 date_of_birth = $3,
                 activity_level = $4,
                 fitness_goal = $5,
+                bmr_formula = $6,
+                body_fat_percentage = $7,
+                tdee_override = $8,
                 updated_at = NOW(),
-                updated_by_id = $6
+                updated_by_id = $9
             WHERE
-                id = $7
+                id = $10
             RETURNING *
             ",
             sex,
@@ -82,6 +98,9 @@ impl Profile {
             date_of_birth,
             activity_level,
             fitness_goal,
+            bmr_formula,
+            body_fat_percentage,
+            tdee_override,
             request_user_id,
             id,
         )
@@ -90,14 +109,17 @@ impl Profile {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(
             Self,
             "DELETE FROM user_profile WHERE id = $1 RETURNING *",
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
+        tx.commit().await?;
         Ok(query)
     }
 }
@@ -13,20 +13,21 @@ use crate::util::param::get_username;
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 use super::activity_level::ActivityLevel;
+use super::bmr_formula::BmrFormula;
 use super::fitness_goal::FitnessGoal;
 use super::sex::Sex;
 
 #[cfg(feature = "ssr")]
 use crate::{
     auth::model::User, auth::service::get_request_user, error::Error, profile::model::Profile,
-    setup::get_pool,
+    setup::get_pool, user_privacy::section::PrivacySection,
 };
 
 #[server(endpoint = "get-profile-update")]
 async fn get_profile_update(username: String) -> Result<ProfileQuery, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Profile).await?;
     let date = Utc::now().date_naive();
     let object = ProfileQuery::get_latest_by_username(&pool, &username, date)
         .await?
@@ -35,6 +36,7 @@ async fn get_profile_update(username: String) -> Result<ProfileQuery, ServerFnEr
 }
 
 #[server(endpoint = "profile-update")]
+#[allow(clippy::too_many_arguments)]
 async fn profile_update(
     username: String,
     sex: String,
@@ -42,11 +44,14 @@ async fn profile_update(
     date_of_birth: NaiveDate,
     activity_level: String,
     fitness_goal: String,
+    bmr_formula: String,
+    body_fat_percentage: Option<Decimal>,
+    tdee_override: Option<i32>,
 ) -> Result<(), ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
 
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Profile).await?;
 
     let date = Utc::now().date_naive();
     let object = ProfileQuery::get_latest_by_username(&pool, &username, date)
@@ -58,9 +63,12 @@ async fn profile_update(
         &sex,
         &activity_level,
         &fitness_goal,
+        &bmr_formula,
         height,
         Decimal::from(50),
         date_of_birth,
+        body_fat_percentage,
+        tdee_override,
     )?;
     Profile::update(
         &pool,
@@ -70,7 +78,10 @@ async fn profile_update(
         date_of_birth,
         &activity_level,
         &fitness_goal,
-        user.id,
+        &bmr_formula,
+        body_fat_percentage,
+        tdee_override,
+        user.actor_id(),
     )
     .await?;
 
@@ -96,6 +107,15 @@ pub fn ProfileUpdatePage() -> impl IntoView {
             let sex_options = Sex::to_form_options();
             let activity_options = ActivityLevel::to_form_options();
             let goal_options = FitnessGoal::to_form_options();
+            let bmr_formula_options = BmrFormula::to_form_options();
+            let body_fat_percentage = data
+                .body_fat_percentage
+                .map(|value| value.to_string())
+                .unwrap_or_default();
+            let tdee_override = data
+                .tdee_override
+                .map(|value| value.to_string())
+                .unwrap_or_default();
 
             view! {
                 <ActionForm action>
@@ -124,6 +144,28 @@ pub fn ProfileUpdatePage() -> impl IntoView {
                         input_type="date"
                         value=data.date_of_birth.to_string()
                     />
+                    <FieldSelect
+                        name="bmr_formula"
+                        label="BMR formula"
+                        options=bmr_formula_options
+                        value=data.bmr_formula
+                    />
+                    <NumberInput
+                        action_value
+                        name="body_fat_percentage"
+                        label="Body Fat %"
+                        step="0.1"
+                        placeholder="Required for Katch-McArdle"
+                        value=body_fat_percentage
+                    />
+                    <NumberInput
+                        action_value
+                        name="tdee_override"
+                        label="TDEE Override"
+                        step="1"
+                        placeholder="Leave blank to calculate from activity level"
+                        value=tdee_override
+                    />
                     <SubmitButton loading=action_loading label="Update Profile"/>
                 </ActionForm>
             }
@@ -63,7 +63,7 @@ pub async fn profile_image_upload(data: MultipartData) -> Result<(), ServerFnErr
         }
     }
 
-    ProfileImage::update_profile_image(&pool, user.id, &new_file_name, user.id).await?;
+    ProfileImage::update_profile_image(&pool, user.id, &new_file_name, user.actor_id()).await?;
     Ok(())
 }
 
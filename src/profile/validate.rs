@@ -4,18 +4,23 @@ use rust_decimal::Decimal;
 use crate::util::validation_error::ValidationError;
 
 use super::activity_level::ActivityLevel;
+use super::bmr_formula::BmrFormula;
 use super::fitness_goal::FitnessGoal;
 use super::model::ProfileQuery;
 use super::sex::Sex;
 
 impl ProfileQuery {
+    #[allow(clippy::too_many_arguments)]
     pub fn validate(
         sex: &str,
         activity_level: &str,
         fitness_goal: &str,
+        bmr_formula: &str,
         height: Decimal,
         weight: Decimal,
         date_of_birth: NaiveDate,
+        body_fat_percentage: Option<Decimal>,
+        tdee_override: Option<i32>,
     ) -> Result<(), ValidationError> {
         let min_height = Decimal::from(50);
         let max_height = Decimal::from(250);
@@ -30,11 +35,29 @@ impl ProfileQuery {
             &ActivityLevel::all_variants(),
         );
         errors.validate_choice("fitness_goal", fitness_goal, &FitnessGoal::all_variants());
+        errors.validate_choice("bmr_formula", bmr_formula, &BmrFormula::all_variants());
 
         errors.validate_decimal("height", height, Some(min_height), Some(max_height));
         errors.validate_decimal("weight", weight, Some(min_weight), Some(max_weight));
         errors.validate_date("date_of_birth", date_of_birth, 365 * 150, 0);
 
+        if let Some(body_fat_percentage) = body_fat_percentage {
+            errors.validate_decimal(
+                "body_fat_percentage",
+                body_fat_percentage,
+                Some(Decimal::from(2)),
+                Some(Decimal::from(70)),
+            );
+        } else if matches!(bmr_formula.parse(), Ok(BmrFormula::KatchMcArdle)) {
+            errors.add_error(
+                "body_fat_percentage",
+                "Body fat percentage is required for the Katch-McArdle formula.".to_string(),
+            );
+        }
+        if let Some(tdee_override) = tdee_override {
+            errors.validate_number("tdee_override", tdee_override, Some(500), Some(10000));
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
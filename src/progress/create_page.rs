@@ -40,7 +40,7 @@ pub async fn progress_create(
         weight_kg,
         energy_burnt,
         notes,
-        user.id,
+        user.actor_id(),
     )
     .await?;
 
@@ -25,7 +25,7 @@ pub async fn progress_delete(username: String, date: NaiveDate) -> Result<(), Se
         .ok_or(Error::NotFound)?;
     object.can_delete(&user).await?;
 
-    Progress::delete(&pool, object.id).await?;
+    Progress::delete(&pool, object.id, user.actor_id()).await?;
 
     leptos_axum::redirect(&format!("/users/{}/{}", username, date));
 
@@ -3,6 +3,7 @@ use leptos_router::*;
 
 use chrono::prelude::*;
 
+use crate::component::link::Link;
 use crate::component::template::{
     DetailPageTemplate, ErrorComponent, LoadingComponent, UpdateDeleteButtonRow,
 };
@@ -12,7 +13,10 @@ use super::detail_table::ProgressDetailTable;
 use super::model::ProgressQuery;
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server(endpoint = "progress-detail-latest")]
 pub async fn get_progress_detail_latest(
@@ -21,7 +25,7 @@ pub async fn get_progress_detail_latest(
 ) -> Result<Option<ProgressQuery>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Progress).await?;
     let query = ProgressQuery::get_latest_by_username_date(&pool, &username, date).await?;
     Ok(query)
 }
@@ -33,7 +37,7 @@ pub async fn get_progress_detail(
 ) -> Result<ProgressQuery, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Progress).await?;
     let query = ProgressQuery::get_by_username_date(&pool, &username, date)
         .await?
         .ok_or(Error::NotFound)?;
@@ -53,6 +57,10 @@ pub fn ProgressDetailPage() -> impl IntoView {
         resource.and_then(|data| {
             view! {
                 <ProgressDetailTable data=data.clone()/>
+                <section class="flex gap-2 justify-end mt-4">
+                    <Link text="Photos" href="photos"/>
+                    <Link text="Compare" href="../compare"/>
+                </section>
                 <UpdateDeleteButtonRow/>
             }
         })
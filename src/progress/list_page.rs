@@ -20,7 +20,10 @@ use crate::progress::update_page::ProgressUpdate;
 use crate::util::param::{extract_page, extract_param, extract_size, UsernameParam};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server(endpoint = "progress-list", input = GetUrl)]
 pub async fn get_progress_list(
@@ -32,7 +35,7 @@ pub async fn get_progress_list(
 ) -> Result<Vec<ProgressQuery>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Progress).await?;
     let results = ProgressQuery::filter(&pool, &search, &username, &order, size, page).await?;
     Ok(results)
 }
@@ -44,7 +47,7 @@ pub async fn get_progress_list_count(
 ) -> Result<i64, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Progress).await?;
     let count = ProgressQuery::count(&pool, &search, &username).await?;
     Ok(count)
 }
@@ -19,7 +19,7 @@ pub struct Progress {
     pub updated_by_id: Option<Uuid>,
 }
 
-#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow, utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ProgressQuery {
     pub id: Uuid,
@@ -1,8 +1,9 @@
 use chrono::prelude::*;
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::util::database::Filter;
 
@@ -35,7 +36,7 @@ impl Progress {
     }
 
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         user_id: Uuid,
         date: NaiveDate,
         weight: Option<Decimal>,
@@ -67,7 +68,7 @@ impl Progress {
             notes,
             request_user_id,
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(query)
     }
@@ -108,10 +109,13 @@ impl Progress {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(Self, "DELETE FROM progress WHERE id = $1 RETURNING *", id)
-            .fetch_one(pool)
+            .fetch_one(&mut *tx)
             .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
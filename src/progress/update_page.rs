@@ -42,7 +42,7 @@ pub async fn progress_update(
         weight_kg,
         energy_burnt,
         notes,
-        user.id,
+        user.actor_id(),
     )
     .await?;
 
@@ -0,0 +1,152 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_router::*;
+
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::component::template::{DetailPageTemplate, ErrorComponent, LoadingComponent};
+use crate::util::datetime::DATE_FORMAT_SHORT;
+use crate::util::param::{extract_param, get_username};
+
+use super::model::ProgressPhotoQuery;
+use super::pose::PhotoPose;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::model::User, auth::service::get_request_user, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProgressPhotoCompare {
+    pub dates: Vec<NaiveDate>,
+    pub before: Option<NaiveDate>,
+    pub after: Option<NaiveDate>,
+    pub before_photos: Vec<ProgressPhotoQuery>,
+    pub after_photos: Vec<ProgressPhotoQuery>,
+}
+
+#[server(endpoint = "progress-photo-compare", input = GetUrl)]
+pub async fn get_progress_photo_compare(
+    username: String,
+    before: String,
+    after: String,
+) -> Result<ProgressPhotoCompare, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Progress).await?;
+
+    let dates = ProgressPhotoQuery::dates_by_username(&pool, &username).await?;
+    // Default to comparing the earliest photos against the most recent.
+    let before = NaiveDate::parse_from_str(&before, "%Y-%m-%d")
+        .ok()
+        .or_else(|| dates.last().copied());
+    let after = NaiveDate::parse_from_str(&after, "%Y-%m-%d")
+        .ok()
+        .or_else(|| dates.first().copied());
+
+    let before_photos = match before {
+        Some(date) => ProgressPhotoQuery::all_by_username_date(&pool, &username, date).await?,
+        None => Vec::new(),
+    };
+    let after_photos = match after {
+        Some(date) => ProgressPhotoQuery::all_by_username_date(&pool, &username, date).await?,
+        None => Vec::new(),
+    };
+
+    Ok(ProgressPhotoCompare {
+        dates,
+        before,
+        after,
+        before_photos,
+        after_photos,
+    })
+}
+
+#[component]
+pub fn ProgressPhotoComparePage() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+    let username = move || get_username(&params);
+    let before = move || extract_param(&query, "before");
+    let after = move || extract_param(&query, "after");
+
+    let resource = Resource::new(
+        move || (username(), before(), after()),
+        |(username, before, after)| get_progress_photo_compare(username, before, after),
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            let rows = PhotoPose::all_variants()
+                .into_iter()
+                .map(|pose| {
+                    let find = |photos: &[ProgressPhotoQuery]| {
+                        photos
+                            .iter()
+                            .find(|photo| photo.get_pose() == pose)
+                            .map(|photo| {
+                                let src = photo.image_url();
+                                view! { <img src=src alt=pose.to_string()/> }
+                            })
+                    };
+                    view! {
+                        <div class="font-bold col-span-2">{pose.to_string()}</div>
+                        <div>{find(&data.before_photos)}</div>
+                        <div>{find(&data.after_photos)}</div>
+                    }
+                })
+                .collect_view();
+            let dates = data.dates.clone();
+            let (before, after) = (data.before, data.after);
+            view! {
+                <Form method="GET" action="" class="flex gap-2 mb-4">
+                    <CompareDateSelect name="before" dates=dates.clone() selected=before/>
+                    <CompareDateSelect name="after" dates=dates selected=after/>
+                </Form>
+                <div class="grid grid-cols-2 gap-4">{rows}</div>
+            }
+        })
+    };
+
+    view! {
+        <DetailPageTemplate title="Compare Progress Photos">
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </DetailPageTemplate>
+    }
+}
+
+#[component]
+fn CompareDateSelect(
+    name: &'static str,
+    dates: Vec<NaiveDate>,
+    selected: Option<NaiveDate>,
+) -> impl IntoView {
+    let options = dates
+        .into_iter()
+        .map(|date| {
+            view! {
+                <option value=date.to_string() selected=Some(date) == selected>
+                    {date.format(DATE_FORMAT_SHORT).to_string()}
+                </option>
+            }
+        })
+        .collect_view();
+    view! {
+        <label class="block flex-1">
+            <span class="block mb-1 font-bold capitalize">{name}</span>
+            <select
+                name=name
+                onchange="this.form.requestSubmit()"
+                class="block py-1.5 px-3 w-full bg-white rounded border focus:border-blue-500 focus:ring-2 focus:ring-blue-500 focus:outline-none"
+            >
+                {options}
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,55 @@
+use axum::extract::Path;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::model::{RequestUser, User};
+use crate::error::{Error, Result};
+use crate::user_privacy::section::PrivacySection;
+
+use super::model::ProgressPhoto;
+
+/// Serves a progress photo from private storage once the request user has passed the
+/// owner's privacy level check.
+pub async fn progress_photo_handler(
+    Extension(pool): Extension<PgPool>,
+    request_user: Option<Extension<RequestUser>>,
+    Path(id): Path<Uuid>,
+) -> Response {
+    let request_user = request_user.map(|Extension(user)| user).unwrap_or_default();
+    match read_progress_photo(&pool, &request_user, id).await {
+        Ok((content_type, bytes)) => (
+            [
+                (header::CONTENT_TYPE, content_type),
+                (header::CACHE_CONTROL, "private, no-store"),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Err(Error::Forbidden) => StatusCode::FORBIDDEN.into_response(),
+        Err(Error::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+async fn read_progress_photo(
+    pool: &PgPool,
+    request_user: &RequestUser,
+    id: Uuid,
+) -> Result<(&'static str, Vec<u8>)> {
+    let photo = ProgressPhoto::get_by_id(pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    User::check_view_permission_by_user_id(
+        pool,
+        request_user,
+        photo.user_id,
+        PrivacySection::Progress,
+    )
+    .await?;
+    let upload_dir = ProgressPhoto::upload_dir().map_err(|_| Error::InternalServer)?;
+    let bytes = std::fs::read(upload_dir.join(&photo.file_name)).map_err(|_| Error::NotFound)?;
+    Ok((photo.content_type(), bytes))
+}
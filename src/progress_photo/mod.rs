@@ -0,0 +1,10 @@
+pub mod compare_page;
+#[cfg(feature = "ssr")]
+pub mod handler;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod permission;
+pub mod pose;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod upload_page;
@@ -0,0 +1,115 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::pose::PhotoPose;
+
+#[cfg(feature = "ssr")]
+use std::{env, path::PathBuf};
+
+#[cfg(feature = "ssr")]
+pub const PROGRESS_PHOTO_DIR: &str = "uploads/progress";
+
+#[cfg(feature = "ssr")]
+pub const PROGRESS_PHOTO_MAX_BYTES: usize = 10 * 1024 * 1024;
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct ProgressPhoto {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub progress_id: Uuid,
+    pub pose: i32,
+    pub file_name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub created_by_id: Uuid,
+    pub updated_by_id: Option<Uuid>,
+}
+
+#[cfg(feature = "ssr")]
+impl ProgressPhoto {
+    /// Progress photos are written outside of the public site root so they can only be
+    /// served through the authorised handler.
+    pub fn upload_dir() -> std::io::Result<PathBuf> {
+        Ok(env::current_dir()?.join(PROGRESS_PHOTO_DIR))
+    }
+
+    pub fn generate_filename(user_id: Uuid, date: NaiveDate, pose: i32, ext: &str) -> String {
+        let timestamp = Utc::now().format("%Y%m%dT%H%M%S").to_string();
+        format!(
+            "progress_{}_{}_{}_{}.{}",
+            user_id, date, pose, timestamp, ext
+        )
+    }
+
+    /// Returns the extension for the image format the bytes start with, or None when they are
+    /// not a JPEG, PNG or WebP image. The file name the client sent is not trusted.
+    pub fn sniff_extension(bytes: &[u8]) -> Option<&'static str> {
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some("jpg")
+        } else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+            Some("png")
+        } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+            Some("webp")
+        } else {
+            None
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        let ext = self.file_name.rsplit('.').next().unwrap_or_default();
+        match ext.to_lowercase().as_str() {
+            "jpg" | "jpeg" => "image/jpeg",
+            "png" => "image/png",
+            "webp" => "image/webp",
+            _ => "application/octet-stream",
+        }
+    }
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ProgressPhotoQuery {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub progress_id: Uuid,
+    pub pose: i32,
+    pub date: NaiveDate,
+    pub username: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProgressPhotoQuery {
+    pub fn get_pose(&self) -> PhotoPose {
+        PhotoPose::from(self.pose)
+    }
+
+    pub fn image_url(&self) -> String {
+        format!("/progress-photos/{}", self.id)
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_extension() {
+        assert_eq!(
+            ProgressPhoto::sniff_extension(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Some("jpg")
+        );
+        assert_eq!(
+            ProgressPhoto::sniff_extension(b"\x89PNG\r\n\x1a\n...."),
+            Some("png")
+        );
+        assert_eq!(
+            ProgressPhoto::sniff_extension(b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+            Some("webp")
+        );
+        assert_eq!(ProgressPhoto::sniff_extension(b"<svg></svg>"), None);
+        assert_eq!(ProgressPhoto::sniff_extension(b"RIFF"), None);
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum PhotoPose {
+    Front,
+    Side,
+    Back,
+    Unknown(i32),
+}
+
+impl fmt::Display for PhotoPose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhotoPose::Front => write!(f, "Front"),
+            PhotoPose::Side => write!(f, "Side"),
+            PhotoPose::Back => write!(f, "Back"),
+            PhotoPose::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl PhotoPose {
+    pub fn all_variants() -> Vec<PhotoPose> {
+        vec![PhotoPose::Front, PhotoPose::Side, PhotoPose::Back]
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        let options = [("1", "Front"), ("2", "Side"), ("3", "Back")];
+        options.to_vec()
+    }
+}
+
+impl From<i32> for PhotoPose {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => PhotoPose::Front,
+            2 => PhotoPose::Side,
+            3 => PhotoPose::Back,
+            _ => PhotoPose::Unknown(value),
+        }
+    }
+}
+
+impl From<PhotoPose> for i32 {
+    fn from(pose: PhotoPose) -> Self {
+        match pose {
+            PhotoPose::Front => 1,
+            PhotoPose::Side => 2,
+            PhotoPose::Back => 3,
+            PhotoPose::Unknown(value) => value,
+        }
+    }
+}
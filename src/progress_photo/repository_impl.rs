@@ -0,0 +1,144 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::Result;
+
+use super::model::{ProgressPhoto, ProgressPhotoQuery};
+
+impl ProgressPhoto {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM progress_photo WHERE id = $1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn get_by_progress_id_pose(
+        pool: &PgPool,
+        progress_id: Uuid,
+        pose: i32,
+    ) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM progress_photo WHERE progress_id = $1 AND pose = $2",
+            progress_id,
+            pose
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create_or_update(
+        pool: &PgPool,
+        user_id: Uuid,
+        progress_id: Uuid,
+        pose: i32,
+        file_name: &str,
+        request_user_id: Uuid,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                progress_photo (
+                    user_id,
+                    progress_id,
+                    pose,
+                    file_name,
+                    created_by_id
+                )
+            VALUES
+                ($1, $2, $3, $4, $5)
+            ON CONFLICT (progress_id, pose) DO UPDATE
+            SET
+                file_name = EXCLUDED.file_name,
+                updated_at = NOW(),
+                updated_by_id = EXCLUDED.created_by_id
+            RETURNING
+                *
+            ",
+            user_id,
+            progress_id,
+            pose,
+            file_name,
+            request_user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
+            Self,
+            "DELETE FROM progress_photo WHERE id = $1 RETURNING *",
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+}
+
+impl ProgressPhotoQuery {
+    pub async fn all_by_username_date(
+        pool: &PgPool,
+        username: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.id,
+                t1.user_id,
+                t1.progress_id,
+                t1.pose,
+                t2.date,
+                t3.username,
+                t1.created_at
+            FROM
+                progress_photo t1
+                INNER JOIN progress t2 ON t2.id = t1.progress_id
+                INNER JOIN users_user t3 ON t3.id = t1.user_id
+            WHERE
+                t3.username = $1
+                AND t2.date = $2
+            ORDER BY
+                t1.pose
+            ",
+            username,
+            date
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn dates_by_username(pool: &PgPool, username: &str) -> Result<Vec<NaiveDate>> {
+        let query: Vec<NaiveDate> = sqlx::query_scalar(
+            "
+            SELECT DISTINCT
+                t2.date
+            FROM
+                progress_photo t1
+                INNER JOIN progress t2 ON t2.id = t1.progress_id
+                INNER JOIN users_user t3 ON t3.id = t1.user_id
+            WHERE
+                t3.username = $1
+            ORDER BY
+                t2.date DESC
+            ",
+        )
+        .bind(username)
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
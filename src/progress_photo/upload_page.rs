@@ -0,0 +1,225 @@
+use leptos::*;
+use leptos_router::*;
+use server_fn::codec::{MultipartData, MultipartFormData};
+
+use chrono::prelude::*;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{FormData, HtmlFormElement, SubmitEvent};
+
+use crate::component::button::SubmitButton;
+use crate::component::select::FieldSelect;
+use crate::component::template::{DetailPageTemplate, ErrorComponent, LoadingComponent};
+use crate::util::param::{get_date, get_username};
+
+use super::model::ProgressPhotoQuery;
+use super::pose::PhotoPose;
+
+#[cfg(feature = "ssr")]
+use {
+    super::model::{ProgressPhoto, PROGRESS_PHOTO_MAX_BYTES},
+    crate::{
+        auth::model::User, auth::service::get_request_user, error::Error,
+        progress::model::Progress, setup::get_pool, user_privacy::section::PrivacySection,
+    },
+    std::fs::{self, File},
+    std::io::Write,
+};
+
+#[server(endpoint = "progress-photo-list")]
+pub async fn get_progress_photo_list(
+    username: String,
+    date: NaiveDate,
+) -> Result<Vec<ProgressPhotoQuery>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Progress).await?;
+    let query = ProgressPhotoQuery::all_by_username_date(&pool, &username, date).await?;
+    Ok(query)
+}
+
+#[server(input = MultipartFormData)]
+pub async fn progress_photo_upload(data: MultipartData) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let mut data = data.into_inner().ok_or(Error::FileUpload)?;
+
+    let mut username = String::new();
+    let mut date = String::new();
+    let mut pose = String::new();
+    let mut file_bytes = Vec::new();
+
+    while let Some(mut field) = data.next_field().await.map_err(|_| Error::FileUpload)? {
+        let field_name = field.name().unwrap_or_default().to_string();
+        if field_name == "username" {
+            username = field.text().await.unwrap_or_default();
+        } else if field_name == "date" {
+            date = field.text().await.unwrap_or_default();
+        } else if field_name == "pose" {
+            pose = field.text().await.unwrap_or_default();
+        } else if field_name == "file_upload" {
+            while let Some(chunk) = field.chunk().await.map_err(|_| Error::FileUpload)? {
+                if file_bytes.len() + chunk.len() > PROGRESS_PHOTO_MAX_BYTES {
+                    return Err(Error::Other("Photos must be 10 MB or smaller.".into()).into());
+                }
+                file_bytes.extend_from_slice(&chunk);
+            }
+        }
+    }
+
+    let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d").map_err(|_| Error::FileUpload)?;
+    let pose = pose.parse::<i32>().map_err(|_| Error::FileUpload)?;
+    if matches!(PhotoPose::from(pose), PhotoPose::Unknown(_)) {
+        return Err(Error::FileUpload.into());
+    }
+    let file_extension = ProgressPhoto::sniff_extension(&file_bytes)
+        .ok_or_else(|| Error::Other("Upload a JPEG, PNG or WebP image.".into()))?;
+
+    let target_user = User::get_by_username(&pool, &username)
+        .await?
+        .ok_or(Error::NotFound)?;
+    ProgressPhoto::can_create(&user, target_user.id).await?;
+
+    let progress = match Progress::get_by_username_date(&pool, &username, date).await? {
+        Some(progress) => progress,
+        None => {
+            Progress::create(
+                &pool,
+                target_user.id,
+                date,
+                None,
+                None,
+                None,
+                user.actor_id(),
+            )
+            .await?
+        }
+    };
+
+    let upload_dir = ProgressPhoto::upload_dir().map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !upload_dir.exists() {
+        fs::create_dir_all(&upload_dir).map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    let new_file_name =
+        ProgressPhoto::generate_filename(target_user.id, date, pose, file_extension);
+    let file_path = upload_dir.join(&new_file_name);
+    let mut file = File::create(&file_path).map_err(|e| ServerFnError::new(e.to_string()))?;
+    file.write_all(&file_bytes)
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let existing = ProgressPhoto::get_by_progress_id_pose(&pool, progress.id, pose).await?;
+    ProgressPhoto::create_or_update(
+        &pool,
+        target_user.id,
+        progress.id,
+        pose,
+        &new_file_name,
+        user.actor_id(),
+    )
+    .await?;
+    if let Some(existing) = existing {
+        let _ = fs::remove_file(upload_dir.join(existing.file_name));
+    }
+    Ok(())
+}
+
+#[server(endpoint = "progress-photo-delete")]
+pub async fn progress_photo_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+
+    let object = ProgressPhoto::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    object.can_delete(&user).await?;
+
+    ProgressPhoto::delete(&pool, object.id, user.actor_id()).await?;
+    if let Ok(upload_dir) = ProgressPhoto::upload_dir() {
+        let _ = fs::remove_file(upload_dir.join(object.file_name));
+    }
+    Ok(())
+}
+
+#[component]
+pub fn ProgressPhotoUploadPage() -> impl IntoView {
+    let params = use_params_map();
+    let username = move || get_username(&params);
+    let date = move || get_date(&params);
+
+    let upload_action = create_action(|data: &FormData| {
+        let data = data.clone();
+        progress_photo_upload(data.into())
+    });
+    let upload_loading = upload_action.pending();
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        let target = ev.target().unwrap().unchecked_into::<HtmlFormElement>();
+        let form_data = FormData::new_with_form(&target).unwrap();
+        upload_action.dispatch(form_data);
+    };
+
+    let action_delete = Action::<ProgressPhotoDelete, _>::server();
+
+    let resource = Resource::new(
+        move || {
+            (
+                username(),
+                date(),
+                upload_action.version().get(),
+                action_delete.version().get(),
+            )
+        },
+        |(username, date, _, _)| get_progress_photo_list(username, date),
+    );
+    let response = move || {
+        resource.and_then(|data| {
+            data.iter()
+                .map(|photo| {
+                    let id = photo.id;
+                    let pose = photo.get_pose().to_string();
+                    let src = photo.image_url();
+                    view! {
+                        <div class="flex flex-col gap-2">
+                            <div class="font-bold">{pose.clone()}</div>
+                            <img src=src alt=pose/>
+                            <ActionForm action=action_delete>
+                                <input type="hidden" name="id" value=id.to_string()/>
+                                <SubmitButton label="Delete"/>
+                            </ActionForm>
+                        </div>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <DetailPageTemplate title="Progress Photos">
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>
+                    <div class="grid grid-cols-3 gap-4 mb-4">{response}</div>
+                </ErrorBoundary>
+            </Transition>
+
+            <form on:submit=on_submit>
+                <input type="hidden" name="username" value=username/>
+                <input type="hidden" name="date" value=move || date().to_string()/>
+                <FieldSelect name="pose" options=PhotoPose::to_form_options()/>
+                <label class="block my-4">
+                    <div class="mb-1">"Upload"</div>
+                    <input
+                        name="file_upload"
+                        type="file"
+                        accept="image/jpeg,image/png,image/webp"
+                        class="block w-full rounded border px-3 py-1.5 shadow-sm focus:border-blue-500 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    />
+                </label>
+                <SubmitButton loading=upload_loading/>
+            </form>
+        </DetailPageTemplate>
+    }
+}
@@ -10,7 +10,7 @@ use crate::component::icon::IconFilePlus;
 #[cfg(feature = "ssr")]
 use crate::{
     auth::service::get_request_user, error::Error, exercise::model::ExerciseBase,
-    set::model::SetModel, setup::get_pool, workout::model::WorkoutBase,
+    notification::service, set::model::SetModel, setup::get_pool, workout::model::WorkoutBase,
 };
 
 #[server(endpoint = "set-create")]
@@ -30,14 +30,25 @@ pub async fn set_create(
     let workout = WorkoutBase::get_by_id(&pool, exercise.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_update(&pool, &user).await?;
 
     let weight = weight.unwrap_or_default();
     let reps = reps.unwrap_or_default();
     let rest = rest.unwrap_or_default();
 
     SetModel::validate(order, weight, reps, rest)?;
-    SetModel::create(&pool, exercise_id, order, weight, reps, rest, user.id).await?;
+    SetModel::create(
+        &pool,
+        exercise_id,
+        order,
+        weight,
+        reps,
+        rest,
+        None,
+        user.actor_id(),
+    )
+    .await?;
+    service::notify_personal_record(&pool, exercise_id).await;
     Ok(())
 }
 
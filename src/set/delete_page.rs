@@ -31,9 +31,9 @@ pub async fn set_delete(set_id: Uuid, redirect_to: Option<String>) -> Result<(),
     let workout = WorkoutBase::get_by_id(&pool, exercise.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_update(&pool, &user).await?;
 
-    SetModel::delete(&pool, set_id).await?;
+    SetModel::delete(&pool, set_id, user.actor_id()).await?;
 
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
@@ -1,7 +1,8 @@
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Result;
 use crate::exercise::model::ExerciseBase;
 // use crate::exercise_plan::model::ExercisePlan;
@@ -16,27 +17,30 @@ impl SetModel {
         Ok(query)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         exercise_id: Uuid,
         order: i32,
         weight: Decimal,
         reps: i32,
         rest: i32,
+        notes: Option<String>,
         request_user_id: Uuid,
     ) -> Result<Self> {
         let query = sqlx::query_as!(
             Self,
-            r#"INSERT INTO tracked_set (exercise_id, "order", weight, reps, rest, created_by_id)
-            VALUES ($1, $2, $3, $4, $5, $6) RETURNING *"#,
+            r#"INSERT INTO tracked_set (exercise_id, "order", weight, reps, rest, notes, created_by_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *"#,
             exercise_id,
             order,
             weight,
             reps,
             rest,
+            notes,
             request_user_id,
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
         Ok(query)
     }
@@ -75,14 +79,17 @@ impl SetModel {
         Ok(query)
     }
 
-    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<Self> {
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query_as!(
             Self,
             "DELETE FROM tracked_set WHERE id = $1 RETURNING *",
             id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
+        tx.commit().await?;
         Ok(query)
     }
 
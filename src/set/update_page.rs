@@ -40,7 +40,7 @@ pub async fn set_update(
     let workout = WorkoutBase::get_by_id(&pool, exercise.workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_update(&pool, &user).await?;
 
     SetModel::validate(order, weight, reps, rest)?;
     SetModel::update(
@@ -51,7 +51,7 @@ pub async fn set_update(
         weight,
         reps,
         rest,
-        user.id,
+        user.actor_id(),
     )
     .await?;
     Ok(())
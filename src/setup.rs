@@ -4,7 +4,8 @@ use leptos::{get_configuration, use_context, *};
 use leptos_axum::{generate_route_list, LeptosRoutes};
 
 use axum::response::IntoResponse;
-use axum::Router;
+use axum::routing::get;
+use axum::{Extension, Router};
 use sqlx::postgres::{PgPool, PgPoolOptions};
 use tokio::net::TcpListener;
 use tower::ServiceBuilder;
@@ -14,9 +15,16 @@ use tower_http::trace::{
 };
 use tracing::Level;
 
+use crate::account_deletion::service::run_account_deletion_sweeper;
+use crate::api::router::api_router;
 use crate::app::App;
+use crate::data_export::handler::data_export_handler;
 use crate::error::{Error, Result};
+use crate::login_throttle::service::run_login_throttle_sweeper;
 use crate::middleware::auth_token_middleware;
+use crate::oidc::handler::{oidc_callback_handler, oidc_login_handler};
+use crate::progress_photo::handler::progress_photo_handler;
+use crate::user_session::service::run_user_session_sweeper;
 
 pub fn get_pool() -> Result<PgPool> {
     use_context::<PgPool>().ok_or(Error::InternalServer)
@@ -30,6 +38,12 @@ pub async fn initialize_db_pool(database_url: &str) -> PgPool {
         .expect("could not create a database pool")
 }
 
+pub fn spawn_background_tasks(pool: &PgPool) {
+    tokio::spawn(run_account_deletion_sweeper(pool.clone()));
+    tokio::spawn(run_login_throttle_sweeper(pool.clone()));
+    tokio::spawn(run_user_session_sweeper(pool.clone()));
+}
+
 pub async fn load_leptos_config() -> LeptosOptions {
     get_configuration(None).await.unwrap().leptos_options
 }
@@ -55,6 +69,20 @@ pub async fn setup_router(pool: PgPool, leptos_options: LeptosOptions) -> Router
         .route_service("/favicon.ico", favicon)
         .nest_service("/images", image_dir)
         .nest_service("/pkg", site_pkg_dir)
+        .route(
+            "/progress-photos/:id",
+            get(progress_photo_handler).layer(Extension(pool.clone())),
+        )
+        .route(
+            "/settings/export/:id/download",
+            get(data_export_handler).layer(Extension(pool.clone())),
+        )
+        .route("/auth/oidc/login", get(oidc_login_handler))
+        .route(
+            "/auth/oidc/callback",
+            get(oidc_callback_handler).layer(Extension(pool.clone())),
+        )
+        .nest("/api/v1", api_router(pool.clone()))
         .leptos_routes_with_context(
             &shared_leptos_options,
             routes,
@@ -71,7 +99,10 @@ pub async fn setup_router(pool: PgPool, leptos_options: LeptosOptions) -> Router
         })
         .layer(
             ServiceBuilder::new()
-                .layer(axum::middleware::from_fn(auth_token_middleware))
+                .layer(axum::middleware::from_fn_with_state(
+                    pool.clone(),
+                    auth_token_middleware,
+                ))
                 .layer(
                     TraceLayer::new_for_http()
                         .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
@@ -87,7 +118,10 @@ pub async fn start_server(addr: &SocketAddr, app: Router) {
     let listener = TcpListener::bind(addr)
         .await
         .expect("failed to bind address");
-    axum::serve(listener, app.into_make_service())
-        .await
-        .unwrap();
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
@@ -0,0 +1,127 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::kind::TemplateKind;
+use super::model::SharedTemplateStatus;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user,
+    error::Error,
+    setup::get_pool,
+    shared_template::model::{SharedTemplate, SharedTemplateQuery},
+    util::validation_error::ValidationError,
+};
+
+#[server(endpoint = "shared-template-status", input = GetUrl)]
+pub async fn get_shared_template_status(
+    kind: i32,
+    source_id: Uuid,
+) -> Result<SharedTemplateStatus, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let owner_id = SharedTemplate::get_source_owner_id(&pool, kind.into(), source_id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let can_publish = SharedTemplate::can_create(owner_id, &user).is_ok();
+    let template = SharedTemplateQuery::get_by_source_id(&pool, source_id).await?;
+    Ok(SharedTemplateStatus {
+        can_publish,
+        template,
+    })
+}
+
+#[server(endpoint = "shared-template-create")]
+pub async fn shared_template_create(
+    kind: i32,
+    source_id: Uuid,
+    name: String,
+    description: String,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let kind = TemplateKind::from(kind);
+    let owner_id = SharedTemplate::get_source_owner_id(&pool, kind, source_id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    SharedTemplate::can_create(owner_id, &user)?;
+
+    let name = name.trim();
+    let description = description.trim();
+    SharedTemplate::validate(name, description)?;
+    if SharedTemplateQuery::get_by_source_id(&pool, source_id)
+        .await?
+        .is_some()
+    {
+        let mut errors = ValidationError::new();
+        errors.add_non_field_error("This has already been shared as a template.");
+        return Err(Error::from(errors).into());
+    }
+    SharedTemplate::create(&pool, user.id, kind, source_id, name, description).await?;
+    Ok(())
+}
+
+/// Shows the template a meal or workout is shared as, or a form for its owner to share it.
+#[component]
+pub fn SharedTemplatePanel(kind: TemplateKind, source_id: Uuid, name: String) -> impl IntoView {
+    let action = Action::<SharedTemplateCreate, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error =
+        move || extract_other_errors(action_value, &["non_field_errors", "name", "description"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    let resource = Resource::new(
+        move || action.version().get(),
+        move |_| get_shared_template_status(kind.into(), source_id),
+    );
+    let name = store_value(name);
+
+    let response = move || {
+        resource.and_then(|data| match (&data.template, data.can_publish) {
+            (Some(template), _) => {
+                let clones = format!("Cloned {} times", template.clone_count);
+                let template_name = template.name.clone();
+                view! {
+                    <p>
+                        "Shared as "
+                        <A href=template.href() class="font-bold hover:underline">
+                            {template_name}
+                        </A>
+                        ". "
+                        {clones}
+                    </p>
+                }
+                .into_view()
+            }
+            (None, true) => view! {
+                <h2 class="mb-2 text-base font-bold">"Share as Template"</h2>
+                <p class="mb-4">"Anyone can view and clone a shared template."</p>
+                <div class="mb-4 font-bold text-red-500">{action_error}</div>
+                <div class="mb-4 font-bold text-red-500">{non_field_errors}</div>
+                <ActionForm action>
+                    <input type="hidden" name="kind" value=i32::from(kind)/>
+                    <input type="hidden" name="source_id" value=source_id.to_string()/>
+                    <TextInput action_value name="name" value=name.get_value()/>
+                    <TextInput action_value name="description"/>
+                    <SubmitButton loading=action_loading label="Share"/>
+                </ActionForm>
+            }
+            .into_view(),
+            (None, false) => ().into_view(),
+        })
+    };
+
+    view! {
+        <section class="mt-4">
+            <Transition>{response}</Transition>
+        </section>
+    }
+}
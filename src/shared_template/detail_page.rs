@@ -0,0 +1,212 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use chrono::prelude::*;
+use uuid::Uuid;
+
+use crate::component::button::{Button, ButtonVariant, SubmitButton};
+use crate::component::input::TextInput;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::param::get_slug;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::kind::TemplateKind;
+use super::model::{SharedTemplateDetail, SharedTemplateItem};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user,
+    error::Error,
+    meal::model::Meal,
+    setup::get_pool,
+    shared_template::model::{SharedTemplate, SharedTemplateQuery},
+};
+
+#[server(endpoint = "shared-template-detail", input = GetUrl)]
+pub async fn get_shared_template_detail(
+    slug: String,
+) -> Result<SharedTemplateDetail, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let template = SharedTemplate::get_by_slug(&pool, &slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let items = SharedTemplateItem::all_by_template(&pool, &template).await?;
+    let can_delete = template.can_delete(&user).is_ok();
+    let template = SharedTemplateQuery::get_by_slug(&pool, &slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(SharedTemplateDetail {
+        template,
+        items,
+        can_delete,
+    })
+}
+
+#[server(endpoint = "shared-template-clone-meal")]
+pub async fn shared_template_clone_meal(slug: String, name: String) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let template = SharedTemplate::get_by_slug(&pool, &slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    template.can_clone(&user, TemplateKind::Meal)?;
+    Meal::validate(&name)?;
+    let meal_id = template.clone_meal(&pool, user.id, &name).await?;
+    leptos_axum::redirect(&format!("/food/meals/{}", meal_id));
+    Ok(())
+}
+
+#[server(endpoint = "shared-template-clone-workout")]
+pub async fn shared_template_clone_workout(
+    slug: String,
+    date: NaiveDate,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let template = SharedTemplate::get_by_slug(&pool, &slug)
+        .await?
+        .ok_or(Error::NotFound)?;
+    template.can_clone(&user, TemplateKind::Workout)?;
+    let workout_id = template.clone_workout(&pool, user.id, date).await?;
+    leptos_axum::redirect(&format!(
+        "/users/{}/workouts/{}/{}",
+        user.username, date, workout_id
+    ));
+    Ok(())
+}
+
+#[server(endpoint = "shared-template-delete")]
+pub async fn shared_template_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let template = SharedTemplate::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    template.can_delete(&user)?;
+    SharedTemplate::delete(&pool, template.id, user.actor_id()).await?;
+    leptos_axum::redirect("/templates");
+    Ok(())
+}
+
+#[component]
+pub fn SharedTemplateDetailPage() -> impl IntoView {
+    let params = use_params_map();
+    let slug = move || get_slug(&params);
+
+    let action_delete = Action::<SharedTemplateDelete, _>::server();
+    let resource = Resource::new(slug, get_shared_template_detail);
+
+    let response = move || {
+        resource.and_then(|data| {
+            let template = &data.template;
+            let id = template.id;
+            let kind = template.get_kind();
+            let clones = format!("Cloned {} times", template.clone_count);
+            let delete = data.can_delete.then(|| {
+                view! {
+                    <ActionForm action=action_delete class="contents">
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <Button label="Unshare" variant=ButtonVariant::Danger/>
+                    </ActionForm>
+                }
+            });
+            let clone_form = match kind {
+                TemplateKind::Meal => view! {
+                    <MealCloneForm slug=template.slug.clone() name=template.name.clone()/>
+                }
+                .into_view(),
+                _ => view! { <WorkoutCloneForm slug=template.slug.clone()/> }.into_view(),
+            };
+            view! {
+                <header class="flex flex-wrap gap-2 justify-between mb-4">
+                    <div>
+                        <h1 class="text-xl font-bold">{template.name.clone()}</h1>
+                        <p>{kind.to_string()} " by " {template.username.clone()}</p>
+                        <p class="text-sm text-gray-500">{clones}</p>
+                    </div>
+                    <div class="flex gap-2 items-start">{delete}</div>
+                </header>
+                <p class="mb-4">{template.description.clone()}</p>
+                <SharedTemplateItemList items=data.items.clone()/>
+                <section class="pt-4 mt-4 border-t">{clone_form}</section>
+            }
+        })
+    };
+
+    let delete_error = move || extract_other_errors(action_delete.value(), &[]);
+
+    view! {
+        <Title text="Template"/>
+        <main class="p-4 m-4 bg-white border">
+            <div class="mb-4 font-bold text-red-500">{delete_error}</div>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </main>
+    }
+}
+
+#[component]
+pub fn SharedTemplateItemList(items: Vec<SharedTemplateItem>) -> impl IntoView {
+    let rows = items
+        .into_iter()
+        .map(|item| {
+            view! {
+                <div class="contents group">
+                    <div class="p-2 border-b group-hover:bg-amber-200 group-odd:bg-gray-50">
+                        {item.name}
+                    </div>
+                    <div class="p-2 border-b text-end group-hover:bg-amber-200 group-odd:bg-gray-50">
+                        {item.detail}
+                    </div>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! { <section class="grid grid-cols-[1fr_auto]">{rows}</section> }
+}
+
+#[component]
+fn MealCloneForm(slug: String, name: String) -> impl IntoView {
+    let action = Action::<SharedTemplateCloneMeal, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors", "name"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    view! {
+        <h2 class="mb-2 text-base font-bold">"Add to my meals"</h2>
+        <div class="mb-4 font-bold text-red-500">{action_error}</div>
+        <div class="mb-4 font-bold text-red-500">{non_field_errors}</div>
+        <ActionForm action>
+            <input type="hidden" name="slug" value=slug/>
+            <TextInput action_value name="name" value=name/>
+            <SubmitButton loading=action_loading label="Clone Meal"/>
+        </ActionForm>
+    }
+}
+
+#[component]
+fn WorkoutCloneForm(slug: String) -> impl IntoView {
+    let action = Action::<SharedTemplateCloneWorkout, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors", "date"]);
+    let today = Utc::now().date_naive();
+
+    view! {
+        <h2 class="mb-2 text-base font-bold">"Add to my workouts"</h2>
+        <div class="mb-4 font-bold text-red-500">{action_error}</div>
+        <ActionForm action>
+            <input type="hidden" name="slug" value=slug/>
+            <TextInput action_value name="date" input_type="date" value=today.to_string()/>
+            <SubmitButton loading=action_loading label="Clone Workout"/>
+        </ActionForm>
+    }
+}
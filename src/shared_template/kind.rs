@@ -0,0 +1,46 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum TemplateKind {
+    Meal,
+    Workout,
+    Unknown(i32),
+}
+
+impl fmt::Display for TemplateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateKind::Meal => write!(f, "Meal"),
+            TemplateKind::Workout => write!(f, "Workout"),
+            TemplateKind::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl TemplateKind {
+    pub fn to_filter_options() -> Vec<(&'static str, &'static str)> {
+        vec![("", "All"), ("1", "Meals"), ("2", "Workouts")]
+    }
+}
+
+impl From<i32> for TemplateKind {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => TemplateKind::Meal,
+            2 => TemplateKind::Workout,
+            _ => TemplateKind::Unknown(value),
+        }
+    }
+}
+
+impl From<TemplateKind> for i32 {
+    fn from(kind: TemplateKind) -> Self {
+        match kind {
+            TemplateKind::Meal => 1,
+            TemplateKind::Workout => 2,
+            TemplateKind::Unknown(value) => value,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use leptos::server_fn::codec::GetUrl;
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::input::FilterInput;
+use crate::component::paginator::Paginator;
+use crate::component::select::FilterSelect;
+use crate::component::template::{
+    ErrorComponent, ListLoadingComponent, ListNotFoundComponent, Loading,
+};
+use crate::util::misc::ListResponse;
+use crate::util::param::{extract_page, extract_param, extract_size};
+
+use super::kind::TemplateKind;
+use super::model::SharedTemplateQuery;
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, setup::get_pool};
+
+#[server(endpoint = "shared-template-list", input = GetUrl)]
+pub async fn get_shared_template_list(
+    search: String,
+    kind: String,
+    order: String,
+    size: i64,
+    page: i64,
+) -> Result<ListResponse<SharedTemplateQuery>, ServerFnError> {
+    get_request_user()?;
+    let pool = get_pool()?;
+    let count = SharedTemplateQuery::count(&pool, &search, &kind).await?;
+    let results = SharedTemplateQuery::filter(&pool, &search, &kind, &order, size, page).await?;
+    Ok(ListResponse { count, results })
+}
+
+#[component]
+pub fn SharedTemplateListPage() -> impl IntoView {
+    let query = use_query_map();
+    let search = move || extract_param(&query, "search");
+    let kind = move || extract_param(&query, "kind");
+    let order = move || extract_param(&query, "order");
+    let size = move || extract_size(&query);
+    let page = move || extract_page(&query);
+
+    let resource = Resource::new(
+        move || (search(), kind(), order(), size(), page()),
+        |(search, kind, order, size, page)| {
+            get_shared_template_list(search, kind, order, size, page)
+        },
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.results.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.results
+                    .iter()
+                    .map(|data| view! { <SharedTemplateListItem data=data.clone()/> })
+                    .collect_view()
+            }
+        })
+    };
+    let count = move || {
+        resource.with(|res| {
+            res.as_ref()
+                .and_then(|data| data.as_ref().ok().map(|res| res.count))
+        })
+    };
+
+    view! {
+        <Title text="Templates"/>
+        <main class="md:p-4">
+            <div class="p-4 bg-white border">
+                <header class="mb-4">
+                    <h1 class="text-xl font-bold">"Templates"</h1>
+                    <p class="text-gray-400">
+                        "Results: " <Transition fallback=Loading>{count}</Transition>
+                    </p>
+                </header>
+
+                <section class="flex flex-wrap gap-2 mb-4">
+                    <Form method="GET" action="" class="contents">
+                        <input type="hidden" name="size" value=size/>
+                        <input type="hidden" name="page" value=1/>
+                        <FilterInput name="search" value=Signal::derive(search)/>
+                        <FilterSelect
+                            name="kind"
+                            value=Signal::derive(kind)
+                            options=TemplateKind::to_filter_options()
+                        />
+                        <FilterSelect
+                            name="order"
+                            value=Signal::derive(order)
+                            options=SharedTemplateQuery::to_sort_options()
+                        />
+                    </Form>
+                </section>
+
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+
+                <Form method="GET" action="" class="contents">
+                    <input type="hidden" name="search" value=search/>
+                    <input type="hidden" name="kind" value=kind/>
+                    <input type="hidden" name="order" value=order/>
+                    <input type="hidden" name="page" value=page/>
+                    <Transition>
+                        <Paginator count/>
+                    </Transition>
+                </Form>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+pub fn SharedTemplateListItem(data: SharedTemplateQuery) -> impl IntoView {
+    let href = data.href();
+    let kind = data.get_kind().to_string();
+    let items = match data.get_kind() {
+        TemplateKind::Meal => format!("{} foods", data.item_count),
+        _ => format!("{} exercises", data.item_count),
+    };
+    let clones = format!("{} clones", data.clone_count);
+
+    view! {
+        <A href=href class="flex gap-2 justify-between items-start p-2 mb-2 bg-gray-100 hover:bg-amber-200">
+            <div>
+                <div class="font-bold">{data.name}</div>
+                <div class="text-sm">{kind} " by " {data.username}</div>
+                <div class="text-xs text-gray-500">{data.description}</div>
+            </div>
+            <div class="text-sm text-end">
+                <div>{items}</div>
+                <div class="font-bold">{clones}</div>
+            </div>
+        </A>
+    }
+}
@@ -0,0 +1,11 @@
+pub mod component;
+pub mod detail_page;
+pub mod kind;
+pub mod list_page;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod permission;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+#[cfg(feature = "ssr")]
+pub mod validate;
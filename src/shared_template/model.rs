@@ -0,0 +1,82 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::kind::TemplateKind;
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct SharedTemplate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: i32,
+    pub meal_id: Option<Uuid>,
+    pub workout_id: Option<Uuid>,
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub clone_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SharedTemplateQuery {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub username: String,
+    pub kind: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub clone_count: i32,
+    /// Foods in a meal template, or exercises in a workout template.
+    pub item_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SharedTemplateQuery {
+    const SORT_OPTIONS_DISPLAY: &'static [(&'static str, &'static str)] = &[
+        ("-clone_count", "Most Cloned"),
+        ("-created_at", "Newest"),
+        ("name", "Name (A-z)"),
+        ("-name", "Name (Z-a)"),
+    ];
+
+    pub fn get_kind(&self) -> TemplateKind {
+        TemplateKind::from(self.kind)
+    }
+
+    pub fn href(&self) -> String {
+        format!("/templates/{}", self.slug)
+    }
+
+    pub fn to_sort_options() -> Vec<(&'static str, &'static str)> {
+        let options = Self::SORT_OPTIONS_DISPLAY;
+        options.to_vec()
+    }
+}
+
+/// A food or exercise in a template, as shown before it is cloned.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SharedTemplateItem {
+    pub name: String,
+    pub detail: String,
+}
+
+/// Whether the request user can share a meal or workout, and its template if already shared.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SharedTemplateStatus {
+    pub can_publish: bool,
+    pub template: Option<SharedTemplateQuery>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SharedTemplateDetail {
+    pub template: SharedTemplateQuery,
+    pub items: Vec<SharedTemplateItem>,
+    pub can_delete: bool,
+}
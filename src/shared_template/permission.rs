@@ -0,0 +1,41 @@
+use uuid::Uuid;
+
+use crate::auth::model::RequestUser;
+use crate::error::{Error, Result};
+
+use super::kind::TemplateKind;
+use super::model::SharedTemplate;
+
+impl SharedTemplate {
+    /// Only the owner of a meal or workout can share it, so coaches cannot publish a client's.
+    pub fn can_create(owner_id: Uuid, user: &RequestUser) -> Result<()> {
+        if owner_id == user.id {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+
+    /// A template can only be cloned as the kind it was shared as.
+    pub fn can_clone(&self, user: &RequestUser, kind: TemplateKind) -> Result<()> {
+        if !user.is_active {
+            return Err(Error::Forbidden);
+        }
+        if TemplateKind::from(self.kind) != kind {
+            let kind = kind.to_string().to_lowercase();
+            return Err(Error::Other(format!(
+                "This template cannot be cloned as a {}.",
+                kind
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn can_delete(&self, user: &RequestUser) -> Result<()> {
+        if self.user_id == user.id || user.is_superuser {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+}
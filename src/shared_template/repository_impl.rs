@@ -0,0 +1,452 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::{handle_sqlx_contraint_error, Result};
+use crate::util::database::Filter;
+use crate::util::server::{normalize_whitespace, slugify};
+
+use super::kind::TemplateKind;
+use super::model::{SharedTemplate, SharedTemplateItem, SharedTemplateQuery};
+
+impl SharedTemplate {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM shared_template WHERE id = $1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM shared_template WHERE slug = $1", slug)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    /// The user a meal or workout belongs to, if it exists.
+    pub async fn get_source_owner_id(
+        pool: &PgPool,
+        kind: TemplateKind,
+        source_id: Uuid,
+    ) -> Result<Option<Uuid>> {
+        let query = match kind {
+            TemplateKind::Meal => {
+                sqlx::query_scalar!("SELECT user_id FROM meal WHERE id = $1", source_id)
+                    .fetch_optional(pool)
+                    .await?
+            }
+            TemplateKind::Workout => {
+                sqlx::query_scalar!("SELECT user_id FROM workout WHERE id = $1", source_id)
+                    .fetch_optional(pool)
+                    .await?
+            }
+            TemplateKind::Unknown(_) => None,
+        };
+        Ok(query)
+    }
+
+    /// The slug is the slugified name followed by the start of the template id, so templates
+    /// can share a name.
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        kind: TemplateKind,
+        source_id: Uuid,
+        name: &str,
+        description: &str,
+    ) -> Result<Self> {
+        let (meal_id, workout_id) = match kind {
+            TemplateKind::Meal => (Some(source_id), None),
+            _ => (None, Some(source_id)),
+        };
+        let kind = i32::from(kind);
+        let slug = slugify(name);
+        let query = sqlx::query_as!(
+            Self,
+            "
+            WITH
+                new_template AS (
+                    SELECT
+                        uuid_generate_v4 () AS id
+                )
+            INSERT INTO
+                shared_template (id, user_id, kind, meal_id, workout_id, slug, name, description)
+            SELECT
+                id,
+                $1,
+                $2,
+                $3,
+                $4,
+                CONCAT_WS('-', NULLIF($5, ''), LEFT(id::TEXT, 8)),
+                $6,
+                $7
+            FROM
+                new_template
+            RETURNING
+                *
+            ",
+            user_id,
+            kind,
+            meal_id,
+            workout_id,
+            slug,
+            name,
+            description,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query!("DELETE FROM shared_template WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+
+    /// Copies the meal and its foods into the user's meals, returning the new meal id.
+    pub async fn clone_meal(&self, pool: &PgPool, user_id: Uuid, name: &str) -> Result<Uuid> {
+        let name = normalize_whitespace(name);
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user_id).await?;
+        let meal_id = sqlx::query_scalar!(
+            "INSERT INTO meal (user_id, name, created_by_id) VALUES ($1, $2, $1) RETURNING id",
+            user_id,
+            name,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| {
+            handle_sqlx_contraint_error(err, "Meal", "name", &["unique_user_id_name"])
+        })?;
+        sqlx::query!(
+            "
+            INSERT INTO
+                meal_food (meal_id, food_id, quantity, created_by_id)
+            SELECT
+                $1,
+                food_id,
+                quantity,
+                $2
+            FROM
+                meal_food
+            WHERE
+                meal_id = $3
+            ",
+            meal_id,
+            user_id,
+            self.meal_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+        Self::increment_clone_count(&mut tx, self.id).await?;
+        tx.commit().await?;
+        Ok(meal_id)
+    }
+
+    /// Copies the workout, its exercises and their sets into a new workout for the user on the
+    /// date, returning the new workout id.
+    pub async fn clone_workout(
+        &self,
+        pool: &PgPool,
+        user_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<Uuid> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user_id).await?;
+        let workout_id = sqlx::query_scalar!(
+            "INSERT INTO workout (user_id, date, created_by_id) VALUES ($1, $2, $1) RETURNING id",
+            user_id,
+            date,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        sqlx::query!(
+            r#"
+            WITH
+                source_exercise AS (
+                    SELECT
+                        id,
+                        movement_id,
+                        "order",
+                        uuid_generate_v4 () AS new_id
+                    FROM
+                        exercise
+                    WHERE
+                        workout_id = $1
+                ),
+                new_exercise AS (
+                    INSERT INTO
+                        exercise (id, workout_id, movement_id, "order", created_by_id)
+                    SELECT
+                        new_id,
+                        $2,
+                        movement_id,
+                        "order",
+                        $3
+                    FROM
+                        source_exercise
+                )
+            INSERT INTO
+                tracked_set (exercise_id, weight, reps, rest, "order", created_by_id)
+            SELECT
+                t2.new_id,
+                t1.weight,
+                t1.reps,
+                t1.rest,
+                t1."order",
+                $3
+            FROM
+                tracked_set t1
+                JOIN source_exercise t2 ON t2.id = t1.exercise_id
+            "#,
+            self.workout_id,
+            workout_id,
+            user_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+        Self::increment_clone_count(&mut tx, self.id).await?;
+        tx.commit().await?;
+        Ok(workout_id)
+    }
+
+    async fn increment_clone_count(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: Uuid,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE shared_template SET clone_count = clone_count + 1 WHERE id = $1",
+            id
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
+
+impl SharedTemplateQuery {
+    pub async fn get_by_slug(pool: &PgPool, slug: &str) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.user_id,
+                t2.username,
+                t1.kind,
+                t1.slug,
+                t1.name,
+                t1.description,
+                t1.clone_count,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        meal_food
+                    WHERE
+                        meal_id = t1.meal_id
+                ) + (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        exercise
+                    WHERE
+                        workout_id = t1.workout_id
+                ) AS "item_count!",
+                t1.created_at
+            FROM
+                shared_template t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t1.slug = $1
+            "#,
+            slug,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// The template a meal or workout is shared as, if any.
+    pub async fn get_by_source_id(pool: &PgPool, source_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.user_id,
+                t2.username,
+                t1.kind,
+                t1.slug,
+                t1.name,
+                t1.description,
+                t1.clone_count,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        meal_food
+                    WHERE
+                        meal_id = t1.meal_id
+                ) + (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        exercise
+                    WHERE
+                        workout_id = t1.workout_id
+                ) AS "item_count!",
+                t1.created_at
+            FROM
+                shared_template t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t1.meal_id = $1
+                OR t1.workout_id = $1
+            "#,
+            source_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn count(pool: &PgPool, search: &str, kind: &str) -> Result<i64> {
+        let mut qb = sqlx::QueryBuilder::new(
+            "
+            SELECT
+                COUNT(*)
+            FROM
+                shared_template t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t2.is_active
+            ",
+        );
+        qb.filter("t1.name", "ilike", search);
+        qb.filter("t1.kind::TEXT", "=", kind);
+        let query = qb.build_query_scalar().fetch_one(pool).await?;
+        Ok(query)
+    }
+
+    pub async fn filter(
+        pool: &PgPool,
+        search: &str,
+        kind: &str,
+        order: &str,
+        size: i64,
+        page: i64,
+    ) -> Result<Vec<Self>> {
+        let order_by_column = match order {
+            "name" => "t1.name",
+            "-name" => "t1.name DESC",
+            "-created_at" => "t1.created_at DESC",
+            _ => "t1.clone_count DESC, t1.created_at DESC",
+        };
+        let mut qb = sqlx::QueryBuilder::new(
+            "
+            SELECT
+                t1.id,
+                t1.user_id,
+                t2.username,
+                t1.kind,
+                t1.slug,
+                t1.name,
+                t1.description,
+                t1.clone_count,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        meal_food
+                    WHERE
+                        meal_id = t1.meal_id
+                ) + (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        exercise
+                    WHERE
+                        workout_id = t1.workout_id
+                ) AS item_count,
+                t1.created_at
+            FROM
+                shared_template t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t2.is_active
+            ",
+        );
+        qb.filter("t1.name", "ilike", search);
+        qb.filter("t1.kind::TEXT", "=", kind);
+
+        qb.push(" ORDER BY ");
+        qb.push(order_by_column);
+
+        qb.paginate(size, page);
+        let query = qb.build_query_as().fetch_all(pool).await?;
+        Ok(query)
+    }
+}
+
+impl SharedTemplateItem {
+    pub async fn all_by_template(pool: &PgPool, template: &SharedTemplate) -> Result<Vec<Self>> {
+        let query = match (template.meal_id, template.workout_id) {
+            (Some(meal_id), _) => {
+                sqlx::query_as!(
+                    Self,
+                    r#"
+                    SELECT
+                        CONCAT(t2.name, ', ', t3.name) AS "name!",
+                        CONCAT(ROUND(t1.quantity * t2.data_value), t2.data_measurement) AS "detail!"
+                    FROM
+                        meal_food t1
+                        JOIN food t2 ON t2.id = t1.food_id
+                        JOIN food_brand t3 ON t3.id = t2.brand_id
+                    WHERE
+                        t1.meal_id = $1
+                    ORDER BY
+                        t1.created_at
+                    "#,
+                    meal_id,
+                )
+                .fetch_all(pool)
+                .await?
+            }
+            (None, Some(workout_id)) => {
+                sqlx::query_as!(
+                    Self,
+                    r#"
+                    SELECT
+                        t2.name AS "name!",
+                        CONCAT(COUNT(t3.id), ' sets, ', COALESCE(SUM(t3.reps), 0), ' reps') AS "detail!"
+                    FROM
+                        exercise t1
+                        JOIN movement t2 ON t2.id = t1.movement_id
+                        LEFT JOIN tracked_set t3 ON t3.exercise_id = t1.id
+                    WHERE
+                        t1.workout_id = $1
+                    GROUP BY
+                        t1.id,
+                        t2.name
+                    ORDER BY
+                        t1."order",
+                        t1.created_at
+                    "#,
+                    workout_id,
+                )
+                .fetch_all(pool)
+                .await?
+            }
+            (None, None) => Vec::new(),
+        };
+        Ok(query)
+    }
+}
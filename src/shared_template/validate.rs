@@ -0,0 +1,28 @@
+use crate::util::validation_error::ValidationError;
+use crate::util::validation_field::{validate_max_length, validate_non_empty};
+
+use super::model::SharedTemplate;
+
+impl SharedTemplate {
+    pub const MAX_NAME_LENGTH: usize = 100;
+    pub const MAX_DESCRIPTION_LENGTH: usize = 255;
+
+    pub fn validate(name: &str, description: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        if let Some(error) = validate_non_empty(name) {
+            errors.add_error("name", error.to_string());
+        } else if let Some(error) = validate_max_length(name, Self::MAX_NAME_LENGTH) {
+            errors.add_error("name", error.to_string());
+        }
+        if let Some(error) = validate_max_length(description, Self::MAX_DESCRIPTION_LENGTH) {
+            errors.add_error("description", error.to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
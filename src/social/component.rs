@@ -0,0 +1,419 @@
+use leptos::*;
+use leptos_router::*;
+
+use chrono::prelude::*;
+use uuid::Uuid;
+
+use crate::component::badge::Badge;
+use crate::component::button::SubmitButton;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::datetime::format_datetime;
+use crate::util::validation_error::{extract_other_errors, get_field_errors, get_non_field_errors};
+
+use super::model::{CommentList, CommentQuery, SocialSummary};
+use super::target::SocialTarget;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::service::get_request_user, error::Error, notification::service, setup::get_pool,
+    social::model::Comment, social::reaction_kind::ReactionKind,
+};
+
+#[server(endpoint = "social-summary")]
+pub async fn get_social_summary(
+    username: String,
+    date: NaiveDate,
+    workout_id: Option<Uuid>,
+) -> Result<SocialSummary, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let target = SocialTarget {
+        username,
+        date,
+        workout_id,
+    };
+    let owner = target.can_view(&pool, &user).await?;
+    let query = SocialSummary::get(&pool, owner.id, date, workout_id, user.id).await?;
+    Ok(query)
+}
+
+#[server(endpoint = "social-reaction-toggle")]
+pub async fn social_reaction_toggle(
+    username: String,
+    date: NaiveDate,
+    workout_id: Option<Uuid>,
+    kind: i32,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    if let ReactionKind::Unknown(_) = ReactionKind::from(kind) {
+        return Err(Error::Other("Select a valid reaction.".into()).into());
+    }
+    let target = SocialTarget {
+        username,
+        date,
+        workout_id,
+    };
+    let owner = target.can_comment(&pool, &user).await?;
+    SocialSummary::toggle_reaction(&pool, owner.id, date, workout_id, user.id, kind).await?;
+    Ok(())
+}
+
+#[server(endpoint = "social-comment-list")]
+pub async fn get_social_comment_list(
+    username: String,
+    date: NaiveDate,
+    workout_id: Option<Uuid>,
+) -> Result<CommentList, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let target = SocialTarget {
+        username,
+        date,
+        workout_id,
+    };
+    let owner = target.can_view(&pool, &user).await?;
+    let can_moderate = user.is_superuser || owner.id == user.id;
+    let comments =
+        CommentQuery::all_by_target(&pool, owner.id, date, workout_id, user.id, can_moderate)
+            .await?;
+    Ok(CommentList {
+        user_id: user.id,
+        can_moderate,
+        comments,
+    })
+}
+
+#[server(endpoint = "social-comment-create")]
+pub async fn social_comment_create(
+    username: String,
+    date: NaiveDate,
+    workout_id: Option<Uuid>,
+    parent_id: Option<Uuid>,
+    body: String,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let target = SocialTarget {
+        username,
+        date,
+        workout_id,
+    };
+    let owner = target.can_comment(&pool, &user).await?;
+    Comment::validate(&body)?;
+    let parent = match parent_id {
+        Some(parent_id) => {
+            let parent = Comment::get_by_id(&pool, parent_id)
+                .await?
+                .ok_or(Error::NotFound)?;
+            if parent.owner_id != owner.id || parent.date != date || parent.workout_id != workout_id
+            {
+                return Err(Error::NotFound.into());
+            }
+            Some(parent)
+        }
+        None => None,
+    };
+    Comment::create(&pool, owner.id, date, workout_id, parent_id, user.id, &body).await?;
+    service::notify_comment(&pool, &user, owner.id, &target, parent.as_ref()).await;
+    Ok(())
+}
+
+#[server(endpoint = "social-comment-hide")]
+pub async fn social_comment_hide(id: Uuid, is_hidden: bool) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let comment = Comment::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    comment.can_moderate(&user)?;
+    Comment::update_hidden(&pool, comment.id, is_hidden, user.actor_id()).await?;
+    Ok(())
+}
+
+#[server(endpoint = "social-comment-delete")]
+pub async fn social_comment_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let comment = Comment::get_by_id(&pool, id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    comment.can_delete(&user)?;
+    Comment::delete(&pool, comment.id, user.actor_id()).await?;
+    Ok(())
+}
+
+/// Reaction and comment counts, linking to where the comments are shown.
+#[component]
+pub fn SocialCountBadges(target: SocialTarget, href: String) -> impl IntoView {
+    let resource = Resource::once(move || {
+        let target = target.clone();
+        get_social_summary(target.username, target.date, target.workout_id)
+    });
+    let href = store_value(href);
+    let response = move || {
+        resource.and_then(|data| {
+            let reaction_total = data.reaction_total();
+            let comment_count = data.comment_count;
+            view! {
+                <a href=href.get_value() class="flex gap-2">
+                    <Badge label="reactions" value=reaction_total/>
+                    <Badge label="comments" value=comment_count/>
+                </a>
+            }
+        })
+    };
+    view! {
+        <Transition>
+            <ErrorBoundary fallback=|_| ()>{response}</ErrorBoundary>
+        </Transition>
+    }
+}
+
+/// Reactions and the comment thread for a workout or diet day.
+#[component]
+pub fn SocialPanel(target: Signal<SocialTarget>) -> impl IntoView {
+    let action_reaction = Action::<SocialReactionToggle, _>::server();
+    let action_create = Action::<SocialCommentCreate, _>::server();
+    let action_hide = Action::<SocialCommentHide, _>::server();
+    let action_delete = Action::<SocialCommentDelete, _>::server();
+
+    let summary_resource = Resource::new(
+        move || {
+            (
+                target.get(),
+                action_reaction.version().get(),
+                action_create.version().get(),
+                action_hide.version().get(),
+                action_delete.version().get(),
+            )
+        },
+        |(target, ..)| get_social_summary(target.username, target.date, target.workout_id),
+    );
+    let comment_resource = Resource::new(
+        move || {
+            (
+                target.get(),
+                action_create.version().get(),
+                action_hide.version().get(),
+                action_delete.version().get(),
+            )
+        },
+        |(target, ..)| get_social_comment_list(target.username, target.date, target.workout_id),
+    );
+
+    let reaction_errors = move || extract_other_errors(action_reaction.value(), &[]);
+    let moderation_errors = move || {
+        extract_other_errors(action_hide.value(), &[])
+            .or_else(|| extract_other_errors(action_delete.value(), &[]))
+    };
+
+    let summary_response = move || {
+        summary_resource.and_then(|data| {
+            let comment_count = data.comment_count;
+            let reactions = data
+                .reaction_counts()
+                .into_iter()
+                .map(|(kind, count, reacted)| {
+                    view! {
+                        <ActionForm action=action_reaction>
+                            <TargetInputs target/>
+                            <input type="hidden" name="kind" value=i32::from(kind)/>
+                            <button
+                                type="submit"
+                                title=kind.to_string()
+                                class="py-1 px-2 border hover:bg-amber-200"
+                                class=("bg-amber-100", reacted)
+                            >
+                                {kind.emoji()}
+                                " "
+                                {count}
+                            </button>
+                        </ActionForm>
+                    }
+                })
+                .collect_view();
+            view! {
+                <div class="flex flex-wrap gap-2 items-center">
+                    {reactions} <Badge label="comments" value=comment_count/>
+                </div>
+            }
+        })
+    };
+
+    let comment_response = move || {
+        comment_resource.and_then(|data| {
+            let user_id = data.user_id;
+            let can_moderate = data.can_moderate;
+            let thread = CommentQuery::thread(&data.comments);
+            if thread.is_empty() {
+                return view! { <p class="text-gray-500">"No comments yet."</p> }.into_view();
+            }
+            thread
+                .into_iter()
+                .map(|(depth, comment)| {
+                    let can_delete = can_moderate || comment.user_id == user_id;
+                    let can_report = comment.user_id != user_id;
+                    view! {
+                        <CommentListItem
+                            data=comment
+                            depth
+                            target
+                            can_moderate
+                            can_delete
+                            can_report
+                            action_create
+                            action_hide
+                            action_delete
+                        />
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <section class="p-4 mt-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Reactions and Comments"</h2>
+            <div class="mb-2 text-red-500 font-bold">{reaction_errors}</div>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{summary_response}</ErrorBoundary>
+            </Transition>
+            <div class="my-4">
+                <CommentForm target action=action_create/>
+            </div>
+            <div class="mb-2 text-red-500 font-bold">{moderation_errors}</div>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{comment_response}</ErrorBoundary>
+            </Transition>
+        </section>
+    }
+}
+
+#[component]
+fn TargetInputs(target: Signal<SocialTarget>) -> impl IntoView {
+    move || {
+        let target = target.get();
+        let workout_id = target.workout_id.map(|workout_id| {
+            view! { <input type="hidden" name="workout_id" value=workout_id.to_string()/> }
+        });
+        view! {
+            <input type="hidden" name="username" value=target.username/>
+            <input type="hidden" name="date" value=target.date.to_string()/>
+            {workout_id}
+        }
+    }
+}
+
+#[component]
+fn CommentForm(
+    target: Signal<SocialTarget>,
+    action: Action<SocialCommentCreate, Result<(), ServerFnError>>,
+    #[prop(optional)] parent_id: Option<Uuid>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["non_field_errors", "body"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let body_errors =
+        move || get_field_errors(action_value, "body").map(|errors| errors.join(", "));
+    let parent_input = parent_id.map(|parent_id| {
+        view! { <input type="hidden" name="parent_id" value=parent_id.to_string()/> }
+    });
+    let (label, placeholder) = match parent_id {
+        Some(_) => ("Reply", "Write a reply"),
+        None => ("Comment", "Write a comment"),
+    };
+
+    view! {
+        <div class="text-red-500 font-bold">{action_error}</div>
+        <div class="text-red-500 font-bold">{non_field_errors}</div>
+        <div class="text-red-500">{body_errors}</div>
+        <ActionForm action>
+            <TargetInputs target/>
+            {parent_input}
+            <textarea
+                name="body"
+                rows="2"
+                maxlength="1000"
+                placeholder=placeholder
+                class="block p-2 mb-2 w-full max-w-lg border"
+            ></textarea>
+            <SubmitButton loading=action_loading label/>
+        </ActionForm>
+    }
+}
+
+#[component]
+#[allow(clippy::too_many_arguments)]
+fn CommentListItem(
+    data: CommentQuery,
+    depth: usize,
+    target: Signal<SocialTarget>,
+    can_moderate: bool,
+    can_delete: bool,
+    can_report: bool,
+    action_create: Action<SocialCommentCreate, Result<(), ServerFnError>>,
+    action_hide: Action<SocialCommentHide, Result<(), ServerFnError>>,
+    action_delete: Action<SocialCommentDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let id = data.id;
+    let is_hidden = data.is_hidden;
+    let created_at = format_datetime(&Some(data.created_at));
+    let user_href = format!("/users/{}", data.username);
+    let report_href = format!("/users/{}/report?comment={}", data.username, data.id);
+    let indent = format!("margin-left: {}rem", depth.min(4) * 2);
+    let show_reply = RwSignal::new(false);
+    let toggle_reply = move |_| show_reply.update(|value| *value = !*value);
+
+    view! {
+        <article class="py-2 border-b" style=indent>
+            <header class="flex flex-wrap gap-2 items-baseline">
+                <A href=user_href class="font-bold hover:underline">
+                    {data.username}
+                </A>
+                <span class="text-xs text-gray-500">{created_at}</span>
+                <Show when=move || is_hidden>
+                    <span class="px-2 text-xs bg-gray-200 rounded">"Hidden"</span>
+                </Show>
+            </header>
+            <p class="whitespace-pre-wrap">{data.body}</p>
+            <footer class="flex gap-4 text-xs">
+                <button type="button" class="hover:underline" on:click=toggle_reply>
+                    "Reply"
+                </button>
+                <Show when=move || can_moderate>
+                    <ActionForm action=action_hide>
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <input type="hidden" name="is_hidden" value=(!is_hidden).to_string()/>
+                        <button type="submit" class="hover:underline">
+                            {if is_hidden { "Unhide" } else { "Hide" }}
+                        </button>
+                    </ActionForm>
+                </Show>
+                <Show when=move || can_delete>
+                    <ActionForm action=action_delete>
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <button type="submit" class="hover:underline">
+                            "Delete"
+                        </button>
+                    </ActionForm>
+                </Show>
+                <Show when=move || can_report>
+                    <A href=report_href.clone() class="hover:underline">
+                        "Report"
+                    </A>
+                </Show>
+            </footer>
+            <Show when=move || show_reply.get()>
+                <div class="mt-2">
+                    <CommentForm target action=action_create parent_id=id/>
+                </div>
+            </Show>
+        </article>
+    }
+}
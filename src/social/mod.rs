@@ -0,0 +1,10 @@
+pub mod component;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod permission;
+pub mod reaction_kind;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod target;
+#[cfg(feature = "ssr")]
+pub mod validate;
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::reaction_kind::ReactionKind;
+
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+pub struct Comment {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub date: NaiveDate,
+    pub workout_id: Option<Uuid>,
+    pub user_id: Uuid,
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct CommentQuery {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub user_id: Uuid,
+    pub username: String,
+    pub body: String,
+    pub is_hidden: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CommentQuery {
+    /// Orders comments so that replies follow the comment they reply to, paired with how deeply
+    /// each is nested. Replies to comments that are not in the list are left out.
+    pub fn thread(comments: &[CommentQuery]) -> Vec<(usize, CommentQuery)> {
+        let mut replies: HashMap<Option<Uuid>, Vec<&CommentQuery>> = HashMap::new();
+        for comment in comments {
+            replies.entry(comment.parent_id).or_default().push(comment);
+        }
+        let mut thread = Vec::with_capacity(comments.len());
+        let mut stack: Vec<(usize, &CommentQuery)> = replies
+            .get(&None)
+            .map(|roots| roots.iter().rev().map(|comment| (0, *comment)).collect())
+            .unwrap_or_default();
+        while let Some((depth, comment)) = stack.pop() {
+            thread.push((depth, comment.clone()));
+            if let Some(children) = replies.get(&Some(comment.id)) {
+                stack.extend(children.iter().rev().map(|child| (depth + 1, *child)));
+            }
+        }
+        thread
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CommentList {
+    pub user_id: Uuid,
+    pub can_moderate: bool,
+    pub comments: Vec<CommentQuery>,
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReactionCount {
+    pub kind: i32,
+    pub count: i64,
+    pub reacted: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SocialSummary {
+    pub reactions: Vec<ReactionCount>,
+    pub comment_count: i64,
+}
+
+impl SocialSummary {
+    pub fn reaction_total(&self) -> i64 {
+        self.reactions.iter().map(|reaction| reaction.count).sum()
+    }
+
+    /// The count for every kind of reaction, including those nobody has used.
+    pub fn reaction_counts(&self) -> Vec<(ReactionKind, i64, bool)> {
+        ReactionKind::all()
+            .into_iter()
+            .map(|kind| {
+                let kind_value = i32::from(kind);
+                self.reactions
+                    .iter()
+                    .find(|reaction| reaction.kind == kind_value)
+                    .map_or((kind, 0, false), |reaction| {
+                        (kind, reaction.count, reaction.reacted)
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(id: u128, parent_id: Option<u128>) -> CommentQuery {
+        CommentQuery {
+            id: Uuid::from_u128(id),
+            parent_id: parent_id.map(Uuid::from_u128),
+            user_id: Uuid::from_u128(100),
+            username: "user".to_string(),
+            body: id.to_string(),
+            is_hidden: false,
+            created_at: Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, id as u32).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_thread_nests_replies_under_parent() {
+        let comments = vec![
+            comment(1, None),
+            comment(2, None),
+            comment(3, Some(1)),
+            comment(4, Some(3)),
+            comment(5, Some(1)),
+        ];
+        let thread: Vec<(usize, String)> = CommentQuery::thread(&comments)
+            .into_iter()
+            .map(|(depth, comment)| (depth, comment.body))
+            .collect();
+        assert_eq!(
+            thread,
+            vec![
+                (0, "1".to_string()),
+                (1, "3".to_string()),
+                (2, "4".to_string()),
+                (1, "5".to_string()),
+                (0, "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_thread_leaves_out_replies_to_missing_comments() {
+        let comments = vec![comment(1, None), comment(3, Some(2)), comment(4, Some(3))];
+        let thread = CommentQuery::thread(&comments);
+        assert_eq!(thread.len(), 1);
+        assert_eq!(thread[0].1.id, Uuid::from_u128(1));
+    }
+}
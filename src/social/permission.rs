@@ -0,0 +1,64 @@
+use sqlx::PgPool;
+
+use crate::auth::model::{RequestUser, User};
+use crate::error::{Error, Result};
+use crate::user_block::model::UserBlock;
+use crate::user_privacy::section::PrivacySection;
+use crate::workout::model::WorkoutBase;
+
+use super::model::Comment;
+use super::target::SocialTarget;
+
+impl SocialTarget {
+    pub fn section(&self) -> PrivacySection {
+        match self.workout_id {
+            Some(_) => PrivacySection::Workout,
+            None => PrivacySection::DietLog,
+        }
+    }
+
+    /// Returns the owner of the content once the user is known to be allowed to see it.
+    pub async fn can_view(&self, pool: &PgPool, user: &RequestUser) -> Result<User> {
+        User::check_view_permission(pool, user, &self.username, self.section()).await?;
+        let owner = User::get_by_username(pool, &self.username)
+            .await?
+            .ok_or(Error::NotFound)?;
+        if let Some(workout_id) = self.workout_id {
+            let workout = WorkoutBase::get_by_id(pool, workout_id)
+                .await?
+                .ok_or(Error::NotFound)?;
+            if workout.user_id != owner.id || workout.date != self.date {
+                return Err(Error::NotFound);
+            }
+        }
+        Ok(owner)
+    }
+
+    /// Users the owner has blocked cannot comment, even where they can still see the content.
+    pub async fn can_comment(&self, pool: &PgPool, user: &RequestUser) -> Result<User> {
+        let owner = self.can_view(pool, user).await?;
+        if owner.id != user.id && UserBlock::is_blocked(pool, owner.id, user.id).await? {
+            return Err(Error::Forbidden);
+        }
+        Ok(owner)
+    }
+}
+
+impl Comment {
+    /// The owner of the content can hide or delete any comment on it.
+    pub fn can_moderate(&self, user: &RequestUser) -> Result<()> {
+        if user.is_superuser || self.owner_id == user.id {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+
+    pub fn can_delete(&self, user: &RequestUser) -> Result<()> {
+        if self.user_id == user.id {
+            Ok(())
+        } else {
+            self.can_moderate(user)
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum ReactionKind {
+    Like,
+    Strong,
+    Fire,
+    Unknown(i32),
+}
+
+impl fmt::Display for ReactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReactionKind::Like => write!(f, "Like"),
+            ReactionKind::Strong => write!(f, "Strong"),
+            ReactionKind::Fire => write!(f, "Fire"),
+            ReactionKind::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl ReactionKind {
+    pub fn all() -> [ReactionKind; 3] {
+        [ReactionKind::Like, ReactionKind::Strong, ReactionKind::Fire]
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            ReactionKind::Like => "👍",
+            ReactionKind::Strong => "💪",
+            ReactionKind::Fire => "🔥",
+            ReactionKind::Unknown(_) => "?",
+        }
+    }
+}
+
+impl From<i32> for ReactionKind {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ReactionKind::Like,
+            2 => ReactionKind::Strong,
+            3 => ReactionKind::Fire,
+            _ => ReactionKind::Unknown(value),
+        }
+    }
+}
+
+impl From<ReactionKind> for i32 {
+    fn from(kind: ReactionKind) -> Self {
+        match kind {
+            ReactionKind::Like => 1,
+            ReactionKind::Strong => 2,
+            ReactionKind::Fire => 3,
+            ReactionKind::Unknown(value) => value,
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::Result;
+
+use super::model::{Comment, CommentQuery, ReactionCount, SocialSummary};
+
+impl SocialSummary {
+    pub async fn get(
+        pool: &PgPool,
+        owner_id: Uuid,
+        date: NaiveDate,
+        workout_id: Option<Uuid>,
+        user_id: Uuid,
+    ) -> Result<Self> {
+        let reactions = sqlx::query_as!(
+            ReactionCount,
+            r#"
+            SELECT
+                kind,
+                COUNT(*) AS "count!",
+                BOOL_OR(user_id = $4) AS "reacted!"
+            FROM
+                social_reaction
+            WHERE
+                owner_id = $1
+                AND date = $2
+                AND workout_id IS NOT DISTINCT FROM $3
+            GROUP BY
+                kind
+            ORDER BY
+                kind
+            "#,
+            owner_id,
+            date,
+            workout_id,
+            user_id,
+        )
+        .fetch_all(pool)
+        .await?;
+        let comment_count = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                social_comment
+            WHERE
+                owner_id = $1
+                AND date = $2
+                AND workout_id IS NOT DISTINCT FROM $3
+                AND NOT is_hidden
+            "#,
+            owner_id,
+            date,
+            workout_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(Self {
+            reactions,
+            comment_count,
+        })
+    }
+
+    /// Adds the user's reaction, or removes it if they have already reacted. Returns whether the
+    /// user now has the reaction.
+    pub async fn toggle_reaction(
+        pool: &PgPool,
+        owner_id: Uuid,
+        date: NaiveDate,
+        workout_id: Option<Uuid>,
+        user_id: Uuid,
+        kind: i32,
+    ) -> Result<bool> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, user_id).await?;
+        let deleted = sqlx::query!(
+            "
+            DELETE FROM social_reaction
+            WHERE
+                owner_id = $1
+                AND date = $2
+                AND workout_id IS NOT DISTINCT FROM $3
+                AND user_id = $4
+                AND kind = $5
+            ",
+            owner_id,
+            date,
+            workout_id,
+            user_id,
+            kind,
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if deleted == 0 {
+            sqlx::query!(
+                "
+                INSERT INTO
+                    social_reaction (owner_id, date, workout_id, user_id, kind)
+                VALUES
+                    ($1, $2, $3, $4, $5)
+                ON CONFLICT DO NOTHING
+                ",
+                owner_id,
+                date,
+                workout_id,
+                user_id,
+                kind,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(deleted == 0)
+    }
+}
+
+impl CommentQuery {
+    /// Hidden comments are only included for their author, or when `include_hidden` is set.
+    pub async fn all_by_target(
+        pool: &PgPool,
+        owner_id: Uuid,
+        date: NaiveDate,
+        workout_id: Option<Uuid>,
+        user_id: Uuid,
+        include_hidden: bool,
+    ) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t1.id,
+                t1.parent_id,
+                t1.user_id,
+                t2.username,
+                t1.body,
+                t1.is_hidden,
+                t1.created_at
+            FROM
+                social_comment t1
+                JOIN users_user t2 ON t2.id = t1.user_id
+            WHERE
+                t1.owner_id = $1
+                AND t1.date = $2
+                AND t1.workout_id IS NOT DISTINCT FROM $3
+                AND (
+                    NOT t1.is_hidden
+                    OR t1.user_id = $4
+                    OR $5
+                )
+            ORDER BY
+                t1.created_at
+            ",
+            owner_id,
+            date,
+            workout_id,
+            user_id,
+            include_hidden,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+impl Comment {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                id,
+                owner_id,
+                date,
+                workout_id,
+                user_id
+            FROM
+                social_comment
+            WHERE
+                id = $1
+            ",
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        owner_id: Uuid,
+        date: NaiveDate,
+        workout_id: Option<Uuid>,
+        parent_id: Option<Uuid>,
+        user_id: Uuid,
+        body: &str,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                social_comment (owner_id, date, workout_id, parent_id, user_id, body)
+            VALUES
+                ($1, $2, $3, $4, $5, $6)
+            RETURNING
+                id,
+                owner_id,
+                date,
+                workout_id,
+                user_id
+            ",
+            owner_id,
+            date,
+            workout_id,
+            parent_id,
+            user_id,
+            body.trim(),
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn update_hidden(
+        pool: &PgPool,
+        id: Uuid,
+        is_hidden: bool,
+        request_user_id: Uuid,
+    ) -> Result<Self> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query_as!(
+            Self,
+            "
+            UPDATE social_comment
+            SET
+                is_hidden = $2,
+                updated_at = NOW()
+            WHERE
+                id = $1
+            RETURNING
+                id,
+                owner_id,
+                date,
+                workout_id,
+                user_id
+            ",
+            id,
+            is_hidden,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query)
+    }
+
+    /// Deleting a comment also deletes the replies to it.
+    pub async fn delete(pool: &PgPool, id: Uuid, request_user_id: Uuid) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
+        let query = sqlx::query!("DELETE FROM social_comment WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+}
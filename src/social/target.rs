@@ -0,0 +1,40 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The content reacted to or commented on: one of a user's workouts, or their diet log for a
+/// day when there is no workout.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SocialTarget {
+    pub username: String,
+    pub date: NaiveDate,
+    pub workout_id: Option<Uuid>,
+}
+
+impl SocialTarget {
+    pub fn workout(username: String, date: NaiveDate, workout_id: Uuid) -> Self {
+        Self {
+            username,
+            date,
+            workout_id: Some(workout_id),
+        }
+    }
+
+    pub fn diet_day(username: String, date: NaiveDate) -> Self {
+        Self {
+            username,
+            date,
+            workout_id: None,
+        }
+    }
+
+    pub fn href(&self) -> String {
+        match self.workout_id {
+            Some(workout_id) => format!(
+                "/users/{}/workouts/{}/{}",
+                self.username, self.date, workout_id
+            ),
+            None => format!("/users/{}/diet/{}", self.username, self.date),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::util::validation_error::ValidationError;
+use crate::util::validation_field::{validate_max_length, validate_non_empty};
+
+use super::model::Comment;
+
+impl Comment {
+    pub const MAX_BODY_LENGTH: usize = 1000;
+
+    pub fn validate(body: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        if let Some(error) = validate_non_empty(body) {
+            errors.add_error("body", error.to_string());
+        } else if let Some(error) = validate_max_length(body, Self::MAX_BODY_LENGTH) {
+            errors.add_error("body", error.to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
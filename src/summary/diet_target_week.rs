@@ -5,7 +5,9 @@ use leptos::*;
 use leptos_router::*;
 
 use crate::component::bulk_delete_date::BulkDeleteDateRangeForm;
+use crate::component::link::Link;
 use crate::component::template::{ErrorComponent, ListLoadingComponent};
+use crate::diet_target::template_apply_form::DietTargetTemplateApplyForm;
 use crate::diet_target::update_or_create_form::DietTargetBulkUpdateOrCreateForm;
 use crate::util::param::{get_date, get_username};
 
@@ -75,6 +77,7 @@ pub fn DietTargetWeekSummaryComponent() -> impl IntoView {
 
     let action_bulk_delete = Action::server();
     let action_bulk_update = Action::server();
+    let action_template_apply = Action::server();
 
     let week_resource = Resource::new(
         move || {
@@ -83,6 +86,7 @@ pub fn DietTargetWeekSummaryComponent() -> impl IntoView {
                 date(),
                 action_bulk_delete.version().get(),
                 action_bulk_update.version().get(),
+                action_template_apply.version().get(),
             )
         },
         |(username, date, ..)| get_diet_target_week_summary(username, date),
@@ -156,6 +160,19 @@ pub fn DietTargetWeekSummaryComponent() -> impl IntoView {
                 </div>
             </div>
 
+            <div class="mt-4 col-span-full">
+                <div class="p-4 max-w-md bg-white border">
+                    <div class="flex justify-between items-center mb-4">
+                        <h2 class="text-base font-bold">"Apply Diet Target Template"</h2>
+                        <Link
+                            text="Templates"
+                            href=move || format!("/users/{}/diet-targets/templates", username())
+                        />
+                    </div>
+                    <DietTargetTemplateApplyForm action=action_template_apply/>
+                </div>
+            </div>
+
         </div>
     }
 }
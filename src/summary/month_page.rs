@@ -12,7 +12,10 @@ use crate::util::datetime::Resolution;
 use crate::util::param::{get_date, get_username};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server]
 pub async fn get_month_summary(
@@ -21,7 +24,7 @@ pub async fn get_month_summary(
 ) -> Result<Vec<MonthSummary>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::DietLog).await?;
     let query = MonthSummary::get(&pool, &username, date).await?;
     Ok(query)
 }
@@ -0,0 +1,5 @@
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+#[cfg(feature = "ssr")]
+pub mod service;
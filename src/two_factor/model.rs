@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use chrono::prelude::*;
+#[cfg(feature = "ssr")]
+use uuid::Uuid;
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserTotp {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub secret: String,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserRecoveryCode {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    pub recovery_codes_remaining: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TwoFactorEnrolment {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
@@ -0,0 +1,206 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::{Error, Result};
+use crate::util::validation_error::ValidationError;
+
+use super::model::{UserRecoveryCode, UserTotp};
+
+impl UserTotp {
+    pub async fn get_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(Self, "SELECT * FROM user_totp WHERE user_id = $1", user_id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(query)
+    }
+
+    pub async fn get_enabled_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "SELECT * FROM user_totp WHERE user_id = $1 AND enabled_at IS NOT NULL",
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Stores a new secret awaiting verification, replacing any previous unverified secret.
+    pub async fn create_pending(pool: &PgPool, user_id: Uuid, secret: &str) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                user_totp (user_id, secret)
+            VALUES
+                ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET
+                secret = EXCLUDED.secret,
+                last_used_step = NULL,
+                created_at = NOW()
+            WHERE
+                user_totp.enabled_at IS NULL
+            RETURNING
+                *
+            ",
+            user_id,
+            secret,
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| Error::Other("Two-factor authentication is already enabled.".into()))?;
+        Ok(query)
+    }
+
+    /// Enables the verified secret and stores the hashed recovery codes.
+    pub async fn enable(pool: &PgPool, id: Uuid, step: i64, code_hashes: &[String]) -> Result<()> {
+        let mut tx = pool.begin().await?;
+        let totp = sqlx::query_as!(
+            Self,
+            "
+            UPDATE user_totp
+            SET
+                enabled_at = NOW(),
+                last_used_step = $2
+            WHERE
+                id = $1
+            RETURNING
+                *
+            ",
+            id,
+            step,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        UserRecoveryCode::replace_all(&mut tx, totp.user_id, code_hashes).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Records the step of an accepted code. Returns false when the step has already been used.
+    pub async fn update_last_used_step(pool: &PgPool, id: Uuid, step: i64) -> Result<bool> {
+        let query = sqlx::query!(
+            "
+            UPDATE user_totp
+            SET
+                last_used_step = $2
+            WHERE
+                id = $1
+                AND (
+                    last_used_step IS NULL
+                    OR last_used_step < $2
+                )
+            ",
+            id,
+            step,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected() == 1)
+    }
+
+    pub async fn delete_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<()> {
+        let mut tx = pool.begin().await?;
+        sqlx::query!("DELETE FROM user_recovery_code WHERE user_id = $1", user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query!("DELETE FROM user_totp WHERE user_id = $1", user_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Accepts either a code from the authenticator app or an unused recovery code.
+    pub async fn verify_login(pool: &PgPool, user_id: Uuid, code: &str) -> Result<()> {
+        let totp = Self::get_enabled_by_user_id(pool, user_id)
+            .await?
+            .ok_or(Error::InvalidCredentials)?;
+        let timestamp = Utc::now().timestamp();
+        if let Some(step) = Self::verify_code(&totp.secret, code, timestamp, totp.last_used_step) {
+            if Self::update_last_used_step(pool, totp.id, step).await? {
+                return Ok(());
+            }
+        }
+        let code_hash = UserRecoveryCode::hash(code);
+        if UserRecoveryCode::use_code(pool, user_id, &code_hash)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+        let mut errors = ValidationError::new();
+        errors.add_error("code", "Invalid authentication code.".into());
+        Err(Error::Validation(errors))
+    }
+}
+
+impl UserRecoveryCode {
+    pub async fn count_remaining(pool: &PgPool, user_id: Uuid) -> Result<i64> {
+        let query = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM user_recovery_code WHERE user_id = $1 AND used_at IS NULL"#,
+            user_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn replace_all(
+        conn: &mut sqlx::PgConnection,
+        user_id: Uuid,
+        code_hashes: &[String],
+    ) -> Result<()> {
+        sqlx::query!("DELETE FROM user_recovery_code WHERE user_id = $1", user_id)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query!(
+            "
+            INSERT INTO
+                user_recovery_code (user_id, code_hash)
+            SELECT
+                $1,
+                code_hash
+            FROM
+                UNNEST($2::TEXT[]) AS code_hash
+            ",
+            user_id,
+            code_hashes,
+        )
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn regenerate(pool: &PgPool, user_id: Uuid, code_hashes: &[String]) -> Result<()> {
+        let mut tx = pool.begin().await?;
+        Self::replace_all(&mut tx, user_id, code_hashes).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Marks an unused code as used, returning it when one matched.
+    pub async fn use_code(pool: &PgPool, user_id: Uuid, code_hash: &str) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            UPDATE user_recovery_code
+            SET
+                used_at = NOW()
+            WHERE
+                user_id = $1
+                AND code_hash = $2
+                AND used_at IS NULL
+            RETURNING
+                *
+            ",
+            user_id,
+            code_hash,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+}
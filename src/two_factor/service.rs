@@ -0,0 +1,214 @@
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand::distributions::{Alphanumeric, Standard};
+use rand::{thread_rng, Rng};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use super::model::{UserRecoveryCode, UserTotp};
+
+pub const TOTP_ISSUER: &str = "Trackedfitness";
+
+impl UserTotp {
+    const SECRET_BYTES: usize = 20;
+    const CODE_DIGITS: u32 = 6;
+    const STEP_SECONDS: i64 = 30;
+    /// Number of steps either side of the current one that are accepted, allowing for clock drift.
+    const ALLOWED_DRIFT: i64 = 1;
+
+    pub fn generate_secret() -> String {
+        let bytes: Vec<u8> = thread_rng()
+            .sample_iter(Standard)
+            .take(Self::SECRET_BYTES)
+            .collect();
+        BASE32_NOPAD.encode(&bytes)
+    }
+
+    /// The provisioning URI read by authenticator apps.
+    pub fn otpauth_uri(secret: &str, account: &str) -> String {
+        format!(
+            "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={digits}&period={period}",
+            issuer = TOTP_ISSUER,
+            account = percent_encode(account),
+            digits = Self::CODE_DIGITS,
+            period = Self::STEP_SECONDS,
+        )
+    }
+
+    pub fn time_step(timestamp: i64) -> i64 {
+        timestamp.div_euclid(Self::STEP_SECONDS)
+    }
+
+    /// Generates the code for a time step following RFC 6238 with HMAC-SHA1.
+    pub fn generate_code(secret: &[u8], step: i64) -> String {
+        let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("hmac accepts any key length");
+        mac.update(&step.to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+        let code = binary % 10u32.pow(Self::CODE_DIGITS);
+        format!("{:0width$}", code, width = Self::CODE_DIGITS as usize)
+    }
+
+    /// Returns the matching time step when the code is valid at the given timestamp. Steps at or
+    /// before the last used step are rejected so that a code can only be used once.
+    pub fn verify_code(
+        secret: &str,
+        code: &str,
+        timestamp: i64,
+        last_used_step: Option<i64>,
+    ) -> Option<i64> {
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        if code.len() != Self::CODE_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let secret = BASE32_NOPAD.decode(secret.as_bytes()).ok()?;
+        let current = Self::time_step(timestamp);
+        (current - Self::ALLOWED_DRIFT..=current + Self::ALLOWED_DRIFT)
+            .filter(|step| last_used_step.is_none_or(|last| *step > last))
+            .find(|step| Self::generate_code(&secret, *step) == code)
+    }
+}
+
+impl UserRecoveryCode {
+    pub const COUNT: usize = 10;
+    const GROUP_LENGTH: usize = 5;
+
+    /// Generates a set of plain recovery codes, formatted as two groups, e.g. `a1b2c-d3e4f`.
+    pub fn generate() -> Vec<String> {
+        (0..Self::COUNT)
+            .map(|_| {
+                let code: String = thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(Self::GROUP_LENGTH * 2)
+                    .map(|c| char::from(c).to_ascii_lowercase())
+                    .collect();
+                format!(
+                    "{}-{}",
+                    &code[..Self::GROUP_LENGTH],
+                    &code[Self::GROUP_LENGTH..]
+                )
+            })
+            .collect()
+    }
+
+    /// Hashes a code after removing separators and case, so that codes can be entered loosely.
+    pub fn hash(code: &str) -> String {
+        let normalized: String = code
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        format!("{:x}", Sha256::digest(normalized.as_bytes()))
+    }
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(b).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn test_generate_code_matches_rfc_6238() {
+        // RFC 6238 appendix B SHA1 vectors, truncated to six digits.
+        let cases = [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1111111111, "050471"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+        ];
+        for (timestamp, expected) in cases {
+            let step = UserTotp::time_step(timestamp);
+            assert_eq!(UserTotp::generate_code(RFC_SECRET, step), expected);
+        }
+    }
+
+    #[test]
+    fn test_verify_code() {
+        let secret = BASE32_NOPAD.encode(RFC_SECRET);
+        let step = UserTotp::time_step(59);
+        assert_eq!(
+            UserTotp::verify_code(&secret, "287082", 59, None),
+            Some(step)
+        );
+        assert_eq!(
+            UserTotp::verify_code(&secret, "287 082", 59, None),
+            Some(step)
+        );
+        assert_eq!(
+            UserTotp::verify_code(&secret, "287082", 59 + 30, None),
+            Some(step)
+        );
+        assert_eq!(
+            UserTotp::verify_code(&secret, "287082", 59 + 90, None),
+            None
+        );
+        assert_eq!(UserTotp::verify_code(&secret, "287083", 59, None), None);
+        assert_eq!(UserTotp::verify_code(&secret, "28708", 59, None), None);
+    }
+
+    #[test]
+    fn test_verify_code_rejects_reuse() {
+        let secret = BASE32_NOPAD.encode(RFC_SECRET);
+        let step = UserTotp::time_step(59);
+        assert_eq!(
+            UserTotp::verify_code(&secret, "287082", 59, Some(step)),
+            None
+        );
+        assert_eq!(
+            UserTotp::verify_code(&secret, "287082", 59, Some(step - 1)),
+            Some(step)
+        );
+    }
+
+    #[test]
+    fn test_generate_secret() {
+        let secret = UserTotp::generate_secret();
+        assert_eq!(BASE32_NOPAD.decode(secret.as_bytes()).unwrap().len(), 20);
+    }
+
+    #[test]
+    fn test_otpauth_uri() {
+        assert_eq!(
+            UserTotp::otpauth_uri("ABC", "joe+fit@example.com"),
+            "otpauth://totp/Trackedfitness:joe%2Bfit%40example.com?secret=ABC&issuer=Trackedfitness&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_recovery_codes() {
+        let codes = UserRecoveryCode::generate();
+        assert_eq!(codes.len(), UserRecoveryCode::COUNT);
+        for code in &codes {
+            assert_eq!(code.len(), 11);
+            assert_eq!(&code[5..6], "-");
+        }
+        assert_eq!(
+            UserRecoveryCode::hash("ab12c-de34f"),
+            UserRecoveryCode::hash(" AB12CDE34F ")
+        );
+        assert_ne!(
+            UserRecoveryCode::hash("ab12c-de34f"),
+            UserRecoveryCode::hash("ab12c-de34g")
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use leptos::*;
 use leptos_meta::*;
 
+use crate::adherence::component::AdherencePanel;
 use crate::component::date_navigation::DateNavigation;
 use crate::diet_target::component::DietTargetDetailPanelComponent;
 use crate::profile::component::ProfileDetailPanel;
@@ -26,6 +27,10 @@ pub fn UserDetailPage() -> impl IntoView {
                 <section class="col-span-4">
                     <DietTargetDetailPanelComponent/>
                 </section>
+
+                <section class="col-span-4">
+                    <AdherencePanel/>
+                </section>
             </div>
 
         </div>
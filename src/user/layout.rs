@@ -87,6 +87,14 @@ pub fn UserLayout() -> impl IntoView {
                                 label=unfollow_wording
                             />
                         </div>
+                        <div class=("hidden", data.is_self)>
+                            <A
+                                href=format!("/users/{}/report", data.username)
+                                class="text-sm text-gray-500 hover:underline"
+                            >
+                                "Report"
+                            </A>
+                        </div>
                     </section>
                 </nav>
                 <Outlet/>
@@ -11,6 +11,7 @@ use crate::profile::detail_page::ProfileDetailPage;
 use crate::profile::update_page::ProfileUpdatePage;
 
 use crate::user::detail_page::UserDetailPage;
+use crate::user_report::create_page::UserReportPage;
 use crate::workout::router::WorkoutRouter;
 
 use crate::summary::month_page::UserSummaryMonthPage;
@@ -20,6 +21,8 @@ use crate::diet_target::create_page::DietTargetCreatePage;
 use crate::diet_target::delete_page::DietTargetDeletePage;
 use crate::diet_target::detail_page::DietTargetDetailPage;
 use crate::diet_target::list_page::DietTargetListPage;
+use crate::diet_target::meal_target_page::DietMealTargetPage;
+use crate::diet_target::template_page::DietTargetTemplatePage;
 use crate::diet_target::update_page::DietTargetUpdatePage;
 
 use crate::progress::create_page::ProgressCreatePage;
@@ -27,6 +30,8 @@ use crate::progress::delete_page::ProgressDeletePage;
 use crate::progress::detail_page::ProgressDetailPage;
 use crate::progress::list_page::ProgressListPage;
 use crate::progress::update_page::ProgressUpdatePage;
+use crate::progress_photo::compare_page::ProgressPhotoComparePage;
+use crate::progress_photo::upload_page::ProgressPhotoUploadPage;
 
 #[component(transparent)]
 pub fn UserRouter() -> impl IntoView {
@@ -34,20 +39,25 @@ pub fn UserRouter() -> impl IntoView {
         <Route path="/users/:username" view=UserLayout>
             <Route path="/followers" view=UserFollowerListPage/>
             <Route path="/following" view=UserFollowingPage/>
+            <Route path="/report" view=UserReportPage/>
 
             <Route path="" view=UserFollowerProtectedRoute>
                 <Route path="/:date?" view=UserDetailPage/>
                 <Route path="/diet-targets" view=DietTargetListPage/>
                 <Route path="/diet-targets/create" view=DietTargetCreatePage/>
+                <Route path="/diet-targets/templates" view=DietTargetTemplatePage/>
+                <Route path="/diet-targets/meals" view=DietMealTargetPage/>
                 <Route path="/diet-targets/:date" view=DietTargetDetailPage/>
                 <Route path="/diet-targets/:date/update" view=DietTargetUpdatePage/>
                 <Route path="/diet-targets/:date/delete" view=DietTargetDeletePage/>
 
                 <Route path="/progress" view=ProgressListPage/>
                 <Route path="/progress/create" view=ProgressCreatePage/>
+                <Route path="/progress/compare" view=ProgressPhotoComparePage/>
                 <Route path="/progress/:date" view=ProgressDetailPage/>
                 <Route path="/progress/:date/update" view=ProgressUpdatePage/>
                 <Route path="/progress/:date/delete" view=ProgressDeletePage/>
+                <Route path="/progress/:date/photos" view=ProgressPhotoUploadPage/>
 
                 <Route path="/week/:date?" view=UserSummaryWeekPage/>
                 <Route path="/month/:date?" view=UserSummaryMonthPage/>
@@ -45,3 +45,13 @@ impl From<i32> for UserBlockStatus {
         }
     }
 }
+
+impl From<UserBlockStatus> for i32 {
+    fn from(status: UserBlockStatus) -> Self {
+        match status {
+            UserBlockStatus::Unblocked => 0,
+            UserBlockStatus::Blocked => 1,
+            UserBlockStatus::Unknown(value) => value,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use sqlx::postgres::PgRow;
 use sqlx::{FromRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::audit_log::model::AuditLog;
 use crate::error::Error;
 use crate::util::database::Filter;
 
@@ -56,7 +57,10 @@ impl UserBlock {
         blocker_id: Uuid,
         blocked_id: Uuid,
         blocked_status: i32,
-    ) -> sqlx::Result<u64> {
+        request_user_id: Uuid,
+    ) -> crate::error::Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query(
             "
             INSERT INTO user_block (blocker_id, blocked_id, blocked_status)
@@ -66,9 +70,10 @@ impl UserBlock {
         .bind(blocker_id)
         .bind(blocked_id)
         .bind(blocked_status)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
+        tx.commit().await?;
         Ok(query)
     }
 
@@ -78,7 +83,10 @@ impl UserBlock {
         blocker_id: Uuid,
         blocked_id: Uuid,
         blocked_status: i32,
-    ) -> sqlx::Result<u64> {
+        request_user_id: Uuid,
+    ) -> crate::error::Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, request_user_id).await?;
         let query = sqlx::query(
             "
             UPDATE user_block 
@@ -94,12 +102,41 @@ impl UserBlock {
         .bind(blocked_id)
         .bind(blocked_status)
         .bind(id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?
         .rows_affected();
+        tx.commit().await?;
         Ok(query)
     }
 
+    pub async fn is_blocked(
+        pool: &PgPool,
+        blocker_id: Uuid,
+        blocked_id: Uuid,
+    ) -> crate::error::Result<bool> {
+        let blocked = sqlx::query_scalar!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_block
+                    WHERE
+                        blocker_id = $1
+                        AND blocked_id = $2
+                        AND blocked_status = $3
+                ) AS "exists!"
+            "#,
+            blocker_id,
+            blocked_id,
+            i32::from(UserBlockStatus::Blocked)
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(blocked)
+    }
+
     pub async fn update_by_username_pair(
         pool: &PgPool,
         blocker_username: &str,
@@ -0,0 +1,11 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A user whose activity is left out of the muter's feed and notifications. Unlike a block, the
+/// muted user is not told and can still follow the muter and see their content.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserMute {
+    pub muted_username: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,137 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::Result;
+
+use super::model::UserMute;
+
+impl UserMute {
+    pub async fn all_by_muter_id(pool: &PgPool, muter_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                t2.username AS muted_username,
+                t1.created_at
+            FROM
+                user_mute t1
+                JOIN users_user t2 ON t2.id = t1.muted_id
+            WHERE
+                t1.muter_id = $1
+            ORDER BY
+                t2.username
+            ",
+            muter_id,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Mutes the user with `muted_username`. Returns the number of mutes created, which is zero
+    /// when the user does not exist or is already muted.
+    pub async fn create(pool: &PgPool, muter_id: Uuid, muted_username: &str) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, muter_id).await?;
+        let query = sqlx::query!(
+            "
+            INSERT INTO
+                user_mute (muter_id, muted_id)
+            SELECT
+                $1,
+                id
+            FROM
+                users_user
+            WHERE
+                username = $2
+                AND id <> $1
+            ON CONFLICT (muter_id, muted_id) DO NOTHING
+            ",
+            muter_id,
+            muted_username,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+
+    pub async fn delete(pool: &PgPool, muter_id: Uuid, muted_username: &str) -> Result<u64> {
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, muter_id).await?;
+        let query = sqlx::query!(
+            "
+            DELETE FROM user_mute USING users_user t1
+            WHERE
+                user_mute.muted_id = t1.id
+                AND user_mute.muter_id = $1
+                AND t1.username = $2
+            ",
+            muter_id,
+            muted_username,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(query.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::feed::model::FeedItem;
+    use crate::notification::kind::NotificationKind;
+    use crate::notification::model::Notification;
+    use crate::util::test_fixture::{create_follower, create_user};
+
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_mute_hides_feed_items_and_notifications(pool: PgPool) {
+        let muted_id = create_user(&pool, "muted").await;
+        let muter_id = create_user(&pool, "muter").await;
+        create_follower(&pool, muted_id, muter_id).await;
+        sqlx::query(
+            "INSERT INTO progress (user_id, date, weight_kg, created_by_id) VALUES ($1, $2, 80, $1)",
+        )
+        .bind(muted_id)
+        .bind(Utc::now().date_naive())
+        .execute(&pool)
+        .await
+        .unwrap();
+        let feed = FeedItem::all_by_follower_id(&pool, muter_id, None, 10)
+            .await
+            .unwrap();
+        assert_eq!(feed.len(), 1);
+
+        assert_eq!(UserMute::create(&pool, muter_id, "muted").await.unwrap(), 1);
+        assert_eq!(UserMute::create(&pool, muter_id, "muted").await.unwrap(), 0);
+        assert_eq!(UserMute::create(&pool, muter_id, "muter").await.unwrap(), 0);
+        let mutes = UserMute::all_by_muter_id(&pool, muter_id).await.unwrap();
+        assert_eq!(mutes.len(), 1);
+        assert_eq!(mutes[0].muted_username, "muted");
+
+        let feed = FeedItem::all_by_follower_id(&pool, muter_id, None, 10)
+            .await
+            .unwrap();
+        assert!(feed.is_empty());
+        let kind = NotificationKind::Comment;
+        let created = Notification::create(&pool, muter_id, Some(muted_id), kind, "Hi", None)
+            .await
+            .unwrap();
+        assert_eq!(created, 0);
+        let created = Notification::create(&pool, muted_id, Some(muter_id), kind, "Hi", None)
+            .await
+            .unwrap();
+        assert_eq!(created, 1);
+
+        assert_eq!(UserMute::delete(&pool, muter_id, "muted").await.unwrap(), 1);
+        let feed = FeedItem::all_by_follower_id(&pool, muter_id, None, 10)
+            .await
+            .unwrap();
+        assert_eq!(feed.len(), 1);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod model;
+pub mod panel;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod section;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The privacy level of each section of a user's data other than their profile, which uses the
+/// privacy level on the user.
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserPrivacySetting {
+    pub user_id: Uuid,
+    pub progress: i32,
+    pub diet_log: i32,
+    pub diet_target: i32,
+    pub workout: i32,
+}
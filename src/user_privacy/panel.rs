@@ -0,0 +1,120 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::auth::privacy_level::PrivacyLevel;
+use crate::component::button::SubmitButton;
+use crate::component::select::FieldSelect;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+use super::model::UserPrivacySetting;
+use super::section::PrivacySection;
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, error::Error, setup::get_pool};
+
+#[server(endpoint = "user-privacy-setting-detail")]
+pub async fn get_user_privacy_setting() -> Result<UserPrivacySetting, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let query = UserPrivacySetting::get_by_user_id(&pool, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(query)
+}
+
+#[server(endpoint = "user-privacy-setting-update")]
+pub async fn user_privacy_setting_update(
+    progress: i32,
+    diet_log: i32,
+    diet_target: i32,
+    workout: i32,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    UserPrivacySetting::validate(progress, diet_log, diet_target, workout)?;
+    UserPrivacySetting::upsert(&pool, user.id, progress, diet_log, diet_target, workout).await?;
+    Ok(())
+}
+
+#[component]
+pub fn UserPrivacyPanel() -> impl IntoView {
+    let action = Action::<UserPrivacySettingUpdate, _>::server();
+    let resource = Resource::new(
+        move || action.version().get(),
+        |_| get_user_privacy_setting(),
+    );
+    let response =
+        move || resource.and_then(|data| view! { <UserPrivacyForm data=data.clone() action/> });
+
+    view! {
+        <div class="p-4 mt-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Privacy"</h2>
+            <p class="mb-4">
+                "Choose who can see each part of your tracking. Your profile privacy level is set in your account settings above."
+            </p>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+pub fn UserPrivacyForm(
+    data: UserPrivacySetting,
+    action: Action<UserPrivacySettingUpdate, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || {
+        extract_other_errors(
+            action_value,
+            &[
+                "non_field_errors",
+                "progress",
+                "diet_log",
+                "diet_target",
+                "workout",
+            ],
+        )
+    };
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let value = |level: i32| PrivacyLevel::from(level).to_form_value();
+
+    view! {
+        <div class="max-w-sm">
+            <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+            <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+            <ActionForm action>
+                <FieldSelect
+                    name="progress"
+                    label="Weight and Progress"
+                    options=PrivacySection::to_form_options()
+                    value=value(data.progress)
+                />
+                <FieldSelect
+                    name="diet_log"
+                    label="Diet Log"
+                    options=PrivacySection::to_form_options()
+                    value=value(data.diet_log)
+                />
+                <FieldSelect
+                    name="diet_target"
+                    label="Diet Targets"
+                    options=PrivacySection::to_form_options()
+                    value=value(data.diet_target)
+                />
+                <FieldSelect
+                    name="workout"
+                    label="Workouts"
+                    options=PrivacySection::to_form_options()
+                    value=value(data.workout)
+                />
+                <SubmitButton loading=action_loading label="Update Privacy"/>
+            </ActionForm>
+        </div>
+    }
+}
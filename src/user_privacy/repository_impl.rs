@@ -0,0 +1,98 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::auth::privacy_level::PrivacyLevel;
+use crate::error::Result;
+use crate::util::validation_error::ValidationError;
+
+use super::model::UserPrivacySetting;
+
+impl UserPrivacySetting {
+    /// Users without saved settings get their profile privacy level for every section.
+    pub async fn get_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id AS "user_id!",
+                GREATEST(COALESCE(t2.progress, t1.privacy_level), 1) AS "progress!",
+                GREATEST(COALESCE(t2.diet_log, t1.privacy_level), 1) AS "diet_log!",
+                GREATEST(COALESCE(t2.diet_target, t1.privacy_level), 1) AS "diet_target!",
+                GREATEST(COALESCE(t2.workout, t1.privacy_level), 1) AS "workout!"
+            FROM
+                users_user t1
+                LEFT JOIN user_privacy_setting t2 ON t2.user_id = t1.id
+            WHERE
+                t1.id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn upsert(
+        pool: &PgPool,
+        user_id: Uuid,
+        progress: i32,
+        diet_log: i32,
+        diet_target: i32,
+        workout: i32,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                user_privacy_setting (user_id, progress, diet_log, diet_target, workout)
+            VALUES
+                ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE
+            SET
+                progress = EXCLUDED.progress,
+                diet_log = EXCLUDED.diet_log,
+                diet_target = EXCLUDED.diet_target,
+                workout = EXCLUDED.workout,
+                updated_at = NOW()
+            RETURNING
+                user_id,
+                progress,
+                diet_log,
+                diet_target,
+                workout
+            ",
+            user_id,
+            progress,
+            diet_log,
+            diet_target,
+            workout,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub fn validate(
+        progress: i32,
+        diet_log: i32,
+        diet_target: i32,
+        workout: i32,
+    ) -> std::result::Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+        for (field, value) in [
+            ("progress", progress),
+            ("diet_log", diet_log),
+            ("diet_target", diet_target),
+            ("workout", workout),
+        ] {
+            if let PrivacyLevel::Unknown(_) = PrivacyLevel::from(value) {
+                errors.add_error(field, "Select a valid privacy level.".into());
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
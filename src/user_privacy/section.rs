@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// A part of a user's data with its own visibility setting.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum PrivacySection {
+    Profile,
+    Progress,
+    DietLog,
+    DietTarget,
+    Workout,
+}
+
+impl PrivacySection {
+    /// The SQL expression for the section's privacy level, where `t1` is `users_user` and `t4`
+    /// is `user_privacy_setting`. Sections without a saved setting use the profile setting.
+    #[cfg(feature = "ssr")]
+    pub fn level_sql(&self) -> &'static str {
+        match self {
+            PrivacySection::Profile => "t1.privacy_level",
+            PrivacySection::Progress => "COALESCE(t4.progress, t1.privacy_level)",
+            PrivacySection::DietLog => "COALESCE(t4.diet_log, t1.privacy_level)",
+            PrivacySection::DietTarget => "COALESCE(t4.diet_target, t1.privacy_level)",
+            PrivacySection::Workout => "COALESCE(t4.workout, t1.privacy_level)",
+        }
+    }
+
+    /// Whether a user's coaches can see the section whatever its privacy level. Coaches are
+    /// joined as `t5` (`coach_client`) in the view permission checks.
+    #[cfg(feature = "ssr")]
+    pub fn coach_sql(&self) -> &'static str {
+        match self {
+            PrivacySection::Progress => "FALSE",
+            _ => "t5.id IS NOT NULL",
+        }
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("1", "Public - All users"),
+            ("2", "Followers Only - Only your followers"),
+            ("3", "Private - Only you"),
+        ]
+    }
+}
@@ -0,0 +1,138 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::component::button::SubmitButton;
+use crate::component::select::FieldSelect;
+use crate::util::param::{extract_param, UsernameParam};
+use crate::util::validation_error::{extract_other_errors, get_field_errors, get_non_field_errors};
+
+use super::reason::ReportReason;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
+    social::model::Comment, social::target::SocialTarget, user_report::model::UserReport,
+    util::validation_error::ValidationError,
+};
+
+#[server(endpoint = "user-report-create")]
+pub async fn user_report_create(
+    username: String,
+    reason: i32,
+    details: String,
+    comment_id: Option<Uuid>,
+) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let reported = User::get_by_username(&pool, &username)
+        .await?
+        .ok_or(Error::NotFound)?;
+    UserReport::can_create(&user, reported.id)?;
+    let details = details.trim();
+    UserReport::validate(reason, details)?;
+
+    let link = match comment_id {
+        Some(comment_id) => {
+            let comment = Comment::get_by_id(&pool, comment_id)
+                .await?
+                .filter(|comment| comment.user_id == reported.id)
+                .ok_or(Error::NotFound)?;
+            let owner = User::get_by_id(&pool, comment.owner_id)
+                .await?
+                .ok_or(Error::NotFound)?;
+            let target = match comment.workout_id {
+                Some(workout_id) => SocialTarget::workout(owner.username, comment.date, workout_id),
+                None => SocialTarget::diet_day(owner.username, comment.date),
+            };
+            Some(target.href())
+        }
+        None => None,
+    };
+    let created = UserReport::create(
+        &pool,
+        user.id,
+        reported.id,
+        comment_id,
+        link.as_deref(),
+        reason.into(),
+        details,
+    )
+    .await?;
+    if !created {
+        let mut errors = ValidationError::new();
+        errors.add_non_field_error("You have already reported this and it is being reviewed.");
+        return Err(Error::from(errors).into());
+    }
+    Ok(())
+}
+
+#[component]
+pub fn UserReportPage() -> impl IntoView {
+    let action = Action::<UserReportCreate, _>::server();
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error =
+        move || extract_other_errors(action_value, &["non_field_errors", "reason", "details"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    let details_errors =
+        move || get_field_errors(action_value, "details").map(|errors| errors.join(", "));
+    let submitted = move || matches!(action_value.get(), Some(Ok(())));
+
+    let params = use_params::<UsernameParam>();
+    let username =
+        move || params.with(|p| p.as_ref().map(|p| p.username.clone()).unwrap_or_default());
+    let query = use_query_map();
+    let comment_id = move || extract_param(&query, "comment");
+    let comment_input = move || {
+        let comment_id = comment_id();
+        (!comment_id.is_empty())
+            .then(|| view! { <input type="hidden" name="comment_id" value=comment_id/> })
+    };
+    let title = move || {
+        if comment_id().is_empty() {
+            format!("Report {}", username())
+        } else {
+            format!("Report a comment by {}", username())
+        }
+    };
+
+    view! {
+        <Title text="Report"/>
+        <main class="p-4 m-4 max-w-lg bg-white border">
+            <h1 class="mb-2 text-xl font-bold">{title}</h1>
+            <Show
+                when=submitted
+                fallback=move || {
+                    view! {
+                        <p class="mb-4">
+                            "Reports are reviewed by the moderators. You will be notified of the outcome."
+                        </p>
+                        <div class="mb-4 font-bold text-red-500">{action_error}</div>
+                        <div class="mb-4 font-bold text-red-500">{non_field_errors}</div>
+                        <ActionForm action>
+                            <input type="hidden" name="username" value=username/>
+                            {comment_input}
+                            <FieldSelect name="reason" options=ReportReason::to_form_options()/>
+                            <label class="block mb-4">
+                                <span class="block mb-1 text-sm font-bold">"Details"</span>
+                                <textarea
+                                    name="details"
+                                    rows="4"
+                                    maxlength="1000"
+                                    class="block p-2 w-full border"
+                                ></textarea>
+                                <span class="text-sm text-red-500">{details_errors}</span>
+                            </label>
+                            <SubmitButton loading=action_loading label="Send Report"/>
+                        </ActionForm>
+                    }
+                }
+            >
+                <p>"Thanks, your report has been sent to the moderators."</p>
+            </Show>
+        </main>
+    }
+}
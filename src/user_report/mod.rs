@@ -0,0 +1,10 @@
+pub mod create_page;
+pub mod model;
+#[cfg(feature = "ssr")]
+pub mod permission;
+pub mod reason;
+#[cfg(feature = "ssr")]
+pub mod repository_impl;
+pub mod status;
+#[cfg(feature = "ssr")]
+pub mod validate;
@@ -0,0 +1,78 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::reason::ReportReason;
+use super::status::ReportStatus;
+
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct UserReport {
+    pub id: Uuid,
+    pub reporter_id: Uuid,
+    pub reported_id: Uuid,
+    pub comment_id: Option<Uuid>,
+    pub link: Option<String>,
+    pub reason: i32,
+    pub details: String,
+    pub status: i32,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by_id: Option<Uuid>,
+}
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserReportQuery {
+    pub id: Uuid,
+    pub reporter_id: Uuid,
+    pub reporter_username: String,
+    pub reported_id: Uuid,
+    pub reported_username: String,
+    pub reported_is_active: bool,
+    pub reported_is_superuser: bool,
+    pub comment_id: Option<Uuid>,
+    pub comment_body: Option<String>,
+    /// The page of the reported content.
+    pub link: Option<String>,
+    pub reason: i32,
+    pub details: String,
+    pub status: i32,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by_username: Option<String>,
+}
+
+impl UserReportQuery {
+    pub fn get_reason(&self) -> ReportReason {
+        ReportReason::from(self.reason)
+    }
+
+    pub fn get_status(&self) -> ReportStatus {
+        ReportStatus::from(self.status)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ModerationAction {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    /// None once the moderator's account has been deleted.
+    pub actor_username: Option<String>,
+    pub action: i32,
+    pub note: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ModerationAction {
+    pub fn get_action(&self) -> ReportStatus {
+        ReportStatus::from(self.action)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserReportDetail {
+    pub report: UserReportQuery,
+    pub actions: Vec<ModerationAction>,
+}
@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use crate::auth::model::RequestUser;
+use crate::error::{Error, Result};
+
+use super::model::{UserReport, UserReportQuery};
+use super::status::ReportStatus;
+
+impl UserReport {
+    pub fn can_create(user: &RequestUser, reported_id: Uuid) -> Result<()> {
+        if user.is_active && user.id != reported_id {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+}
+
+impl UserReportQuery {
+    /// Superusers cannot be suspended through a report.
+    pub fn can_resolve(&self, user: &RequestUser, action: ReportStatus) -> Result<()> {
+        let suspends_superuser = action == ReportStatus::Suspended && self.reported_is_superuser;
+        if user.is_superuser && !suspends_superuser {
+            Ok(())
+        } else {
+            Err(Error::Forbidden)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    Inappropriate,
+    Impersonation,
+    Other,
+    Unknown(i32),
+}
+
+impl fmt::Display for ReportReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportReason::Spam => write!(f, "Spam"),
+            ReportReason::Harassment => write!(f, "Harassment or bullying"),
+            ReportReason::Inappropriate => write!(f, "Inappropriate content"),
+            ReportReason::Impersonation => write!(f, "Impersonation"),
+            ReportReason::Other => write!(f, "Other"),
+            ReportReason::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl ReportReason {
+    pub fn all() -> [ReportReason; 5] {
+        [
+            ReportReason::Spam,
+            ReportReason::Harassment,
+            ReportReason::Inappropriate,
+            ReportReason::Impersonation,
+            ReportReason::Other,
+        ]
+    }
+
+    pub fn to_form_options() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("1", "Spam"),
+            ("2", "Harassment or bullying"),
+            ("3", "Inappropriate content"),
+            ("4", "Impersonation"),
+            ("5", "Other"),
+        ]
+    }
+}
+
+impl From<i32> for ReportReason {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ReportReason::Spam,
+            2 => ReportReason::Harassment,
+            3 => ReportReason::Inappropriate,
+            4 => ReportReason::Impersonation,
+            5 => ReportReason::Other,
+            _ => ReportReason::Unknown(value),
+        }
+    }
+}
+
+impl From<ReportReason> for i32 {
+    fn from(reason: ReportReason) -> Self {
+        match reason {
+            ReportReason::Spam => 1,
+            ReportReason::Harassment => 2,
+            ReportReason::Inappropriate => 3,
+            ReportReason::Impersonation => 4,
+            ReportReason::Other => 5,
+            ReportReason::Unknown(value) => value,
+        }
+    }
+}
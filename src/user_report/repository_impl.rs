@@ -0,0 +1,383 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::audit_log::model::AuditLog;
+use crate::error::Result;
+use crate::util::database::Filter;
+
+use super::model::{ModerationAction, UserReport, UserReportQuery};
+use super::reason::ReportReason;
+use super::status::ReportStatus;
+
+impl UserReport {
+    /// Returns false without creating a report when the reporter already has an open report
+    /// about the same user and comment.
+    pub async fn create(
+        pool: &PgPool,
+        reporter_id: Uuid,
+        reported_id: Uuid,
+        comment_id: Option<Uuid>,
+        link: Option<&str>,
+        reason: ReportReason,
+        details: &str,
+    ) -> Result<bool> {
+        let reason = i32::from(reason);
+        let open = i32::from(ReportStatus::Open);
+        let query = sqlx::query!(
+            "
+            INSERT INTO
+                user_report (reporter_id, reported_id, comment_id, link, reason, details)
+            SELECT
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            WHERE
+                NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_report
+                    WHERE
+                        reporter_id = $1
+                        AND reported_id = $2
+                        AND comment_id IS NOT DISTINCT FROM $3
+                        AND status = $7
+                )
+            ",
+            reporter_id,
+            reported_id,
+            comment_id,
+            link,
+            reason,
+            details,
+            open,
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected() > 0)
+    }
+
+    /// Sets the report's status and records the action taken, deactivating the reported user
+    /// when they are suspended. A suspension also drops any pending account deletion, so its
+    /// cancel link cannot be used to reactivate the account.
+    pub async fn resolve(
+        pool: &PgPool,
+        id: Uuid,
+        reported_id: Uuid,
+        actor_id: Uuid,
+        action: ReportStatus,
+        note: &str,
+    ) -> Result<()> {
+        let action = i32::from(action);
+        let mut tx = pool.begin().await?;
+        AuditLog::set_actor(&mut tx, actor_id).await?;
+        sqlx::query!(
+            "
+            UPDATE user_report
+            SET
+                status = $1,
+                resolved_at = NOW(),
+                resolved_by_id = $2
+            WHERE
+                id = $3
+            ",
+            action,
+            actor_id,
+            id,
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "
+            INSERT INTO
+                moderation_action (report_id, user_id, actor_id, action, note)
+            VALUES
+                ($1, $2, $3, $4, $5)
+            ",
+            id,
+            reported_id,
+            actor_id,
+            action,
+            note,
+        )
+        .execute(&mut *tx)
+        .await?;
+        if action == i32::from(ReportStatus::Suspended) {
+            sqlx::query!(
+                "UPDATE users_user SET is_active = FALSE, updated_at = NOW() WHERE id = $1",
+                reported_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query!(
+                "DELETE FROM account_deletion WHERE user_id = $1",
+                reported_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+impl UserReportQuery {
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.reporter_id,
+                t2.username AS reporter_username,
+                t1.reported_id,
+                t3.username AS reported_username,
+                t3.is_active AS reported_is_active,
+                t3.is_superuser AS reported_is_superuser,
+                t1.comment_id,
+                t4.body AS "comment_body?",
+                t1.link,
+                t1.reason,
+                t1.details,
+                t1.status,
+                t1.created_at,
+                t1.resolved_at,
+                t5.username AS "resolved_by_username?"
+            FROM
+                user_report t1
+                JOIN users_user t2 ON t2.id = t1.reporter_id
+                JOIN users_user t3 ON t3.id = t1.reported_id
+                LEFT JOIN social_comment t4 ON t4.id = t1.comment_id
+                LEFT JOIN users_user t5 ON t5.id = t1.resolved_by_id
+            WHERE
+                t1.id = $1
+            "#,
+            id,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn count(pool: &PgPool, reported: &str, reason: &str, status: &str) -> Result<i64> {
+        let mut qb = sqlx::QueryBuilder::new(
+            "
+            SELECT
+                COUNT(*)
+            FROM
+                user_report t1
+                JOIN users_user t3 ON t3.id = t1.reported_id
+            WHERE
+                TRUE
+            ",
+        );
+        qb.filter("t3.username", "ilike", reported);
+        qb.filter("t1.reason::TEXT", "=", reason);
+        Self::filter_status(&mut qb, status);
+        let query = qb.build_query_scalar().fetch_one(pool).await?;
+        Ok(query)
+    }
+
+    pub async fn filter(
+        pool: &PgPool,
+        reported: &str,
+        reason: &str,
+        status: &str,
+        order: &str,
+        size: i64,
+        page: i64,
+    ) -> Result<Vec<Self>> {
+        let order_by_column = match order {
+            "-created_at" => "t1.created_at DESC",
+            "reported_username" => "t3.username, t1.created_at",
+            "-reported_username" => "t3.username DESC, t1.created_at",
+            "-resolved_at" => "t1.resolved_at DESC NULLS LAST",
+            _ => "t1.created_at",
+        };
+        let mut qb = sqlx::QueryBuilder::new(
+            "
+            SELECT
+                t1.id,
+                t1.reporter_id,
+                t2.username AS reporter_username,
+                t1.reported_id,
+                t3.username AS reported_username,
+                t3.is_active AS reported_is_active,
+                t3.is_superuser AS reported_is_superuser,
+                t1.comment_id,
+                t4.body AS comment_body,
+                t1.link,
+                t1.reason,
+                t1.details,
+                t1.status,
+                t1.created_at,
+                t1.resolved_at,
+                t5.username AS resolved_by_username
+            FROM
+                user_report t1
+                JOIN users_user t2 ON t2.id = t1.reporter_id
+                JOIN users_user t3 ON t3.id = t1.reported_id
+                LEFT JOIN social_comment t4 ON t4.id = t1.comment_id
+                LEFT JOIN users_user t5 ON t5.id = t1.resolved_by_id
+            WHERE
+                TRUE
+            ",
+        );
+        qb.filter("t3.username", "ilike", reported);
+        qb.filter("t1.reason::TEXT", "=", reason);
+        Self::filter_status(&mut qb, status);
+
+        qb.push(" ORDER BY ");
+        qb.push(order_by_column);
+
+        qb.paginate(size, page);
+        let query = qb.build_query_as().fetch_all(pool).await?;
+        Ok(query)
+    }
+
+    /// An empty status lists the open reports, and `all` lists every report.
+    fn filter_status(qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, status: &str) {
+        let status = match status {
+            "" => Some(i32::from(ReportStatus::Open)),
+            "all" => None,
+            status => Some(status.parse::<i32>().unwrap_or_default()),
+        };
+        if let Some(status) = status {
+            qb.push(" AND t1.status = ");
+            qb.push_bind(status);
+        }
+    }
+}
+
+impl ModerationAction {
+    pub async fn all_by_report_id(pool: &PgPool, report_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            r#"
+            SELECT
+                t1.id,
+                t1.report_id,
+                t2.username AS "actor_username?",
+                t1.action,
+                t1.note,
+                t1.created_at
+            FROM
+                moderation_action t1
+                LEFT JOIN users_user t2 ON t2.id = t1.actor_id
+            WHERE
+                t1.report_id = $1
+            ORDER BY
+                t1.created_at
+            "#,
+            report_id,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+
+    use crate::account_deletion::model::AccountDeletion;
+    use crate::util::test_fixture::create_user;
+
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_resolve_suspends_and_keeps_history_of_deleted_moderator(pool: PgPool) {
+        let reporter_id = create_user(&pool, "reporter").await;
+        let reported_id = create_user(&pool, "reported").await;
+        let moderator_id = create_user(&pool, "moderator").await;
+        let reason = ReportReason::Spam;
+        for expected in [true, false] {
+            let created =
+                UserReport::create(&pool, reporter_id, reported_id, None, None, reason, "Ads")
+                    .await
+                    .unwrap();
+            assert_eq!(created, expected);
+        }
+        let reports = UserReportQuery::filter(&pool, "", "", "", "", 10, 1)
+            .await
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+        let report_id = reports[0].id;
+
+        UserReport::resolve(
+            &pool,
+            report_id,
+            reported_id,
+            moderator_id,
+            ReportStatus::Suspended,
+            "Spam account",
+        )
+        .await
+        .unwrap();
+        let report = UserReportQuery::get_by_id(&pool, report_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.get_status(), ReportStatus::Suspended);
+        assert!(!report.reported_is_active);
+        assert_eq!(report.resolved_by_username.as_deref(), Some("moderator"));
+
+        sqlx::query("DELETE FROM users_user WHERE id = $1")
+            .bind(moderator_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let actions = ModerationAction::all_by_report_id(&pool, report_id)
+            .await
+            .unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].get_action(), ReportStatus::Suspended);
+        assert_eq!(actions[0].actor_username, None);
+    }
+
+    #[sqlx::test]
+    async fn test_suspension_cannot_be_undone_by_cancelling_deletion(pool: PgPool) {
+        let reporter_id = create_user(&pool, "reporter").await;
+        let reported_id = create_user(&pool, "reported").await;
+        AccountDeletion::create(&pool, reported_id, Utc::now() + TimeDelta::days(7))
+            .await
+            .unwrap();
+        UserReport::create(
+            &pool,
+            reporter_id,
+            reported_id,
+            None,
+            None,
+            ReportReason::Spam,
+            "Ads",
+        )
+        .await
+        .unwrap();
+        let reports = UserReportQuery::filter(&pool, "", "", "", "", 10, 1)
+            .await
+            .unwrap();
+
+        UserReport::resolve(
+            &pool,
+            reports[0].id,
+            reported_id,
+            reporter_id,
+            ReportStatus::Suspended,
+            "Spam account",
+        )
+        .await
+        .unwrap();
+
+        assert!(AccountDeletion::cancel(&pool, reported_id).await.is_err());
+        let is_active: bool = sqlx::query_scalar("SELECT is_active FROM users_user WHERE id = $1")
+            .bind(reported_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(!is_active);
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The state of a report, set by the latest moderation action taken on it.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum ReportStatus {
+    Open,
+    Dismissed,
+    Warned,
+    Suspended,
+    Unknown(i32),
+}
+
+impl fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportStatus::Open => write!(f, "Open"),
+            ReportStatus::Dismissed => write!(f, "Dismissed"),
+            ReportStatus::Warned => write!(f, "Warned"),
+            ReportStatus::Suspended => write!(f, "Suspended"),
+            ReportStatus::Unknown(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+impl ReportStatus {
+    /// The actions a moderator can take on a report.
+    pub fn actions() -> [ReportStatus; 3] {
+        [
+            ReportStatus::Dismissed,
+            ReportStatus::Warned,
+            ReportStatus::Suspended,
+        ]
+    }
+
+    pub fn to_action_options() -> Vec<(&'static str, &'static str)> {
+        vec![("2", "Dismiss"), ("3", "Warn user"), ("4", "Suspend user")]
+    }
+
+    /// Open reports are listed when no status is selected.
+    pub fn to_filter_options() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("", "Open"),
+            ("2", "Dismissed"),
+            ("3", "Warned"),
+            ("4", "Suspended"),
+            ("all", "All"),
+        ]
+    }
+
+    /// The outcome as told to the reporter.
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            ReportStatus::Dismissed => "no action was needed",
+            ReportStatus::Warned => "the user has been warned",
+            ReportStatus::Suspended => "the user has been suspended",
+            ReportStatus::Open | ReportStatus::Unknown(_) => "it is still open",
+        }
+    }
+}
+
+impl From<i32> for ReportStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ReportStatus::Open,
+            2 => ReportStatus::Dismissed,
+            3 => ReportStatus::Warned,
+            4 => ReportStatus::Suspended,
+            _ => ReportStatus::Unknown(value),
+        }
+    }
+}
+
+impl From<ReportStatus> for i32 {
+    fn from(status: ReportStatus) -> Self {
+        match status {
+            ReportStatus::Open => 1,
+            ReportStatus::Dismissed => 2,
+            ReportStatus::Warned => 3,
+            ReportStatus::Suspended => 4,
+            ReportStatus::Unknown(value) => value,
+        }
+    }
+}
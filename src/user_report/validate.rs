@@ -0,0 +1,58 @@
+use crate::util::validation_error::ValidationError;
+use crate::util::validation_field::validate_max_length;
+
+use super::model::{ModerationAction, UserReport};
+use super::reason::ReportReason;
+use super::status::ReportStatus;
+
+impl UserReport {
+    pub const MAX_DETAILS_LENGTH: usize = 1000;
+
+    pub fn validate(reason: i32, details: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        let reason = ReportReason::from(reason);
+        if !ReportReason::all().contains(&reason) {
+            errors.add_error("reason", "Select a valid reason.".to_string());
+        }
+        if reason == ReportReason::Other && details.is_empty() {
+            errors.add_error(
+                "details",
+                "Describe the problem when the reason is other.".to_string(),
+            );
+        } else if let Some(error) = validate_max_length(details, Self::MAX_DETAILS_LENGTH) {
+            errors.add_error("details", error.to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl ModerationAction {
+    pub const MAX_NOTE_LENGTH: usize = 200;
+
+    /// A warning is sent to the reported user, so it needs a note explaining it.
+    pub fn validate(action: i32, note: &str) -> Result<(), ValidationError> {
+        let mut errors = ValidationError::new();
+
+        let action = ReportStatus::from(action);
+        if !ReportStatus::actions().contains(&action) {
+            errors.add_error("action", "Select a valid action.".to_string());
+        }
+        if action == ReportStatus::Warned && note.is_empty() {
+            errors.add_error("note", "A warning needs a note for the user.".to_string());
+        } else if let Some(error) = validate_max_length(note, Self::MAX_NOTE_LENGTH) {
+            errors.add_error("note", error.to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+const BROWSERS: [(&str, &str); 5] = [
+    ("Edg/", "Edge"),
+    ("OPR/", "Opera"),
+    ("Firefox/", "Firefox"),
+    ("Chrome/", "Chrome"),
+    ("Safari/", "Safari"),
+];
+
+const OPERATING_SYSTEMS: [(&str, &str); 6] = [
+    ("iPhone", "iOS"),
+    ("iPad", "iPadOS"),
+    ("Android", "Android"),
+    ("Windows", "Windows"),
+    ("Mac OS X", "macOS"),
+    ("Linux", "Linux"),
+];
+
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserSession {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_agent: String,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// The superuser viewing the site as the user, for sessions started by impersonation.
+    pub impersonator_id: Option<Uuid>,
+}
+
+impl UserSession {
+    /// A short description of the browser and operating system from the user agent.
+    pub fn device(&self) -> String {
+        let find = |candidates: &[(&str, &'static str)]| {
+            candidates
+                .iter()
+                .find(|(needle, _)| self.user_agent.contains(needle))
+                .map(|(_, name)| *name)
+        };
+        match (find(&BROWSERS), find(&OPERATING_SYSTEMS)) {
+            (Some(browser), Some(os)) => format!("{} on {}", browser, os),
+            (Some(name), None) | (None, Some(name)) => name.to_string(),
+            (None, None) => "Unknown device".to_string(),
+        }
+    }
+}
+
+/// A single use token exchanged for a new access token when the current one expires.
+#[cfg(feature = "ssr")]
+#[allow(dead_code)]
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SessionRefreshToken {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTokenState {
+    Valid,
+    Expired,
+    /// Used moments ago, most likely by a concurrent request from the same browser.
+    RecentlyUsed,
+    /// Used earlier and presented again, which means the token has been copied.
+    Reused,
+}
+
+#[cfg(feature = "ssr")]
+impl SessionRefreshToken {
+    /// How long after rotation a token is still accepted, so that requests sent in parallel with
+    /// the refresh do not trigger reuse detection.
+    pub const REUSE_GRACE_SECONDS: i64 = 30;
+
+    pub fn state(&self, now: DateTime<Utc>) -> RefreshTokenState {
+        match self.used_at {
+            Some(used_at) if (now - used_at).num_seconds() <= Self::REUSE_GRACE_SECONDS => {
+                RefreshTokenState::RecentlyUsed
+            }
+            Some(_) => RefreshTokenState::Reused,
+            None if self.expires_at <= now => RefreshTokenState::Expired,
+            None => RefreshTokenState::Valid,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Debug)]
+pub enum RefreshRotation {
+    /// The token was exchanged for the new token returned.
+    Rotated(SessionRefreshToken),
+    /// The token was exchanged by another request within the grace period.
+    RecentlyRotated(SessionRefreshToken),
+    /// The token had already been exchanged, so the session has been ended.
+    Revoked,
+    Invalid,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserSessionList {
+    pub current_session_id: Option<Uuid>,
+    pub sessions: Vec<UserSession>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "ssr")]
+    use chrono::TimeDelta;
+
+    #[cfg(feature = "ssr")]
+    fn refresh_token(
+        expires_at: DateTime<Utc>,
+        used_at: Option<DateTime<Utc>>,
+    ) -> SessionRefreshToken {
+        SessionRefreshToken {
+            id: Uuid::nil(),
+            session_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            token_hash: String::new(),
+            created_at: Utc::now(),
+            expires_at,
+            used_at,
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn test_refresh_token_state() {
+        let now = Utc::now();
+        let later = now + TimeDelta::days(1);
+        assert_eq!(
+            refresh_token(later, None).state(now),
+            RefreshTokenState::Valid
+        );
+        assert_eq!(
+            refresh_token(now - TimeDelta::seconds(1), None).state(now),
+            RefreshTokenState::Expired
+        );
+        assert_eq!(
+            refresh_token(later, Some(now - TimeDelta::seconds(5))).state(now),
+            RefreshTokenState::RecentlyUsed
+        );
+        assert_eq!(
+            refresh_token(later, Some(now - TimeDelta::minutes(5))).state(now),
+            RefreshTokenState::Reused
+        );
+    }
+
+    fn session(user_agent: &str) -> UserSession {
+        UserSession {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            user_agent: user_agent.to_string(),
+            ip_address: None,
+            created_at: Utc::now(),
+            last_seen_at: Utc::now(),
+            impersonator_id: None,
+        }
+    }
+
+    #[test]
+    fn test_device() {
+        let cases = [
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36",
+                "Chrome on Windows",
+            ),
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36 Edg/126.0.0.0",
+                "Edge on Windows",
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+                "Safari on iOS",
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Mobile Safari/537.36",
+                "Chrome on Android",
+            ),
+            (
+                "Mozilla/5.0 (X11; Linux x86_64; rv:127.0) Gecko/20100101 Firefox/127.0",
+                "Firefox on Linux",
+            ),
+            ("curl/8.5.0", "Unknown device"),
+            ("", "Unknown device"),
+        ];
+        for (user_agent, expected) in cases {
+            assert_eq!(session(user_agent).device(), expected);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::prelude::*;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+use super::model::{RefreshRotation, RefreshTokenState, SessionRefreshToken, UserSession};
+
+impl UserSession {
+    const USER_AGENT_MAX_LENGTH: usize = 512;
+
+    pub async fn all_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<Vec<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                *
+            FROM
+                user_session
+            WHERE
+                user_id = $1
+            ORDER BY
+                last_seen_at DESC
+            ",
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        user_id: Uuid,
+        user_agent: &str,
+        ip_address: Option<&str>,
+        impersonator_id: Option<Uuid>,
+    ) -> Result<Self> {
+        let user_agent: String = user_agent
+            .chars()
+            .take(Self::USER_AGENT_MAX_LENGTH)
+            .collect();
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                user_session (user_id, user_agent, ip_address, impersonator_id)
+            VALUES
+                ($1, $2, $3, $4)
+            RETURNING
+                *
+            ",
+            user_id,
+            user_agent,
+            ip_address,
+            impersonator_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Returns whether the session is still active, updating its last seen time at most once
+    /// every few minutes.
+    pub async fn touch(pool: &PgPool, id: Uuid, user_id: Uuid) -> Result<bool> {
+        let query = sqlx::query_scalar!(
+            r#"
+            WITH
+                touched AS (
+                    UPDATE user_session
+                    SET
+                        last_seen_at = NOW()
+                    WHERE
+                        id = $1
+                        AND user_id = $2
+                        AND last_seen_at < NOW() - INTERVAL '5 minutes'
+                )
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        user_session
+                    WHERE
+                        id = $1
+                        AND user_id = $2
+                ) AS "exists!"
+            "#,
+            id,
+            user_id,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn get_impersonator_id(pool: &PgPool, id: Uuid) -> Result<Option<Uuid>> {
+        let query =
+            sqlx::query_scalar!("SELECT impersonator_id FROM user_session WHERE id = $1", id)
+                .fetch_optional(pool)
+                .await?;
+        Ok(query.flatten())
+    }
+
+    pub async fn delete(pool: &PgPool, id: Uuid, user_id: Uuid) -> Result<Option<Self>> {
+        let query = sqlx::query_as!(
+            Self,
+            "DELETE FROM user_session WHERE id = $1 AND user_id = $2 RETURNING *",
+            id,
+            user_id,
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(query)
+    }
+
+    pub async fn delete_all_by_user_id(pool: &PgPool, user_id: Uuid) -> Result<u64> {
+        let query = sqlx::query!("DELETE FROM user_session WHERE user_id = $1", user_id)
+            .execute(pool)
+            .await?;
+        Ok(query.rows_affected())
+    }
+
+    /// Removes sessions that have not been used since before `last_seen_before`.
+    pub async fn delete_inactive(pool: &PgPool, last_seen_before: DateTime<Utc>) -> Result<u64> {
+        let query = sqlx::query!(
+            "DELETE FROM user_session WHERE last_seen_at < $1",
+            last_seen_before
+        )
+        .execute(pool)
+        .await?;
+        Ok(query.rows_affected())
+    }
+}
+
+impl SessionRefreshToken {
+    pub async fn create(
+        pool: &PgPool,
+        session_id: Uuid,
+        user_id: Uuid,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        let query = sqlx::query_as!(
+            Self,
+            "
+            INSERT INTO
+                session_refresh_token (session_id, user_id, token_hash, expires_at)
+            VALUES
+                ($1, $2, $3, $4)
+            RETURNING
+                *
+            ",
+            session_id,
+            user_id,
+            token_hash,
+            expires_at,
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(query)
+    }
+
+    /// Exchanges a refresh token for a new one. Presenting a token that has already been
+    /// exchanged ends the session, as either the old or the new token has been stolen.
+    pub async fn rotate(
+        pool: &PgPool,
+        token_hash: &str,
+        new_token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<RefreshRotation> {
+        let mut tx = pool.begin().await?;
+        let token = sqlx::query_as!(
+            Self,
+            "SELECT * FROM session_refresh_token WHERE token_hash = $1 FOR UPDATE",
+            token_hash
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(token) = token else {
+            return Ok(RefreshRotation::Invalid);
+        };
+
+        let rotation = match token.state(Utc::now()) {
+            RefreshTokenState::Expired => RefreshRotation::Invalid,
+            RefreshTokenState::RecentlyUsed => RefreshRotation::RecentlyRotated(token),
+            RefreshTokenState::Reused => {
+                sqlx::query!("DELETE FROM user_session WHERE id = $1", token.session_id)
+                    .execute(&mut *tx)
+                    .await?;
+                tracing::warn!(
+                    "Refresh token reused, session revoked: {} {}",
+                    token.user_id,
+                    token.session_id
+                );
+                RefreshRotation::Revoked
+            }
+            RefreshTokenState::Valid => {
+                sqlx::query!(
+                    "UPDATE session_refresh_token SET used_at = NOW() WHERE id = $1",
+                    token.id
+                )
+                .execute(&mut *tx)
+                .await?;
+                sqlx::query!(
+                    "UPDATE user_session SET last_seen_at = NOW() WHERE id = $1",
+                    token.session_id
+                )
+                .execute(&mut *tx)
+                .await?;
+                let new_token = sqlx::query_as!(
+                    Self,
+                    "
+                    INSERT INTO
+                        session_refresh_token (session_id, user_id, token_hash, expires_at)
+                    VALUES
+                        ($1, $2, $3, $4)
+                    RETURNING
+                        *
+                    ",
+                    token.session_id,
+                    token.user_id,
+                    new_token_hash,
+                    expires_at,
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+                RefreshRotation::Rotated(new_token)
+            }
+        };
+        tx.commit().await?;
+        Ok(rotation)
+    }
+
+    pub async fn delete_expired(pool: &PgPool) -> Result<u64> {
+        let query = sqlx::query!("DELETE FROM session_refresh_token WHERE expires_at < NOW()")
+            .execute(pool)
+            .await?;
+        Ok(query.rows_affected())
+    }
+}
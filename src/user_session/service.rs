@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use chrono::prelude::*;
+use chrono::TimeDelta;
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+use crate::config::get_config;
+
+use super::model::{SessionRefreshToken, UserSession};
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+impl SessionRefreshToken {
+    const TOKEN_LENGTH: usize = 64;
+
+    /// Returns the token for the cookie and the hash to store.
+    pub fn generate_token() -> (String, String) {
+        let token: String = thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(Self::TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+        let hash = Self::hash_token(&token);
+        (token, hash)
+    }
+
+    pub fn hash_token(token: &str) -> String {
+        format!("{:x}", Sha256::digest(token.as_bytes()))
+    }
+
+    /// Refresh tokens expire after the authentication token duration, counted from the last
+    /// refresh, so that a session stays open while it is in use.
+    pub fn expires_at() -> DateTime<Utc> {
+        Utc::now() + TimeDelta::seconds(get_config().token_duration_authentication)
+    }
+}
+
+/// Periodically removes expired refresh tokens and the sessions that have not been seen since
+/// their last token expired.
+pub async fn run_user_session_sweeper(pool: PgPool) {
+    let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(err) = SessionRefreshToken::delete_expired(&pool).await {
+            tracing::error!("refresh token sweep failed: {:?}", err);
+        }
+        let last_seen_before =
+            Utc::now() - TimeDelta::seconds(get_config().token_duration_authentication);
+        if let Err(err) = UserSession::delete_inactive(&pool, last_seen_before).await {
+            tracing::error!("user session sweep failed: {:?}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_token() {
+        let (token, hash) = SessionRefreshToken::generate_token();
+        assert_eq!(token.len(), 64);
+        assert_eq!(hash, SessionRefreshToken::hash_token(&token));
+        assert_eq!(hash.len(), 64);
+    }
+}
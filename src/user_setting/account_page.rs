@@ -2,12 +2,15 @@ use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
 
+use crate::account_deletion::panel::AccountDeletionPanel;
 use crate::auth::model::User;
 use crate::auth::privacy_level::PrivacyLevel;
 use crate::component::button::SubmitButton;
 use crate::component::input::TextInput;
 use crate::component::select::FieldSelect;
 use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::data_export::panel::DataExportPanel;
+use crate::user_privacy::panel::UserPrivacyPanel;
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
@@ -42,6 +45,7 @@ async fn request_user_update(
         None,
         None,
         privacy_level,
+        user.actor_id(),
     )
     .await?;
     Ok(())
@@ -68,6 +72,9 @@ pub fn UserAccountSettingsPage() -> impl IntoView {
                 }>{response}</ErrorBoundary>
             </Transition>
         </div>
+        <UserPrivacyPanel/>
+        <DataExportPanel/>
+        <AccountDeletionPanel/>
     }
 }
 
@@ -25,6 +25,24 @@ pub fn UserSettingsLayout() -> impl IntoView {
                         href="blocked-users"
                         text="Blocked Users"
                     />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="muted-users"
+                        text="Muted Users"
+                    />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="notifications"
+                        text="Notifications"
+                    />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="coaching"
+                        text="Coaching"
+                    />
                     <Link
                         exact=true
                         variant=LinkVariant::UserNavLink
@@ -43,6 +61,30 @@ pub fn UserSettingsLayout() -> impl IntoView {
                         href="change-password"
                         text="Change Password"
                     />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="two-factor"
+                        text="Two-Factor Auth"
+                    />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="sessions"
+                        text="Sessions"
+                    />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="api-tokens"
+                        text="API Tokens"
+                    />
+                    <Link
+                        exact=true
+                        variant=LinkVariant::UserNavLink
+                        href="import"
+                        text="Import Data"
+                    />
                 </section>
                 <section class="col-span-4 lg:col-span-10">
                     <Outlet/>
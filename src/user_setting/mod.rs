@@ -3,5 +3,8 @@ pub mod follower_page;
 pub mod layout;
 pub mod password_change_page;
 pub mod router;
+pub mod session_page;
 pub mod site_statistics_page;
+pub mod two_factor_page;
 pub mod user_block_page;
+pub mod user_mute_page;
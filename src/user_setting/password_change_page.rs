@@ -4,24 +4,51 @@ use leptos_router::*;
 
 use crate::component::button::SubmitButton;
 use crate::component::input::TextInput;
+use crate::component::template::{ErrorComponent, LoadingComponent};
 use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool};
+use crate::{
+    auth::model::User,
+    auth::service::{get_request_user, AuthService},
+    error::Error,
+    setup::get_pool,
+    user_session::model::UserSession,
+};
 
+#[server(endpoint = "password-change-has-password")]
+pub async fn get_has_usable_password() -> Result<bool, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let user = User::get_by_id(&pool, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(user.has_usable_password())
+}
+
+/// Users who signed up through an identity provider have no password, so they set one without
+/// entering an old password.
 #[server(endpoint = "password-change")]
 pub async fn password_change(
-    old_password: String,
+    old_password: Option<String>,
     new_password: String,
 ) -> Result<(), ServerFnError> {
     let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
     let pool = get_pool()?;
-    User::validate_password_change(&old_password, &new_password)?;
     let user = User::get_by_username(&pool, &user.username)
         .await?
         .ok_or(Error::NotFound)?;
-    user.verify_password(&old_password)?;
-    User::update_password(&pool, user.id, &new_password).await?;
+    if user.has_usable_password() {
+        let old_password = old_password.unwrap_or_default();
+        User::validate_password_change(&old_password, &new_password)?;
+        user.verify_password(&old_password)?;
+    } else {
+        User::validate_new_password(&new_password)?;
+    }
+    User::update_password(&pool, user.id, &new_password, user.id).await?;
+    UserSession::delete_all_by_user_id(&pool, user.id).await?;
+    AuthService::start_session(&pool, &user).await?;
     leptos_axum::redirect("/settings");
     Ok(())
 }
@@ -39,23 +66,36 @@ pub fn PasswordUpdatePage() -> impl IntoView {
     };
     let non_field_errors = move || get_non_field_errors(action_value);
 
-    view! {
-        <Title text="Change Password"/>
-        <main class="p-4 bg-white">
-            <div class="max-w-sm">
-                <h1 class="mb-2 text-base font-bold">"Change Password"</h1>
-                <p class="mb-4">"Update your password below."</p>
+    let resource = Resource::once(get_has_usable_password);
+    let response = move || {
+        resource.and_then(|has_password| {
+            let has_password = *has_password;
+            let (title, description, label) = if has_password {
+                ("Change Password", "Update your password below.", "Update Password")
+            } else {
+                (
+                    "Set Password",
+                    "You sign in through your identity provider. Set a password to also log in with your email address.",
+                    "Set Password",
+                )
+            };
+            view! {
+                <Title text=title/>
+                <h1 class="mb-2 text-base font-bold">{title}</h1>
+                <p class="mb-4">{description}</p>
                 <div class="mb-4 text-red-500 font-bold">{action_error}</div>
                 <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
                 <ActionForm action>
-                    <TextInput
-                        action_value
-                        label="Old password"
-                        name="old_password"
-                        input_type="password"
-                        autocomplete="new-password"
-                        placeholder="Enter your old password"
-                    />
+                    <Show when=move || has_password>
+                        <TextInput
+                            action_value
+                            label="Old password"
+                            name="old_password"
+                            input_type="password"
+                            autocomplete="current-password"
+                            placeholder="Enter your old password"
+                        />
+                    </Show>
                     <TextInput
                         action_value
                         label="New password"
@@ -64,8 +104,20 @@ pub fn PasswordUpdatePage() -> impl IntoView {
                         autocomplete="new-password"
                         placeholder="Enter your new password"
                     />
-                    <SubmitButton loading=action_loading label="Update Password"/>
+                    <SubmitButton loading=action_loading label=label/>
                 </ActionForm>
+            }
+        })
+    };
+
+    view! {
+        <main class="p-4 bg-white">
+            <div class="max-w-sm">
+                <Transition fallback=LoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
             </div>
         </main>
     }
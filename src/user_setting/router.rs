@@ -1,30 +1,44 @@
 use leptos::*;
 use leptos_router::*;
 
+use crate::api_token::page::ApiTokenListPage;
 use crate::auth::email_change_complete_page::EmailChangeCompletePage;
 use crate::auth::email_change_confirm_page::EmailChangeConfirmPage;
 use crate::auth::email_change_done_page::EmailChangeRequestDonePage;
 use crate::auth::email_change_page::EmailChangeRequestPage;
+use crate::coach::page::CoachClientPage;
+use crate::data_import::page::DataImportPage;
+use crate::notification::preference_page::NotificationPreferencePage;
 use crate::profile::upload_page::ProfileImageUploadPage;
 use crate::user_setting::account_page::UserAccountSettingsPage;
 use crate::user_setting::follower_page::FollowerRequestListPage;
 use crate::user_setting::layout::UserSettingsLayout;
 use crate::user_setting::password_change_page::PasswordUpdatePage;
+use crate::user_setting::session_page::UserSessionListPage;
 use crate::user_setting::site_statistics_page::UserStatsDetailPage;
+use crate::user_setting::two_factor_page::TwoFactorPage;
 use crate::user_setting::user_block_page::UserBlockListPage;
+use crate::user_setting::user_mute_page::UserMuteListPage;
 
 #[component(transparent)]
 pub fn UserSettingsRouter() -> impl IntoView {
     view! {
         <Route path="/settings" view=UserSettingsLayout>
             <Route path="/change-password" view=PasswordUpdatePage/>
+            <Route path="/two-factor" view=TwoFactorPage/>
+            <Route path="/sessions" view=UserSessionListPage/>
             <Route path="/stats" view=UserStatsDetailPage/>
             <Route path="/followers" view=FollowerRequestListPage/>
             <Route path="/blocked-users" view=UserBlockListPage/>
+            <Route path="/muted-users" view=UserMuteListPage/>
+            <Route path="/notifications" view=NotificationPreferencePage/>
+            <Route path="/coaching" view=CoachClientPage/>
             <Route path="/change-email" view=EmailChangeRequestPage/>
             <Route path="/change-email/email-sent" view=EmailChangeRequestDonePage/>
             <Route path="/change-email/confirm" view=EmailChangeConfirmPage/>
             <Route path="/change-email/complete" view=EmailChangeCompletePage/>
+            <Route path="/api-tokens" view=ApiTokenListPage/>
+            <Route path="/import" view=DataImportPage/>
             <Route path="/upload" view=ProfileImageUploadPage/>
             <Route path="/" view=UserAccountSettingsPage/>
         </Route>
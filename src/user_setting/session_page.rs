@@ -0,0 +1,165 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use uuid::Uuid;
+
+use crate::app::UserResource;
+use crate::component::button::SubmitButton;
+use crate::component::template::{ErrorComponent, ListLoadingComponent};
+use crate::user_session::model::{UserSession, UserSessionList};
+use crate::util::datetime::format_datetime;
+use crate::util::validation_error::get_non_field_errors;
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::{cookie::delete_auth_cookies, service::get_request_user},
+    error::Error,
+    setup::get_pool,
+};
+
+#[server(endpoint = "user-session-list")]
+pub async fn get_user_session_list() -> Result<UserSessionList, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let sessions = UserSession::all_by_user_id(&pool, user.id).await?;
+    Ok(UserSessionList {
+        current_session_id: user.session_id,
+        sessions,
+    })
+}
+
+#[server(endpoint = "user-session-delete")]
+pub async fn user_session_delete(id: Uuid) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    if user.session_id == Some(id) {
+        return Err(Error::Other("Log out to end the current session.".into()).into());
+    }
+    UserSession::delete(&pool, id, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    Ok(())
+}
+
+#[server(endpoint = "user-session-delete-all")]
+pub async fn user_session_delete_all() -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    UserSession::delete_all_by_user_id(&pool, user.id).await?;
+    delete_auth_cookies()?;
+    leptos_axum::redirect("/login");
+    Ok(())
+}
+
+#[component]
+pub fn UserSessionListPage() -> impl IntoView {
+    let action_delete = Action::<UserSessionDelete, _>::server();
+    let action_delete_all = Action::<UserSessionDeleteAll, _>::server();
+
+    let resource = Resource::new(
+        move || action_delete.version().get(),
+        |_| get_user_session_list(),
+    );
+
+    let user_resource = expect_context::<UserResource>();
+    let delete_all_value = action_delete_all.value();
+    create_effect(move |_| {
+        if let Some(Ok(())) = delete_all_value.get() {
+            user_resource.refetch();
+        }
+    });
+
+    let delete_errors = move || get_non_field_errors(action_delete.value());
+    let delete_all_loading = action_delete_all.pending();
+    let delete_all_errors = move || get_non_field_errors(delete_all_value);
+
+    let response = move || {
+        resource.and_then(|data| {
+            let current_session_id = data.current_session_id;
+            data.sessions
+                .iter()
+                .map(|session| {
+                    let is_current = current_session_id == Some(session.id);
+                    view! { <UserSessionListItem data=session.clone() is_current action_delete/> }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <Title text="Sessions"/>
+        <div class="p-4 bg-white border">
+            <h1 class="mb-2 text-base font-bold">"Sessions"</h1>
+            <p class="mb-4">
+                "These are the devices currently logged in to your account. Revoke any session you do not recognise. Changing your password ends every other session."
+            </p>
+            <div class="mb-4 text-red-500 font-bold">{delete_errors}</div>
+            <div class="grid grid-cols-5">
+                <div class="p-2 font-bold border-b">"Device"</div>
+                <div class="p-2 font-bold border-b">"IP Address"</div>
+                <div class="p-2 font-bold border-b">"Created"</div>
+                <div class="p-2 font-bold border-b">"Last Seen"</div>
+                <div class="p-2 border-b"></div>
+                <Transition fallback=ListLoadingComponent>
+                    <ErrorBoundary fallback=|errors| {
+                        view! { <ErrorComponent errors/> }
+                    }>{response}</ErrorBoundary>
+                </Transition>
+            </div>
+        </div>
+        <div class="p-4 mt-4 bg-white border">
+            <h2 class="mb-2 text-base font-bold">"Log Out Everywhere"</h2>
+            <p class="mb-4">"End every session, including this one."</p>
+            <div class="mb-4 text-red-500 font-bold">{delete_all_errors}</div>
+            <ActionForm action=action_delete_all>
+                <SubmitButton loading=delete_all_loading label="Log Out Everywhere"/>
+            </ActionForm>
+        </div>
+    }
+}
+
+#[component]
+fn UserSessionListItem(
+    data: UserSession,
+    is_current: bool,
+    action_delete: Action<UserSessionDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let id = data.id;
+    let device = data.device();
+    let created_at = format_datetime(&Some(data.created_at));
+    let last_seen_at = format_datetime(&Some(data.last_seen_at));
+    let ip_address = data.ip_address.unwrap_or_else(|| "Unknown".to_string());
+    let is_impersonation = data.impersonator_id.is_some();
+    view! {
+        <div class="contents">
+            <div class="p-2 border-b">
+                <div>{device}</div>
+                <div class="text-xs text-gray-400 truncate" title=data.user_agent.clone()>
+                    {data.user_agent.clone()}
+                </div>
+                <Show when=move || is_impersonation>
+                    <div class="text-xs text-red-500">"Opened by a superuser impersonating you"</div>
+                </Show>
+            </div>
+            <div class="p-2 text-sm border-b">{ip_address}</div>
+            <div class="p-2 text-sm border-b">{created_at}</div>
+            <div class="p-2 text-sm border-b">{last_seen_at}</div>
+            <div class="p-2 text-right border-b">
+                <Show
+                    when=move || !is_current
+                    fallback=|| view! { <span class="text-sm text-green-700">"This session"</span> }
+                >
+                    <ActionForm action=action_delete>
+                        <input type="hidden" name="id" value=id.to_string()/>
+                        <button type="submit" class="hover:underline">
+                            "Revoke"
+                        </button>
+                    </ActionForm>
+                </Show>
+            </div>
+        </div>
+    }
+}
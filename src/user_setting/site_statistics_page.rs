@@ -111,6 +111,22 @@ pub fn UserStatDetailComponent(data: UserStatistic) -> impl IntoView {
                     <th class="p-2 w-1/2 text-left border">"Muscle Groups Created"</th>
                     <td class="p-2 w-1/2 text-right border">{data.muscle_group_created_count}</td>
                 </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Current Logging Streak"</th>
+                    <td class="p-2 w-1/2 text-right border">{data.current_logging_streak}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Longest Logging Streak"</th>
+                    <td class="p-2 w-1/2 text-right border">{data.longest_logging_streak}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Current Adherence Streak"</th>
+                    <td class="p-2 w-1/2 text-right border">{data.current_adherence_streak}</td>
+                </tr>
+                <tr>
+                    <th class="p-2 w-1/2 text-left border">"Longest Adherence Streak"</th>
+                    <td class="p-2 w-1/2 text-right border">{data.longest_adherence_streak}</td>
+                </tr>
             </tbody>
         </table>
     }
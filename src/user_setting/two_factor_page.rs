@@ -0,0 +1,323 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::button::SubmitButton;
+use crate::component::input::TextInput;
+use crate::component::template::{ErrorComponent, LoadingComponent};
+use crate::two_factor::model::{TwoFactorEnrolment, TwoFactorStatus};
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{
+    auth::{model::User, service::get_request_user},
+    error::Error,
+    setup::get_pool,
+    two_factor::model::{UserRecoveryCode, UserTotp},
+    util::validation_error::ValidationError,
+};
+
+#[cfg(feature = "ssr")]
+async fn verify_request_user_password(password: &str) -> Result<User, ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let user = User::get_by_id(&pool, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    user.verify_password(password)?;
+    Ok(user)
+}
+
+#[cfg(feature = "ssr")]
+fn generate_recovery_codes() -> (Vec<String>, Vec<String>) {
+    let codes = UserRecoveryCode::generate();
+    let hashes = codes
+        .iter()
+        .map(|code| UserRecoveryCode::hash(code))
+        .collect();
+    (codes, hashes)
+}
+
+#[server(endpoint = "two-factor-status")]
+pub async fn get_two_factor_status() -> Result<TwoFactorStatus, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let enabled = UserTotp::get_enabled_by_user_id(&pool, user.id)
+        .await?
+        .is_some();
+    let recovery_codes_remaining = UserRecoveryCode::count_remaining(&pool, user.id).await?;
+    Ok(TwoFactorStatus {
+        enabled,
+        recovery_codes_remaining,
+    })
+}
+
+#[server(endpoint = "two-factor-setup")]
+pub async fn two_factor_setup() -> Result<TwoFactorEnrolment, ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let user = User::get_by_id(&pool, user.id)
+        .await?
+        .ok_or(Error::NotFound)?;
+    let secret = UserTotp::generate_secret();
+    UserTotp::create_pending(&pool, user.id, &secret).await?;
+    let otpauth_uri = UserTotp::otpauth_uri(&secret, &user.email);
+    Ok(TwoFactorEnrolment {
+        secret,
+        otpauth_uri,
+    })
+}
+
+#[server(endpoint = "two-factor-enable")]
+pub async fn two_factor_enable(code: String) -> Result<Vec<String>, ServerFnError> {
+    let user = get_request_user()?;
+    user.ensure_not_impersonated()?;
+    let pool = get_pool()?;
+    let totp = UserTotp::get_by_user_id(&pool, user.id)
+        .await?
+        .filter(|totp| totp.enabled_at.is_none())
+        .ok_or_else(|| Error::Other("Start the two-factor setup again.".into()))?;
+
+    let timestamp = chrono::Utc::now().timestamp();
+    let Some(step) = UserTotp::verify_code(&totp.secret, &code, timestamp, None) else {
+        let mut errors = ValidationError::new();
+        errors.add_error("code", "Invalid authentication code.".into());
+        return Err(Error::Validation(errors).into());
+    };
+
+    let (codes, hashes) = generate_recovery_codes();
+    UserTotp::enable(&pool, totp.id, step, &hashes).await?;
+    Ok(codes)
+}
+
+#[server(endpoint = "two-factor-recovery-codes")]
+pub async fn two_factor_recovery_codes(password: String) -> Result<Vec<String>, ServerFnError> {
+    let user = verify_request_user_password(&password).await?;
+    let pool = get_pool()?;
+    UserTotp::get_enabled_by_user_id(&pool, user.id)
+        .await?
+        .ok_or_else(|| Error::Other("Two-factor authentication is not enabled.".into()))?;
+    let (codes, hashes) = generate_recovery_codes();
+    UserRecoveryCode::regenerate(&pool, user.id, &hashes).await?;
+    Ok(codes)
+}
+
+#[server(endpoint = "two-factor-disable")]
+pub async fn two_factor_disable(password: String) -> Result<(), ServerFnError> {
+    let user = verify_request_user_password(&password).await?;
+    let pool = get_pool()?;
+    UserTotp::delete_by_user_id(&pool, user.id).await?;
+    Ok(())
+}
+
+#[component]
+pub fn TwoFactorPage() -> impl IntoView {
+    let action_setup = Action::<TwoFactorSetup, _>::server();
+    let action_enable = Action::<TwoFactorEnable, _>::server();
+    let action_recovery_codes = Action::<TwoFactorRecoveryCodes, _>::server();
+    let action_disable = Action::<TwoFactorDisable, _>::server();
+
+    let resource = Resource::new(
+        move || {
+            (
+                action_enable.version().get(),
+                action_recovery_codes.version().get(),
+                action_disable.version().get(),
+            )
+        },
+        |_| get_two_factor_status(),
+    );
+
+    let response = move || {
+        resource.and_then(|status| {
+            if status.enabled {
+                view! {
+                    <TwoFactorEnabled
+                        status=status.clone()
+                        action_enable
+                        action_recovery_codes
+                        action_disable
+                    />
+                }
+            } else {
+                view! { <TwoFactorSetupForm action_setup action_enable/> }
+            }
+        })
+    };
+
+    view! {
+        <Title text="Two-Factor Authentication"/>
+        <div class="p-4 bg-white border">
+            <h1 class="mb-2 text-base font-bold">"Two-Factor Authentication"</h1>
+            <p class="mb-4">
+                "Require a code from an authenticator app, in addition to your password, when logging in."
+            </p>
+            <Transition fallback=LoadingComponent>
+                <ErrorBoundary fallback=|errors| {
+                    view! { <ErrorComponent errors/> }
+                }>{response}</ErrorBoundary>
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn TwoFactorSetupForm(
+    action_setup: Action<TwoFactorSetup, Result<TwoFactorEnrolment, ServerFnError>>,
+    action_enable: Action<TwoFactorEnable, Result<Vec<String>, ServerFnError>>,
+) -> impl IntoView {
+    let setup_loading = action_setup.pending();
+    let setup_value = action_setup.value();
+    let setup_errors = move || get_non_field_errors(setup_value);
+
+    let enable_loading = action_enable.pending();
+    let enable_value = action_enable.value();
+    let enable_error = move || extract_other_errors(enable_value, &["non_field_errors", "code"]);
+    let enable_non_field_errors = move || get_non_field_errors(enable_value);
+
+    let setup = move || {
+        setup_value.with(|value| {
+            value
+                .as_ref()
+                .and_then(|result| result.as_ref().ok())
+                .cloned()
+        })
+    };
+
+    view! {
+        <div class="max-w-md">
+            <div class="mb-4 text-red-500 font-bold">{setup_errors}</div>
+            <Show
+                when=move || setup().is_some()
+                fallback=move || {
+                    view! {
+                        <ActionForm action=action_setup>
+                            <SubmitButton loading=setup_loading label="Set Up Two-Factor Authentication"/>
+                        </ActionForm>
+                    }
+                }
+            >
+                {move || {
+                    setup()
+                        .map(|enrolment| {
+                            view! {
+                                <p class="mb-2">
+                                    "Add this account to your authenticator app using the link below, or by entering the secret key manually."
+                                </p>
+                                <p class="mb-2">
+                                    <a
+                                        href=enrolment.otpauth_uri.clone()
+                                        rel="external"
+                                        class="text-blue-500 hover:underline break-all"
+                                    >
+                                        {enrolment.otpauth_uri.clone()}
+                                    </a>
+                                </p>
+                                <code class="block p-2 mb-4 break-all bg-gray-50 border">
+                                    {enrolment.secret}
+                                </code>
+                            }
+                        })
+                }}
+                <div class="mb-4 text-red-500 font-bold">{enable_error}</div>
+                <div class="mb-4 text-red-500 font-bold">{enable_non_field_errors}</div>
+                <ActionForm action=action_enable>
+                    <TextInput
+                        action_value=enable_value
+                        name="code"
+                        label="Authentication code"
+                        autocomplete="one-time-code"
+                        placeholder="Enter the code from your app"
+                    />
+                    <SubmitButton loading=enable_loading label="Enable"/>
+                </ActionForm>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn TwoFactorEnabled(
+    status: TwoFactorStatus,
+    action_enable: Action<TwoFactorEnable, Result<Vec<String>, ServerFnError>>,
+    action_recovery_codes: Action<TwoFactorRecoveryCodes, Result<Vec<String>, ServerFnError>>,
+    action_disable: Action<TwoFactorDisable, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let codes_loading = action_recovery_codes.pending();
+    let codes_value = action_recovery_codes.value();
+    let codes_error = move || extract_other_errors(codes_value, &["non_field_errors", "password"]);
+    let codes_non_field_errors = move || get_non_field_errors(codes_value);
+
+    let disable_loading = action_disable.pending();
+    let disable_value = action_disable.value();
+    let disable_error =
+        move || extract_other_errors(disable_value, &["non_field_errors", "password"]);
+    let disable_non_field_errors = move || get_non_field_errors(disable_value);
+
+    let new_codes = move || {
+        let codes = codes_value
+            .get()
+            .or_else(|| action_enable.value().get())
+            .and_then(|result| result.ok())?;
+        Some(view! { <RecoveryCodeList codes/> })
+    };
+
+    view! {
+        <div class="max-w-md">
+            <p class="mb-4 font-bold text-green-700">"Two-factor authentication is enabled."</p>
+            {new_codes}
+            <p class="mb-4">
+                {status.recovery_codes_remaining} " unused recovery codes remaining."
+            </p>
+
+            <h2 class="mb-2 font-bold">"Recovery Codes"</h2>
+            <p class="mb-2">
+                "Generating new recovery codes replaces any you have not used."
+            </p>
+            <div class="mb-4 text-red-500 font-bold">{codes_error}</div>
+            <div class="mb-4 text-red-500 font-bold">{codes_non_field_errors}</div>
+            <ActionForm action=action_recovery_codes>
+                <TextInput
+                    action_value=codes_value
+                    name="password"
+                    input_type="password"
+                    autocomplete="current-password"
+                    placeholder="Enter your password"
+                />
+                <SubmitButton loading=codes_loading label="Generate New Codes"/>
+            </ActionForm>
+
+            <h2 class="mt-4 mb-2 font-bold">"Disable"</h2>
+            <div class="mb-4 text-red-500 font-bold">{disable_error}</div>
+            <div class="mb-4 text-red-500 font-bold">{disable_non_field_errors}</div>
+            <ActionForm action=action_disable>
+                <TextInput
+                    action_value=disable_value
+                    name="password"
+                    input_type="password"
+                    autocomplete="current-password"
+                    placeholder="Enter your password"
+                />
+                <SubmitButton loading=disable_loading label="Disable Two-Factor Authentication"/>
+            </ActionForm>
+        </div>
+    }
+}
+
+#[component]
+fn RecoveryCodeList(codes: Vec<String>) -> impl IntoView {
+    let codes = codes
+        .into_iter()
+        .map(|code| view! { <li class="font-mono">{code}</li> })
+        .collect_view();
+    view! {
+        <div class="p-4 mb-4 bg-amber-50 border border-amber-300">
+            <p class="mb-2 font-bold">
+                "Save these recovery codes somewhere safe. Each can be used once to log in if you lose access to your authenticator app. They will not be shown again."
+            </p>
+            <ul class="grid grid-cols-2 gap-1">{codes}</ul>
+        </div>
+    }
+}
@@ -39,7 +39,7 @@ pub async fn create_user_block(username: String) -> Result<(), ServerFnError> {
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    let results = UserBlock::create(&pool, user.id, target_user.id, 1).await?;
+    let results = UserBlock::create(&pool, user.id, target_user.id, 1, user.actor_id()).await?;
     if results == 0 {
         return Err(ServerFnError::new("Error blocking user"))?;
     }
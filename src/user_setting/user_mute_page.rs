@@ -0,0 +1,159 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+
+use crate::component::button::{Button, SubmitButton};
+use crate::component::icon::IconUserMinus;
+use crate::component::input::TextInput;
+use crate::component::template::{ErrorComponent, ListLoadingComponent, ListNotFoundComponent};
+use crate::user_mute::model::UserMute;
+use crate::util::datetime::format_datetime;
+use crate::util::validation_error::{extract_other_errors, get_non_field_errors};
+
+#[cfg(feature = "ssr")]
+use crate::{auth::service::get_request_user, setup::get_pool};
+
+#[server(endpoint = "user-mute-list")]
+pub async fn get_user_mute_list() -> Result<Vec<UserMute>, ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    let query = UserMute::all_by_muter_id(&pool, user.id).await?;
+    Ok(query)
+}
+
+#[server(endpoint = "user-mute-create")]
+pub async fn user_mute_create(username: String) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    if user.username == username {
+        return Err(ServerFnError::new("You cannot mute yourself"));
+    }
+    if UserMute::create(&pool, user.id, &username).await? == 0 {
+        return Err(ServerFnError::new("User not found or is already muted"));
+    }
+    Ok(())
+}
+
+#[server(endpoint = "user-mute-delete")]
+pub async fn user_mute_delete(username: String) -> Result<(), ServerFnError> {
+    let user = get_request_user()?;
+    let pool = get_pool()?;
+    if UserMute::delete(&pool, user.id, &username).await? == 0 {
+        return Err(ServerFnError::new("User is not muted"));
+    }
+    Ok(())
+}
+
+#[component]
+pub fn UserMuteListPage() -> impl IntoView {
+    let action_create = Action::<UserMuteCreate, _>::server();
+    let action_delete = Action::<UserMuteDelete, _>::server();
+
+    let resource = Resource::new(
+        move || (action_create.version().get(), action_delete.version().get()),
+        |_| get_user_mute_list(),
+    );
+
+    let response = move || {
+        resource.and_then(|data| {
+            if data.is_empty() {
+                view! { <ListNotFoundComponent/> }
+            } else {
+                data.iter()
+                    .map(
+                        |data| view! { <UserMuteListItem data=data.clone() action=action_delete/> },
+                    )
+                    .collect_view()
+            }
+        })
+    };
+
+    let action_value = action_delete.value();
+    let action_error = move || extract_other_errors(action_value, &["username"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+
+    view! {
+        <Title text="Muted Users"/>
+        <div class="grid grid-cols-4 gap-4 md:grid-cols-12">
+            <div class="col-span-4 md:col-span-8">
+                <div class="p-4 bg-white border shadow-sm">
+                    <header class="mb-4">
+                        <h2 class="mb-2 text-base font-bold">"Muted Users"</h2>
+                        <p>
+                            "Muted users are left out of your feed and notifications. They are not told, and can still follow you."
+                        </p>
+                    </header>
+                    <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+                    <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+                    <Transition fallback=ListLoadingComponent>
+                        <ErrorBoundary fallback=|errors| {
+                            view! { <ErrorComponent errors/> }
+                        }>{response}</ErrorBoundary>
+                    </Transition>
+                </div>
+            </div>
+            <div class="col-span-4">
+                <div class="p-4 bg-white border shadow-sm">
+                    <header class="mb-4">
+                        <h2 class="mb-2 text-base font-bold">"Mute User"</h2>
+                    </header>
+                    <div class="max-w-md">
+                        <UserMuteCreateForm action=action_create/>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn UserMuteListItem(
+    data: UserMute,
+    action: Action<UserMuteDelete, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let created_at = format_datetime(&Some(data.created_at));
+    let href = format!("/users/{}", data.muted_username);
+    let label = data.muted_username.clone();
+    let username = data.muted_username;
+    view! {
+        <div class="flex items-start p-2 mb-4 bg-gray-100">
+            <div class="flex-1">
+                <div>
+                    <A href=href class="text-blue-500 hover:underline">
+                        {label}
+                    </A>
+                </div>
+                <div class="text-xs text-gray-500">{created_at}</div>
+            </div>
+            <ActionForm action class="contents">
+                <input type="hidden" name="username" value=username/>
+                <Button label="Unmute">
+                    <IconUserMinus/>
+                </Button>
+            </ActionForm>
+        </div>
+    }
+}
+
+#[component]
+pub fn UserMuteCreateForm(
+    action: Action<UserMuteCreate, Result<(), ServerFnError>>,
+) -> impl IntoView {
+    let action_loading = action.pending();
+    let action_value = action.value();
+    let action_error = move || extract_other_errors(action_value, &["username"]);
+    let non_field_errors = move || get_non_field_errors(action_value);
+    view! {
+        <div class="mb-4 text-red-500 font-bold">{action_error}</div>
+        <div class="mb-4 text-red-500 font-bold">{non_field_errors}</div>
+        <ActionForm action class="contents">
+            <TextInput
+                action_value
+                name="username"
+                label="Username"
+                placeholder="Enter the username of who you wish to mute"
+            />
+            <SubmitButton loading=action_loading label="Mute User"/>
+        </ActionForm>
+    }
+}
@@ -25,4 +25,8 @@ pub struct UserStatistic {
     pub meal_of_day_created_count: i64,
     pub movement_created_count: i64,
     pub muscle_group_created_count: i64,
+    pub current_logging_streak: i64,
+    pub longest_logging_streak: i64,
+    pub current_adherence_streak: i64,
+    pub longest_adherence_streak: i64,
 }
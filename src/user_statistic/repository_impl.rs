@@ -1,14 +1,20 @@
 use sqlx::PgPool;
 
+use crate::adherence::model::DEFAULT_TOLERANCE_PCT;
 use crate::error::Result;
 use crate::util::database::Filter;
 
 use super::model::UserStatistic;
 impl UserStatistic {
     pub async fn get_by_username(pool: &PgPool, username: &str) -> Result<Option<Self>> {
-        let query = sqlx::query_file_as!(Self, "sql/user_statistic_by_username.sql", username)
-            .fetch_optional(pool)
-            .await?;
+        let query = sqlx::query_file_as!(
+            Self,
+            "sql/user_statistic_by_username.sql",
+            username,
+            DEFAULT_TOLERANCE_PCT,
+        )
+        .fetch_optional(pool)
+        .await?;
         Ok(query)
     }
 
@@ -58,6 +64,14 @@ impl UserStatistic {
             "-movement_created_count" => "movement_created_count DESC",
             "muscle_group_created_count" => "muscle_group_created_count",
             "-muscle_group_created_count" => "muscle_group_created_count DESC",
+            "current_logging_streak" => "current_logging_streak",
+            "-current_logging_streak" => "current_logging_streak DESC",
+            "longest_logging_streak" => "longest_logging_streak",
+            "-longest_logging_streak" => "longest_logging_streak DESC",
+            "current_adherence_streak" => "current_adherence_streak",
+            "-current_adherence_streak" => "current_adherence_streak DESC",
+            "longest_adherence_streak" => "longest_adherence_streak",
+            "-longest_adherence_streak" => "longest_adherence_streak DESC",
             _ => "username",
         };
         let mut qb = sqlx::QueryBuilder::new(
@@ -83,7 +97,11 @@ impl UserStatistic {
                 COALESCE(meal_food_count.meal_food_created_count, 0) AS meal_food_created_count,
                 COALESCE(exercise_count.exercise_count, 0) AS exercise_count,
                 COALESCE(set_count.set_count, 0) AS set_count,
-                COALESCE(rep_count.rep_count, 0) AS rep_count
+                COALESCE(rep_count.rep_count, 0) AS rep_count,
+                COALESCE(streak.current_logging_streak, 0) AS current_logging_streak,
+                COALESCE(streak.longest_logging_streak, 0) AS longest_logging_streak,
+                COALESCE(streak.current_adherence_streak, 0) AS current_adherence_streak,
+                COALESCE(streak.longest_adherence_streak, 0) AS longest_adherence_streak
             FROM
                 users_user t1
                 LEFT JOIN user_profile t2 ON t2.user_id = t1.id
@@ -183,10 +201,11 @@ impl UserStatistic {
                     JOIN workout r3 ON r3.id = r2.workout_id
                     GROUP BY r3.user_id
                 ) rep_count ON rep_count.user_id = t1.id
-            WHERE
-                TRUE
+                CROSS JOIN LATERAL diet_streak (t1.id,
             ",
         );
+        qb.push_bind(DEFAULT_TOLERANCE_PCT);
+        qb.push(") streak WHERE TRUE");
         qb.filter("t1.username", "ilike", search);
 
         qb.push(" ORDER BY ");
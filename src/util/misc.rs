@@ -1,7 +1,15 @@
 use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use crate::food::model::FoodQuery;
 // use wasm_bindgen::JsCast;
 // use web_sys::{Event, TouchEvent};
 
+#[cfg_attr(
+    feature = "ssr",
+    derive(utoipa::ToSchema),
+    aliases(FoodListResponse = ListResponse<FoodQuery>)
+)]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ListResponse<T> {
     pub count: i64,
@@ -3,6 +3,8 @@ pub mod database;
 pub mod datetime;
 pub mod misc;
 pub mod param;
+#[cfg(all(test, feature = "ssr"))]
+pub mod test_fixture;
 #[cfg(feature = "ssr")]
 pub mod server;
 pub mod text;
@@ -0,0 +1,165 @@
+//! Rows shared by the database tests, which run against a fresh database per test with the
+//! migrations applied.
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn create_user(pool: &PgPool, username: &str) -> Uuid {
+    sqlx::query_scalar(
+        "
+        INSERT INTO
+            users_user (name, username, email, password, is_active)
+        VALUES
+            ($1, $1, $1 || '@example.com', 'password', TRUE)
+        RETURNING
+            id
+        ",
+    )
+    .bind(username)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+/// A food where one unit has 100 kcal, 10g protein, 20g carbohydrate and 5g fat.
+pub async fn create_food(pool: &PgPool, created_by_id: Uuid) -> Uuid {
+    sqlx::query_scalar(
+        "
+        WITH
+            brand AS (
+                INSERT INTO
+                    food_brand (name, slug, created_by_id)
+                VALUES
+                    ('Brand', 'brand', $1)
+                RETURNING
+                    id
+            )
+        INSERT INTO
+            food (
+                name,
+                slug,
+                brand_id,
+                data_value,
+                data_measurement,
+                energy,
+                fat,
+                saturates,
+                carbohydrate,
+                sugars,
+                fibre,
+                protein,
+                salt,
+                created_by_id
+            )
+        SELECT
+            'Oats',
+            'oats',
+            id,
+            1,
+            'srv',
+            100,
+            5,
+            0,
+            20,
+            0,
+            0,
+            10,
+            0,
+            $1
+        FROM
+            brand
+        RETURNING
+            id
+        ",
+    )
+    .bind(created_by_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+pub async fn create_food_log(
+    pool: &PgPool,
+    user_id: Uuid,
+    food_id: Uuid,
+    date: NaiveDate,
+    quantity: i64,
+) -> Uuid {
+    sqlx::query_scalar(
+        "
+        WITH
+            meal_of_day AS (
+                INSERT INTO
+                    meal_of_day (name, slug, ordering, created_by_id)
+                VALUES
+                    ('Breakfast', 'breakfast', 1, $1)
+                ON CONFLICT (ordering) DO UPDATE
+                SET
+                    ordering = EXCLUDED.ordering
+                RETURNING
+                    id
+            )
+        INSERT INTO
+            food_log (user_id, food_id, date, meal_of_day_id, quantity, created_by_id)
+        SELECT
+            $1,
+            $2,
+            $3,
+            id,
+            $4,
+            $1
+        FROM
+            meal_of_day
+        RETURNING
+            id
+        ",
+    )
+    .bind(user_id)
+    .bind(food_id)
+    .bind(date)
+    .bind(Decimal::from(quantity))
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+/// A daily target of 2000 kcal, 200g protein, 400g carbohydrate and 100g fat from 2000-01-01,
+/// which 20 units of the food from `create_food` meet exactly.
+pub async fn create_diet_target(pool: &PgPool, user_id: Uuid) {
+    sqlx::query(
+        "
+        INSERT INTO
+            diet_target (
+                user_id,
+                date,
+                weight,
+                energy,
+                fat,
+                saturates,
+                carbohydrate,
+                sugars,
+                fibre,
+                protein,
+                salt,
+                created_by_id
+            )
+        VALUES
+            ($1, '2000-01-01', 80, 2000, 100, 0, 400, 0, 0, 200, 0, $1)
+        ",
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+/// An accepted follow of `user_id` by `follower_id`.
+pub async fn create_follower(pool: &PgPool, user_id: Uuid, follower_id: Uuid) {
+    sqlx::query("INSERT INTO user_follower (user_id, follower_id, status) VALUES ($1, $2, 1)")
+        .bind(user_id)
+        .bind(follower_id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
@@ -47,6 +47,18 @@ impl ValidationError {
     }
 }
 
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut fields: Vec<_> = self.field_errors.iter().collect();
+        fields.sort_by_key(|(field, _)| field.as_str());
+        let messages: Vec<String> = fields
+            .into_iter()
+            .map(|(field, errors)| format!("{}: {}", field, errors.join(", ")))
+            .collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
 impl From<ValidationError> for ServerFnError {
     fn from(error: ValidationError) -> Self {
         #[cfg(feature = "ssr")]
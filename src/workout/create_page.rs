@@ -20,8 +20,8 @@ pub async fn workout_create(username: String, date: NaiveDate) -> Result<(), Ser
     let target_user = User::get_by_username(&pool, &username)
         .await?
         .ok_or(Error::NotFound)?;
-    WorkoutBase::can_create(&target_user, &user).await?;
-    WorkoutBase::create(&pool, target_user.id, date, user.id).await?;
+    WorkoutBase::can_create(&pool, &target_user, &user).await?;
+    WorkoutBase::create(&pool, target_user.id, date, user.actor_id()).await?;
     Ok(())
 }
 
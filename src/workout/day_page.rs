@@ -16,6 +16,8 @@ use crate::exercise::delete_page::{ExerciseDelete, ExerciseDeleteForm};
 use crate::set::create_form::{SetCreate, SetCreateForm};
 use crate::set::delete_page::{SetDelete, SetDeleteForm};
 use crate::set::update_page::{SetRowUpdateForm, SetUpdate};
+use crate::social::component::SocialCountBadges;
+use crate::social::target::SocialTarget;
 use crate::util::param::{get_date, get_username};
 
 use super::create_page::WorkoutCreate;
@@ -24,7 +26,10 @@ use super::model::{WorkoutDayExerciseQuery, WorkoutDayQuery, WorkoutDaySetQuery}
 use super::week_navigation::WorkoutWeekNavComponent;
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server(endpoint = "user-workout-day")]
 pub async fn get_workout_day(
@@ -33,7 +38,7 @@ pub async fn get_workout_day(
 ) -> Result<Vec<WorkoutDayQuery>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Workout).await?;
     let query = WorkoutDayQuery::all_by_username_date(&pool, &username, date).await?;
     Ok(query)
 }
@@ -128,6 +133,9 @@ pub fn WorkoutListItemComponent(data: WorkoutDayQuery) -> impl IntoView {
     let set_count = data.set_count;
     let rep_count = data.rep_count;
     let workout_id = data.workout_id.to_string();
+    let social_target =
+        SocialTarget::workout(data.username.clone(), data.workout_date, data.workout_id);
+    let social_href = detail_url.clone();
 
     view! {
         <header class="flex flex-wrap gap-4 items-start p-2 mb-1 bg-gray-300">
@@ -140,6 +148,7 @@ pub fn WorkoutListItemComponent(data: WorkoutDayQuery) -> impl IntoView {
                 <Badge label="exercises" value=exercise_count/>
                 <Badge label="sets" value=set_count/>
                 <Badge label="reps" value=rep_count/>
+                <SocialCountBadges target=social_target href=social_href/>
             </section>
 
             <section class="flex gap-x-2">
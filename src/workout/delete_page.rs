@@ -26,8 +26,8 @@ pub async fn workout_delete(
     let workout = WorkoutBase::get_by_id(&pool, workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_delete(&user).await?;
-    WorkoutBase::delete(&pool, workout_id).await?;
+    workout.can_delete(&pool, &user).await?;
+    WorkoutBase::delete(&pool, workout_id, user.actor_id()).await?;
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
     }
@@ -6,13 +6,21 @@ use uuid::Uuid;
 use crate::component::template::{
     DetailPageTemplate, ErrorComponent, LoadingComponent, UpdateDeleteButtonRow,
 };
+use crate::shared_template::component::SharedTemplatePanel;
+use crate::shared_template::kind::TemplateKind;
+use crate::social::component::SocialPanel;
+use crate::social::target::SocialTarget;
 use crate::util::datetime::format_datetime;
+use crate::util::param::{get_date, get_username};
 
 use super::model::WorkoutBase;
 use super::router::WorkoutDetailParam;
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, error::Error, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server(endpoint = "workout-detail")]
 pub async fn get_workout_detail(workout_id: Uuid) -> Result<WorkoutBase, ServerFnError> {
@@ -21,7 +29,8 @@ pub async fn get_workout_detail(workout_id: Uuid) -> Result<WorkoutBase, ServerF
     let workout = WorkoutBase::get_by_id(&pool, workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    User::check_view_permission_by_user_id(&pool, &user, workout.user_id).await?;
+    User::check_view_permission_by_user_id(&pool, &user, workout.user_id, PrivacySection::Workout)
+        .await?;
     Ok(workout)
 }
 
@@ -33,6 +42,14 @@ pub fn WorkoutDetailPage() -> impl IntoView {
     let resource = Resource::new(workout_id, get_workout_detail);
     let response =
         move || resource.and_then(|data| view! { <WorkoutDetailComponent data=data.clone()/> });
+    let params_map = use_params_map();
+    let social_target = Signal::derive(move || {
+        SocialTarget::workout(
+            get_username(&params_map),
+            get_date(&params_map),
+            workout_id(),
+        )
+    });
     view! {
         <DetailPageTemplate title="Workout">
             <Transition fallback=LoadingComponent>
@@ -40,6 +57,7 @@ pub fn WorkoutDetailPage() -> impl IntoView {
                     view! { <ErrorComponent errors/> }
                 }>{response}</ErrorBoundary>
             </Transition>
+            <SocialPanel target=social_target/>
         </DetailPageTemplate>
     }
 }
@@ -48,6 +66,7 @@ pub fn WorkoutDetailPage() -> impl IntoView {
 pub fn WorkoutDetailComponent(data: WorkoutBase) -> impl IntoView {
     let created_at = format_datetime(&Some(data.created_at));
     let updated_at = format_datetime(&data.updated_at);
+    let template_name = format!("Workout {}", data.date);
 
     view! {
         <header class="mb-4">
@@ -77,5 +96,6 @@ pub fn WorkoutDetailComponent(data: WorkoutBase) -> impl IntoView {
             </tbody>
         </table>
         <UpdateDeleteButtonRow/>
+        <SharedTemplatePanel kind=TemplateKind::Workout source_id=data.id name=template_name/>
     }
 }
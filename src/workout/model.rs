@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::exercise::model::ExerciseQuery;
 use crate::util::datetime::DATE_FORMAT_SHORT;
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WorkoutBase {
     pub id: Uuid,
@@ -48,6 +49,7 @@ pub struct WorkoutQuery {
     pub exercises: Vec<ExerciseQuery>,
 }
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct WorkoutDayQuery {
     pub user_id: Uuid,
@@ -61,6 +63,7 @@ pub struct WorkoutDayQuery {
     pub exercises: Vec<WorkoutDayExerciseQuery>,
 }
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct WorkoutDayExerciseQuery {
     pub exercise_id: Uuid,
@@ -72,6 +75,7 @@ pub struct WorkoutDayExerciseQuery {
     pub sets: Vec<WorkoutDaySetQuery>,
 }
 
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct WorkoutDaySetQuery {
     pub set_id: Uuid,
@@ -20,8 +20,8 @@ use crate::util::param::{extract_page, extract_param, extract_size, get_date, ge
 #[cfg(feature = "ssr")]
 use crate::{
     auth::model::User, auth::service::get_request_user, error::Error,
-    exercise::model::ExerciseBase, movement::model::MovementQuery, set::model::SetModel,
-    setup::get_pool, workout::model::WorkoutBase,
+    exercise::model::ExerciseBase, movement::model::MovementQuery, notification::service,
+    set::model::SetModel, setup::get_pool, workout::model::WorkoutBase,
 };
 
 #[server]
@@ -45,11 +45,21 @@ pub async fn workout_exercise_set_create(
         .await?
         .ok_or(Error::NotFound)?;
 
-    WorkoutBase::can_create(&target_user, &user).await?;
-    let workout = WorkoutBase::create(&pool, target_user.id, date, user.id).await?;
-    let exercise = ExerciseBase::create(&pool, workout.id, movement_id, user.id).await?;
+    WorkoutBase::can_create(&pool, &target_user, &user).await?;
+    let workout = WorkoutBase::create(&pool, target_user.id, date, user.actor_id()).await?;
+    let exercise = ExerciseBase::create(&pool, workout.id, movement_id, 1, user.actor_id()).await?;
 
-    SetModel::bulk_create(&pool, exercise.id, weight, reps, rest, set_count, user.id).await?;
+    SetModel::bulk_create(
+        &pool,
+        exercise.id,
+        weight,
+        reps,
+        rest,
+        set_count,
+        user.actor_id(),
+    )
+    .await?;
+    service::notify_personal_record(&pool, exercise.id).await;
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
     }
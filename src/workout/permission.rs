@@ -1,5 +1,8 @@
+use sqlx::PgPool;
+
 use super::model::WorkoutBase;
 use crate::auth::model::{RequestUser, User};
+use crate::coach::model::CoachClient;
 use crate::error::{Error, Result};
 
 impl WorkoutBase {
@@ -11,28 +14,15 @@ impl WorkoutBase {
         }
     }
 
-    pub async fn can_create(target_user: &User, user: &RequestUser) -> Result<()> {
-        let _target = target_user;
-        if user.is_active {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_create(pool: &PgPool, target_user: &User, user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, user, target_user.id).await
     }
 
-    pub async fn can_update(&self, user: &RequestUser) -> Result<()> {
-        if user.is_active {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_update(&self, pool: &PgPool, user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, user, self.user_id).await
     }
 
-    pub async fn can_delete(&self, user: &RequestUser) -> Result<()> {
-        if user.is_active {
-            Ok(())
-        } else {
-            Err(Error::Forbidden)
-        }
+    pub async fn can_delete(&self, pool: &PgPool, user: &RequestUser) -> Result<()> {
+        CoachClient::authorize(pool, user, self.user_id).await
     }
 }
@@ -29,9 +29,9 @@ pub async fn workout_update(
     let workout = WorkoutBase::get_by_id(&pool, workout_id)
         .await?
         .ok_or(Error::NotFound)?;
-    workout.can_update(&user).await?;
+    workout.can_update(&pool, &user).await?;
 
-    WorkoutBase::update(&pool, workout.id, date, user.id).await?;
+    WorkoutBase::update(&pool, workout.id, date, user.actor_id()).await?;
 
     if let Some(redirect_to) = redirect_to {
         leptos_axum::redirect(&redirect_to);
@@ -13,7 +13,10 @@ use crate::util::param::{get_date, get_username};
 use crate::workout::model::WorkoutQuery;
 
 #[cfg(feature = "ssr")]
-use crate::{auth::model::User, auth::service::get_request_user, setup::get_pool};
+use crate::{
+    auth::model::User, auth::service::get_request_user, setup::get_pool,
+    user_privacy::section::PrivacySection,
+};
 
 #[server]
 pub async fn get_workout_week(
@@ -22,7 +25,7 @@ pub async fn get_workout_week(
 ) -> Result<Vec<WorkoutWeek>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Workout).await?;
     let query = WorkoutWeek::all(&pool, &username, date).await?;
     Ok(query)
 }
@@ -13,6 +13,7 @@ use crate::{
     auth::model::User,
     auth::service::get_request_user,
     setup::get_pool,
+    user_privacy::section::PrivacySection,
     util::datetime::{get_week_end, get_week_start},
 };
 
@@ -23,7 +24,7 @@ pub async fn get_workout_week_nav(
 ) -> Result<Vec<WorkoutDaySummary>, ServerFnError> {
     let user = get_request_user()?;
     let pool = get_pool()?;
-    User::check_view_permission(&pool, &user, &username).await?;
+    User::check_view_permission(&pool, &user, &username, PrivacySection::Workout).await?;
     let start = get_week_start(date);
     let end = get_week_end(date);
     let query =